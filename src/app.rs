@@ -1,143 +1,481 @@
 use std::{
-    collections::HashMap,
-    sync::mpsc::{self, Receiver, Sender},
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime},
+};
+
+use tokio::{
+    runtime::Runtime,
+    sync::{
+        mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+        watch,
+    },
 };
 
+use chrono::{DateTime, Local};
 use ratatui::{
     crossterm::{
-        event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-        terminal::{disable_raw_mode, enable_raw_mode},
+        event::{
+            self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+            Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+        },
+        execute,
     },
-    init,
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Position, Rect},
+    prelude::Backend,
     restore,
     style::{Color, Style},
     symbols::border,
     text::{Line, Span},
+    try_init,
     widgets::{Block, ListState, Paragraph},
-    DefaultTerminal, Frame,
+    DefaultTerminal, Frame, Terminal,
 };
 use sysinfo::Signal;
 
 use crate::{
     components::{
-        network::draw_network_info, process::draw_process_info,
-        theme::get_and_return_app_color_info,
+        compact::draw_compact_view,
+        cpu::{draw_cpu_info, is_cpu_topology_detected},
+        disk::{draw_disk_info, draw_empty_disk_info},
+        memory::draw_memory_info,
+        network::{draw_empty_network_info, draw_network_info},
+        plugin::draw_plugin_panels,
+        process::{draw_process_info, ProcessView},
+        status_bar::draw_status_bar,
+        system::{draw_system_info_header, draw_system_summary_line},
+        theme::{
+            get_all_theme_names, get_app_color_info_from_theme_name, get_config_directory,
+            get_disk_free_thresholds_config, get_graph_axis_config, get_graph_style_config,
+            get_memory_unit_config, get_mouse_capture_config, get_network_unit_config,
+            get_ping_target_config, get_public_ip_lookup_config, get_refresh_tick_config,
+            get_script_config, get_status_bar_config, get_summary_line_config,
+            get_system_appearance, get_tabs_config, get_theme_config_modified_time,
+            read_theme_config, save_custom_theme, save_tabs_config, set_theme, AUTO_THEME,
+            MAX_REFRESH_TICK, MAX_TAB_COUNT, MIN_REFRESH_TICK, SYSTEM_THEME,
+        },
+        themes::presentation::PRESENTATION,
     },
-    cpu::draw_cpu_info,
-    disk::draw_disk_info,
-    get_sys_info::{spawn_process_info_collector, spawn_system_info_collector},
-    memory::draw_memory_info,
+    get_sys_info::{get_static_system_info, MetricsSource, SysinfoMetricsSource},
+    plugins::{get_plugins, spawn_plugin_collectors, WidgetPlugin},
+    scripting::ScriptEngine,
     types::{
-        AppColorInfo, AppPopUpType, AppState, CProcessesInfo, CSysInfo,
-        CurrentProcessSignalStateData, MemoryData, ProcessData, ProcessSortType, ProcessesInfo,
-        SelectedContainer, SysInfo,
+        get_maximum_data_collection, get_maximum_process_data_collection, AppColorInfo,
+        AppPopUpType, AppState, CProcessesInfo, CSysInfo, ContainerLayout,
+        CurrentProcessSignalStateData, DiskFreeThresholds, GraphStyle, LayoutTab, MemoryUnit,
+        NetworkUnit, PingData, PingResult, ProcessData, ProcessSortType, ProcessesInfo,
+        SelectedContainer, ShownSystemInfo, SignalExt, SmartInfo, StoragePool, SysInfo,
+        ThemeAppearance, ThemeConfig, ThemeWizardState, ThreadData, APP_COLOR_INFO_FIELDS,
     },
     utils::{
-        get_signal_from_int, process_processes_info, process_sys_info, render_pop_up_menu,
-        send_signal,
+        copy_to_clipboard, get_aggregate_network, get_child_pids, get_container_area,
+        get_container_areas, get_descendant_pids, get_ordered_disks, get_signal_from_int,
+        get_signal_id_from_name, process_processes_info, process_sys_info, render_go_to_pid_pop_up,
+        render_pop_up_menu, render_theme_menu_pop_up, render_theme_wizard_pop_up, render_toast,
+        render_user_menu_pop_up, send_signal, send_signal_to_pids, MAX_SIGNAL_ID,
     },
 };
 
-// this need to be the same as MAXIMUM_DATA_COLLECTION in types.rs
-const MAX_GRAPH_SHOWN_RANGE: usize = 500;
+// the height of the plugins container when the layout doesn't place it
+const PLUGINS_CONTAINER_HEIGHT: u16 = 8;
+
+// how many points will the graphs be panned with { and }
+const GRAPH_PAN_STEP: usize = 10;
+
+// when the program start, we let the info collector to collect at 100ms
+// only after the initial collection, we reset to the user selected tick
+const INITIAL_COLLECT_TICK: u32 = 100;
 
 struct App {
-    is_quit: bool,                          // to indicate is user wanted to quit the app
+    is_quit: bool,                   // to indicate is user wanted to quit the app
     tick: u32, // refresh rate for the metrics ( default is 1000ms, customizable by user )
-    tx: Sender<CSysInfo>, // this will be pass to another thread that will be spawn for collecting metrics to send the data collected back
-    rx: Receiver<CSysInfo>, // this will be in the main app to receive the data info send back
-    process_tx: Sender<CProcessesInfo>, // this will be pass to another thread that will be spawn for collecting process metrics to send the data collected back
-    process_rx: Receiver<CProcessesInfo>, // this will be in the main app to receive the process data info send back
-    tick_tx: Sender<u32>, // this will be for sending the updated tick to the thread spawn to update the frequency of collecting data
-    process_tick_tx: Sender<u32>, // this will be for sending the updated tick to the thread spawn to update the frequency of collecting process data
-    sys_info: SysInfo,            // the system info collected
-    process_info: ProcessesInfo,  // the system process info collected
-    selected_container: SelectedContainer, // current selected container in the UI
-    state: AppState,              // current state of the app
-    pop_up_type: AppPopUpType,    // current pop up type
-    cpu_graph_shown_range: usize, // range of graph shown for CPU
-    memory_graph_shown_range: usize, // range of graph shown for MEMORY
-    disk_graph_shown_range: usize, // range of graph shown for DISK
-    network_graph_shown_range: usize, // range of graph shown for NETWORK
+    process_tick: u32, // refresh rate for the processes, adjusted separately when the process container is selected
+    tx: UnboundedSender<CSysInfo>, // this will be pass to the collector task for sending the collected metrics
+    rx: UnboundedReceiver<CSysInfo>, // this will be in the main app to receive the data info, it was drained into the queue every iteration
+    process_tx: UnboundedSender<CProcessesInfo>, // this will be pass to the collector task for sending the collected process metrics
+    process_rx: UnboundedReceiver<CProcessesInfo>, // this will be in the main app to receive the process changes, none of them can be missed
+    tick_tx: watch::Sender<u32>, // this will be for updating the tick watched by the collector task to update the frequency of collecting data
+    process_tick_tx: watch::Sender<u32>, // this will be for updating the tick watched by the collector task to update the frequency of collecting process data
+    smart_tx: Sender<HashMap<String, SmartInfo>>, // this will be pass to the task spawn for collecting the SMART health of the disks
+    smart_rx: Receiver<HashMap<String, SmartInfo>>, // this will be in the main app to receive the SMART health send back
+    smart_info: HashMap<String, SmartInfo>, // the latest SMART health, keyed by the mount point of the disk
+    storage_pool_tx: Sender<Vec<StoragePool>>, // this will be pass to the task spawn for collecting the btrfs and ZFS pools
+    storage_pool_rx: Receiver<Vec<StoragePool>>, // this will be in the main app to receive the btrfs and ZFS pools send back
+    storage_pools: Vec<StoragePool>,             // the latest btrfs and ZFS pools
+    ping_target: Option<String>, // the host to ping continuously, None if the ping wasn't enabled in the settings
+    ping_tx: Sender<PingResult>, // this will be pass to the task spawn for pinging the target
+    ping_rx: Receiver<PingResult>, // this will be in the main app to receive the ping results send back
+    ping_data: Option<PingData>, // the round trip time history, None until the first ping result was received
+    plugins: Vec<Arc<dyn WidgetPlugin>>, // the widget plugins shown in the plugins container
+    plugin_tx: Sender<(usize, Vec<String>)>, // this will be pass to the plugin tasks for sending the data of the plugin
+    plugin_rx: Receiver<(usize, Vec<String>)>, // this will be in the main app to receive the data of the plugins
+    plugin_data: HashMap<usize, Vec<String>>, // the latest data of each plugin, keyed by the index of the plugin
+    script_engine: ScriptEngine, // the compiled custom column and alert scripts from the settings file
+    custom_column_names: Vec<String>, // the names of the custom process columns
+    custom_column_values: HashMap<String, Vec<String>>, // the values of the custom columns of each process, keyed by the pid
+    custom_column_queue: VecDeque<String>, // the pids of the changed processes that their custom columns were waiting to be evaluated
+    custom_column_queued_pids: HashSet<String>, // the pids in the custom column queue, so a process will only be queued once
+    is_public_ip_lookup_enabled: bool, // the public IP will only be looked up if it was enabled in the settings
+    public_ip_tx: Sender<String>, // this will be pass to the task spawn for looking up the public IP
+    public_ip_rx: Receiver<String>, // this will be in the main app to receive the public IP send back
+    thread_pid_tx: watch::Sender<Option<u32>>, // this will be for updating the pid watched by the thread collector task, None while the threads aren't shown
+    shown_system_info_tx: watch::Sender<ShownSystemInfo>, // this will be for updating the slow parts of the system info that should be collected, as they were shown
+    thread_tx: Sender<(u32, Vec<ThreadData>)>, // this will be pass to the task spawn for collecting the threads of the process
+    thread_rx: Receiver<(u32, Vec<ThreadData>)>, // this will be in the main app to receive the threads send back
+    process_threads: Option<(u32, Vec<ThreadData>)>, // the latest collected threads and the pid of their process
+    sys_info: SysInfo,                               // the system info collected
+    process_info: ProcessesInfo,                     // the system process info collected
+    selected_container: SelectedContainer,           // current selected container in the UI
+    state: AppState,                                 // current state of the app
+    pop_up_type: AppPopUpType,                       // current pop up type
+    graph_data_points: Vec<(f64, f64)>, // the points of the graph being drawn, reused by all the graphs so they were not allocated on every frame
+    cpu_graph_shown_range: usize,       // range of graph shown for CPU
+    memory_graph_shown_range: usize,    // range of graph shown for MEMORY
+    disk_graph_shown_range: usize,      // range of graph shown for DISK
+    network_graph_shown_range: usize,   // range of graph shown for NETWORK
     process_graph_shown_range: usize, // range of graph shown for PROCESS [ this will the the graph shown in the process detail layout ]
-    cpu_selected_state: ListState,    // current selected individual cpu
-    disk_selected_entry: usize,       // current selected individual disk
-    network_selected_entry: usize,    // current selected individual network
-    process_current_list: Vec<ProcessData>, // current process list after filtering/sorting
+    cpu_graph_offset: usize, // how many of the latest points were skipped to pan back through the CPU graph history
+    memory_graph_offset: usize, // how many of the latest points were skipped to pan back through the MEMORY graph history
+    disk_graph_offset: usize, // how many of the latest points were skipped to pan back through the DISK graph history
+    network_graph_offset: usize, // how many of the latest points were skipped to pan back through the NETWORK graph history
+    process_graph_offset: usize, // how many of the latest points were skipped to pan back through the PROCESS graph history
+    cpu_graph_style: GraphStyle, // graph type and marker of the CPU charts
+    memory_graph_style: GraphStyle, // graph type and marker of the MEMORY charts
+    disk_graph_style: GraphStyle, // graph type and marker of the DISK charts
+    network_graph_style: GraphStyle, // graph type and marker of the NETWORK charts
+    process_graph_style: GraphStyle, // graph type and marker of the PROCESS charts
+    is_graph_axis_shown: bool,   // show the y axis labels and grid lines of the charts
+    is_status_bar_shown: bool,   // show the key hints of the current context at the bottom
+    is_summary_line_shown: bool, // show the clock, uptime, user count and load average below the header
+    tabs: Vec<LayoutTab>,        // the named tabs with their own arrangement of the containers
+    selected_tab: usize,         // the tab that is shown, switched with the number keys
+    tab_selected_containers: Vec<SelectedContainer>, // the selected container of each tab, restored when switching back to the tab
+    layout_presets: Vec<LayoutTab>, // the built-in arrangements of the containers, cycled with 0
+    selected_layout_preset: Option<usize>, // the preset that override the layout of the tab, None to use the layout of the tab
+    hidden_containers: Vec<SelectedContainer>, // the containers that were hidden with Shift + the container key
+    cpu_selected_state: ListState,             // current selected individual cpu
+    cpu_selectable_entries: usize, // the number of entries in the cpu list, it changes when the hyperthread siblings were aggregated
+    is_cpu_ht_aggregated: bool, // to show the hyperthread siblings of a physical core as one entry in the cpu list
+    is_cpu_grid_view: bool, // to show a small graph for each core when the cpu container is in full screen
+    disk_selected_entry: usize, // current selected individual disk
+    is_disk_list_view: bool, // to list all the disks in the disk container
+    disk_free_thresholds: DiskFreeThresholds, // the disk will be highlighted and alerted when its free space drop below the threshold
+    network_selected_entry: usize, // current selected individual network, 0 is the synthetic entry for all the interfaces
+    network_ip_selected_entry: usize, // current shown address of the selected network
+    process_current_list: Vec<u32>, // the pids of the current process list after filtering/sorting
+    process_header_columns: Vec<(Rect, ProcessSortType)>, // the area of each sortable header of the last drawn process list
     process_selectable_entries: usize, // current selectable entries in the process list
     process_selected_state: ListState, // current selected individual process
-    process_sort_selected_state: u8,  // current selected sorting
+    process_sort_selected_state: u8,   // current selected sorting
     process_sort_type: ProcessSortType, // current sorting type
+    process_command_scroll_offset: usize, // horizontal scroll offset (in characters) of the command column in the process list
+    process_detail_command_scroll_offset: usize, // vertical scroll offset (in lines) of the wrapped command in the process detail
     process_sort_is_reversed: bool, // by default the sorting will be in descending order (true), by setting this to false, the sort will be in ascending order
     process_filter: String,         // current user input for filtering
-    process_show_details: bool,     // indicate if user wanted to show process details
+    process_user_filter: Option<String>, // the user picked from the user menu, only the processes owned by this user will be shown
+    user_names: Vec<String>,             // all the users shown in the user menu pop up
+    user_selected_state: ListState,      // current selected user in the user menu pop up
+    signal_menu_selected_state: ListState, // current selected signal in the signal menu pop up, kept for its scroll offset
+    go_to_pid_input: String,               // current user input for the PID to jump to
+    toast: Option<(String, Instant)>, // the message shown at the bottom of the screen and the time it was shown
+    process_show_details: bool,       // indicate if user wanted to show process details
     current_showing_process_detail: Option<HashMap<String, ProcessData>>, // the current showing process detail
-    is_renderable: bool,         // to indicate if this app UI is renderable
-    is_init: bool,               // to indicate is this app has done initialization
+    is_process_thread_view: bool, // to show the threads of the process instead of the memory graph in the process detail
+    process_child_navigation: Option<(String, usize)>, // the PID of the process whose children are being cycled through in the process detail and the index of the current child
+    is_renderable: bool,                               // to indicate if this app UI is renderable
+    is_compact_view: bool, // the compact view was shown instead of the containers, only Esc was handled in it
+    is_redraw_needed: bool, // to indicate if anything was changed since the last draw, the UI was only redrawn when it was
+    is_focused: bool, // to indicate if the terminal was focused, the collection was slowed down and the UI wasn't redrawn while unfocused
+    is_init: bool,    // to indicate is this app has done initialization
     container_full_screen: bool, // to indicate is user choose to full screen the current selected container
+    is_presentation_mode: bool, // to indicate is user choose the presentation mode, for recording or streaming with simplified colors and hidden sensitive values
+    memory_unit: MemoryUnit, // the unit of the memory values in the memory container and the process list
+    network_unit: NetworkUnit, // the unit of the rates in the network container
+    session_start_time: Instant, // the time the app started monitoring
+    disk_session_since: DateTime<Local>, // the time the disk session counters was started or reset
+    network_session_since: DateTime<Local>, // the time the network session counters was started or reset
+    is_privacy_mode: bool, // to indicate is user choose to hide the sensitive values ( user, command line, ip address ) without the presentation mode
+    is_paused: bool, // to freeze the UI on the current data, the collectors keep running and the pending data will be applied on resume
+    queued_sys_info: VecDeque<CSysInfo>, // the system info collected while paused, up to the maximum history length
+    queued_process_info: VecDeque<CProcessesInfo>, // the process changes collected while paused, since the latest snapshot
     current_process_signal_state_data: Option<CurrentProcessSignalStateData>, // this was used to temporary save the data when user trigger the process signal related pop-up
+    app_color_info: AppColorInfo,      // the colors of the current theme
+    theme_config: ThemeConfig, // the settings the app was started with, reloaded when the settings file was modified
+    config_directory: Option<PathBuf>, // the directory of the settings file, nothing will be read or saved without it
+    current_theme: String,             // name of the current applied theme
+    theme_names: Vec<String>,          // all the themes shown in the theme menu pop up
+    theme_selected_state: ListState,   // current selected theme in the theme menu pop up
+    theme_wizard_state: Option<ThemeWizardState>, // state of the theme wizard pop up when user is creating a theme
+    theme_config_modified_time: Option<SystemTime>, // last known modified time of the settings file, used for theme hot reload
+    theme_config_last_checked: Instant, // last time we checked if the settings file was modified
+    system_appearance: Option<ThemeAppearance>, // last known OS light/dark preference, used when the theme is "system"
+    is_system_theme_tx: watch::Sender<bool>, // this will be for telling the appearance collector task to check the OS preference, only while the theme is "system"
+    system_appearance_tx: Sender<Option<ThemeAppearance>>, // this will be pass to the task spawn for checking the OS light/dark preference
+    system_appearance_rx: Receiver<Option<ThemeAppearance>>, // this will be in the main app to receive the OS light/dark preference send back
 }
 
 const MIN_HEIGHT: u16 = 25;
 const MIN_WIDTH: u16 = 90;
+// the share in percentage that the selected container will grow or shrink with , and .
+const CONTAINER_RESIZE_STEP: i32 = 5;
+// below this size, the compact view will be shown instead of the containers
+const COMPACT_MIN_HEIGHT: u16 = 8;
+const COMPACT_MIN_WIDTH: u16 = 40;
+
+// the slowest collection tick while the terminal was unfocused (in ms), the configured tick was restored on focus
+const UNFOCUSED_REFRESH_TICK: u32 = 5000;
+
+// how long should we wait for an event before checking the collected info again (in ms)
+const EVENT_POLL_INTERVAL: u64 = 100;
+
+// how long should the toast message be shown (in ms)
+const TOAST_DURATION: u64 = 3000;
+
+// how many characters to scroll for each horizontal scroll of the process command column
+const PROCESS_COMMAND_SCROLL_STEP: usize = 8;
+
+// how long can the custom column scripts be evaluated in each iteration of the main loop (in ms), so the scripts
+// can't freeze the UI when there are a lot of processes
+const CUSTOM_COLUMN_EVALUATION_BUDGET: u64 = 20;
+
+// how often should we check the settings file for theme changes (in ms)
+const THEME_CONFIG_CHECK_INTERVAL: u64 = 1000;
+
+// the view is the container to start in full screen
+pub fn app(view: Option<SelectedContainer>) -> io::Result<()> {
+    install_panic_hook();
+    // all the collectors run as tasks of this runtime instead of their own threads
+    let runtime = Runtime::new()?;
+    let _runtime_guard = runtime.enter();
+    let config_directory = get_config_directory();
+    // the auto theme is used for this session if the settings file can't be parsed, it won't be written back
+    let (theme_config, theme_config_error) = match read_theme_config(config_directory.as_deref()) {
+        Ok(theme_config) => (theme_config, None),
+        Err(e) => (ThemeConfig::new(AUTO_THEME), Some(e)),
+    };
+    let mut terminal = init_terminal(get_mouse_capture_config(&theme_config))?;
+    let (mut app, tick_rx, process_tick_rx) =
+        new_app(theme_config, config_directory, get_system_appearance());
+    if let Some(e) = theme_config_error {
+        app.show_toast(format!("Failed to read the settings: {}", e));
+    }
+    if let Some(view) = view {
+        app.start_in_view(view);
+    }
+    app.plugins = get_plugins();
+
+    let result = app.run(
+        &mut terminal,
+        &SysinfoMetricsSource,
+        tick_rx,
+        process_tick_rx,
+    );
+    restore_terminal();
+    // the collectors can be in the middle of a slow command ( eg: smartctl ), so they weren't waited for
+    drop(_runtime_guard);
+    runtime.shutdown_background();
+    return result;
+}
+
+// restore the terminal before the panic message was printed, so a panic in the UI or any of the collector tasks
+// won't leave the shell in raw mode, the app exits as it can't keep running without the panicked collector
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        restore_terminal();
+        log::error!("{}", panic_info);
+        default_hook(panic_info);
+        std::process::exit(1);
+    }));
+}
+
+// the terminal was also asked to report the focus changes, so the collection can be slowed down while it's unfocused
+// and the mouse clicks if it was enabled in the settings, so the process list can be sorted by clicking on its headers
+fn init_terminal(is_mouse_captured: bool) -> io::Result<DefaultTerminal> {
+    let terminal = try_init()?;
+    execute!(io::stdout(), EnableFocusChange)?;
+    if is_mouse_captured {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
+    return Ok(terminal);
+}
 
-pub fn app() {
-    enable_raw_mode().unwrap();
-    let mut terminal = init();
-    let (tx, rx) = mpsc::channel();
-    let (process_tx, process_rx) = mpsc::channel();
-    let (tick_tx, tick_rx) = mpsc::channel();
-    let (process_tick_tx, process_tick_rx) = mpsc::channel();
+fn restore_terminal() {
+    let _ = execute!(io::stdout(), DisableFocusChange, DisableMouseCapture);
+    restore();
+}
 
-    let mut app = App {
+// create the app with the given settings, the returned receivers were for the info collectors to receive the updated tick
+// the settings will only be saved and hot reloaded when there is a config directory
+fn new_app(
+    theme_config: ThemeConfig,
+    config_directory: Option<PathBuf>,
+    system_appearance: Option<ThemeAppearance>,
+) -> (App, watch::Receiver<u32>, watch::Receiver<u32>) {
+    let (tx, rx) = unbounded_channel();
+    let (process_tx, process_rx) = unbounded_channel();
+    let (tick_tx, tick_rx) = watch::channel(INITIAL_COLLECT_TICK);
+    let (process_tick_tx, process_tick_rx) = watch::channel(INITIAL_COLLECT_TICK);
+    let (smart_tx, smart_rx) = mpsc::channel();
+    let (storage_pool_tx, storage_pool_rx) = mpsc::channel();
+    let (ping_tx, ping_rx) = mpsc::channel();
+    let (plugin_tx, plugin_rx) = mpsc::channel();
+    let (public_ip_tx, public_ip_rx) = mpsc::channel();
+    let (thread_pid_tx, _) = watch::channel(None);
+    let (shown_system_info_tx, _) = watch::channel(ShownSystemInfo::default());
+    let (thread_tx, thread_rx) = mpsc::channel();
+    let (is_system_theme_tx, _) = watch::channel(false);
+    let (system_appearance_tx, system_appearance_rx) = mpsc::channel();
+
+    let (tick, process_tick) = get_refresh_tick_config(&theme_config);
+    // the first script error will be shown, the rest can be found in the debug log
+    let (script_engine, script_errors) = ScriptEngine::new(&get_script_config(&theme_config));
+    let app_color_info = get_app_color_info_from_theme_name(
+        &theme_config.theme,
+        &theme_config,
+        config_directory.as_deref(),
+        system_appearance,
+    );
+
+    let app = App {
         is_quit: false,
-        tick: 1000,
+        tick,
+        process_tick,
         tx,
         rx,
         process_tx,
         process_rx,
         tick_tx,
         process_tick_tx,
-        sys_info: SysInfo {
-            cpus: vec![],
-            memory: MemoryData::default(),
-            disks: HashMap::new(),
-            networks: HashMap::new(),
-        },
-        process_info: ProcessesInfo {
-            processes: HashMap::new(),
-        },
+        smart_tx,
+        smart_rx,
+        smart_info: HashMap::new(),
+        storage_pool_tx,
+        storage_pool_rx,
+        storage_pools: vec![],
+        ping_target: get_ping_target_config(&theme_config),
+        ping_tx,
+        ping_rx,
+        ping_data: None,
+        plugins: vec![],
+        plugin_tx,
+        plugin_rx,
+        plugin_data: HashMap::new(),
+        custom_column_names: script_engine.get_column_names(),
+        script_engine,
+        custom_column_values: HashMap::new(),
+        custom_column_queue: VecDeque::new(),
+        custom_column_queued_pids: HashSet::new(),
+        is_public_ip_lookup_enabled: get_public_ip_lookup_config(&theme_config),
+        public_ip_tx,
+        public_ip_rx,
+        thread_pid_tx,
+        shown_system_info_tx,
+        thread_tx,
+        thread_rx,
+        process_threads: None,
+        sys_info: SysInfo::new(get_static_system_info()),
+        process_info: ProcessesInfo::default(),
         selected_container: SelectedContainer::None,
         state: AppState::View,
         pop_up_type: AppPopUpType::None,
+        graph_data_points: Vec::new(),
         cpu_graph_shown_range: 100,
         memory_graph_shown_range: 100,
         disk_graph_shown_range: 100,
         network_graph_shown_range: 100,
         process_graph_shown_range: 100,
+        cpu_graph_offset: 0,
+        memory_graph_offset: 0,
+        disk_graph_offset: 0,
+        network_graph_offset: 0,
+        process_graph_offset: 0,
+        cpu_graph_style: get_graph_style_config(&theme_config, "cpu"),
+        memory_graph_style: get_graph_style_config(&theme_config, "memory"),
+        disk_graph_style: get_graph_style_config(&theme_config, "disk"),
+        network_graph_style: get_graph_style_config(&theme_config, "network"),
+        process_graph_style: get_graph_style_config(&theme_config, "process"),
+        is_graph_axis_shown: get_graph_axis_config(&theme_config),
+        is_status_bar_shown: get_status_bar_config(&theme_config),
+        is_summary_line_shown: get_summary_line_config(&theme_config),
+        tabs: get_tabs_config(&theme_config),
+        selected_tab: 0,
+        tab_selected_containers: vec![SelectedContainer::None; MAX_TAB_COUNT],
+        layout_presets: ContainerLayout::get_presets(),
+        selected_layout_preset: None,
+        hidden_containers: vec![],
         cpu_selected_state: ListState::default(),
+        cpu_selectable_entries: 0,
+        is_cpu_ht_aggregated: false,
+        is_cpu_grid_view: false,
         disk_selected_entry: 0,
-        network_selected_entry: 0,
+        is_disk_list_view: false,
+        disk_free_thresholds: get_disk_free_thresholds_config(&theme_config),
+        // the first interface was selected by default, the entry for all the interfaces is before it
+        network_selected_entry: 1,
+        network_ip_selected_entry: 0,
         process_current_list: vec![],
+        process_header_columns: vec![],
         process_selectable_entries: 0,
         process_selected_state: ListState::default(),
         process_sort_selected_state: 0,
         process_sort_type: ProcessSortType::Thread,
+        process_command_scroll_offset: 0,
+        process_detail_command_scroll_offset: 0,
         process_sort_is_reversed: true,
         process_filter: String::new(),
+        process_user_filter: None,
+        user_names: vec![],
+        user_selected_state: ListState::default(),
+        signal_menu_selected_state: ListState::default(),
+        go_to_pid_input: String::new(),
+        toast: script_errors
+            .first()
+            .map(|script_error| (script_error.clone(), Instant::now())),
         process_show_details: false,
         current_showing_process_detail: None,
+        is_process_thread_view: false,
+        process_child_navigation: None,
         is_renderable: true,
+        is_compact_view: false,
+        is_redraw_needed: true,
+        is_focused: true,
         is_init: false,
         container_full_screen: false,
+        is_presentation_mode: false,
+        memory_unit: get_memory_unit_config(&theme_config),
+        network_unit: get_network_unit_config(&theme_config),
+        is_privacy_mode: false,
+        is_paused: false,
+        queued_sys_info: VecDeque::new(),
+        queued_process_info: VecDeque::new(),
+        session_start_time: Instant::now(),
+        disk_session_since: Local::now(),
+        network_session_since: Local::now(),
         current_process_signal_state_data: None,
+        app_color_info,
+        current_theme: theme_config.theme.clone(),
+        theme_names: get_all_theme_names(config_directory.as_deref()),
+        theme_selected_state: ListState::default(),
+        theme_wizard_state: None,
+        theme_config_modified_time: config_directory
+            .as_deref()
+            .and_then(get_theme_config_modified_time),
+        theme_config_last_checked: Instant::now(),
+        system_appearance,
+        is_system_theme_tx,
+        system_appearance_tx,
+        system_appearance_rx,
+        theme_config,
+        config_directory,
     };
 
-    let app_color_info = get_and_return_app_color_info();
-    app.run(&mut terminal, tick_rx, process_tick_rx, app_color_info);
-    disable_raw_mode().unwrap();
-    restore();
+    return (app, tick_rx, process_tick_rx);
 }
 
 impl App {
@@ -145,15 +483,60 @@ impl App {
     pub fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        tick_rx: Receiver<u32>,
-        process_tick_rx: Receiver<u32>,
-        app_color_info: AppColorInfo,
+        metrics_source: &impl MetricsSource,
+        tick_rx: watch::Receiver<u32>,
+        process_tick_rx: watch::Receiver<u32>,
+    ) -> io::Result<()> {
+        self.start_collectors(metrics_source, tick_rx, process_tick_rx);
+        self.wait_for_initial_info();
+
+        while !self.is_quit {
+            // the events were still read while the tui isn't renderable, so the resize will be redrawn
+            let event = if event::poll(Duration::from_millis(EVENT_POLL_INTERVAL))? {
+                Some(event::read()?)
+            } else {
+                None
+            };
+            self.run_once(terminal, event)?;
+        }
+
+        return Ok(());
+    }
+
+    // start the collectors of the metrics source, the collected info will be received in the channels of the app
+    fn start_collectors(
+        &self,
+        metrics_source: &impl MetricsSource,
+        tick_rx: watch::Receiver<u32>,
+        process_tick_rx: watch::Receiver<u32>,
     ) {
-        // when the program start, we let the info collector to collect at 100ms
-        // only after the initial collection, we reset to the user selected tick ( this will be able to be configure at a later stage )
-        spawn_system_info_collector(tick_rx, self.tx.clone(), 100);
-        spawn_process_info_collector(process_tick_rx, self.process_tx.clone(), 100);
+        metrics_source.spawn_system_info_collector(
+            tick_rx,
+            self.shown_system_info_tx.subscribe(),
+            self.tx.clone(),
+        );
+        metrics_source.spawn_process_info_collector(process_tick_rx, self.process_tx.clone());
+        metrics_source.spawn_smart_info_collector(self.smart_tx.clone());
+        metrics_source.spawn_storage_pool_collector(self.storage_pool_tx.clone());
+        if let Some(ping_target) = self.ping_target.clone() {
+            metrics_source.spawn_ping_collector(ping_target, self.ping_tx.clone());
+        }
+        if self.is_public_ip_lookup_enabled {
+            metrics_source.spawn_public_ip_collector(self.public_ip_tx.clone());
+        }
+        metrics_source
+            .spawn_thread_info_collector(self.thread_pid_tx.subscribe(), self.thread_tx.clone());
+        metrics_source.spawn_system_appearance_collector(
+            self.is_system_theme_tx.subscribe(),
+            self.system_appearance_tx.clone(),
+        );
+        if !self.plugins.is_empty() {
+            spawn_plugin_collectors(&self.plugins, self.plugin_tx.clone());
+        }
+    }
 
+    // block until the first collected system and process info was received
+    fn wait_for_initial_info(&mut self) {
         while !self.is_init {
             match self.rx.try_recv() {
                 Ok(c_sys_info) => {
@@ -181,36 +564,236 @@ impl App {
 
         self.process_selectable_entries = self.process_info.processes.len();
         self.process_selected_state.select(None);
+        let pids: Vec<String> = self.process_info.processes.keys().cloned().collect();
+        self.queue_custom_column_evaluation(pids);
         let _ = self.tick_tx.send(self.tick);
-        let _ = self.process_tick_tx.send(self.tick);
+        let _ = self.process_tick_tx.send(self.process_tick);
+    }
 
-        while !self.is_quit {
-            let c_sys_info = self.rx.try_recv();
-            if c_sys_info.is_ok() {
-                process_sys_info(&mut self.sys_info, c_sys_info.unwrap());
+    // queue the processes that were changed, so their custom columns will be evaluated again
+    fn queue_custom_column_evaluation(&mut self, pids: impl IntoIterator<Item = String>) {
+        if self.custom_column_names.is_empty() {
+            return;
+        }
+        for pid in pids {
+            if self.custom_column_queued_pids.insert(pid.clone()) {
+                self.custom_column_queue.push_back(pid);
+            }
+        }
+    }
+
+    // evaluate the custom column scripts of the queued processes on the UI thread, it stops when the evaluations of
+    // this iteration took longer than the budget, the rest will be evaluated in the next iterations in the queued order
+    fn update_custom_column_values(&mut self) {
+        // the values of the exited processes were removed
+        let processes = &self.process_info.processes;
+        self.custom_column_values
+            .retain(|pid, _| processes.contains_key(pid));
+
+        let evaluation_start = Instant::now();
+        while evaluation_start.elapsed() < Duration::from_millis(CUSTOM_COLUMN_EVALUATION_BUDGET) {
+            let Some(pid) = self.custom_column_queue.pop_front() else {
+                break;
+            };
+            self.custom_column_queued_pids.remove(&pid);
+            if let Some(process) = self.process_info.processes.get(&pid) {
+                let values = self.script_engine.evaluate_columns(process);
+                self.custom_column_values.insert(pid, values);
             }
+        }
+    }
 
-            let c_process_info = self.process_rx.try_recv();
-            if c_process_info.is_ok() {
-                process_processes_info(
-                    &mut self.process_info,
-                    c_process_info.unwrap(),
-                    &mut self.current_showing_process_detail,
-                );
+    // a single iteration of the main loop, process the collected info, draw the UI and handle the event
+    // this was separated from the run loop so it can be driven by scripted events against any backend
+    fn run_once<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        event: Option<Event>,
+    ) -> io::Result<()> {
+        // the collected info was queued while paused and applied on resume, so the graph history won't have a gap
+        // only the latest collections that fit in the history were kept, the older points would be trimmed anyway
+        while let Ok(c_sys_info) = self.rx.try_recv() {
+            self.queued_sys_info.push_back(c_sys_info);
+            if self.queued_sys_info.len() > get_maximum_data_collection() {
+                self.queued_sys_info.pop_front();
+            }
+        }
+        // the snapshot has the full info of all the processes, so the changes queued before it were no longer needed
+        while let Ok(c_process_info) = self.process_rx.try_recv() {
+            if c_process_info.is_snapshot {
+                self.queued_process_info.clear();
+            }
+            self.queued_process_info.push_back(c_process_info);
+        }
+        if !self.is_paused {
+            // the processes were applied first, so the alerts were evaluated against the latest processes
+            if !self.queued_process_info.is_empty() {
+                while let Some(c_process_info) = self.queued_process_info.pop_front() {
+                    // only the custom columns of the changed processes were evaluated again
+                    let changed_pids: Vec<String> = c_process_info
+                        .processes
+                        .iter()
+                        .map(|process| process.pid)
+                        .chain(
+                            c_process_info
+                                .updated_processes
+                                .iter()
+                                .map(|process| process.pid),
+                        )
+                        .map(|pid| pid.to_string())
+                        .collect();
+                    self.queue_custom_column_evaluation(changed_pids);
+                    process_processes_info(
+                        &mut self.process_info,
+                        c_process_info,
+                        &mut self.current_showing_process_detail,
+                    );
+                }
+                self.is_redraw_needed = true;
+            }
+            if !self.custom_column_queue.is_empty() {
+                self.update_custom_column_values();
+                self.is_redraw_needed = true;
+            }
+            if !self.queued_sys_info.is_empty() {
+                self.apply_queued_sys_info();
+            }
+        }
+
+        if let Ok(smart_info) = self.smart_rx.try_recv() {
+            self.smart_info = smart_info;
+            self.is_redraw_needed = true;
+        }
+
+        if let Ok(storage_pools) = self.storage_pool_rx.try_recv() {
+            self.storage_pools = storage_pools;
+            self.is_redraw_needed = true;
+        }
+
+        // the ping has its own interval, so all the pending results were processed
+        while let Ok(ping_result) = self.ping_rx.try_recv() {
+            self.ping_data
+                .get_or_insert_with(|| PingData::new(ping_result.target.clone()))
+                .update(ping_result);
+            self.is_redraw_needed = true;
+        }
+
+        if let Ok(public_ip) = self.public_ip_rx.try_recv() {
+            self.sys_info.connectivity.public_ip = Some(public_ip);
+            self.is_redraw_needed = true;
+        }
+
+        while let Ok((plugin_index, data)) = self.plugin_rx.try_recv() {
+            self.plugin_data.insert(plugin_index, data);
+            self.is_redraw_needed = true;
+        }
+
+        // the threads were only collected while they were shown, the pid was watched by the collector
+        let thread_pid = if self.is_process_thread_view && self.process_show_details {
+            self.get_current_showing_process_pid()
+        } else {
+            None
+        };
+        self.thread_pid_tx.send_if_modified(|watched_pid| {
+            if *watched_pid == thread_pid {
+                return false;
+            }
+            *watched_pid = thread_pid;
+            return true;
+        });
+        while let Ok(process_threads) = self.thread_rx.try_recv() {
+            self.process_threads = Some(process_threads);
+            self.is_redraw_needed = true;
+        }
+
+        // the slow parts of the system info were only collected while they were shown
+        let shown_system_info = ShownSystemInfo {
+            is_connection_list_shown: self.container_full_screen
+                && self.selected_container == SelectedContainer::Network,
+            is_summary_line_shown: self.is_summary_line_shown,
+        };
+        self.shown_system_info_tx
+            .send_if_modified(|watched_shown_system_info| {
+                if *watched_shown_system_info == shown_system_info {
+                    return false;
+                }
+                *watched_shown_system_info = shown_system_info;
+                return true;
+            });
+
+        // hot reload the theme if the settings file was changed outside of the app
+        if self.theme_config_last_checked.elapsed()
+            >= Duration::from_millis(THEME_CONFIG_CHECK_INTERVAL)
+        {
+            self.reload_theme_if_changed();
+        }
+
+        // follow the OS light/dark preference if the theme is "system", it was only checked by the collector while it is
+        let is_system_theme = self.current_theme == SYSTEM_THEME;
+        self.is_system_theme_tx
+            .send_if_modified(|watched_is_system_theme| {
+                if *watched_is_system_theme == is_system_theme {
+                    return false;
+                }
+                *watched_is_system_theme = is_system_theme;
+                return true;
+            });
+        while let Ok(system_appearance) = self.system_appearance_rx.try_recv() {
+            self.apply_system_appearance(system_appearance);
+        }
+
+        // the expired toast should be cleared from the screen
+        if let Some((_, shown_time)) = self.toast.as_ref() {
+            if shown_time.elapsed() >= Duration::from_millis(TOAST_DURATION) {
+                self.is_redraw_needed = true;
             }
-            let _ = terminal.draw(|frame| self.draw(frame, &app_color_info));
+        }
 
-            // we only handle event if the tui is renderable
-            if self.is_renderable {
-                self.handle_events();
+        // only redraw when something was changed, so rtop won't use the cpu to draw the same frame again
+        // the changes while unfocused were drawn when the terminal was focused again
+        if self.is_redraw_needed && self.is_focused {
+            terminal.draw(|frame| self.draw(frame))?;
+            self.is_redraw_needed = false;
+        }
+
+        if let Some(event) = event {
+            // we only handle event if the tui is renderable, except the focus changes and the resize
+            let is_event_handled =
+                if self.is_renderable || matches!(event, Event::FocusGained | Event::FocusLost) {
+                    self.handle_event(event)
+                } else {
+                    matches!(event, Event::Resize(_, _))
+                };
+            // the result of the event ( or the resize ) will be drawn in the next iteration, the events that were
+            // ignored ( like the mouse moves ) won't redraw the same frame again
+            if is_event_handled {
+                self.is_redraw_needed = true;
             }
         }
+
+        return Ok(());
     }
 
-    fn draw(&mut self, frame: &mut Frame, app_color_info: &AppColorInfo) {
+    fn draw(&mut self, frame: &mut Frame) {
+        // presentation mode will override the current theme with the simplified colors
+        let app_color_info = if self.is_presentation_mode {
+            &PRESENTATION
+        } else {
+            &self.app_color_info
+        };
+        // sensitive values ( user, command arguments, ip address ) will be hidden in presentation mode or privacy mode
+        let is_redacted = self.is_presentation_mode || self.is_privacy_mode;
+        // the process list will set its headers again if it was drawn
+        self.process_header_columns.clear();
+        // copied out as the process list state was borrowed mutably when the process container was drawn
+        let process_threads = self
+            .get_current_showing_process_threads()
+            .map(|threads| threads.to_vec());
+
         //
-        //                       The TUI Layout
+        //     The default TUI Layout ( it can be rearranged with `layout` in the settings file )
         //
+        //          HOST NAME │ OS │ KERNEL │ ARCH  (header 1 line)
         //   ------------------------------------------------------------
         //   |                                                          |
         //   |                  CPU INFO (top 30.0%)                    |
@@ -226,15 +809,40 @@ impl App {
         //   ------------------------------------------------------------
 
         // split and init the layout space for each container
-        let top_and_bottom = Layout::vertical([Constraint::Fill(30), Constraint::Fill(70)]);
-        let [cpu_area, bottom] = top_and_bottom.areas(frame.area());
-        let [bottom_left, process_area] =
-            Layout::horizontal([Constraint::Fill(45), Constraint::Fill(55)]).areas(bottom);
-        let [memory_disk_area, network_area] =
-            Layout::vertical([Constraint::Fill(65), Constraint::Fill(35)]).areas(bottom_left);
-        let [memory_area, disk_area] =
-            Layout::horizontal([Constraint::Fill(50), Constraint::Fill(50)])
-                .areas(memory_disk_area);
+        let [header_area, summary_line_area, main_view_rect, status_bar_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(if self.is_summary_line_shown { 1 } else { 0 }),
+            Constraint::Fill(1),
+            Constraint::Length(if self.is_status_bar_shown { 1 } else { 0 }),
+        ])
+        .areas(frame.area());
+        // the plugins container will be placed below the other containers if the layout doesn't have it
+        let is_plugins_container_placed = self
+            .get_current_layout()
+            .get_containers()
+            .contains(&SelectedContainer::Plugins);
+        let [layout_rect, plugins_rect] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(
+                if !self.plugins.is_empty() && !is_plugins_container_placed {
+                    PLUGINS_CONTAINER_HEIGHT
+                } else {
+                    0
+                },
+            ),
+        ])
+        .areas(main_view_rect);
+        // the plugins container won't take up any space without any plugin
+        let mut hidden_containers = self.hidden_containers.clone();
+        if self.plugins.is_empty() {
+            hidden_containers.push(SelectedContainer::Plugins);
+        }
+        // the containers were arranged according to the layout in the settings file, see ContainerLayout::default_layout
+        let mut container_areas =
+            get_container_areas(self.get_current_layout(), &hidden_containers, layout_rect);
+        if !self.plugins.is_empty() && !is_plugins_container_placed {
+            container_areas.push((SelectedContainer::Plugins, plugins_rect));
+        }
 
         // set the bg
         let background =
@@ -243,38 +851,86 @@ impl App {
 
         // check if the terminal size is valid
         let full_frame_view_rect = frame.area();
-        if full_frame_view_rect.width < MIN_WIDTH || full_frame_view_rect.height < MIN_HEIGHT {
+        if full_frame_view_rect.width < COMPACT_MIN_WIDTH
+            || full_frame_view_rect.height < COMPACT_MIN_HEIGHT
+        {
+            // the last resort when even the compact view can't fit
             self.is_renderable = false;
+            self.is_compact_view = false;
             draw_not_renderable_message(frame, app_color_info);
             return;
+        } else if full_frame_view_rect.width < MIN_WIDTH || full_frame_view_rect.height < MIN_HEIGHT
+        {
+            // fallback to the single panel layout instead of the containers
+            self.is_renderable = true;
+            self.is_compact_view = true;
+            draw_compact_view(
+                &self.sys_info.cpus,
+                &self.sys_info.memory,
+                &self.process_info.processes,
+                full_frame_view_rect,
+                frame,
+                app_color_info,
+            );
+            return;
         } else {
             self.is_renderable = true;
+            self.is_compact_view = false;
         }
 
         if self.is_renderable {
             // we check the selcted disk entry to prevent selecting a disk that got removed
             //
-            // default to the first disk entry
-            let mut selected_disk = self.sys_info.disks.iter().nth(0).unwrap().1;
+            // the disks were ordered by their physical device, so the partitions of the same device were selected one after another
+            let ordered_disks = get_ordered_disks(&self.sys_info.disks);
+            // default to the first disk entry, there will be no disk in containers or VMs that don't report any
+            let mut selected_disk = ordered_disks.first().copied();
             // if the selected disk is valid, override the selected default disk
-            if let Some((_, value)) = self.sys_info.disks.iter().nth(self.disk_selected_entry) {
-                selected_disk = value;
+            if let Some(value) = ordered_disks.get(self.disk_selected_entry) {
+                selected_disk = Some(value);
             } else {
                 self.disk_selected_entry = 0;
             }
 
-            // default to the first network entry
-            let mut selected_network = self.sys_info.networks.iter().nth(0).unwrap().1;
+            // the first entry is the synthetic entry that sum up all the network interfaces
+            let aggregate_network = get_aggregate_network(&self.sys_info.networks);
+            // default to the entry for all the interfaces
+            let mut selected_network = &aggregate_network;
             // if the selected network is valid, override the selected default network
-            if let Some((_, value)) = self
-                .sys_info
-                .networks
-                .iter()
-                .nth(self.network_selected_entry)
-            {
-                selected_network = value;
-            } else {
-                self.network_selected_entry = 0;
+            if self.network_selected_entry > 0 {
+                if let Some((_, value)) = self
+                    .sys_info
+                    .networks
+                    .iter()
+                    .nth(self.network_selected_entry - 1)
+                {
+                    selected_network = value;
+                } else {
+                    self.network_selected_entry = 0;
+                }
+            }
+
+            draw_system_info_header(
+                &self.sys_info.static_info,
+                header_area,
+                frame,
+                app_color_info,
+                is_redacted,
+                self.is_paused,
+                &self
+                    .tabs
+                    .iter()
+                    .map(|tab| tab.name.clone())
+                    .collect::<Vec<String>>(),
+                self.selected_tab,
+            );
+            if self.is_summary_line_shown {
+                draw_system_summary_line(
+                    &self.sys_info.system,
+                    summary_line_area,
+                    frame,
+                    app_color_info,
+                );
             }
 
             // handling for full screen mode
@@ -283,24 +939,38 @@ impl App {
                     draw_cpu_info(
                         self.tick as u64,
                         &self.sys_info.cpus,
-                        full_frame_view_rect,
+                        main_view_rect,
                         frame,
                         &mut self.cpu_selected_state,
+                        &mut self.cpu_selectable_entries,
+                        self.is_cpu_ht_aggregated,
                         self.cpu_graph_shown_range,
+                        self.cpu_graph_offset,
+                        self.cpu_graph_style,
+                        self.is_graph_axis_shown,
                         if self.selected_container == SelectedContainer::Cpu {
                             true
                         } else {
                             false
                         },
                         app_color_info,
+                        true,
+                        self.is_cpu_grid_view,
+                        self.session_start_time.elapsed().as_secs(),
+                        &self.sys_info.system,
+                        &self.sys_info.pressure.cpu,
+                        &mut self.graph_data_points,
                     );
                 } else if self.selected_container == SelectedContainer::Memory {
                     draw_memory_info(
                         self.tick as u64,
                         &self.sys_info.memory,
-                        full_frame_view_rect,
+                        main_view_rect,
                         frame,
                         self.memory_graph_shown_range,
+                        self.memory_graph_offset,
+                        self.memory_graph_style,
+                        self.is_graph_axis_shown,
                         if self.selected_container == SelectedContainer::Memory {
                             true
                         } else {
@@ -308,180 +978,480 @@ impl App {
                         },
                         app_color_info,
                         true,
+                        &self.memory_unit,
+                        &self.sys_info.pressure.memory,
+                        &mut self.graph_data_points,
                     )
                 } else if self.selected_container == SelectedContainer::Disk {
-                    draw_disk_info(
-                        self.tick as u64,
-                        &selected_disk,
-                        full_frame_view_rect,
-                        frame,
-                        self.disk_graph_shown_range,
-                        if self.selected_container == SelectedContainer::Disk {
-                            true
-                        } else {
-                            false
+                    if let Some(selected_disk) = selected_disk {
+                        draw_disk_info(
+                            self.tick as u64,
+                            &selected_disk,
+                            &self.sys_info.disks,
+                            self.disk_selected_entry,
+                            &self.disk_free_thresholds,
+                            main_view_rect,
+                            frame,
+                            self.disk_graph_shown_range,
+                            self.disk_graph_offset,
+                            self.disk_graph_style,
+                            self.is_graph_axis_shown,
+                            if self.selected_container == SelectedContainer::Disk {
+                                true
+                            } else {
+                                false
+                            },
+                            app_color_info,
+                            true,
+                            &self.disk_session_since,
+                            self.is_disk_list_view,
+                            self.smart_info.get(&selected_disk.mount_point),
+                            &self.sys_info.raid_arrays,
+                            self.storage_pools
+                                .iter()
+                                .find(|storage_pool| storage_pool.contains_disk(selected_disk)),
+                            &self.sys_info.pressure.io,
+                            &mut self.graph_data_points,
+                        )
+                    } else {
+                        draw_empty_disk_info(
+                            main_view_rect,
+                            frame,
+                            self.selected_container == SelectedContainer::Disk,
+                            app_color_info,
+                        );
+                    }
+                } else if self.selected_container == SelectedContainer::Network {
+                    if self.sys_info.networks.is_empty() {
+                        draw_empty_network_info(
+                            main_view_rect,
+                            frame,
+                            self.selected_container == SelectedContainer::Network,
+                            app_color_info,
+                        );
+                    } else {
+                        draw_network_info(
+                            self.tick as u64,
+                            selected_network,
+                            main_view_rect,
+                            frame,
+                            self.network_graph_shown_range,
+                            self.network_graph_offset,
+                            self.network_graph_style,
+                            self.is_graph_axis_shown,
+                            if self.selected_container == SelectedContainer::Network {
+                                true
+                            } else {
+                                false
+                            },
+                            app_color_info,
+                            true,
+                            is_redacted,
+                            &self.network_session_since,
+                            &self.network_unit,
+                            self.network_ip_selected_entry,
+                            self.ping_data.as_ref(),
+                            &self.sys_info.connections,
+                            &self.sys_info.connectivity,
+                            &mut self.graph_data_points,
+                        )
+                    }
+                } else if self.selected_container == SelectedContainer::Process {
+                    draw_process_info(
+                        ProcessView {
+                            tick: self.process_tick as u64,
+                            process_data: &self.process_info.processes,
+                            process_current_list: &mut self.process_current_list,
+                            process_header_columns: &mut self.process_header_columns,
+                            process_selectable_entries: &mut self.process_selectable_entries,
+                            process_selected_state: &mut self.process_selected_state,
+                            process_sort_type: &self.process_sort_type,
+                            process_sort_is_reversed: self.process_sort_is_reversed,
+                            process_command_scroll_offset: self.process_command_scroll_offset,
+                            process_detail_command_scroll_offset: &mut self
+                                .process_detail_command_scroll_offset,
+                            process_filter: self.process_filter.clone(),
+                            process_user_filter: &self.process_user_filter,
+                            process_show_detail: self.process_show_details,
+                            current_showing_process_detail: &self.current_showing_process_detail,
+                            process_threads: process_threads.as_deref(),
+                            total_memory: self.sys_info.memory.total_memory,
+                            memory_unit: &self.memory_unit,
+                            is_filtering: self.state == AppState::Typing,
+                            graph_show_range: self.process_graph_shown_range,
+                            graph_offset: self.process_graph_offset,
+                            graph_style: self.process_graph_style,
+                            is_graph_axis_shown: self.is_graph_axis_shown,
+                            is_selected: self.selected_container == SelectedContainer::Process,
+                            app_color_info,
+                            is_full_screen: true,
+                            is_redacted,
+                            is_presentation_mode: self.is_presentation_mode,
+                            custom_columns: &self.custom_column_names,
+                            custom_column_values: &self.custom_column_values,
+                            graph_data_points: &mut self.graph_data_points,
                         },
-                        app_color_info,
-                        true,
+                        main_view_rect,
+                        frame,
                     )
-                } else if self.selected_container == SelectedContainer::Network {
-                    draw_network_info(
+                } else if self.selected_container == SelectedContainer::Plugins {
+                    draw_plugin_panels(
+                        &self.plugins,
+                        &self.plugin_data,
+                        main_view_rect,
+                        frame,
+                        app_color_info,
+                    );
+                }
+            } else {
+                if let Some(cpu_area) = get_container_area(&container_areas, SelectedContainer::Cpu)
+                {
+                    draw_cpu_info(
                         self.tick as u64,
-                        &selected_network,
-                        full_frame_view_rect,
+                        &self.sys_info.cpus,
+                        cpu_area,
                         frame,
-                        self.network_graph_shown_range,
-                        if self.selected_container == SelectedContainer::Network {
+                        &mut self.cpu_selected_state,
+                        &mut self.cpu_selectable_entries,
+                        self.is_cpu_ht_aggregated,
+                        self.cpu_graph_shown_range,
+                        self.cpu_graph_offset,
+                        self.cpu_graph_style,
+                        self.is_graph_axis_shown,
+                        if self.selected_container == SelectedContainer::Cpu {
                             true
                         } else {
                             false
                         },
                         app_color_info,
-                        true,
-                    )
-                } else if self.selected_container == SelectedContainer::Process {
-                    draw_process_info(
+                        false,
+                        self.is_cpu_grid_view,
+                        self.session_start_time.elapsed().as_secs(),
+                        &self.sys_info.system,
+                        &self.sys_info.pressure.cpu,
+                        &mut self.graph_data_points,
+                    );
+                }
+
+                if let Some(memory_area) =
+                    get_container_area(&container_areas, SelectedContainer::Memory)
+                {
+                    draw_memory_info(
                         self.tick as u64,
-                        &self.process_info.processes,
-                        &mut self.process_current_list,
-                        &mut self.process_selectable_entries,
-                        &mut self.process_selected_state,
-                        &self.process_sort_type,
-                        self.process_sort_is_reversed,
-                        self.process_filter.clone(),
-                        self.process_show_details,
-                        &self.current_showing_process_detail,
-                        self.sys_info.memory.total_memory,
-                        self.state == AppState::Typing,
-                        full_frame_view_rect,
+                        &self.sys_info.memory,
+                        memory_area,
                         frame,
-                        self.process_graph_shown_range,
-                        if self.selected_container == SelectedContainer::Process {
+                        self.memory_graph_shown_range,
+                        self.memory_graph_offset,
+                        self.memory_graph_style,
+                        self.is_graph_axis_shown,
+                        if self.selected_container == SelectedContainer::Memory {
                             true
                         } else {
                             false
                         },
                         app_color_info,
-                        true,
-                    )
+                        false,
+                        &self.memory_unit,
+                        &self.sys_info.pressure.memory,
+                        &mut self.graph_data_points,
+                    );
                 }
-            } else {
-                draw_cpu_info(
-                    self.tick as u64,
-                    &self.sys_info.cpus,
-                    cpu_area,
-                    frame,
-                    &mut self.cpu_selected_state,
-                    self.cpu_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Cpu {
-                        true
+
+                if let Some(disk_area) =
+                    get_container_area(&container_areas, SelectedContainer::Disk)
+                {
+                    if let Some(selected_disk) = selected_disk {
+                        draw_disk_info(
+                            self.tick as u64,
+                            &selected_disk,
+                            &self.sys_info.disks,
+                            self.disk_selected_entry,
+                            &self.disk_free_thresholds,
+                            disk_area,
+                            frame,
+                            self.disk_graph_shown_range,
+                            self.disk_graph_offset,
+                            self.disk_graph_style,
+                            self.is_graph_axis_shown,
+                            if self.selected_container == SelectedContainer::Disk {
+                                true
+                            } else {
+                                false
+                            },
+                            app_color_info,
+                            false,
+                            &self.disk_session_since,
+                            self.is_disk_list_view,
+                            self.smart_info.get(&selected_disk.mount_point),
+                            &self.sys_info.raid_arrays,
+                            self.storage_pools
+                                .iter()
+                                .find(|storage_pool| storage_pool.contains_disk(selected_disk)),
+                            &self.sys_info.pressure.io,
+                            &mut self.graph_data_points,
+                        );
                     } else {
-                        false
-                    },
-                    app_color_info,
-                );
+                        draw_empty_disk_info(
+                            disk_area,
+                            frame,
+                            self.selected_container == SelectedContainer::Disk,
+                            app_color_info,
+                        );
+                    }
+                }
 
-                draw_memory_info(
-                    self.tick as u64,
-                    &self.sys_info.memory,
-                    memory_area,
-                    frame,
-                    self.memory_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Memory {
-                        true
+                if let Some(network_area) =
+                    get_container_area(&container_areas, SelectedContainer::Network)
+                {
+                    if self.sys_info.networks.is_empty() {
+                        draw_empty_network_info(
+                            network_area,
+                            frame,
+                            self.selected_container == SelectedContainer::Network,
+                            app_color_info,
+                        );
                     } else {
-                        false
-                    },
-                    app_color_info,
-                    false,
-                );
+                        draw_network_info(
+                            self.tick as u64,
+                            selected_network,
+                            network_area,
+                            frame,
+                            self.network_graph_shown_range,
+                            self.network_graph_offset,
+                            self.network_graph_style,
+                            self.is_graph_axis_shown,
+                            if self.selected_container == SelectedContainer::Network {
+                                true
+                            } else {
+                                false
+                            },
+                            app_color_info,
+                            false,
+                            is_redacted,
+                            &self.network_session_since,
+                            &self.network_unit,
+                            self.network_ip_selected_entry,
+                            self.ping_data.as_ref(),
+                            &self.sys_info.connections,
+                            &self.sys_info.connectivity,
+                            &mut self.graph_data_points,
+                        );
+                    }
+                }
+
+                if let Some(process_area) =
+                    get_container_area(&container_areas, SelectedContainer::Process)
+                {
+                    draw_process_info(
+                        ProcessView {
+                            tick: self.process_tick as u64,
+                            process_data: &self.process_info.processes,
+                            process_current_list: &mut self.process_current_list,
+                            process_header_columns: &mut self.process_header_columns,
+                            process_selectable_entries: &mut self.process_selectable_entries,
+                            process_selected_state: &mut self.process_selected_state,
+                            process_sort_type: &self.process_sort_type,
+                            process_sort_is_reversed: self.process_sort_is_reversed,
+                            process_command_scroll_offset: self.process_command_scroll_offset,
+                            process_detail_command_scroll_offset: &mut self
+                                .process_detail_command_scroll_offset,
+                            process_filter: self.process_filter.clone(),
+                            process_user_filter: &self.process_user_filter,
+                            process_show_detail: self.process_show_details,
+                            current_showing_process_detail: &self.current_showing_process_detail,
+                            process_threads: process_threads.as_deref(),
+                            total_memory: self.sys_info.memory.total_memory,
+                            memory_unit: &self.memory_unit,
+                            is_filtering: self.state == AppState::Typing,
+                            graph_show_range: self.process_graph_shown_range,
+                            graph_offset: self.process_graph_offset,
+                            graph_style: self.process_graph_style,
+                            is_graph_axis_shown: self.is_graph_axis_shown,
+                            is_selected: self.selected_container == SelectedContainer::Process,
+                            app_color_info,
+                            is_full_screen: false,
+                            is_redacted,
+                            is_presentation_mode: self.is_presentation_mode,
+                            custom_columns: &self.custom_column_names,
+                            custom_column_values: &self.custom_column_values,
+                            graph_data_points: &mut self.graph_data_points,
+                        },
+                        process_area,
+                        frame,
+                    );
+                }
+
+                if let Some(plugins_area) =
+                    get_container_area(&container_areas, SelectedContainer::Plugins)
+                {
+                    draw_plugin_panels(
+                        &self.plugins,
+                        &self.plugin_data,
+                        plugins_area,
+                        frame,
+                        app_color_info,
+                    );
+                }
+            }
 
-                draw_disk_info(
-                    self.tick as u64,
-                    &selected_disk,
-                    disk_area,
+            if self.is_status_bar_shown {
+                draw_status_bar(
+                    &self.get_status_bar_hints(),
+                    status_bar_area,
                     frame,
-                    self.disk_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Disk {
-                        true
-                    } else {
-                        false
-                    },
                     app_color_info,
-                    false,
                 );
+            }
 
-                draw_network_info(
-                    self.tick as u64,
-                    &selected_network,
-                    network_area,
+            // render pop up after all the main components are rendered
+            // for the pop up size, it will be decide at the function according to the pop up type
+            if self.state == AppState::Popup && self.pop_up_type == AppPopUpType::ThemeMenu {
+                render_theme_menu_pop_up(
+                    full_frame_view_rect,
                     frame,
-                    self.network_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Network {
-                        true
-                    } else {
-                        false
-                    },
+                    &self.theme_names,
+                    &mut self.theme_selected_state,
                     app_color_info,
-                    false,
                 );
-
-                draw_process_info(
-                    self.tick as u64,
-                    &self.process_info.processes,
-                    &mut self.process_current_list,
-                    &mut self.process_selectable_entries,
-                    &mut self.process_selected_state,
-                    &self.process_sort_type,
-                    self.process_sort_is_reversed,
-                    self.process_filter.clone(),
-                    self.process_show_details,
-                    &self.current_showing_process_detail,
-                    self.sys_info.memory.total_memory,
-                    self.state == AppState::Typing,
-                    process_area,
+            } else if self.state == AppState::Popup && self.pop_up_type == AppPopUpType::ThemeWizard
+            {
+                if let Some(theme_wizard_state) = self.theme_wizard_state.as_mut() {
+                    render_theme_wizard_pop_up(
+                        full_frame_view_rect,
+                        frame,
+                        theme_wizard_state,
+                        app_color_info,
+                    );
+                }
+            } else if self.state == AppState::Popup && self.pop_up_type == AppPopUpType::GoToPid {
+                render_go_to_pid_pop_up(
+                    full_frame_view_rect,
                     frame,
-                    self.process_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Process {
-                        true
-                    } else {
-                        false
-                    },
+                    &self.go_to_pid_input,
                     app_color_info,
-                    false,
-                )
-            }
-
-            // render pop up after all the main components are rendered
-            // for the pop up size, it will be decide at the function according to the pop up type
-            if self.state == AppState::Popup && self.pop_up_type != AppPopUpType::None {
-                render_pop_up_menu(
+                );
+            } else if self.state == AppState::Popup && self.pop_up_type == AppPopUpType::UserMenu {
+                render_user_menu_pop_up(
                     full_frame_view_rect,
                     frame,
-                    &mut self.pop_up_type,
-                    self.current_process_signal_state_data.as_ref().unwrap(),
+                    &self.user_names,
+                    &mut self.user_selected_state,
+                    is_redacted,
                     app_color_info,
                 );
+            } else if self.state == AppState::Popup && self.pop_up_type != AppPopUpType::None {
+                if let Some(current_process_signal_state_data) =
+                    self.current_process_signal_state_data.as_ref()
+                {
+                    render_pop_up_menu(
+                        full_frame_view_rect,
+                        frame,
+                        &mut self.pop_up_type,
+                        current_process_signal_state_data,
+                        &self.process_info.processes,
+                        &mut self.signal_menu_selected_state,
+                        app_color_info,
+                    );
+                }
+            }
+
+            // the toast will be shown on top of everything until it expired
+            if let Some((message, shown_time)) = self.toast.as_ref() {
+                if shown_time.elapsed() < Duration::from_millis(TOAST_DURATION) {
+                    render_toast(full_frame_view_rect, frame, message, app_color_info);
+                } else {
+                    self.toast = None;
+                }
             }
         }
     }
 
-    fn handle_events(&mut self) {
-        if event::poll(std::time::Duration::from_millis(100)).unwrap() {
-            match event::read().unwrap() {
-                // it's important to check that the event is a key press event as
-                // crossterm also emits key release and repeat events on Windows.
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    if self.state == AppState::View {
-                        self.handle_key_event(key_event);
-                    } else if self.state == AppState::Typing {
-                        self.handle_typing_key_event(key_event);
-                    } else if self.state == AppState::Popup {
+    // return true if the event was handled, so its result will be drawn
+    fn handle_event(&mut self, event: Event) -> bool {
+        match event {
+            // it's important to check that the event is a key press event as
+            // crossterm also emits key release and repeat events on Windows.
+            // the compact view doesn't show the selection, the pop ups or the toasts, so Esc will only quit in it
+            // the pop up that was opened before the terminal shrank will be shown again when it was enlarged
+            Event::Key(key_event)
+                if key_event.kind == KeyEventKind::Press && self.is_compact_view =>
+            {
+                if key_event.code == KeyCode::Esc {
+                    self.is_quit = true;
+                }
+            }
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                if self.state == AppState::View {
+                    self.handle_key_event(key_event);
+                    // the containers that were hidden or left out of the layout can't be selected
+                    if !self.is_container_shown(self.selected_container) {
+                        self.selected_container = SelectedContainer::None;
+                        self.container_full_screen = false;
+                    }
+                } else if self.state == AppState::Typing {
+                    self.handle_typing_key_event(key_event);
+                } else if self.state == AppState::Popup {
+                    if self.pop_up_type == AppPopUpType::ThemeMenu {
+                        self.handle_theme_menu_event(key_event);
+                    } else if self.pop_up_type == AppPopUpType::ThemeWizard {
+                        self.handle_theme_wizard_event(key_event);
+                    } else if self.pop_up_type == AppPopUpType::UserMenu {
+                        self.handle_user_menu_event(key_event);
+                    } else if self.pop_up_type == AppPopUpType::GoToPid {
+                        self.handle_go_to_pid_event(key_event);
+                    } else {
                         self.handle_pop_up_event(key_event);
                     }
                 }
-                _ => {}
-            };
+            }
+            // clicking on a header of the process list sorts by its column
+            Event::Mouse(mouse_event)
+                if mouse_event.kind == MouseEventKind::Down(MouseButton::Left)
+                    && self.state == AppState::View
+                    && !self.is_compact_view =>
+            {
+                self.sort_by_process_header(mouse_event.column, mouse_event.row);
+            }
+            Event::FocusGained => self.set_focus(true),
+            Event::FocusLost => self.set_focus(false),
+            Event::Resize(_, _) => {}
+            // the key releases, the mouse moves and drags and the paste were ignored
+            _ => return false,
+        };
+        return true;
+    }
+
+    // sort by the column of the clicked header, clicking the header of the current sort reverses it
+    fn sort_by_process_header(&mut self, column: u16, row: u16) {
+        let Some(sort_type) = self
+            .process_header_columns
+            .iter()
+            .find(|(header_area, _)| header_area.contains(Position::new(column, row)))
+            .map(|(_, sort_type)| sort_type.clone())
+        else {
+            return;
+        };
+        self.selected_container = SelectedContainer::Process;
+        if sort_type == self.process_sort_type {
+            self.process_sort_is_reversed = !self.process_sort_is_reversed;
+        } else {
+            self.process_sort_selected_state = sort_type.get_sort_id();
+            self.process_sort_type = sort_type;
+        }
+    }
+
+    // slow down the collection while the terminal was unfocused, the configured ticks were restored on focus
+    fn set_focus(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+        if is_focused {
+            let _ = self.tick_tx.send(self.tick);
+            let _ = self.process_tick_tx.send(self.process_tick);
+        } else {
+            let _ = self.tick_tx.send(self.tick.max(UNFOCUSED_REFRESH_TICK));
+            let _ = self
+                .process_tick_tx
+                .send(self.process_tick.max(UNFOCUSED_REFRESH_TICK));
         }
     }
 
@@ -502,25 +1472,41 @@ impl App {
                 }
             }
 
+            // - and + for the refresh tick, the processes will be adjusted separately when the process container is selected
             KeyCode::Char('-') => {
                 if self.state == AppState::View {
-                    if self.tick > 100 {
+                    if self.selected_container == SelectedContainer::Process {
+                        if self.process_tick > MIN_REFRESH_TICK {
+                            self.process_tick -= 100;
+                            let _ = self.process_tick_tx.send(self.process_tick);
+                        }
+                    } else if self.tick > MIN_REFRESH_TICK {
                         self.tick -= 100;
-                        self.tick_tx.send(self.tick).unwrap();
-                        self.process_tick_tx.send(self.tick).unwrap();
+                        let _ = self.tick_tx.send(self.tick);
                     }
                 }
             }
             KeyCode::Char('+') => {
                 if self.state == AppState::View {
-                    if self.tick < 10000 {
+                    if self.selected_container == SelectedContainer::Process {
+                        if self.process_tick < MAX_REFRESH_TICK {
+                            self.process_tick += 100;
+                            let _ = self.process_tick_tx.send(self.process_tick);
+                        }
+                    } else if self.tick < MAX_REFRESH_TICK {
                         self.tick += 100;
-                        self.tick_tx.send(self.tick).unwrap();
-                        self.process_tick_tx.send(self.tick).unwrap();
+                        let _ = self.tick_tx.send(self.tick);
                     }
                 }
             }
 
+            // shift + up/down for scrolling the wrapped command in the process detail
+            KeyCode::Up if key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_process_detail_command(false);
+            }
+            KeyCode::Down if key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_process_detail_command(true);
+            }
             KeyCode::Up => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
@@ -529,9 +1515,17 @@ impl App {
                                 self.cpu_selected_state.select(Some(selected - 1));
                             } else {
                                 self.cpu_selected_state
-                                    .select(Some(self.sys_info.cpus.len() - 1))
+                                    .select(Some(self.cpu_selectable_entries.saturating_sub(1)))
                             }
                         }
+                    } else if self.selected_container == SelectedContainer::Network {
+                        // cycle through the addresses of the selected network
+                        if self.network_ip_selected_entry > 0 {
+                            self.network_ip_selected_entry -= 1;
+                        } else {
+                            self.network_ip_selected_entry =
+                                self.get_selected_network_ip_count().saturating_sub(1);
+                        }
                     } else if self.selected_container == SelectedContainer::Process {
                         if let Some(selected) = self.process_selected_state.selected() {
                             if selected > 0 {
@@ -547,12 +1541,20 @@ impl App {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
                         if let Some(selected) = self.cpu_selected_state.selected() {
-                            if selected < self.sys_info.cpus.len().saturating_sub(1) {
+                            if selected < self.cpu_selectable_entries.saturating_sub(1) {
                                 self.cpu_selected_state.select(Some(selected + 1));
                             } else {
                                 self.cpu_selected_state.select(Some(0))
                             }
                         }
+                    } else if self.selected_container == SelectedContainer::Network {
+                        // cycle through the addresses of the selected network
+                        if self.network_ip_selected_entry + 1 < self.get_selected_network_ip_count()
+                        {
+                            self.network_ip_selected_entry += 1;
+                        } else {
+                            self.network_ip_selected_entry = 0;
+                        }
                     } else if self.selected_container == SelectedContainer::Process {
                         if let Some(selected) = self.process_selected_state.selected() {
                             if selected < self.process_selectable_entries.saturating_sub(1) {
@@ -609,47 +1611,71 @@ impl App {
             KeyCode::Char(']') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
-                        if self.cpu_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.cpu_graph_shown_range < get_maximum_data_collection() {
                             self.cpu_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Memory {
-                        if self.memory_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.memory_graph_shown_range < get_maximum_data_collection() {
                             self.memory_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Disk {
-                        if self.disk_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.disk_graph_shown_range < get_maximum_data_collection() {
                             self.disk_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Network {
-                        if self.network_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.network_graph_shown_range < get_maximum_data_collection() {
                             self.network_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Process {
-                        if self.process_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.process_graph_shown_range < get_maximum_process_data_collection() {
                             self.process_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::None {
-                        if self.cpu_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.cpu_graph_shown_range < get_maximum_data_collection() {
                             self.cpu_graph_shown_range += 10;
                         }
-                        if self.memory_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.memory_graph_shown_range < get_maximum_data_collection() {
                             self.memory_graph_shown_range += 10;
                         }
-                        if self.disk_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.disk_graph_shown_range < get_maximum_data_collection() {
                             self.disk_graph_shown_range += 10;
                         }
-                        if self.network_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.network_graph_shown_range < get_maximum_data_collection() {
                             self.network_graph_shown_range += 10;
                         }
-                        if self.process_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.process_graph_shown_range < get_maximum_process_data_collection() {
                             self.process_graph_shown_range += 10;
                         }
                     }
                 }
             }
 
-            // c and C for selecting the Cpu Block
-            KeyCode::Char('c') => {
+            // { and } for panning the graphs back and forward through the history
+            KeyCode::Char('{') => {
+                if self.state == AppState::View {
+                    self.pan_graph_history(true);
+                }
+            }
+            KeyCode::Char('}') => {
+                if self.state == AppState::View {
+                    self.pan_graph_history(false);
+                }
+            }
+
+            // w and W for switching the graph style of the selected container
+            KeyCode::Char('w') => {
+                if self.state == AppState::View {
+                    self.switch_graph_style();
+                }
+            }
+            KeyCode::Char('W') => {
+                if self.state == AppState::View {
+                    self.switch_graph_style();
+                }
+            }
+
+            // c for selecting the Cpu Block, Shift+C to hide / show it
+            KeyCode::Char('c') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::None
                         || self.selected_container != SelectedContainer::Cpu
@@ -663,18 +1689,11 @@ impl App {
             }
             KeyCode::Char('C') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::None
-                        || self.selected_container != SelectedContainer::Cpu
-                    {
-                        self.selected_container = SelectedContainer::Cpu;
-                    } else {
-                        self.container_full_screen = false;
-                        self.selected_container = SelectedContainer::None;
-                    }
+                    self.toggle_container_visibility(SelectedContainer::Cpu);
                 }
             }
 
-            // m and M for selecting the Memory Block
+            // m for selecting the Memory Block, Shift+M to hide / show it
             KeyCode::Char('m') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::None
@@ -689,18 +1708,11 @@ impl App {
             }
             KeyCode::Char('M') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::None
-                        || self.selected_container != SelectedContainer::Memory
-                    {
-                        self.selected_container = SelectedContainer::Memory;
-                    } else {
-                        self.container_full_screen = false;
-                        self.selected_container = SelectedContainer::None;
-                    }
+                    self.toggle_container_visibility(SelectedContainer::Memory);
                 }
             }
 
-            // d and D for selecting the Disk Block
+            // d for selecting the Disk Block, Shift+D to hide / show it
             KeyCode::Char('d') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::None
@@ -715,18 +1727,11 @@ impl App {
             }
             KeyCode::Char('D') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::None
-                        || self.selected_container != SelectedContainer::Disk
-                    {
-                        self.selected_container = SelectedContainer::Disk;
-                    } else {
-                        self.container_full_screen = false;
-                        self.selected_container = SelectedContainer::None;
-                    }
+                    self.toggle_container_visibility(SelectedContainer::Disk);
                 }
             }
 
-            // n and N for selecting the Disk Block
+            // n for selecting the Network Block, Shift+N to hide / show it
             KeyCode::Char('n') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::None
@@ -741,18 +1746,11 @@ impl App {
             }
             KeyCode::Char('N') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::None
-                        || self.selected_container != SelectedContainer::Network
-                    {
-                        self.selected_container = SelectedContainer::Network;
-                    } else {
-                        self.container_full_screen = false;
-                        self.selected_container = SelectedContainer::None;
-                    }
+                    self.toggle_container_visibility(SelectedContainer::Network);
                 }
             }
 
-            // p and P for selecting the Process Block
+            // p for selecting the Process Block, Shift+P to hide / show it
             KeyCode::Char('p') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::None
@@ -767,14 +1765,7 @@ impl App {
             }
             KeyCode::Char('P') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::None
-                        || self.selected_container != SelectedContainer::Process
-                    {
-                        self.selected_container = SelectedContainer::Process;
-                    } else {
-                        self.container_full_screen = false;
-                        self.selected_container = SelectedContainer::None;
-                    }
+                    self.toggle_container_visibility(SelectedContainer::Process);
                 }
             }
 
@@ -820,234 +1811,267 @@ impl App {
                 }
             }
 
+            // k, t and s for killing, terminating or signaling the process of the detail or the selected row
             KeyCode::Char('K') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                name: program_name,
-                                signal: Some(Signal::Kill),
-                                signal_id: Some(9),
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::KillConfirmation;
-                    }
+                    self.open_signal_pop_up(
+                        Some(Signal::Kill),
+                        Some(9),
+                        AppPopUpType::KillConfirmation,
+                    );
                 }
             }
 
             KeyCode::Char('k') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
-
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                name: program_name,
-                                signal: Some(Signal::Kill),
-                                signal_id: Some(9),
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::KillConfirmation;
-                    }
+                    self.open_signal_pop_up(
+                        Some(Signal::Kill),
+                        Some(9),
+                        AppPopUpType::KillConfirmation,
+                    );
                 }
             }
 
             KeyCode::Char('T') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
-
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                name: program_name,
-                                signal: Some(Signal::Term),
-                                signal_id: Some(15),
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::TerminateConfirmation;
-                    }
+                    self.open_signal_pop_up(
+                        Some(Signal::Term),
+                        Some(15),
+                        AppPopUpType::TerminateConfirmation,
+                    );
                 }
             }
 
             KeyCode::Char('t') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
-
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                name: program_name,
-                                signal: Some(Signal::Term),
-                                signal_id: Some(15),
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::TerminateConfirmation;
-                    }
+                    self.open_signal_pop_up(
+                        Some(Signal::Term),
+                        Some(15),
+                        AppPopUpType::TerminateConfirmation,
+                    );
                 }
             }
 
             KeyCode::Char('S') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
-
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                signal: None,
-                                signal_id: None,
-                                name: program_name,
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::SignalMenu;
-                    }
+                    self.open_signal_pop_up(None, None, AppPopUpType::SignalMenu);
                 }
             }
 
             KeyCode::Char('s') => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Process
-                        && self.process_show_details
-                        && self.current_showing_process_detail.is_some()
-                        && self.process_selected_state.selected().is_none()
-                    {
-                        let (key, value) = self
-                            .current_showing_process_detail
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .next()
-                            .unwrap();
-                        // do nothing if the status is killed
-                        if value.status == "killed" {
-                            return;
-                        }
+                    self.open_signal_pop_up(None, None, AppPopUpType::SignalMenu);
+                }
+            }
 
-                        let program_pib = key.clone();
-                        let program_name = value.name.clone();
-
-                        self.current_process_signal_state_data =
-                            Some(CurrentProcessSignalStateData {
-                                pid: program_pib,
-                                signal: None,
-                                signal_id: None,
-                                name: program_name,
-                                yes_confirmation: true,
-                                no_confirmation: false,
-                            });
-                        self.state = AppState::Popup;
-                        self.pop_up_type = AppPopUpType::SignalMenu;
-                    }
+            // o and O for opening the theme menu
+            KeyCode::Char('o') => {
+                if self.state == AppState::View {
+                    self.open_theme_menu();
+                }
+            }
+            KeyCode::Char('O') => {
+                if self.state == AppState::View {
+                    self.open_theme_menu();
+                }
+            }
+
+            // z and Z for toggling the presentation mode
+            KeyCode::Char('z') => {
+                if self.state == AppState::View {
+                    self.is_presentation_mode = !self.is_presentation_mode;
+                }
+            }
+            KeyCode::Char('Z') => {
+                if self.state == AppState::View {
+                    self.is_presentation_mode = !self.is_presentation_mode;
+                }
+            }
+
+            // i and I for cycling the unit of the memory values ( auto, MiB, GiB, % )
+            KeyCode::Char('i') => {
+                if self.state == AppState::View {
+                    self.memory_unit = self.memory_unit.get_next();
+                }
+            }
+            KeyCode::Char('I') => {
+                if self.state == AppState::View {
+                    self.memory_unit = self.memory_unit.get_next();
+                }
+            }
+
+            // b and B for switching the network rates between bytes and bits per second
+            KeyCode::Char('b') => {
+                if self.state == AppState::View {
+                    self.network_unit = self.network_unit.get_next();
+                }
+            }
+            KeyCode::Char('B') => {
+                if self.state == AppState::View {
+                    self.network_unit = self.network_unit.get_next();
+                }
+            }
+
+            // l and L for toggling the list of all disks in the disk container
+            // or the threads of the process in the process detail
+            KeyCode::Char('l') => {
+                if self.state == AppState::View {
+                    self.toggle_disk_list_view();
+                    self.toggle_process_thread_view();
+                }
+            }
+            KeyCode::Char('L') => {
+                if self.state == AppState::View {
+                    self.toggle_disk_list_view();
+                    self.toggle_process_thread_view();
+                }
+            }
+
+            // a and A for toggling the hyperthread siblings aggregation in the cpu list
+            KeyCode::Char('a') => {
+                if self.state == AppState::View {
+                    self.toggle_cpu_ht_aggregation();
+                }
+            }
+            KeyCode::Char('A') => {
+                if self.state == AppState::View {
+                    self.toggle_cpu_ht_aggregation();
+                }
+            }
+
+            // v and V for toggling the per core grid view of the full screen cpu container
+            KeyCode::Char('v') => {
+                if self.state == AppState::View {
+                    self.toggle_cpu_grid_view();
+                }
+            }
+            KeyCode::Char('V') => {
+                if self.state == AppState::View {
+                    self.toggle_cpu_grid_view();
+                }
+            }
+
+            // g and G for jumping to the parent process of the current showing process detail
+            KeyCode::Char('g') => {
+                if self.state == AppState::View {
+                    self.go_to_parent_process();
+                }
+            }
+            KeyCode::Char('G') => {
+                if self.state == AppState::View {
+                    self.go_to_parent_process();
+                }
+            }
+
+            // j and J for cycling through the child processes of the current showing process detail
+            KeyCode::Char('j') => {
+                if self.state == AppState::View {
+                    self.go_to_next_child_process();
+                }
+            }
+            KeyCode::Char('J') => {
+                if self.state == AppState::View {
+                    self.go_to_next_child_process();
+                }
+            }
+
+            // y and Y for copying the command of the process in the detail or the selected row
+            KeyCode::Char('y') => {
+                if self.state == AppState::View {
+                    self.copy_process_to_clipboard(false);
+                }
+            }
+            KeyCode::Char('Y') => {
+                if self.state == AppState::View {
+                    self.copy_process_to_clipboard(false);
+                }
+            }
+            // # for copying the PID of the process in the detail or the selected row
+            KeyCode::Char('#') => {
+                if self.state == AppState::View {
+                    self.copy_process_to_clipboard(true);
+                }
+            }
+
+            // : for jumping to a process by its PID
+            KeyCode::Char(':') => {
+                if self.state == AppState::View {
+                    self.go_to_pid_input = String::new();
+                    self.state = AppState::Popup;
+                    self.pop_up_type = AppPopUpType::GoToPid;
+                }
+            }
+
+            // u and U for opening the user menu to filter the process list by user
+            KeyCode::Char('u') => {
+                if self.state == AppState::View {
+                    self.open_user_menu();
+                }
+            }
+            KeyCode::Char('U') => {
+                if self.state == AppState::View {
+                    self.open_user_menu();
+                }
+            }
+
+            // x and X for resetting the session counters of the selected disk / network container
+            KeyCode::Char('x') => {
+                if self.state == AppState::View {
+                    self.reset_session_counters();
+                }
+            }
+            KeyCode::Char('X') => {
+                if self.state == AppState::View {
+                    self.reset_session_counters();
+                }
+            }
+
+            // space for pausing / resuming the data updates
+            KeyCode::Char(' ') => {
+                if self.state == AppState::View {
+                    self.is_paused = !self.is_paused;
+                }
+            }
+
+            // h and H for toggling the privacy mode
+            KeyCode::Char('h') => {
+                if self.state == AppState::View {
+                    self.is_privacy_mode = !self.is_privacy_mode;
+                }
+            }
+            KeyCode::Char('H') => {
+                if self.state == AppState::View {
+                    self.is_privacy_mode = !self.is_privacy_mode;
                 }
             }
 
+            // shift + left/right or < and > for scrolling the command column of the process list horizontally
+            KeyCode::Left if key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_process_command(false);
+            }
+            KeyCode::Right if key_event.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_process_command(true);
+            }
+            KeyCode::Char('<') => {
+                self.scroll_process_command(false);
+            }
+            KeyCode::Char('>') => {
+                self.scroll_process_command(true);
+            }
+
             KeyCode::Left => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Disk {
                         if self.disk_selected_entry == 0 {
-                            self.disk_selected_entry = self.sys_info.disks.len() - 1;
+                            self.disk_selected_entry = self.sys_info.disks.len().saturating_sub(1);
                         } else {
                             self.disk_selected_entry -= 1;
                         }
                     } else if self.selected_container == SelectedContainer::Network {
+                        // the networks have an extra entry for all the interfaces at the front
+                        self.network_ip_selected_entry = 0;
                         if self.network_selected_entry == 0 {
-                            self.network_selected_entry = self.sys_info.networks.len() - 1;
+                            self.network_selected_entry = self.sys_info.networks.len();
                         } else {
                             self.network_selected_entry -= 1;
                         }
@@ -1067,13 +2091,14 @@ impl App {
             KeyCode::Right => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Disk {
-                        if self.disk_selected_entry == self.sys_info.disks.len() - 1 {
+                        if self.disk_selected_entry + 1 >= self.sys_info.disks.len() {
                             self.disk_selected_entry = 0
                         } else {
                             self.disk_selected_entry += 1;
                         }
                     } else if self.selected_container == SelectedContainer::Network {
-                        if self.network_selected_entry == self.sys_info.networks.len() - 1 {
+                        self.network_ip_selected_entry = 0;
+                        if self.network_selected_entry >= self.sys_info.networks.len() {
                             self.network_selected_entry = 0;
                         } else {
                             self.network_selected_entry += 1;
@@ -1118,14 +2143,18 @@ impl App {
             KeyCode::Enter => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Process {
-                        if let Some(selected) = self.process_selected_state.selected() {
+                        if let Some(selected_process) = self
+                            .process_selected_state
+                            .selected()
+                            .and_then(|selected| self.process_current_list.get(selected))
+                            .and_then(|pid| self.process_info.processes.get(&pid.to_string()))
+                        {
                             self.process_show_details = true;
-                            let mut selected_process = HashMap::new();
-                            selected_process.insert(
-                                self.process_current_list[selected].pid.to_string(),
-                                self.process_current_list[selected].clone(),
-                            );
-                            self.current_showing_process_detail = Some(selected_process);
+                            self.current_showing_process_detail = Some(HashMap::from([(
+                                selected_process.pid.to_string(),
+                                selected_process.clone(),
+                            )]));
+                            self.process_detail_command_scroll_offset = 0;
 
                             // unselect current selected process item list to enter the process detail container
                             self.process_selected_state.select(None);
@@ -1136,6 +2165,32 @@ impl App {
                     }
                 }
             }
+
+            // , and . for shrinking and growing the share of the selected container in the layout
+            KeyCode::Char(',') => {
+                if self.state == AppState::View {
+                    self.resize_selected_container(-CONTAINER_RESIZE_STEP);
+                }
+            }
+            KeyCode::Char('.') => {
+                if self.state == AppState::View {
+                    self.resize_selected_container(CONTAINER_RESIZE_STEP);
+                }
+            }
+
+            // 0 for cycling through the layout presets
+            KeyCode::Char('0') => {
+                if self.state == AppState::View {
+                    self.cycle_layout_preset();
+                }
+            }
+
+            // 1 - 9 for switching the tabs
+            KeyCode::Char(number @ '1'..='9') => {
+                if self.state == AppState::View {
+                    self.switch_tab(number as usize - '1' as usize);
+                }
+            }
             _ => {}
         }
     }
@@ -1178,60 +2233,89 @@ impl App {
                 self.pop_up_type = AppPopUpType::None;
                 self.current_process_signal_state_data = None;
             }
-            KeyCode::Char('y') => {
-                if self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .signal
-                    .is_some()
-                {
-                    let pid = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .pid
-                        .parse::<usize>()
-                        .unwrap();
-                    let signal = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal
-                        .unwrap();
-                    send_signal(pid, signal);
+            // the letters were the signal name in the signal menu, so they won't be taken as the shortcuts below
+            KeyCode::Char(c)
+                if c.is_ascii_alphabetic() && self.pop_up_type == AppPopUpType::SignalMenu =>
+            {
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    signal_state_data
+                        .signal_name_input
+                        .push(c.to_ascii_uppercase());
+                    let new_signal_id =
+                        get_signal_id_from_name(&signal_state_data.signal_name_input);
+                    signal_state_data.signal_id = new_signal_id;
+                    signal_state_data.signal = new_signal_id.map(get_signal_from_int);
+                }
+            }
+            // up and down select the previous or next signal in the signal menu
+            KeyCode::Up => {
+                if self.pop_up_type == AppPopUpType::SignalMenu {
+                    if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut()
+                    {
+                        let new_signal_id = match signal_state_data.signal_id {
+                            Some(signal_id) => signal_id.saturating_sub(1),
+                            None => 0,
+                        };
+                        signal_state_data.signal_name_input.clear();
+                        signal_state_data.signal_id = Some(new_signal_id);
+                        signal_state_data.signal = Some(get_signal_from_int(new_signal_id));
+                    }
+                }
+            }
+            KeyCode::Down => {
+                if self.pop_up_type == AppPopUpType::SignalMenu {
+                    if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut()
+                    {
+                        let new_signal_id = match signal_state_data.signal_id {
+                            Some(signal_id) => (signal_id + 1).min(MAX_SIGNAL_ID),
+                            None => 0,
+                        };
+                        signal_state_data.signal_name_input.clear();
+                        signal_state_data.signal_id = Some(new_signal_id);
+                        signal_state_data.signal = Some(get_signal_from_int(new_signal_id));
+                    }
+                }
+            }
+            // tab completes the typed signal name
+            KeyCode::Tab => {
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    if let (Some(signal), false) = (
+                        signal_state_data.signal,
+                        signal_state_data.signal_name_input.is_empty(),
+                    ) {
+                        signal_state_data.signal_name_input = signal.get_display_name();
+                    }
                 }
+            }
+            KeyCode::Char('y') => {
+                self.send_pop_up_signal();
                 self.state = AppState::View;
                 self.pop_up_type = AppPopUpType::None;
                 self.current_process_signal_state_data = None;
             }
             KeyCode::Char('Y') => {
-                if self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .signal
-                    .is_some()
-                {
-                    let pid = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .pid
-                        .parse::<usize>()
-                        .unwrap();
-                    let signal = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal
-                        .unwrap();
-                    send_signal(pid, signal);
-                }
+                self.send_pop_up_signal();
                 self.state = AppState::View;
                 self.pop_up_type = AppPopUpType::None;
                 self.current_process_signal_state_data = None;
             }
+            // t and T for sending the signal to the whole process tree in the kill or terminate confirmation
+            KeyCode::Char('t') => {
+                if self.is_confirmation_pop_up() {
+                    self.send_pop_up_signal_to_tree();
+                    self.state = AppState::View;
+                    self.pop_up_type = AppPopUpType::None;
+                    self.current_process_signal_state_data = None;
+                }
+            }
+            KeyCode::Char('T') => {
+                if self.is_confirmation_pop_up() {
+                    self.send_pop_up_signal_to_tree();
+                    self.state = AppState::View;
+                    self.pop_up_type = AppPopUpType::None;
+                    self.current_process_signal_state_data = None;
+                }
+            }
             KeyCode::Char('n') => {
                 self.state = AppState::View;
                 self.pop_up_type = AppPopUpType::None;
@@ -1242,164 +2326,1161 @@ impl App {
                 self.pop_up_type = AppPopUpType::None;
                 self.current_process_signal_state_data = None;
             }
+            // the buttons were yes, tree and no from left to right, the tree button was only in the confirmation
             KeyCode::Left => {
-                self.current_process_signal_state_data
-                    .as_mut()
-                    .unwrap()
-                    .yes_confirmation = true;
-                self.current_process_signal_state_data
-                    .as_mut()
-                    .unwrap()
-                    .no_confirmation = false;
+                let is_confirmation_pop_up = self.is_confirmation_pop_up();
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    if signal_state_data.no_confirmation && is_confirmation_pop_up {
+                        signal_state_data.yes_confirmation = false;
+                        signal_state_data.tree_confirmation = true;
+                    } else {
+                        signal_state_data.yes_confirmation = true;
+                        signal_state_data.tree_confirmation = false;
+                    }
+                    signal_state_data.no_confirmation = false;
+                }
             }
             KeyCode::Right => {
-                self.current_process_signal_state_data
-                    .as_mut()
-                    .unwrap()
-                    .yes_confirmation = false;
-                self.current_process_signal_state_data
-                    .as_mut()
-                    .unwrap()
-                    .no_confirmation = true;
+                let is_confirmation_pop_up = self.is_confirmation_pop_up();
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    if signal_state_data.yes_confirmation && is_confirmation_pop_up {
+                        signal_state_data.tree_confirmation = true;
+                        signal_state_data.no_confirmation = false;
+                    } else {
+                        signal_state_data.tree_confirmation = false;
+                        signal_state_data.no_confirmation = true;
+                    }
+                    signal_state_data.yes_confirmation = false;
+                }
             }
             KeyCode::Enter => {
-                let yes_confirmation = self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .yes_confirmation;
-                let no_confirmation = self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .no_confirmation;
-
-                if yes_confirmation
-                    && !no_confirmation
-                    && self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal
-                        .is_some()
-                {
-                    let pid = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .pid
-                        .parse::<usize>()
-                        .unwrap();
-                    let signal = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal
-                        .unwrap();
-                    send_signal(pid, signal);
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_ref() {
+                    if signal_state_data.tree_confirmation {
+                        self.send_pop_up_signal_to_tree();
+                    } else if signal_state_data.yes_confirmation
+                        && !signal_state_data.no_confirmation
+                    {
+                        self.send_pop_up_signal();
+                    }
                 }
                 self.state = AppState::View;
                 self.pop_up_type = AppPopUpType::None;
                 self.current_process_signal_state_data = None
             }
             KeyCode::Char(c) if c.is_ascii_digit() => {
-                if self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .signal_id
-                    .is_none()
-                {
-                    self.current_process_signal_state_data
-                        .as_mut()
-                        .unwrap()
-                        .signal_id = Some(c.to_digit(10).unwrap() as u16);
-                } else {
-                    let mut current_signal_id_string = self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal_id
-                        .unwrap()
-                        .to_string();
-                    current_signal_id_string.push(c);
-
-                    let new_signal_id: u16 = current_signal_id_string.parse().unwrap();
-                    if new_signal_id > 0 && new_signal_id <= 30 {
-                        self.current_process_signal_state_data
-                            .as_mut()
-                            .unwrap()
-                            .signal_id = Some(new_signal_id);
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    // the typed digit starts over the signal id if a signal name was typed
+                    if !signal_state_data.signal_name_input.is_empty() {
+                        signal_state_data.signal_name_input.clear();
+                        signal_state_data.signal_id = None;
                     }
+                    let new_signal_id = match signal_state_data.signal_id {
+                        None => c.to_digit(10).map(|digit| digit as u16),
+                        Some(signal_id) => {
+                            // the typed digit was ignored if the signal id will be out of range
+                            match format!("{}{}", signal_id, c).parse::<u16>() {
+                                Ok(new_signal_id)
+                                    if new_signal_id > 0 && new_signal_id <= MAX_SIGNAL_ID =>
+                                {
+                                    Some(new_signal_id)
+                                }
+                                _ => Some(signal_id),
+                            }
+                        }
+                    };
+                    signal_state_data.signal_id = new_signal_id;
+                    signal_state_data.signal = new_signal_id.map(get_signal_from_int);
                 }
-
-                self.current_process_signal_state_data
-                    .as_mut()
-                    .unwrap()
-                    .signal = Some(get_signal_from_int(
-                    self.current_process_signal_state_data
-                        .as_mut()
-                        .unwrap()
-                        .signal_id
-                        .unwrap(),
-                ))
             }
             KeyCode::Backspace => {
-                if !self
-                    .current_process_signal_state_data
-                    .as_ref()
-                    .unwrap()
-                    .signal_id
-                    .is_none()
-                {
-                    if self
-                        .current_process_signal_state_data
-                        .as_ref()
-                        .unwrap()
-                        .signal_id
-                        .unwrap()
-                        .to_string()
-                        .len()
-                        == 1
-                    {
-                        self.current_process_signal_state_data
-                            .as_mut()
-                            .unwrap()
-                            .signal_id = None;
-                        self.current_process_signal_state_data
-                            .as_mut()
-                            .unwrap()
-                            .signal = None;
-                    } else {
-                        let mut new_signal_id_string = self
-                            .current_process_signal_state_data
-                            .as_ref()
-                            .unwrap()
-                            .signal_id
-                            .unwrap()
-                            .to_string();
-                        new_signal_id_string.pop();
-
-                        self.current_process_signal_state_data
-                            .as_mut()
-                            .unwrap()
-                            .signal_id = Some(new_signal_id_string.parse::<u16>().unwrap());
-                        self.current_process_signal_state_data
-                            .as_mut()
-                            .unwrap()
-                            .signal = Some(get_signal_from_int(
-                            new_signal_id_string.parse::<u16>().unwrap(),
-                        ));
+                if let Some(signal_state_data) = self.current_process_signal_state_data.as_mut() {
+                    if !signal_state_data.signal_name_input.is_empty() {
+                        // remove the last letter of the signal name and complete it again
+                        signal_state_data.signal_name_input.pop();
+                        let new_signal_id = if signal_state_data.signal_name_input.is_empty() {
+                            None
+                        } else {
+                            get_signal_id_from_name(&signal_state_data.signal_name_input)
+                        };
+                        signal_state_data.signal_id = new_signal_id;
+                        signal_state_data.signal = new_signal_id.map(get_signal_from_int);
+                    } else if let Some(signal_id) = signal_state_data.signal_id {
+                        // remove the last digit, the signal id was cleared when it was the only digit
+                        let new_signal_id = if signal_id < 10 {
+                            None
+                        } else {
+                            Some(signal_id / 10)
+                        };
+                        signal_state_data.signal_id = new_signal_id;
+                        signal_state_data.signal = new_signal_id.map(get_signal_from_int);
                     }
                 }
             }
             _ => {}
         }
     }
-}
 
-fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo) {
-    let block = Block::bordered()
+    // send the chosen signal to the process of the pop up, nothing will be sent without a signal or a valid pid
+    fn send_pop_up_signal(&self) {
+        let Some(signal_state_data) = self.current_process_signal_state_data.as_ref() else {
+            return;
+        };
+        if let (Some(signal), Ok(pid)) = (
+            signal_state_data.signal,
+            signal_state_data.pid.parse::<usize>(),
+        ) {
+            send_signal(pid, signal);
+        }
+    }
+
+    // the process that the signal will be sent to or copied, the process of the detail when it was shown and
+    // no row was selected, otherwise the selected row of the process list
+    fn get_target_process(&self) -> Option<(String, String, String)> {
+        if self.selected_container != SelectedContainer::Process {
+            return None;
+        }
+        if let Some(selected) = self.process_selected_state.selected() {
+            let pid = self.process_current_list.get(selected)?.to_string();
+            let process = self.process_info.processes.get(&pid)?;
+            return Some((pid, process.name.clone(), process.status.clone()));
+        }
+        if !self.process_show_details {
+            return None;
+        }
+        return self
+            .current_showing_process_detail
+            .as_ref()
+            .and_then(|process_detail| process_detail.iter().next())
+            .map(|(pid, process)| (pid.clone(), process.name.clone(), process.status.clone()));
+    }
+
+    // open the kill, terminate confirmation or the signal menu for the target process
+    fn open_signal_pop_up(
+        &mut self,
+        signal: Option<Signal>,
+        signal_id: Option<u16>,
+        pop_up_type: AppPopUpType,
+    ) {
+        let Some((pid, name, status)) = self.get_target_process() else {
+            return;
+        };
+        // do nothing if the status is killed
+        if status == "killed" {
+            return;
+        }
+        self.current_process_signal_state_data = Some(CurrentProcessSignalStateData {
+            pid,
+            name,
+            signal,
+            signal_id,
+            yes_confirmation: true,
+            no_confirmation: false,
+            tree_confirmation: false,
+            signal_name_input: String::new(),
+        });
+        self.state = AppState::Popup;
+        self.pop_up_type = pop_up_type;
+    }
+
+    fn is_confirmation_pop_up(&self) -> bool {
+        return self.pop_up_type == AppPopUpType::KillConfirmation
+            || self.pop_up_type == AppPopUpType::TerminateConfirmation;
+    }
+
+    // send the chosen signal to all the descendants of the process of the pop up and then the process itself,
+    // the children were signaled before their parent so they won't be reparented and survive
+    fn send_pop_up_signal_to_tree(&self) {
+        let Some(signal_state_data) = self.current_process_signal_state_data.as_ref() else {
+            return;
+        };
+        let Some(signal) = signal_state_data.signal else {
+            return;
+        };
+        let mut pids = get_descendant_pids(&signal_state_data.pid, &self.process_info.processes);
+        pids.push(signal_state_data.pid.clone());
+        let pids: Vec<usize> = pids
+            .iter()
+            .filter_map(|pid| pid.parse::<usize>().ok())
+            .collect();
+        send_signal_to_pids(pids, signal);
+    }
+
+    fn scroll_process_command(&mut self, is_scroll_right: bool) {
+        if self.state == AppState::View && self.selected_container == SelectedContainer::Process {
+            if is_scroll_right {
+                self.process_command_scroll_offset += PROCESS_COMMAND_SCROLL_STEP;
+            } else {
+                self.process_command_scroll_offset = self
+                    .process_command_scroll_offset
+                    .saturating_sub(PROCESS_COMMAND_SCROLL_STEP);
+            }
+        }
+    }
+
+    // the offset was clamped to the number of the wrapped lines when the detail was drawn
+    fn scroll_process_detail_command(&mut self, is_scroll_down: bool) {
+        if self.state == AppState::View && self.get_current_detail_pid_and_parent().is_some() {
+            if is_scroll_down {
+                self.process_detail_command_scroll_offset += 1;
+            } else {
+                self.process_detail_command_scroll_offset =
+                    self.process_detail_command_scroll_offset.saturating_sub(1);
+            }
+        }
+    }
+
+    // the full command was copied, even in presentation or privacy mode as it won't be shown on the screen
+    fn copy_process_to_clipboard(&mut self, is_pid: bool) {
+        let Some((pid, _, _)) = self.get_target_process() else {
+            return;
+        };
+        if is_pid {
+            copy_to_clipboard(&pid);
+            self.show_toast(format!("Copied the PID {} to the clipboard", pid));
+            return;
+        }
+        let Some(process) = self.process_info.processes.get(&pid) else {
+            return;
+        };
+        let command = if process.cmd.is_empty() {
+            process.name.clone()
+        } else {
+            process.cmd.join(" ")
+        };
+        copy_to_clipboard(&command);
+        self.show_toast(format!(
+            "Copied the command of PID {} to the clipboard",
+            pid
+        ));
+    }
+
+    fn handle_theme_menu_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Esc => {
+                // revert back to the theme before the theme menu was opened
+                self.app_color_info = self.get_app_color_info(&self.current_theme);
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+            }
+            KeyCode::Up => {
+                if let Some(selected) = self.theme_selected_state.selected() {
+                    if selected > 0 {
+                        self.theme_selected_state.select(Some(selected - 1));
+                    } else {
+                        self.theme_selected_state
+                            .select(Some(self.theme_names.len() - 1));
+                    }
+                    self.preview_selected_theme();
+                }
+            }
+            KeyCode::Down => {
+                if let Some(selected) = self.theme_selected_state.selected() {
+                    if selected < self.theme_names.len() - 1 {
+                        self.theme_selected_state.select(Some(selected + 1));
+                    } else {
+                        self.theme_selected_state.select(Some(0));
+                    }
+                    self.preview_selected_theme();
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(selected) = self.theme_selected_state.selected() {
+                    self.current_theme = self.theme_names[selected].clone();
+                    self.app_color_info = self.get_app_color_info(&self.current_theme);
+                    self.save_theme(self.current_theme.clone());
+                }
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+            }
+            // e and E for creating a new theme from the currently previewed theme
+            KeyCode::Char('e') => {
+                self.open_theme_wizard();
+            }
+            KeyCode::Char('E') => {
+                self.open_theme_wizard();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_go_to_pid_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+            }
+            KeyCode::Backspace => {
+                self.go_to_pid_input.pop();
+            }
+            KeyCode::Char(c) => {
+                if c.is_ascii_digit() {
+                    self.go_to_pid_input.push(c);
+                }
+            }
+            KeyCode::Enter => {
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+                self.go_to_pid(self.go_to_pid_input.clone());
+            }
+            _ => {}
+        }
+    }
+
+    // open the process detail of the given PID, a toast will be shown if the process doesn't exist
+    fn go_to_pid(&mut self, pid: String) {
+        match self.process_info.processes.get(&pid) {
+            Some(process) => {
+                self.selected_container = SelectedContainer::Process;
+                self.process_show_details = true;
+                let mut selected_process = HashMap::new();
+                selected_process.insert(pid, process.clone());
+                self.current_showing_process_detail = Some(selected_process);
+                self.process_detail_command_scroll_offset = 0;
+
+                // unselect current selected process item list to enter the process detail container
+                self.process_selected_state.select(None);
+            }
+            None => {
+                self.show_toast(format!("Process with PID {} was not found", pid));
+            }
+        }
+    }
+
+    fn toggle_cpu_ht_aggregation(&mut self) {
+        if self.selected_container == SelectedContainer::Cpu
+            && is_cpu_topology_detected(&self.sys_info.cpus)
+        {
+            self.is_cpu_ht_aggregated = !self.is_cpu_ht_aggregated;
+            // the entries will be different after toggling, so we go back to the average cpu
+            self.cpu_selected_state.select(Some(0));
+        }
+    }
+
+    // the number of addresses of the selected network, the entry for all the interfaces doesn't have any
+    fn get_selected_network_ip_count(&self) -> usize {
+        if self.network_selected_entry == 0 {
+            return 0;
+        }
+        return self
+            .sys_info
+            .networks
+            .iter()
+            .nth(self.network_selected_entry - 1)
+            .map(|(_, network)| network.ip_networks.len())
+            .unwrap_or(0);
+    }
+
+    fn toggle_disk_list_view(&mut self) {
+        if self.selected_container == SelectedContainer::Disk {
+            self.is_disk_list_view = !self.is_disk_list_view;
+        }
+    }
+
+    // switch the memory graph of the process detail with the threads of the process
+    fn toggle_process_thread_view(&mut self) {
+        if self.get_current_detail_pid_and_parent().is_some() {
+            self.is_process_thread_view = !self.is_process_thread_view;
+        }
+    }
+
+    // the pid of the process in the process detail, even while the process list was being navigated
+    fn get_current_showing_process_pid(&self) -> Option<u32> {
+        return self
+            .current_showing_process_detail
+            .as_ref()
+            .and_then(|process_detail| process_detail.values().next())
+            .map(|process| process.pid);
+    }
+
+    // the threads of the process in the process detail while the threads were shown, empty until they were collected
+    fn get_current_showing_process_threads(&self) -> Option<&[ThreadData]> {
+        if !self.is_process_thread_view {
+            return None;
+        }
+        return match (
+            &self.process_threads,
+            self.get_current_showing_process_pid(),
+        ) {
+            (Some((pid, threads)), Some(showing_pid)) if *pid == showing_pid => Some(threads),
+            _ => Some(&[]),
+        };
+    }
+
+    // pan the graphs of the selected container ( or all containers if none was selected ) by GRAPH_PAN_STEP points
+    // the offset is relative to the latest point, so pausing with space will keep the panned window still
+    fn pan_graph_history(&mut self, is_backward: bool) {
+        // all the histories were collected at the same time, so the cpu history tells how far it can be panned
+        let max_graph_offset = self
+            .sys_info
+            .cpus
+            .first()
+            .map(|cpu| cpu.usage_history_vec.len())
+            .unwrap_or(0)
+            .saturating_sub(1);
+        let pan = |graph_offset: &mut usize| {
+            *graph_offset = if is_backward {
+                (*graph_offset + GRAPH_PAN_STEP).min(max_graph_offset)
+            } else {
+                graph_offset.saturating_sub(GRAPH_PAN_STEP)
+            };
+        };
+
+        match self.selected_container {
+            SelectedContainer::Cpu => pan(&mut self.cpu_graph_offset),
+            SelectedContainer::Memory => pan(&mut self.memory_graph_offset),
+            SelectedContainer::Disk => pan(&mut self.disk_graph_offset),
+            SelectedContainer::Network => pan(&mut self.network_graph_offset),
+            SelectedContainer::Process => pan(&mut self.process_graph_offset),
+            SelectedContainer::None => {
+                pan(&mut self.cpu_graph_offset);
+                pan(&mut self.memory_graph_offset);
+                pan(&mut self.disk_graph_offset);
+                pan(&mut self.network_graph_offset);
+                pan(&mut self.process_graph_offset);
+            }
+            SelectedContainer::Plugins | SelectedContainer::Menu => {}
+        }
+    }
+
+    // switch to the next graph style of the selected container, all the containers will follow the cpu container if none was selected
+    fn is_container_shown(&self, container: SelectedContainer) -> bool {
+        if container == SelectedContainer::None || container == SelectedContainer::Menu {
+            return true;
+        }
+        return self
+            .get_current_layout()
+            .get_containers()
+            .contains(&container)
+            && !self.hidden_containers.contains(&container);
+    }
+
+    // the key hints shown in the status bar, they change with the selected container and the opened pop up
+    fn get_status_bar_hints(&self) -> Vec<(&'static str, &'static str)> {
+        if self.state == AppState::Typing {
+            return vec![("Enter", "apply filter"), ("Esc", "cancel")];
+        }
+        if self.state == AppState::Popup {
+            return match self.pop_up_type {
+                AppPopUpType::ThemeMenu => vec![
+                    ("↑↓", "preview"),
+                    ("Enter", "apply"),
+                    ("e", "new theme"),
+                    ("Esc", "close"),
+                ],
+                AppPopUpType::ThemeWizard => vec![
+                    ("↑↓", "select"),
+                    ("Enter", "pick color"),
+                    ("Esc", "discard"),
+                ],
+                AppPopUpType::UserMenu => {
+                    vec![("↑↓", "select"), ("Enter", "filter"), ("Esc", "close")]
+                }
+                AppPopUpType::GoToPid => vec![("0-9", "PID"), ("Enter", "go"), ("Esc", "close")],
+                AppPopUpType::SignalMenu => vec![
+                    ("↑↓", "select"),
+                    ("0-9/A-Z", "signal"),
+                    ("Tab", "complete"),
+                    ("Enter", "send"),
+                    ("Esc", "close"),
+                ],
+                _ => vec![("y", "confirm"), ("n", "cancel"), ("Esc", "close")],
+            };
+        }
+
+        if self.selected_container == SelectedContainer::None {
+            return vec![
+                ("c m d n p", "select"),
+                ("1-9", "tab"),
+                ("0", "layout"),
+                ("o", "theme"),
+                ("-/+", "tick"),
+                ("space", "pause"),
+                ("Esc", "quit"),
+            ];
+        }
+
+        let mut hints = match self.selected_container {
+            SelectedContainer::Cpu => vec![("↑↓", "select"), ("a", "aggregate HT"), ("v", "grid")],
+            SelectedContainer::Memory => vec![("i", "unit")],
+            SelectedContainer::Disk => {
+                vec![("←→", "disk"), ("l", "list"), ("x", "reset session")]
+            }
+            SelectedContainer::Network => vec![
+                ("←→", "interface"),
+                ("↑↓", "address"),
+                ("b", "bits"),
+                ("x", "reset session"),
+            ],
+            SelectedContainer::Process => {
+                if self.process_show_details && self.process_selected_state.selected().is_none() {
+                    vec![
+                        ("k", "kill"),
+                        ("t", "terminate"),
+                        ("s", "signal"),
+                        ("g", "parent"),
+                        ("j", "child"),
+                        ("l", "threads"),
+                        ("y", "copy cmd"),
+                        ("#", "copy PID"),
+                        ("⇧↑↓", "scroll cmd"),
+                    ]
+                } else if self.process_selected_state.selected().is_some() {
+                    vec![
+                        ("↑↓", "select"),
+                        ("Enter", "details"),
+                        ("k", "kill"),
+                        ("t", "terminate"),
+                        ("s", "signal"),
+                        ("y", "copy cmd"),
+                        ("#", "copy PID"),
+                        ("←→", "sort"),
+                        ("f", "filter"),
+                    ]
+                } else {
+                    vec![
+                        ("↑↓", "select"),
+                        ("Enter", "details"),
+                        ("←→", "sort"),
+                        ("r", "reverse"),
+                        ("f", "filter"),
+                        ("u", "user"),
+                        (":", "PID"),
+                    ]
+                }
+            }
+            _ => vec![],
+        };
+        hints.extend([
+            (
+                "Tab",
+                if self.container_full_screen {
+                    "exit full screen"
+                } else {
+                    "full screen"
+                },
+            ),
+            (", .", "resize"),
+            ("w", "graph style"),
+            ("{ }", "pan"),
+            ("Esc", "back"),
+        ]);
+        return hints;
+    }
+
+    // select the container and show it in full screen, Esc will go back to the layout
+    fn start_in_view(&mut self, container: SelectedContainer) {
+        self.selected_container = container;
+        self.container_full_screen = true;
+    }
+
+    // show the tab, the selected container of the current tab will be restored when switching back to it
+    fn switch_tab(&mut self, tab_index: usize) {
+        if tab_index >= self.tabs.len() || tab_index == self.selected_tab {
+            return;
+        }
+        self.tab_selected_containers[self.selected_tab] = self.selected_container;
+        self.selected_tab = tab_index;
+        self.selected_container = self.tab_selected_containers[tab_index];
+        self.container_full_screen = false;
+        self.selected_layout_preset = None;
+    }
+
+    // the layout preset will be used in place of the layout of the tab when it was selected
+    fn get_current_layout(&self) -> &ContainerLayout {
+        match self.selected_layout_preset {
+            Some(preset_index) => &self.layout_presets[preset_index].layout,
+            None => &self.tabs[self.selected_tab].layout,
+        }
+    }
+
+    // the resized layout of the tab will be saved to the settings file, the layout preset will only be resized until it was switched
+    fn resize_selected_container(&mut self, step: i32) {
+        if self.container_full_screen {
+            return;
+        }
+        let selected_container = self.selected_container;
+        let layout = match self.selected_layout_preset {
+            Some(preset_index) => &mut self.layout_presets[preset_index].layout,
+            None => &mut self.tabs[self.selected_tab].layout,
+        };
+        if layout.resize_container(selected_container, step)
+            && self.selected_layout_preset.is_none()
+        {
+            if let Some(config_directory) = self.config_directory.as_deref() {
+                if let Err(e) = save_tabs_config(config_directory, &self.tabs) {
+                    self.show_toast(format!("Failed to save the layout: {}", e));
+                }
+            }
+        }
+    }
+
+    // cycle through the layout presets, then back to the layout of the tab
+    fn cycle_layout_preset(&mut self) {
+        self.selected_layout_preset = match self.selected_layout_preset {
+            Some(preset_index) if preset_index + 1 < self.layout_presets.len() => {
+                Some(preset_index + 1)
+            }
+            Some(_) => None,
+            None => Some(0),
+        };
+        let layout_name = match self.selected_layout_preset {
+            Some(preset_index) => self.layout_presets[preset_index].name.clone(),
+            None => self.tabs[self.selected_tab].name.clone(),
+        };
+        self.show_toast(format!("Layout: {}", layout_name));
+
+        // the selected container might be left out of the new layout
+        if !self.is_container_shown(self.selected_container) {
+            self.selected_container = SelectedContainer::None;
+            self.container_full_screen = false;
+        }
+    }
+
+    // hide the container and let the remaining containers fill up the space, or show it again if it was hidden
+    fn toggle_container_visibility(&mut self, container: SelectedContainer) {
+        if !self
+            .get_current_layout()
+            .get_containers()
+            .contains(&container)
+        {
+            return;
+        }
+
+        let container_name = match container {
+            SelectedContainer::Cpu => "CPU",
+            SelectedContainer::Memory => "Memory",
+            SelectedContainer::Disk => "Disk",
+            SelectedContainer::Network => "Network",
+            _ => "Process",
+        };
+        if self.hidden_containers.contains(&container) {
+            self.hidden_containers
+                .retain(|hidden_container| *hidden_container != container);
+            self.show_toast(format!("{} container shown", container_name));
+            return;
+        }
+
+        // at least one container will be kept
+        let shown_container_count = self
+            .get_current_layout()
+            .get_containers()
+            .iter()
+            .filter(|shown_container| !self.hidden_containers.contains(shown_container))
+            .count();
+        if shown_container_count <= 1 {
+            self.show_toast("The last container can't be hidden".to_string());
+            return;
+        }
+        self.hidden_containers.push(container);
+        self.show_toast(format!("{} container hidden", container_name));
+    }
+
+    fn switch_graph_style(&mut self) {
+        let (container_name, graph_style) = match self.selected_container {
+            SelectedContainer::Cpu => ("CPU", self.cpu_graph_style.get_next()),
+            SelectedContainer::Memory => ("Memory", self.memory_graph_style.get_next()),
+            SelectedContainer::Disk => ("Disk", self.disk_graph_style.get_next()),
+            SelectedContainer::Network => ("Network", self.network_graph_style.get_next()),
+            SelectedContainer::Process => ("Process", self.process_graph_style.get_next()),
+            SelectedContainer::None => ("All", self.cpu_graph_style.get_next()),
+            SelectedContainer::Plugins | SelectedContainer::Menu => return,
+        };
+
+        if matches!(
+            self.selected_container,
+            SelectedContainer::Cpu | SelectedContainer::None
+        ) {
+            self.cpu_graph_style = graph_style;
+        }
+        if matches!(
+            self.selected_container,
+            SelectedContainer::Memory | SelectedContainer::None
+        ) {
+            self.memory_graph_style = graph_style;
+        }
+        if matches!(
+            self.selected_container,
+            SelectedContainer::Disk | SelectedContainer::None
+        ) {
+            self.disk_graph_style = graph_style;
+        }
+        if matches!(
+            self.selected_container,
+            SelectedContainer::Network | SelectedContainer::None
+        ) {
+            self.network_graph_style = graph_style;
+        }
+        if matches!(
+            self.selected_container,
+            SelectedContainer::Process | SelectedContainer::None
+        ) {
+            self.process_graph_style = graph_style;
+        }
+        self.show_toast(format!(
+            "{} graph style: {}",
+            container_name,
+            graph_style.get_name()
+        ));
+    }
+
+    fn toggle_cpu_grid_view(&mut self) {
+        if self.selected_container == SelectedContainer::Cpu && self.container_full_screen {
+            self.is_cpu_grid_view = !self.is_cpu_grid_view;
+        }
+    }
+
+    // get the PID and the parent PID of the current showing process detail,
+    // None if the process detail isn't shown or user is currently navigating in the process list
+    fn get_current_detail_pid_and_parent(&self) -> Option<(String, String)> {
+        if self.selected_container != SelectedContainer::Process
+            || !self.process_show_details
+            || self.process_selected_state.selected().is_some()
+        {
+            return None;
+        }
+        return self
+            .current_showing_process_detail
+            .as_ref()
+            .and_then(|hashmap| hashmap.iter().next())
+            .map(|(key, value)| (key.clone(), value.parent.clone()));
+    }
+
+    fn go_to_parent_process(&mut self) {
+        let Some((pid, parent_pid)) = self.get_current_detail_pid_and_parent() else {
+            return;
+        };
+        if parent_pid == "-" {
+            self.show_toast(format!("Process with PID {} has no parent process", pid));
+            return;
+        }
+        self.go_to_pid(parent_pid);
+    }
+
+    // the first press will show the first child of the current process, the following presses will cycle through its siblings,
+    // going back to the parent and pressing again will continue from the last shown child
+    fn go_to_next_child_process(&mut self) {
+        let Some((pid, parent_pid)) = self.get_current_detail_pid_and_parent() else {
+            return;
+        };
+        let (cycled_pid, next_index) = match self.process_child_navigation.as_ref() {
+            Some((cycled_pid, index)) if *cycled_pid == parent_pid || *cycled_pid == pid => {
+                (cycled_pid.clone(), index + 1)
+            }
+            _ => (pid.clone(), 0),
+        };
+
+        let child_pids = get_child_pids(&cycled_pid, &self.process_info.processes);
+        if child_pids.is_empty() {
+            self.process_child_navigation = None;
+            self.show_toast(format!(
+                "Process with PID {} has no child process",
+                cycled_pid
+            ));
+            return;
+        }
+        let index = next_index % child_pids.len();
+        self.process_child_navigation = Some((cycled_pid, index));
+        self.go_to_pid(child_pids[index].clone());
+    }
+
+    // apply the queued system info, the alerts were only checked once against the latest info, so the backlog that
+    // was queued while paused won't show the outdated alerts
+    fn apply_queued_sys_info(&mut self) {
+        // the arrays that were already degraded, so the alert will only be shown once when an array degrades
+        let degraded_raid_arrays: Vec<String> = self
+            .sys_info
+            .raid_arrays
+            .iter()
+            .filter(|raid_array| raid_array.is_degraded())
+            .map(|raid_array| raid_array.name.clone())
+            .collect();
+        // the disks that were already low on space, so the alert will only be shown once when the threshold was crossed
+        let low_space_disks: Vec<String> = self
+            .sys_info
+            .disks
+            .values()
+            .filter(|disk| self.disk_free_thresholds.is_low_on_space(disk))
+            .map(|disk| disk.mount_point.clone())
+            .collect();
+        while let Some(c_sys_info) = self.queued_sys_info.pop_front() {
+            process_sys_info(&mut self.sys_info, c_sys_info);
+        }
+        self.is_redraw_needed = true;
+
+        let newly_low_space_disk = get_ordered_disks(&self.sys_info.disks)
+            .into_iter()
+            .find(|disk| {
+                self.disk_free_thresholds.is_low_on_space(disk)
+                    && !low_space_disks.contains(&disk.mount_point)
+            })
+            .map(|disk| {
+                format!(
+                    "Low disk space on {} ( {:.1}% free )",
+                    disk.mount_point,
+                    disk.available_space / disk.total_space * 100.0
+                )
+            });
+        if let Some(message) = newly_low_space_disk {
+            self.show_toast(message);
+        }
+        let newly_degraded_raid_array = self
+            .sys_info
+            .raid_arrays
+            .iter()
+            .find(|raid_array| {
+                raid_array.is_degraded() && !degraded_raid_arrays.contains(&raid_array.name)
+            })
+            .map(|raid_array| raid_array.name.clone());
+        if let Some(raid_array_name) = newly_degraded_raid_array {
+            self.show_toast(format!("RAID array {} is degraded", raid_array_name));
+        }
+        for alert_name in self
+            .script_engine
+            .evaluate_alerts(&self.sys_info, &self.process_info.processes)
+        {
+            self.show_toast(format!("Alert: {}", alert_name));
+        }
+    }
+
+    fn show_toast(&mut self, message: String) {
+        self.toast = Some((message, Instant::now()));
+    }
+
+    fn handle_user_menu_event(&mut self, key_event: KeyEvent) {
+        // the first entry of the user menu was for showing all the users
+        let total_entries = self.user_names.len() + 1;
+        match key_event.code {
+            KeyCode::Esc => {
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+            }
+            KeyCode::Up => {
+                if let Some(selected) = self.user_selected_state.selected() {
+                    if selected > 0 {
+                        self.user_selected_state.select(Some(selected - 1));
+                    } else {
+                        self.user_selected_state.select(Some(total_entries - 1));
+                    }
+                }
+            }
+            KeyCode::Down => {
+                if let Some(selected) = self.user_selected_state.selected() {
+                    if selected < total_entries - 1 {
+                        self.user_selected_state.select(Some(selected + 1));
+                    } else {
+                        self.user_selected_state.select(Some(0));
+                    }
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(selected) = self.user_selected_state.selected() {
+                    self.process_user_filter = if selected == 0 {
+                        None
+                    } else {
+                        Some(self.user_names[selected - 1].clone())
+                    };
+                    // the selected process might not be in the filtered list anymore
+                    self.process_selected_state.select(None);
+                }
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_theme_wizard_event(&mut self, key_event: KeyEvent) {
+        let Some(theme_wizard_state) = self.theme_wizard_state.as_mut() else {
+            return;
+        };
+        let selected_field = APP_COLOR_INFO_FIELDS[theme_wizard_state
+            .field_selected_state
+            .selected()
+            .unwrap_or(0)];
+
+        if theme_wizard_state.is_naming {
+            // typing the name of the new theme
+            match key_event.code {
+                KeyCode::Esc => {
+                    theme_wizard_state.is_naming = false;
+                    theme_wizard_state.message = None;
+                }
+                KeyCode::Backspace => {
+                    theme_wizard_state.theme_name.pop();
+                }
+                KeyCode::Char(c) => {
+                    // the theme name will be used as the file name
+                    if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                        theme_wizard_state.theme_name.push(c);
+                    }
+                }
+                KeyCode::Enter => {
+                    let theme_name = theme_wizard_state.theme_name.clone();
+                    let Some(config_directory) = self.config_directory.clone() else {
+                        theme_wizard_state.message =
+                            Some("There is no settings directory to save the theme".to_string());
+                        return;
+                    };
+                    match save_custom_theme(&config_directory, &theme_name, &self.app_color_info) {
+                        Ok(_) => {
+                            self.save_theme(theme_name.clone());
+                            self.current_theme = theme_name;
+                            self.theme_names = get_all_theme_names(Some(&config_directory));
+                            self.theme_wizard_state = None;
+                            self.state = AppState::View;
+                            self.pop_up_type = AppPopUpType::None;
+                        }
+                        Err(message) => {
+                            theme_wizard_state.message = Some(message);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        } else if theme_wizard_state.is_palette_focused {
+            // choosing the color from the palette, the color is previewed as user navigate through the palette
+            let palette_selected_index = theme_wizard_state.palette_selected_index;
+            match key_event.code {
+                KeyCode::Esc => {
+                    if let Some(original_color) = theme_wizard_state.original_color {
+                        self.app_color_info
+                            .set_color(selected_field, original_color);
+                    }
+                    theme_wizard_state.is_palette_focused = false;
+                    theme_wizard_state.original_color = None;
+                }
+                KeyCode::Enter | KeyCode::Tab => {
+                    theme_wizard_state.is_palette_focused = false;
+                    theme_wizard_state.original_color = None;
+                }
+                KeyCode::Left => {
+                    theme_wizard_state.palette_selected_index =
+                        palette_selected_index.wrapping_sub(1);
+                }
+                KeyCode::Right => {
+                    theme_wizard_state.palette_selected_index =
+                        palette_selected_index.wrapping_add(1);
+                }
+                KeyCode::Up => {
+                    theme_wizard_state.palette_selected_index =
+                        palette_selected_index.wrapping_sub(16);
+                }
+                KeyCode::Down => {
+                    theme_wizard_state.palette_selected_index =
+                        palette_selected_index.wrapping_add(16);
+                }
+                _ => {}
+            }
+
+            if theme_wizard_state.is_palette_focused {
+                self.app_color_info.set_color(
+                    selected_field,
+                    Color::Indexed(theme_wizard_state.palette_selected_index),
+                );
+            }
+        } else {
+            // navigating through the AppColorInfo fields
+            let selected = theme_wizard_state
+                .field_selected_state
+                .selected()
+                .unwrap_or(0);
+            match key_event.code {
+                KeyCode::Esc => {
+                    // discard the theme and revert back to the applied theme
+                    self.app_color_info = self.get_app_color_info(&self.current_theme);
+                    self.theme_wizard_state = None;
+                    self.state = AppState::View;
+                    self.pop_up_type = AppPopUpType::None;
+                }
+                KeyCode::Up => {
+                    if selected > 0 {
+                        theme_wizard_state
+                            .field_selected_state
+                            .select(Some(selected - 1));
+                    } else {
+                        theme_wizard_state
+                            .field_selected_state
+                            .select(Some(APP_COLOR_INFO_FIELDS.len() - 1));
+                    }
+                }
+                KeyCode::Down => {
+                    if selected < APP_COLOR_INFO_FIELDS.len() - 1 {
+                        theme_wizard_state
+                            .field_selected_state
+                            .select(Some(selected + 1));
+                    } else {
+                        theme_wizard_state.field_selected_state.select(Some(0));
+                    }
+                }
+                KeyCode::Tab | KeyCode::Enter => {
+                    let original_color = self.app_color_info.get_color(selected_field);
+                    // start the palette cursor from the current color if it was one of the 256 colors
+                    if let Some(Color::Indexed(index)) = original_color {
+                        theme_wizard_state.palette_selected_index = index;
+                    }
+                    theme_wizard_state.original_color = original_color;
+                    theme_wizard_state.is_palette_focused = true;
+                    self.app_color_info.set_color(
+                        selected_field,
+                        Color::Indexed(theme_wizard_state.palette_selected_index),
+                    );
+                }
+                KeyCode::Char('s') => {
+                    theme_wizard_state.is_naming = true;
+                }
+                KeyCode::Char('S') => {
+                    theme_wizard_state.is_naming = true;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // the theme wizard start from the colors of the currently previewed theme
+    fn open_theme_wizard(&mut self) {
+        self.theme_wizard_state = Some(ThemeWizardState::default());
+        self.pop_up_type = AppPopUpType::ThemeWizard;
+    }
+
+    fn open_theme_menu(&mut self) {
+        // refresh the theme list as user might have added a theme file
+        self.theme_names = get_all_theme_names(self.config_directory.as_deref());
+        let current_theme_index = self
+            .theme_names
+            .iter()
+            .position(|theme| *theme == self.current_theme)
+            .unwrap_or(0);
+        self.theme_selected_state.select(Some(current_theme_index));
+        self.state = AppState::Popup;
+        self.pop_up_type = AppPopUpType::ThemeMenu;
+    }
+
+    fn reset_session_counters(&mut self) {
+        if self.selected_container == SelectedContainer::Disk {
+            for disk in self.sys_info.disks.values_mut() {
+                disk.reset_session();
+            }
+            self.disk_session_since = Local::now();
+        } else if self.selected_container == SelectedContainer::Network {
+            for network in self.sys_info.networks.values_mut() {
+                network.reset_session();
+            }
+            self.network_session_since = Local::now();
+        }
+    }
+
+    fn open_user_menu(&mut self) {
+        // list all the users that currently own a process
+        let mut user_names: Vec<String> = self
+            .process_info
+            .processes
+            .values()
+            .map(|process| process.user.clone())
+            .collect();
+        user_names.sort();
+        user_names.dedup();
+        self.user_names = user_names;
+
+        let current_user_index = match self.process_user_filter.as_ref() {
+            Some(user) => self
+                .user_names
+                .iter()
+                .position(|user_name| user_name == user)
+                .map(|index| index + 1)
+                .unwrap_or(0),
+            None => 0,
+        };
+        self.user_selected_state.select(Some(current_user_index));
+        self.state = AppState::Popup;
+        self.pop_up_type = AppPopUpType::UserMenu;
+    }
+
+    // the colors of the theme, the "auto" and "system" theme were resolved with the current settings
+    fn get_app_color_info(&self, theme: &str) -> AppColorInfo {
+        return get_app_color_info_from_theme_name(
+            theme,
+            &self.theme_config,
+            self.config_directory.as_deref(),
+            self.system_appearance,
+        );
+    }
+
+    // save the theme into the settings file, the hot reload won't pick up the change made by the app itself
+    fn save_theme(&mut self, theme: String) {
+        self.theme_config.theme = theme.clone();
+        let Some(config_directory) = self.config_directory.clone() else {
+            return;
+        };
+        if let Err(e) = set_theme(&config_directory, theme) {
+            self.show_toast(format!("Failed to save the theme: {}", e));
+        }
+        self.theme_config_modified_time = get_theme_config_modified_time(&config_directory);
+    }
+
+    fn preview_selected_theme(&mut self) {
+        if let Some(selected) = self.theme_selected_state.selected() {
+            self.app_color_info = self.get_app_color_info(&self.theme_names[selected]);
+        }
+    }
+
+    // re-apply the "system" theme if the checked OS light/dark preference was changed
+    fn apply_system_appearance(&mut self, system_appearance: Option<ThemeAppearance>) {
+        if system_appearance == self.system_appearance {
+            return;
+        }
+        self.system_appearance = system_appearance;
+
+        // don't override the theme user is currently previewing or creating, the changed preference will be used
+        // when the theme menu was closed
+        if self.current_theme != SYSTEM_THEME
+            || self.pop_up_type == AppPopUpType::ThemeMenu
+            || self.pop_up_type == AppPopUpType::ThemeWizard
+        {
+            return;
+        }
+        self.app_color_info = self.get_app_color_info(SYSTEM_THEME);
+        self.is_redraw_needed = true;
+    }
+
+    // re-read the settings file and apply the theme if it was modified since the last check
+    fn reload_theme_if_changed(&mut self) {
+        self.theme_config_last_checked = Instant::now();
+        let Some(config_directory) = self.config_directory.clone() else {
+            return;
+        };
+        let modified_time = get_theme_config_modified_time(&config_directory);
+        if modified_time == self.theme_config_modified_time {
+            return;
+        }
+        self.theme_config_modified_time = modified_time;
+
+        // don't override the theme user is currently previewing or creating
+        if self.pop_up_type == AppPopUpType::ThemeMenu
+            || self.pop_up_type == AppPopUpType::ThemeWizard
+        {
+            return;
+        }
+        // the light and dark theme of "auto" and "system" were also reloaded, the current settings were kept if
+        // the file can't be parsed
+        match read_theme_config(Some(&config_directory)) {
+            Ok(theme_config) => self.theme_config = theme_config,
+            Err(e) => {
+                self.show_toast(format!("Failed to read the settings: {}", e));
+                self.is_redraw_needed = true;
+                return;
+            }
+        }
+        let theme = self.theme_config.theme.clone();
+        if theme != self.current_theme {
+            self.app_color_info = self.get_app_color_info(&theme);
+            self.current_theme = theme;
+            self.is_redraw_needed = true;
+        }
+    }
+}
+
+fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo) {
+    let block = Block::bordered()
         .style(Color::LightYellow)
         .border_set(border::ROUNDED);
 
@@ -1418,7 +3499,7 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
             ),
             Span::styled(
                 format!(" {} ", width),
-                Style::default().fg(if width >= MIN_WIDTH {
+                Style::default().fg(if width >= COMPACT_MIN_WIDTH {
                     Color::Green
                 } else {
                     Color::Red
@@ -1430,7 +3511,7 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
             ),
             Span::styled(
                 format!(" {} ", height),
-                Style::default().fg(if height >= MIN_HEIGHT {
+                Style::default().fg(if height >= COMPACT_MIN_HEIGHT {
                     Color::Green
                 } else {
                     Color::Red
@@ -1438,9 +3519,12 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
             ),
         ]),
         Line::from(""),
-        Line::from("Need Size for current config.").style(app_color_info.base_app_text_color),
-        Line::from(format!("Width = {} Height = {}  ", MIN_WIDTH, MIN_HEIGHT))
-            .style(app_color_info.base_app_text_color),
+        Line::from("Need Size for the compact view.").style(app_color_info.base_app_text_color),
+        Line::from(format!(
+            "Width = {} Height = {}  ",
+            COMPACT_MIN_WIDTH, COMPACT_MIN_HEIGHT
+        ))
+        .style(app_color_info.base_app_text_color),
     ];
 
     let warning_paragraph = Paragraph::new(text_lines)
@@ -1450,3 +3534,6 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
 
     frame.render_widget(warning_paragraph, frame.area());
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,2695 @@
+// ------------------------------------
+//
+// headless integration tests
+//
+// ------------------------------------
+// the app is driven through the same loop iteration used by `App::run`, with scripted key events and
+// synthetic collected info, and rendered against a `TestBackend` so the rendered buffer can be asserted
+use std::borrow::Cow;
+
+use chrono::{Local, TimeZone};
+use ratatui::{
+    backend::TestBackend,
+    crossterm::event::{KeyEvent, MouseEvent},
+    layout::Rect,
+    style::Color,
+    widgets::GraphType,
+    Terminal,
+};
+
+use super::*;
+use crate::components::themes::default::DEFAULT;
+use crate::components::themes::paper::PAPER;
+use crate::get_sys_info::{parse_mdstat, run_collector, MetricsSource};
+use crate::plugins::WidgetPlugin;
+use crate::scripting::ScriptEngine;
+use crate::types::{
+    CCpuData, CDiskData, CMemoryData, CNetworkData, CPressureData, CPressureInfo, CProcessData,
+    CProcessUpdate, CSystemData, CompressedSwapInfo, ConnectionThroughput, ContainerInfo,
+    ContainerLayout, CpuTimeBreakdown, DiskFreeThresholds, DiskIoStats, GraphGradient,
+    MemoryBreakdown, NamedScript, NetworkConnectivity, NetworkInterfaceDetails, NetworkPacketStats,
+    PingResult, ProcessHeat, ScriptConfig, SmartInfo, StaticSystemInfo, StoragePool, SwapDevice,
+    ThreadData, ZfsArcInfo,
+};
+use crate::utils::{
+    fill_graph_data_points, format_start_time, get_clipboard_sequence, get_container_area,
+    get_container_areas, get_descendant_pids, get_gradient_points, get_scaled_axis_labels,
+    get_signal_id_from_name, get_start_time,
+};
+
+const TEST_WIDTH: u16 = 160;
+const TEST_HEIGHT: u16 = 50;
+
+fn get_test_sys_info() -> CSysInfo {
+    return CSysInfo {
+        cpus: vec![
+            CCpuData {
+                id: -1,
+                brand: "Test CPU".to_string(),
+                usage: 25.0,
+                topology: None,
+                time_breakdown: Some(CpuTimeBreakdown {
+                    user: 15.0,
+                    system: 5.0,
+                    iowait: 12.5,
+                    irq: 1.0,
+                    steal: 0.0,
+                }),
+            },
+            CCpuData {
+                id: 0,
+                brand: "Test CPU".to_string(),
+                usage: 20.0,
+                topology: Some((0, 0)),
+                time_breakdown: None,
+            },
+            CCpuData {
+                id: 1,
+                brand: "Test CPU".to_string(),
+                usage: 30.0,
+                topology: Some((0, 0)),
+                time_breakdown: None,
+            },
+        ],
+        memory: CMemoryData {
+            total_memory: 8_000_000_000.0,
+            available_memory: 4_000_000_000.0,
+            used_memory: 4_000_000_000.0,
+            used_swap: 0.0,
+            free_memory: 2_000_000_000.0,
+            cached_memory: 2_000_000_000.0,
+            breakdown: Some(MemoryBreakdown {
+                buffers: 1024.0 * 1024.0,
+                slab: 0.0,
+                dirty: 0.0,
+                writeback: 0.0,
+                huge_pages_total: 0.0,
+                huge_pages_free: 0.0,
+                huge_page_size: 2.0 * 1024.0 * 1024.0,
+            }),
+            compressed_swap: None,
+            swap_devices: vec![],
+            zfs_arc: None,
+        },
+        disks: vec![CDiskData {
+            name: "testdisk".to_string(),
+            total_space: 100_000_000_000.0,
+            available_space: 60_000_000_000.0,
+            used_space: 40_000_000_000.0,
+            bytes_written: 1024.0,
+            bytes_read: 2048.0,
+            file_system: "ext4".to_string(),
+            mount_point: "/".to_string(),
+            kind: "SSD".to_string(),
+            inodes: Some((1_000_000, 750_000)),
+            temperature: None,
+            io_stats: None,
+            physical_device: "testdisk".to_string(),
+        }],
+        system: CSystemData {
+            load_average_one: 1.25,
+            load_average_five: 0.5,
+            load_average_fifteen: 0.25,
+            uptime: 2 * 24 * 60 * 60 + 3 * 60 * 60,
+            logged_in_users: Some(2),
+        },
+        pressure: CPressureInfo {
+            cpu: Some(CPressureData {
+                some: 1.5,
+                full: 0.0,
+            }),
+            memory: Some(CPressureData {
+                some: 2.25,
+                full: 1.0,
+            }),
+            io: None,
+        },
+        raid_arrays: vec![],
+        connections: vec![],
+        connectivity: NetworkConnectivity {
+            gateway: Some("10.1.2.1".to_string()),
+            dns: Some("10.1.2.53".to_string()),
+            public_ip: None,
+        },
+        networks: vec![CNetworkData {
+            interface_name: "testnet0".to_string(),
+            ip_networks: vec!["10.1.2.3/24".to_string(), "fe80::1".to_string()],
+            vpn_info: None,
+            current_received: 512.0,
+            total_received: 1_000_000.0,
+            current_transmitted: 256.0,
+            total_transmitted: 500_000.0,
+            packet_stats: NetworkPacketStats {
+                packets_received: 12,
+                packets_transmitted: 8,
+                errors_received: 0,
+                errors_transmitted: 0,
+                drops: Some((0, 0)),
+            },
+            interface_details: NetworkInterfaceDetails {
+                mac_address: "02:42:ac:11:00:02".to_string(),
+                mtu: 1500,
+                speed: Some(1000),
+                duplex: Some("full".to_string()),
+            },
+        }],
+    };
+}
+
+fn get_test_process(pid: u32, name: &str, cmd: Vec<&str>, user: &str) -> CProcessData {
+    return CProcessData {
+        pid,
+        name: name.to_string(),
+        exe_path: None,
+        cmd: cmd.iter().map(|arg| arg.to_string()).collect(),
+        user: user.to_string(),
+        cpu_usage: 1.0,
+        thread_count: 1,
+        memory: 1_000_000.0,
+        virtual_memory: 4_000_000.0,
+        shared_memory: Some(500_000.0),
+        oom_score: None,
+        nice: None,
+        status: "Sleeping".to_string(),
+        elapsed: 60,
+        cpu_time: 30_000,
+        parent: "".to_string(),
+        current_read_disk_usage: 0,
+        total_read_disk_usage: 0,
+        current_write_disk_usage: 0,
+        total_write_disk_usage: 0,
+        delay_info: None,
+        container_info: None,
+        cgroup: None,
+        cpu_throttle_info: None,
+    };
+}
+
+fn get_test_processes_info() -> CProcessesInfo {
+    return CProcessesInfo {
+        processes: vec![
+            get_test_process(1, "initd", vec!["/sbin/initd"], "root"),
+            get_test_process(100, "bash", vec!["/bin/bash", "--login"], "alice"),
+            get_test_process(200, "vim", vec!["vim", "notes.txt"], "bob"),
+        ],
+        is_snapshot: true,
+        updated_processes: vec![],
+        removed_pids: vec![],
+    };
+}
+
+// the metrics source that send the synthetic collected info once when the collectors were started
+// so the tests don't depend on the system they run on
+struct MockMetricsSource {
+    sys_info: fn() -> CSysInfo,
+    processes_info: fn() -> CProcessesInfo,
+}
+
+impl MetricsSource for MockMetricsSource {
+    fn spawn_system_info_collector(
+        &self,
+        _tick_receiver: watch::Receiver<u32>,
+        _shown_receiver: watch::Receiver<ShownSystemInfo>,
+        tx: UnboundedSender<CSysInfo>,
+    ) {
+        let _ = tx.send((self.sys_info)());
+    }
+
+    fn spawn_process_info_collector(
+        &self,
+        _tick_receiver: watch::Receiver<u32>,
+        tx: UnboundedSender<CProcessesInfo>,
+    ) {
+        let _ = tx.send((self.processes_info)());
+    }
+}
+
+// create the app with the synthetic collected info already received
+fn start_test_app(width: u16, height: u16) -> (App, Terminal<TestBackend>) {
+    let (mut app, tick_rx, process_tick_rx) = new_app(ThemeConfig::new("default"), None, None);
+    app.start_collectors(
+        &MockMetricsSource {
+            sys_info: get_test_sys_info,
+            processes_info: get_test_processes_info,
+        },
+        tick_rx,
+        process_tick_rx,
+    );
+    app.wait_for_initial_info();
+
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    app.run_once(&mut terminal, None).unwrap();
+    return (app, terminal);
+}
+
+// the fields that were changed directly by the test weren't an event or collected info, so the redraw was requested
+fn redraw(app: &mut App, terminal: &mut Terminal<TestBackend>) {
+    app.is_redraw_needed = true;
+    app.run_once(terminal, None).unwrap();
+}
+
+// feed a key press to the app, then run another iteration so the result of the key press was rendered
+fn press_key(app: &mut App, terminal: &mut Terminal<TestBackend>, key_code: KeyCode) {
+    app.run_once(
+        terminal,
+        Some(Event::Key(KeyEvent::new(key_code, KeyModifiers::NONE))),
+    )
+    .unwrap();
+    app.run_once(terminal, None).unwrap();
+}
+
+// select the process container and sort the process list by the sort type, like it was chosen with the arrow keys
+fn select_process_sort(
+    app: &mut App,
+    terminal: &mut Terminal<TestBackend>,
+    sort_type: ProcessSortType,
+) {
+    press_key(app, terminal, KeyCode::Char('p'));
+    app.process_sort_selected_state = sort_type.get_sort_id();
+    app.process_sort_type = sort_type;
+    redraw(app, terminal);
+}
+
+fn type_text(app: &mut App, terminal: &mut Terminal<TestBackend>, text: &str) {
+    for c in text.chars() {
+        press_key(app, terminal, KeyCode::Char(c));
+    }
+}
+
+// the rendered buffer as plain text, one line per row
+fn get_rendered_text(terminal: &Terminal<TestBackend>) -> String {
+    let buffer = terminal.backend().buffer();
+    let mut lines: Vec<String> = Vec::new();
+    for y in 0..buffer.area.height {
+        let mut line = String::new();
+        for x in 0..buffer.area.width {
+            line.push_str(buffer[(x, y)].symbol());
+        }
+        lines.push(line);
+    }
+    return lines.join("\n");
+}
+
+// the process names in the order shown in the process list
+fn get_sorted_process_names(app: &App) -> Vec<String> {
+    return app
+        .process_current_list
+        .iter()
+        .map(|pid| app.process_info.processes[&pid.to_string()].name.clone())
+        .collect();
+}
+
+fn get_current_process_names(app: &App) -> Vec<String> {
+    let mut names: Vec<String> = app
+        .process_current_list
+        .iter()
+        .map(|pid| app.process_info.processes[&pid.to_string()].name.clone())
+        .collect();
+    names.sort();
+    return names;
+}
+
+#[test]
+fn app_is_built_from_the_given_settings() {
+    let mut theme_config = ThemeConfig::new("paper");
+    theme_config.network_unit = Some("bits".to_string());
+    theme_config.status_bar = Some(true);
+    theme_config.refresh_tick = Some(3000);
+    let (app, _, _) = new_app(theme_config, None, None);
+
+    assert!(app.network_unit == NetworkUnit::Bits);
+    assert!(app.is_status_bar_shown);
+    assert_eq!(app.tick, 3000);
+    assert_eq!(app.current_theme, "paper");
+    // the settings weren't read from or saved into the home directory without the config directory
+    assert!(app.config_directory.is_none());
+    assert!(app.theme_config_modified_time.is_none());
+}
+
+#[test]
+fn renders_all_panels_with_collected_info() {
+    let (_, terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let text = get_rendered_text(&terminal);
+
+    assert!(text.contains("Test CPU"));
+    assert!(text.contains("testdisk"));
+    assert!(text.contains("testnet0"));
+    assert!(text.contains("10.1.2.3/24"));
+    assert!(text.contains("bash"));
+    assert!(text.contains("alice"));
+}
+
+#[test]
+fn renders_message_when_terminal_too_small() {
+    let (mut app, mut terminal) = start_test_app(MIN_WIDTH, COMPACT_MIN_HEIGHT - 1);
+    assert!(get_rendered_text(&terminal).contains("terminal size too small"));
+
+    // events are ignored while the UI is not renderable
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(!app.is_quit);
+}
+
+#[test]
+fn renders_compact_view_below_minimum_size() {
+    let (_, terminal) = start_test_app(MIN_WIDTH - 1, MIN_HEIGHT - 1);
+    let text = get_rendered_text(&terminal);
+
+    assert!(text.contains("rtop ( compact )"));
+    assert!(text.contains("CPU"));
+    assert!(text.contains("MEM"));
+    assert!(text.contains("bash"));
+    assert!(!text.contains("terminal size too small"));
+}
+
+#[test]
+fn only_esc_is_handled_in_the_compact_view() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    app.process_selected_state.select(Some(0));
+
+    // the row that was selected before the terminal shrank can't be signaled without seeing the confirmation
+    terminal.backend_mut().resize(MIN_WIDTH - 1, MIN_HEIGHT - 1);
+    redraw(&mut app, &mut terminal);
+    assert!(app.is_compact_view);
+    press_key(&mut app, &mut terminal, KeyCode::Char('k'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    assert!(app.state == AppState::View);
+    assert!(app.pop_up_type == AppPopUpType::None);
+    assert!(app.current_process_signal_state_data.is_none());
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.is_quit);
+}
+
+#[test]
+fn esc_deselects_container_before_quitting() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    assert!(app.selected_container == SelectedContainer::Process);
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.selected_container == SelectedContainer::None);
+    assert!(!app.is_quit);
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.is_quit);
+}
+
+#[test]
+fn new_collected_info_is_rendered() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("htop"));
+
+    let mut processes_info = get_test_processes_info();
+    processes_info
+        .processes
+        .push(get_test_process(300, "htop", vec!["htop"], "bob"));
+    let _ = app.process_tx.send(processes_info);
+    let _ = app.tx.send(get_test_sys_info());
+    app.run_once(&mut terminal, None).unwrap();
+    app.run_once(&mut terminal, None).unwrap();
+
+    assert!(get_rendered_text(&terminal).contains("htop"));
+}
+
+#[test]
+fn ui_is_only_redrawn_when_something_changed() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.is_summary_line_shown = true;
+
+    // nothing was collected and no key was pressed
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(!app.is_redraw_needed);
+    assert!(!get_rendered_text(&terminal).contains("load average"));
+
+    let _ = app.tx.send(get_test_sys_info());
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(get_rendered_text(&terminal).contains("load average"));
+
+    app.is_summary_line_shown = false;
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    assert!(!get_rendered_text(&terminal).contains("load average"));
+}
+
+#[test]
+fn collection_is_slowed_down_and_redraw_paused_while_unfocused() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.is_summary_line_shown = true;
+    // the ticks watched by the collectors
+    let tick_rx = app.tick_tx.subscribe();
+    let process_tick_rx = app.process_tick_tx.subscribe();
+
+    app.run_once(&mut terminal, Some(Event::FocusLost)).unwrap();
+    assert_eq!(*tick_rx.borrow(), UNFOCUSED_REFRESH_TICK);
+    assert_eq!(*process_tick_rx.borrow(), UNFOCUSED_REFRESH_TICK);
+
+    let _ = app.tx.send(get_test_sys_info());
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(!get_rendered_text(&terminal).contains("load average"));
+
+    // the changes while unfocused were drawn on focus
+    app.run_once(&mut terminal, Some(Event::FocusGained))
+        .unwrap();
+    assert_eq!(*tick_rx.borrow(), app.tick);
+    assert_eq!(*process_tick_rx.borrow(), app.process_tick);
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(get_rendered_text(&terminal).contains("load average"));
+}
+
+#[test]
+fn process_changes_are_applied_to_the_existing_processes() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    // htop was started, vim has exited and only the metrics of bash were sent
+    let _ = app.process_tx.send(CProcessesInfo {
+        is_snapshot: false,
+        processes: vec![get_test_process(300, "htop", vec!["htop"], "bob")],
+        updated_processes: vec![CProcessUpdate {
+            pid: 100,
+            cpu_usage: 50.0,
+            thread_count: 8,
+            memory: 2_000_000.0,
+            virtual_memory: 4_000_000.0,
+            shared_memory: None,
+            oom_score: None,
+            nice: None,
+            status: "Run".to_string(),
+            elapsed: 61,
+            cpu_time: 30_500,
+            current_read_disk_usage: 0,
+            total_read_disk_usage: 0,
+            current_write_disk_usage: 0,
+            total_write_disk_usage: 0,
+            delay_info: None,
+            cpu_throttle_info: None,
+        }],
+        removed_pids: vec![200],
+    });
+    app.run_once(&mut terminal, None).unwrap();
+
+    let bash = &app.process_info.processes["100"];
+    assert_eq!(bash.name, "bash");
+    assert_eq!(bash.user, "alice");
+    assert_eq!(bash.thread_count, 8);
+    assert_eq!(bash.cpu_usage[..], [1.0, 50.0]);
+    assert!(app.process_info.processes.contains_key("300"));
+    assert!(!app.process_info.processes.contains_key("200"));
+
+    // the snapshot removes the processes that weren't in it, even when their removal was missed
+    let _ = app.process_tx.send(get_test_processes_info());
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(!app.process_info.processes.contains_key("300"));
+    assert!(app.process_info.processes.contains_key("200"));
+    assert_eq!(app.process_info.processes["100"].cpu_usage.len(), 3);
+}
+
+#[test]
+fn substring_filter_restricts_process_list() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    assert!(app.state == AppState::Typing);
+    type_text(&mut app, &mut terminal, "vi");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+
+    assert!(app.state == AppState::View);
+    assert_eq!(get_current_process_names(&app), vec!["vim"]);
+    // only the top consumers strip, which ignores the filter, still shows the other processes
+    assert!(get_rendered_text(&terminal)
+        .lines()
+        .filter(|line| !line.contains("Top CPU:"))
+        .all(|line| !line.contains("initd")));
+}
+
+#[test]
+fn process_block_title_shows_the_process_state_summary() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains(" 3 total, 0 running "));
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].status = "Runnable".to_string();
+    processes_info.processes[1].status = "Zombie".to_string();
+    processes_info.processes[2].status = "Stopped".to_string();
+    let _ = app.process_tx.send(processes_info);
+    redraw(&mut app, &mut terminal);
+    assert!(get_rendered_text(&terminal).contains(" 3 total, 1 running, 1 stopped, 1 zombie "));
+}
+
+#[test]
+fn process_block_shows_the_top_consumers() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].cpu_usage = 50.0;
+    processes_info.processes[1].cpu_usage = 5.0;
+    processes_info.processes[2].cpu_usage = 20.0;
+    processes_info.processes[0].memory = 1024.0;
+    processes_info.processes[1].memory = 4096.0;
+    processes_info.processes[2].memory = 2048.0;
+    let _ = app.process_tx.send(processes_info);
+
+    // the strip ignores the filter
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    type_text(&mut app, &mut terminal, "bash");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    let strip = text.lines().find(|line| line.contains("Top CPU:")).unwrap();
+    assert!(strip.contains("Top CPU: initd 50.00%  vim 20.00%  bash 5.00%"));
+    assert!(strip.contains("Top Mem: bash 4.00 KiB  vim 2.00 KiB"));
+}
+
+#[test]
+fn regex_and_fuzzy_filter_restricts_process_list() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    type_text(&mut app, &mut terminal, "re:^(bash|vim)$");
+    assert_eq!(get_current_process_names(&app), vec!["bash", "vim"]);
+
+    // an unfinished regex will not match anything
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    assert!(get_current_process_names(&app).is_empty());
+    assert!(get_rendered_text(&terminal).contains("invalid regex"));
+
+    // backspace in the main view will clear the filter
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    assert_eq!(get_current_process_names(&app).len(), 3);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    type_text(&mut app, &mut terminal, "fz:ntd");
+    assert_eq!(get_current_process_names(&app), vec!["initd"]);
+}
+
+#[test]
+fn user_menu_filters_process_list_by_user() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('u'));
+    assert!(app.pop_up_type == AppPopUpType::UserMenu);
+    assert!(get_rendered_text(&terminal).contains("All Users"));
+
+    // the users are sorted, the first entry is "All Users"
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert!(app.pop_up_type == AppPopUpType::None);
+    assert_eq!(app.process_user_filter, Some("alice".to_string()));
+    assert_eq!(get_current_process_names(&app), vec!["bash"]);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('u'));
+    press_key(&mut app, &mut terminal, KeyCode::Up);
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert_eq!(app.process_user_filter, None);
+    assert_eq!(get_current_process_names(&app).len(), 3);
+}
+
+#[test]
+fn privacy_mode_hides_sensitive_values() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('h'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("redacted"));
+    assert!(!text.contains("alice"));
+    assert!(!text.contains("--login"));
+    assert!(!text.contains("10.1.2.3"));
+    assert!(!text.contains("02:42:ac:11:00:02"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('h'));
+    assert!(get_rendered_text(&terminal).contains("alice"));
+}
+
+#[test]
+fn presentation_mode_overrides_theme_colors() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('z'));
+    assert_eq!(
+        terminal.backend().buffer()[(0, 0)].bg,
+        PRESENTATION.background_color
+    );
+    assert!(!get_rendered_text(&terminal).contains("alice"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('z'));
+    assert_eq!(
+        terminal.backend().buffer()[(0, 0)].bg,
+        DEFAULT.background_color
+    );
+}
+
+#[test]
+fn theme_menu_preview_is_reverted_on_esc() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('o'));
+    assert!(app.state == AppState::Popup);
+    assert!(app.pop_up_type == AppPopUpType::ThemeMenu);
+
+    // preview another theme with a different background
+    while app.app_color_info.background_color == DEFAULT.background_color {
+        press_key(&mut app, &mut terminal, KeyCode::Down);
+    }
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.state == AppState::View);
+    assert_eq!(
+        app.app_color_info.background_color,
+        DEFAULT.background_color
+    );
+    assert_eq!(app.current_theme, "default");
+}
+
+#[test]
+fn every_app_color_info_field_can_be_accessed_by_name() {
+    let value = serde_json::to_value(&DEFAULT).unwrap();
+    // every color field of the theme file was listed
+    let color_field_count = value
+        .as_object()
+        .unwrap()
+        .values()
+        .filter(|field_value| serde_json::from_value::<Color>((*field_value).clone()).is_ok())
+        .count();
+    assert_eq!(APP_COLOR_INFO_FIELDS.len(), color_field_count);
+
+    let mut app_color_info = DEFAULT.clone();
+    for field in APP_COLOR_INFO_FIELDS {
+        let color: Color = serde_json::from_value(value[*field].clone()).unwrap();
+        assert_eq!(app_color_info.get_color(field), Some(color));
+
+        app_color_info.set_color(field, Color::Indexed(42));
+        assert_eq!(app_color_info.get_color(field), Some(Color::Indexed(42)));
+    }
+    assert_eq!(app_color_info.get_color("graph_gradient"), None);
+}
+
+#[test]
+fn system_theme_follows_the_collected_system_appearance() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    // the appearance is only checked while the theme is "system"
+    assert!(!*app.is_system_theme_tx.borrow());
+
+    app.theme_config.light_theme = Some("paper".to_string());
+    app.current_theme = SYSTEM_THEME.to_string();
+    redraw(&mut app, &mut terminal);
+    assert!(*app.is_system_theme_tx.borrow());
+
+    // the change isn't applied while the theme menu is open
+    press_key(&mut app, &mut terminal, KeyCode::Char('o'));
+    app.system_appearance_tx
+        .send(Some(ThemeAppearance::Light))
+        .unwrap();
+    redraw(&mut app, &mut terminal);
+    assert!(app.app_color_info.background_color == DEFAULT.background_color);
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.app_color_info.background_color == PAPER.background_color);
+
+    app.system_appearance_tx
+        .send(Some(ThemeAppearance::Dark))
+        .unwrap();
+    redraw(&mut app, &mut terminal);
+    assert!(app.app_color_info.background_color == DEFAULT.background_color);
+}
+
+#[test]
+fn process_list_can_be_sorted_by_elapsed_and_disk_write() {
+    // wide enough for the process list to show the elapsed column
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].current_write_disk_usage = 10;
+    processes_info.processes[1].current_write_disk_usage = 30;
+    processes_info.processes[2].current_write_disk_usage = 20;
+    processes_info.processes[0].elapsed = 3 * 60 * 60 + 5 * 60;
+    processes_info.processes[1].elapsed = 45;
+    processes_info.processes[2].elapsed = 10 * 60;
+    let _ = app.process_tx.send(processes_info);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::Elapsed);
+    assert!(app.process_sort_type == ProcessSortType::Elapsed);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Elapsed"));
+    assert!(text.contains("3h 5m"));
+    assert_eq!(get_sorted_process_names(&app), vec!["initd", "vim", "bash"]);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::DiskWrite);
+    assert!(app.process_sort_type == ProcessSortType::DiskWrite);
+    assert!(get_rendered_text(&terminal).contains("Disk Write"));
+    assert_eq!(get_sorted_process_names(&app), vec!["bash", "vim", "initd"]);
+}
+
+#[test]
+fn go_to_pid_opens_process_detail_or_shows_toast() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    assert!(app.pop_up_type == AppPopUpType::GoToPid);
+    type_text(&mut app, &mut terminal, "999");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert!(app.state == AppState::View);
+    assert!(!app.process_show_details);
+    assert!(get_rendered_text(&terminal).contains("Process with PID 999 was not found"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "200");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert!(app.selected_container == SelectedContainer::Process);
+    assert!(app.process_show_details);
+    assert!(app
+        .current_showing_process_detail
+        .as_ref()
+        .is_some_and(|process_detail| process_detail.contains_key("200")));
+}
+
+#[test]
+fn process_detail_can_navigate_to_parent_and_children() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].parent = "-".to_string();
+    processes_info.processes[1].parent = "1".to_string();
+    processes_info.processes[2].parent = "1".to_string();
+    let _ = app.process_tx.send(processes_info);
+
+    let get_detail_pid = |app: &App| -> String {
+        return app
+            .current_showing_process_detail
+            .as_ref()
+            .and_then(|process_detail| process_detail.keys().next().cloned())
+            .unwrap_or_default();
+    };
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "1");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert_eq!(get_detail_pid(&app), "1");
+
+    // cycle through the children and wrap around to the first one
+    press_key(&mut app, &mut terminal, KeyCode::Char('j'));
+    assert_eq!(get_detail_pid(&app), "100");
+    press_key(&mut app, &mut terminal, KeyCode::Char('j'));
+    assert_eq!(get_detail_pid(&app), "200");
+    press_key(&mut app, &mut terminal, KeyCode::Char('j'));
+    assert_eq!(get_detail_pid(&app), "100");
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('g'));
+    assert_eq!(get_detail_pid(&app), "1");
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('g'));
+    assert_eq!(get_detail_pid(&app), "1");
+    assert!(get_rendered_text(&terminal).contains("Process with PID 1 has no parent process"));
+}
+
+#[test]
+fn process_can_be_sorted_by_clicking_the_header() {
+    // wide enough for the process list to show all the columns
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+    let click_header = |app: &mut App, terminal: &mut Terminal<TestBackend>, sort_type| {
+        let (header_area, _) = app
+            .process_header_columns
+            .iter()
+            .find(|(_, header_sort_type)| *header_sort_type == sort_type)
+            .cloned()
+            .unwrap();
+        app.run_once(
+            terminal,
+            Some(Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: header_area.x + 1,
+                row: header_area.y,
+                modifiers: KeyModifiers::NONE,
+            })),
+        )
+        .unwrap();
+        app.run_once(terminal, None).unwrap();
+    };
+
+    click_header(&mut app, &mut terminal, ProcessSortType::Memory);
+    assert!(app.selected_container == SelectedContainer::Process);
+    assert!(app.process_sort_type == ProcessSortType::Memory);
+    let is_reversed = app.process_sort_is_reversed;
+    // clicking the header of the current sort reverses it
+    click_header(&mut app, &mut terminal, ProcessSortType::Memory);
+    assert_eq!(app.process_sort_is_reversed, !is_reversed);
+
+    // the cycling continues from the clicked sort
+    click_header(&mut app, &mut terminal, ProcessSortType::Nice);
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    assert!(
+        app.process_sort_type
+            == ProcessSortType::get_process_sort_type_from_int(
+                ProcessSortType::Nice.get_sort_id() - 1
+            )
+    );
+}
+
+#[test]
+fn ignored_mouse_events_do_not_redraw() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    // the mouse was only captured when it was enabled in the settings
+    assert!(!get_mouse_capture_config(&ThemeConfig::new("default")));
+
+    for kind in [
+        MouseEventKind::Moved,
+        MouseEventKind::Drag(MouseButton::Left),
+        MouseEventKind::Up(MouseButton::Left),
+    ] {
+        app.run_once(
+            &mut terminal,
+            Some(Event::Mouse(MouseEvent {
+                kind,
+                column: 1,
+                row: 1,
+                modifiers: KeyModifiers::NONE,
+            })),
+        )
+        .unwrap();
+        assert!(!app.is_redraw_needed);
+    }
+
+    app.run_once(
+        &mut terminal,
+        Some(Event::Key(KeyEvent::new(
+            KeyCode::Char('p'),
+            KeyModifiers::NONE,
+        ))),
+    )
+    .unwrap();
+    assert!(app.is_redraw_needed);
+}
+
+#[test]
+fn kill_confirmation_can_choose_the_process_tree() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].parent = "-".to_string();
+    processes_info.processes[1].parent = "1".to_string();
+    processes_info.processes[2].parent = "100".to_string();
+    let _ = app.process_tx.send(processes_info);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "1");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    press_key(&mut app, &mut terminal, KeyCode::Char('k'));
+    assert!(app.pop_up_type == AppPopUpType::KillConfirmation);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Tree (T/t)"));
+    // the children of the process were listed with the size of the whole tree
+    assert!(text.contains("CHILDREN: 1 ( 2 in the tree )"));
+    assert!(text.contains("100 (bash)"));
+
+    // the tree button was between the yes and no buttons
+    let get_confirmation = |app: &App| {
+        let signal_state_data = app.current_process_signal_state_data.as_ref().unwrap();
+        return (
+            signal_state_data.yes_confirmation,
+            signal_state_data.tree_confirmation,
+            signal_state_data.no_confirmation,
+        );
+    };
+    press_key(&mut app, &mut terminal, KeyCode::Right);
+    assert_eq!(get_confirmation(&app), (false, true, false));
+    press_key(&mut app, &mut terminal, KeyCode::Right);
+    assert_eq!(get_confirmation(&app), (false, false, true));
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    assert_eq!(get_confirmation(&app), (false, true, false));
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    assert_eq!(get_confirmation(&app), (true, false, false));
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.state == AppState::View);
+
+    // the children come before their parent, so the tree was signaled from the leaves up
+    assert_eq!(
+        get_descendant_pids("1", &app.process_info.processes),
+        vec!["200".to_string(), "100".to_string()]
+    );
+    assert!(get_descendant_pids("200", &app.process_info.processes).is_empty());
+}
+
+#[test]
+fn process_can_be_signaled_from_the_selected_row() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let get_signal_pid = |app: &App| {
+        app.current_process_signal_state_data
+            .as_ref()
+            .map(|signal_state_data| signal_state_data.pid.clone())
+    };
+
+    // nothing to signal without a selected row or the detail
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('k'));
+    assert!(app.state == AppState::View);
+
+    app.process_selected_state.select(Some(1));
+    redraw(&mut app, &mut terminal);
+    let selected_pid = app.process_current_list[1].to_string();
+    assert!(app.get_status_bar_hints().contains(&("t", "terminate")));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('k'));
+    assert!(app.pop_up_type == AppPopUpType::KillConfirmation);
+    assert_eq!(get_signal_pid(&app), Some(selected_pid.clone()));
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('T'));
+    assert!(app.pop_up_type == AppPopUpType::TerminateConfirmation);
+    assert_eq!(get_signal_pid(&app), Some(selected_pid.clone()));
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('s'));
+    assert!(app.pop_up_type == AppPopUpType::SignalMenu);
+    assert_eq!(get_signal_pid(&app), Some(selected_pid));
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.state == AppState::View);
+}
+
+#[test]
+fn process_can_be_sorted_by_shared_and_virtual_memory() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].virtual_memory = 2_000_000.0;
+    processes_info.processes[1].virtual_memory = 8_000_000.0;
+    processes_info.processes[2].virtual_memory = 4_000_000.0;
+    processes_info.processes[0].shared_memory = None;
+    processes_info.processes[1].shared_memory = Some(100_000.0);
+    processes_info.processes[2].shared_memory = Some(300_000.0);
+    let _ = app.process_tx.send(processes_info);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::SharedMemory);
+    assert!(app.process_sort_type == ProcessSortType::SharedMemory);
+    assert_eq!(get_sorted_process_names(&app), vec!["vim", "bash", "initd"]);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::VirtualMemory);
+    assert!(app.process_sort_type == ProcessSortType::VirtualMemory);
+    assert_eq!(get_sorted_process_names(&app), vec!["bash", "vim", "initd"]);
+
+    // the detail shows the virtual and shared memory, or N/A when it's unavailable
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "1");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Virtual Memory:"));
+    assert!(text.contains("Shared Memory:"));
+    assert!(text.contains("N/A"));
+}
+
+#[test]
+fn process_can_be_sorted_by_oom_score() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].oom_score = Some((0, -1000));
+    processes_info.processes[1].oom_score = Some((666, 300));
+    processes_info.processes[2].oom_score = None;
+    let _ = app.process_tx.send(processes_info);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::OomScore);
+    assert!(app.process_sort_type == ProcessSortType::OomScore);
+    assert_eq!(get_sorted_process_names(&app), vec!["bash", "initd", "vim"]);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "100");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("OOM Score:"));
+    assert!(text.contains("666"));
+}
+
+#[test]
+fn process_can_be_sorted_by_cpu_time() {
+    // wide enough for the process list to show the cpu time column
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].cpu_time = 2 * 60 * 60 * 1000;
+    processes_info.processes[1].cpu_time = 500;
+    processes_info.processes[2].cpu_time = 90 * 1000;
+    let _ = app.process_tx.send(processes_info);
+
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::CpuTime);
+    assert!(app.process_sort_type == ProcessSortType::CpuTime);
+    assert_eq!(get_sorted_process_names(&app), vec!["initd", "vim", "bash"]);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("CPU Time:"));
+    assert!(text.contains("2h 0m"));
+    assert!(text.contains("1m 30s"));
+
+    // the detail shows the average cpu usage over the lifetime of the process
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "200");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Average CPU:"));
+    assert!(text.contains("150.00%"));
+}
+
+#[test]
+fn process_list_shows_and_filters_the_container() {
+    // wide enough for the process list to show the container column
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[1].container_info = Some(ContainerInfo {
+        id: "a".repeat(64),
+        runtime: "docker".to_string(),
+        name: Some("web".to_string()),
+        image: None,
+        restart_count: None,
+    });
+    processes_info.processes[2].container_info = Some(ContainerInfo {
+        id: "b".repeat(64),
+        runtime: "podman".to_string(),
+        name: None,
+        image: None,
+        restart_count: None,
+    });
+    processes_info.processes[0].cgroup = Some("init.scope".to_string());
+    let _ = app.process_tx.send(processes_info);
+    app.run_once(&mut terminal, None).unwrap();
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Container:"));
+    assert!(text.contains("web"));
+    // the short id was shown if the runtime wasn't queried
+    assert!(text.contains("podman:bbbbbbbbbbbb"));
+    assert!(text.contains("init.scope"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    type_text(&mut app, &mut terminal, "podman");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert_eq!(get_current_process_names(&app), vec!["vim"]);
+}
+
+#[test]
+fn process_detail_wraps_scrolls_and_copies_the_command() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+
+    let mut processes_info = get_test_processes_info();
+    let mut cmd = vec!["java"];
+    let args: Vec<String> = (0..60)
+        .map(|index| format!("-Dopt{}=value", index))
+        .collect();
+    cmd.extend(args.iter().map(|arg| arg.as_str()));
+    cmd.push("com.example.Main");
+    processes_info.processes[2].cmd = cmd.iter().map(|arg| arg.to_string()).collect();
+    let _ = app.process_tx.send(processes_info);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "200");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    assert!(!text.contains("com.example.Main"));
+    assert!(text.contains("↕ 1-3/10"));
+
+    // scrolling past the end stops at the last line
+    for _ in 0..20 {
+        app.run_once(
+            &mut terminal,
+            Some(Event::Key(KeyEvent::new(
+                KeyCode::Down,
+                KeyModifiers::SHIFT,
+            ))),
+        )
+        .unwrap();
+    }
+    app.run_once(&mut terminal, None).unwrap();
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("com.example.Main"));
+    assert!(text.contains("↕ 8-10/10"));
+    app.run_once(
+        &mut terminal,
+        Some(Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT))),
+    )
+    .unwrap();
+    app.run_once(&mut terminal, None).unwrap();
+    assert!(get_rendered_text(&terminal).contains("↕ 7-9/10"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('y'));
+    assert!(get_rendered_text(&terminal).contains("Copied the command of PID 200"));
+}
+
+#[test]
+fn selected_process_pid_and_command_can_be_copied() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    app.process_selected_state.select(Some(0));
+    redraw(&mut app, &mut terminal);
+    let selected_pid = app.process_current_list[0];
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('#'));
+    assert!(get_rendered_text(&terminal)
+        .contains(&format!("Copied the PID {} to the clipboard", selected_pid)));
+    press_key(&mut app, &mut terminal, KeyCode::Char('y'));
+    assert!(get_rendered_text(&terminal)
+        .contains(&format!("Copied the command of PID {}", selected_pid)));
+
+    // Y does the same as y
+    app.toast = None;
+    press_key(&mut app, &mut terminal, KeyCode::Char('Y'));
+    assert!(get_rendered_text(&terminal)
+        .contains(&format!("Copied the command of PID {}", selected_pid)));
+}
+
+#[test]
+fn clipboard_sequence_encodes_the_text_in_base64() {
+    assert_eq!(get_clipboard_sequence("hello"), "\x1b]52;c;aGVsbG8=\x07");
+    assert_eq!(get_clipboard_sequence("vim"), "\x1b]52;c;dmlt\x07");
+    assert_eq!(get_clipboard_sequence("ls -l"), "\x1b]52;c;bHMgLWw=\x07");
+    assert_eq!(get_clipboard_sequence("ab"), "\x1b]52;c;YWI=\x07");
+}
+
+#[test]
+fn process_detail_can_show_the_threads() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    // the full screen detail has the room for the memory usage
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "200");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert_eq!(*app.thread_pid_tx.borrow(), None);
+
+    // the threads were only collected while they were shown
+    press_key(&mut app, &mut terminal, KeyCode::Char('l'));
+    assert_eq!(*app.thread_pid_tx.borrow(), Some(200));
+    assert!(get_rendered_text(&terminal).contains("No thread info available"));
+
+    let _ = app.thread_tx.send((
+        200,
+        vec![
+            ThreadData {
+                tid: 201,
+                name: "io-worker".to_string(),
+                state: "Sleeping".to_string(),
+                cpu_usage: 2.5,
+            },
+            ThreadData {
+                tid: 202,
+                name: "spinner".to_string(),
+                state: "Running".to_string(),
+                cpu_usage: 99.0,
+            },
+        ],
+    ));
+    app.run_once(&mut terminal, None).unwrap();
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Name ( 2 threads ):"));
+    assert!(text.contains("99.0%"));
+    // the busiest thread was listed first
+    assert!(text.find("spinner").unwrap() < text.find("io-worker").unwrap());
+    assert!(!text.contains("MEMORY:"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('l'));
+    assert_eq!(*app.thread_pid_tx.borrow(), None);
+    assert!(get_rendered_text(&terminal).contains("MEMORY:"));
+}
+
+#[test]
+fn process_can_be_sorted_by_start_time() {
+    // wide enough for the process list to show the start time column
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].elapsed = 3 * 24 * 60 * 60;
+    processes_info.processes[1].elapsed = 45;
+    processes_info.processes[2].elapsed = 2 * 60 * 60 + 13 * 60;
+    let _ = app.process_tx.send(processes_info);
+
+    // the latest started process was first
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::StartTime);
+    assert!(app.process_sort_type == ProcessSortType::StartTime);
+    assert_eq!(get_sorted_process_names(&app), vec!["bash", "vim", "initd"]);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Start:"));
+    assert!(text.contains("2h 13m"));
+
+    // the detail shows the humanized elapsed time with the start time below it
+    press_key(&mut app, &mut terminal, KeyCode::Char(':'));
+    type_text(&mut app, &mut terminal, "1");
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("3d 0h"));
+    let now = Local::now();
+    assert!(text.contains(&format_start_time(
+        &get_start_time(3 * 24 * 60 * 60, &now),
+        &now
+    )));
+}
+
+#[test]
+fn start_time_is_formatted_like_ps() {
+    let now = Local.with_ymd_and_hms(2025, 6, 15, 14, 30, 0).unwrap();
+
+    let start_time = get_start_time(2 * 60 * 60 + 28 * 60, &now);
+    assert_eq!(format_start_time(&start_time, &now), "12:02");
+    let start_time = get_start_time(3 * 24 * 60 * 60, &now);
+    assert_eq!(format_start_time(&start_time, &now), "Jun 12");
+    let start_time = get_start_time(400 * 24 * 60 * 60, &now);
+    assert_eq!(format_start_time(&start_time, &now), "2024");
+}
+
+#[test]
+fn process_can_be_sorted_by_nice() {
+    // wide enough for the process list to show the nice column
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH * 2, TEST_HEIGHT);
+
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].nice = Some(-5);
+    processes_info.processes[1].nice = Some(19);
+    processes_info.processes[2].nice = None;
+    let _ = app.process_tx.send(processes_info);
+
+    // the most deprioritized process was first
+    select_process_sort(&mut app, &mut terminal, ProcessSortType::Nice);
+    assert!(app.process_sort_type == ProcessSortType::Nice);
+    assert_eq!(get_sorted_process_names(&app), vec!["bash", "vim", "initd"]);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Nice:"));
+    assert!(text.contains("-5"));
+    assert!(text.contains("19"));
+}
+
+#[test]
+fn cpu_block_shows_load_average_and_uptime() {
+    let (_, terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("load 1.25 0.50 0.25"));
+    assert!(text.contains("up 2d 3h"));
+}
+
+#[test]
+fn header_shows_system_info_and_hides_host_name_in_privacy_mode() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.sys_info.static_info = StaticSystemInfo {
+        host_name: "testhost".to_string(),
+        os_name: "Test OS 1.0".to_string(),
+        kernel_version: "6.1.0".to_string(),
+        architecture: "x86_64".to_string(),
+    };
+    redraw(&mut app, &mut terminal);
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("testhost │ Test OS 1.0 │ kernel 6.1.0 │ x86_64"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('h'));
+    let text = get_rendered_text(&terminal);
+    assert!(!text.contains("testhost"));
+    assert!(text.contains("*** │ Test OS 1.0"));
+}
+
+#[test]
+fn cpu_list_groups_by_core_and_aggregates_hyperthread_siblings() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("S0C0 CPU0"));
+    assert!(text.contains("S0C0 CPU1"));
+    assert_eq!(app.cpu_selectable_entries, 3);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('c'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('a'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("S0C0 x2"));
+    assert!(text.contains("25.00%"));
+    assert!(!text.contains("S0C0 CPU1"));
+    assert_eq!(app.cpu_selectable_entries, 2);
+}
+
+#[test]
+fn pressure_graphs_are_only_shown_when_available() {
+    let (_, terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("some 1.50% full 0.00%"));
+    assert!(text.contains("some 2.25% full 1.00%"));
+    assert!(!text.contains("IO PRESSURE"));
+}
+
+#[test]
+fn full_screen_cpu_can_show_per_core_grid() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    // the grid view is only available in full screen
+    press_key(&mut app, &mut terminal, KeyCode::Char('c'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('v'));
+    assert!(!app.is_cpu_grid_view);
+
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    assert!(get_rendered_text(&terminal).contains("iew grid"));
+    press_key(&mut app, &mut terminal, KeyCode::Char('v'));
+    assert!(app.is_cpu_grid_view);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("iew chart"));
+    assert!(text.contains("S0C0 CPU0") && text.contains("20.0%"));
+    assert!(text.contains("S0C0 CPU1") && text.contains("30.0%"));
+}
+
+#[test]
+fn cpu_graph_shows_time_breakdown_legend_when_available() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("usr 15.0%  sys 5.0%  irq 1.0%  io 12.5%  st 0.0%"));
+
+    // the logical cpus of the fixture don't have the breakdown, so only the total usage is shown
+    press_key(&mut app, &mut terminal, KeyCode::Char('c'));
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert!(!get_rendered_text(&terminal).contains("usr "));
+}
+
+#[test]
+fn memory_breakdown_is_shown_in_large_height_layout() {
+    let (_, terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Buffers:"));
+    assert!(text.contains("HugePages:"));
+
+    let (_, terminal) = start_test_app(TEST_WIDTH, 30);
+    assert!(!get_rendered_text(&terminal).contains("Buffers:"));
+}
+
+#[test]
+fn memory_shows_compressed_swap_when_active() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("zram:"));
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.memory.compressed_swap = Some(CompressedSwapInfo {
+        kind: "zram".to_string(),
+        original_size: 3.0 * 1024.0 * 1024.0 * 1024.0,
+        compressed_size: 1024.0 * 1024.0 * 1024.0,
+    });
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('m'));
+    assert!(get_rendered_text(&terminal).contains("zram:"));
+    assert!(get_rendered_text(&terminal).contains("3.0x"));
+}
+
+#[test]
+fn memory_unit_can_be_cycled() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains("Unit Auto"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('i'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Unit MiB"));
+    assert!(text.contains("3814.70 MiB"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('i'));
+    assert!(get_rendered_text(&terminal).contains("3.73 GiB"));
+
+    // the used memory and the process memory are shown as the percentage of the total memory
+    press_key(&mut app, &mut terminal, KeyCode::Char('i'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Unit %"));
+    assert!(text.contains("50.00%"));
+    assert!(text.contains("0.01%"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('i'));
+    assert!(get_rendered_text(&terminal).contains("Unit Auto"));
+}
+
+#[test]
+fn process_refresh_tick_is_adjusted_separately() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let (tick, process_tick) = (app.tick, app.process_tick);
+
+    // the system refresh tick was adjusted when the process container isn't selected
+    press_key(&mut app, &mut terminal, KeyCode::Char('c'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('+'));
+    assert_eq!(app.tick, tick + 100);
+    assert_eq!(app.process_tick, process_tick);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('-'));
+    assert_eq!(app.tick, tick + 100);
+    assert_eq!(app.process_tick, process_tick - 100);
+
+    // the process container shows its own refresh tick in full screen
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    assert!(get_rendered_text(&terminal).contains(&format!(" {}ms ", process_tick - 100)));
+}
+
+#[test]
+fn disk_and_network_rates_are_shown_per_second() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("512.00 B/s"));
+    assert!(text.contains("1.00 KiB/s"));
+
+    // the bytes collected within the refresh interval are scaled to a second
+    app.tick = 500;
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("1.00 KiB/s"));
+    assert!(text.contains("2.00 KiB/s"));
+}
+
+#[test]
+fn memory_shows_each_swap_device() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.memory.swap_devices = vec![
+        SwapDevice {
+            name: "/dev/sda2".to_string(),
+            kind: "partition".to_string(),
+            size: 2.0 * 1024.0 * 1024.0 * 1024.0,
+            used: 512.0 * 1024.0 * 1024.0,
+            priority: -2,
+        },
+        SwapDevice {
+            name: "/swapfile".to_string(),
+            kind: "file".to_string(),
+            size: 1024.0 * 1024.0 * 1024.0,
+            used: 0.0,
+            priority: 10,
+        },
+    ];
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('m'));
+    assert!(get_rendered_text(&terminal).contains("sda2"));
+
+    // the full screen memory container has enough space to list all the swap devices
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("512.00 MiB / 2.00 GiB"));
+    assert!(text.contains("swapfile"));
+}
+
+#[test]
+fn memory_shows_zfs_arc_when_present() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("ARC:"));
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.memory.zfs_arc = Some(ZfsArcInfo {
+        size: 1024.0 * 1024.0 * 1024.0,
+        target_size: 2.0 * 1024.0 * 1024.0 * 1024.0,
+        hits: 985,
+        misses: 15,
+        hit_rate: 98.5,
+    });
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('m'));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("ARC:"));
+    assert!(text.contains("1.00 GiB / 2.00 GiB hit 98.5%"));
+}
+
+#[test]
+fn disk_shows_inode_usage_when_reported() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains("Inodes: 25.0%"));
+
+    // the inode row is hidden for file system that doesn't report inodes
+    let mut sys_info = get_test_sys_info();
+    sys_info.disks[0].inodes = None;
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(!get_rendered_text(&terminal).contains("Inodes:"));
+}
+
+#[cfg(feature = "smart")]
+#[test]
+fn disk_shows_smart_health_when_available() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("SMART:"));
+
+    let _ = app.smart_tx.send(HashMap::from([(
+        "/".to_string(),
+        SmartInfo {
+            device: "/dev/sda".to_string(),
+            is_healthy: Some(true),
+            reallocated_sectors: Some(3),
+            wear_level: Some(12),
+            temperature: None,
+        },
+    )]));
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("SMART:"));
+    assert!(text.contains("PASSED realloc 3 wear 12%"));
+}
+
+#[cfg(feature = "smart")]
+#[test]
+fn disk_header_shows_temperature_from_hwmon_or_smart() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("Temp:"));
+
+    let _ = app.smart_tx.send(HashMap::from([(
+        "/".to_string(),
+        SmartInfo {
+            device: "/dev/sda".to_string(),
+            is_healthy: Some(true),
+            reallocated_sectors: None,
+            wear_level: None,
+            temperature: Some(38.0),
+        },
+    )]));
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(get_rendered_text(&terminal).contains("Temp: 38°C"));
+
+    // the hwmon temperature was preferred as it was collected every tick
+    let mut sys_info = get_test_sys_info();
+    sys_info.disks[0].temperature = Some(42.4);
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(get_rendered_text(&terminal).contains("Temp: 42°C"));
+    assert_eq!(app.sys_info.disks["/"].temperature_vec[..], [42.4]);
+}
+
+#[test]
+fn full_screen_disk_shows_io_latency_and_utilization() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.disks[0].io_stats = Some(DiskIoStats {
+        await_ms: 4.25,
+        queue_depth: 1.5,
+        utilization: 87.5,
+    });
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(!get_rendered_text(&terminal).contains("AWAIT:"));
+
+    // the io stats graphs are only shown in the full screen disk container
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("87.5%"));
+    assert!(text.contains("4.25ms"));
+    assert!(text.contains("1.50"));
+}
+
+#[test]
+fn disk_list_view_shows_all_disks() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    let mut data_disk = get_test_sys_info().disks.remove(0);
+    data_disk.name = "datadisk".to_string();
+    data_disk.mount_point = "/data".to_string();
+    sys_info.disks.push(data_disk);
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("List all"));
+    assert!(!text.contains(">> /"));
+
+    // every disk is listed and the selected one is marked
+    press_key(&mut app, &mut terminal, KeyCode::Char('l'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("List hide"));
+    assert!(text.contains(">> /"));
+    assert!(text.contains("/data"));
+    assert!(text.contains("40.0%"));
+}
+
+#[test]
+fn disk_list_groups_partitions_under_physical_device() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    let mut nvme_disks = Vec::new();
+    for (partition, mount_point) in [("p1", "/boot"), ("p2", "/home")] {
+        let mut disk = get_test_sys_info().disks.remove(0);
+        disk.name = format!("/dev/nvme0n1{}", partition);
+        disk.mount_point = mount_point.to_string();
+        disk.physical_device = "/dev/nvme0n1".to_string();
+        disk.bytes_written = 1024.0 * 1024.0;
+        disk.bytes_read = 0.0;
+        nvme_disks.push(disk);
+    }
+    sys_info.disks.extend(nvme_disks);
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('l'));
+
+    // the partitions are listed under their device with the total rate of the device
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("nvme0n1 (2)"));
+    assert!(text.contains("testdisk (1)"));
+
+    // the first disk in the device order is selected first
+    assert!(text.contains(">> /boot"));
+    press_key(&mut app, &mut terminal, KeyCode::Right);
+    assert!(get_rendered_text(&terminal).contains(">> /home"));
+
+    // the device rate is shown in the full screen disk container
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    assert!(get_rendered_text(&terminal).contains("W 2.00 MiB/s"));
+}
+
+#[test]
+fn degraded_raid_array_is_shown_and_alerted() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.raid_arrays =
+        parse_mdstat("md0 : active raid1 sdb1[1] sda1[0]\n      1046528 blocks [2/2] [UU]\n");
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("md0:"));
+    assert!(!text.contains("is degraded"));
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.raid_arrays =
+        parse_mdstat("md0 : active raid1 sdb1[1](F) sda1[0]\n      1046528 blocks [2/1] [U_]\n");
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("DEGRADED"));
+    assert!(text.contains("RAID array md0 is degraded"));
+}
+
+#[test]
+fn disk_in_storage_pool_shows_pool_usage() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.disks[0].name = "tank/root".to_string();
+    sys_info.disks[0].file_system = "zfs".to_string();
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(!get_rendered_text(&terminal).contains("Pool:"));
+
+    let _ = app.storage_pool_tx.send(vec![StoragePool {
+        name: "tank".to_string(),
+        kind: "zfs".to_string(),
+        raid_level: "mirror".to_string(),
+        devices: vec![],
+        size: 4.0 * 1024.0 * 1024.0 * 1024.0,
+        allocated: 1024.0 * 1024.0 * 1024.0,
+        health: Some("ONLINE".to_string()),
+        scrub_status: Some("none requested".to_string()),
+    }]);
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("tank mirror ONLINE"));
+    assert!(text.contains("1.00 GiB / 4.00 GiB free 3.00 GiB"));
+    assert!(text.contains("none requested"));
+}
+
+#[test]
+fn disk_below_free_space_threshold_is_alerted() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    // the mount point threshold takes priority over the "*" threshold
+    app.disk_free_thresholds = DiskFreeThresholds::new(HashMap::from([
+        ("*".to_string(), 10.0),
+        ("/".to_string(), 70.0),
+    ]));
+    let mut sys_info = get_test_sys_info();
+    sys_info.disks[0].available_space = 90_000_000_000.0;
+    sys_info.disks[0].used_space = 10_000_000_000.0;
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(!get_rendered_text(&terminal).contains("Low disk space"));
+
+    let _ = app.tx.send(get_test_sys_info());
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    assert!(get_rendered_text(&terminal).contains("Low disk space on / ( 60.0% free )"));
+}
+
+#[test]
+fn network_shows_packet_error_and_drop_counters() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("▼ 12 ▲ 8"));
+    assert!(text.contains("Err 0/0"));
+    assert!(text.contains("Drop 0/0"));
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.networks[0].packet_stats.errors_received = 3;
+    sys_info.networks[0].packet_stats.drops = None;
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Err 3/0"));
+    assert!(!text.contains("Drop"));
+}
+
+#[test]
+fn network_shows_interface_details() {
+    let (_app, terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(
+        get_rendered_text(&terminal).contains("MAC 02:42:ac:11:00:02 | MTU 1500 | 1000 Mb/s full")
+    );
+}
+
+#[test]
+fn network_rates_can_be_switched_to_bits() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let _ = app.tx.send(get_test_sys_info());
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Download: ▼ 512.00 B/s"));
+    assert!(text.contains("byte/s"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('b'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Download: ▼ 4.10 Kbps"));
+    assert!(text.contains("Upload: ▲ 2.05 Kbps"));
+    assert!(text.contains("bit/s"));
+}
+
+#[test]
+fn network_selector_has_an_entry_for_all_interfaces() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let mut sys_info = get_test_sys_info();
+    let mut loopback = get_test_sys_info().networks.remove(0);
+    loopback.interface_name = "lo".to_string();
+    let mut second_network = get_test_sys_info().networks.remove(0);
+    second_network.interface_name = "testnet1".to_string();
+    sys_info.networks.push(loopback);
+    sys_info.networks.push(second_network);
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert!(!get_rendered_text(&terminal).contains("All interfaces"));
+
+    // the entry for all the interfaces is before the first interface, the loopback is not counted
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("All interfaces"));
+    assert!(text.contains("Download: ▼ 1.00 KiB/s"));
+    assert!(text.contains("▼ 24 ▲ 16"));
+}
+
+#[test]
+fn network_addresses_can_be_cycled() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert!(get_rendered_text(&terminal).contains("10.1.2.3/24 (1/2)"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert!(get_rendered_text(&terminal).contains("fe80::1 (2/2)"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert!(get_rendered_text(&terminal).contains("10.1.2.3/24 (1/2)"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Up);
+    assert!(get_rendered_text(&terminal).contains("fe80::1 (2/2)"));
+}
+
+#[test]
+fn network_shows_ping_rtt_and_loss() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("Ping"));
+
+    for rtt_ms in [Some(12.34), None, Some(15.0), Some(14.0)] {
+        let _ = app.ping_tx.send(PingResult {
+            target: "192.168.1.1".to_string(),
+            rtt_ms,
+        });
+    }
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    assert!(get_rendered_text(&terminal).contains("Ping 192.168.1.1: 14.0 ms loss 25%"));
+}
+
+#[test]
+fn full_screen_network_lists_busiest_connections() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let mut sys_info = get_test_sys_info();
+    sys_info.connections = vec![
+        ConnectionThroughput {
+            local: "10.1.2.3:40000".to_string(),
+            remote: "93.184.216.34:443".to_string(),
+            sent: 1024.0,
+            received: 2048.0,
+        },
+        ConnectionThroughput {
+            local: "10.1.2.3:22".to_string(),
+            remote: "10.1.2.9:51234".to_string(),
+            sent: 4096.0,
+            received: 4096.0,
+        },
+    ];
+    let _ = app.tx.send(sys_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert!(!get_rendered_text(&terminal).contains("Connections"));
+    assert!(!app.shown_system_info_tx.borrow().is_connection_list_shown);
+
+    // the connections are only listed in the full screen network container, the busiest first
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Connections"));
+    let busiest_position = text.find("10.1.2.9:51234").unwrap();
+    assert!(busiest_position < text.find("93.184.216.34:443").unwrap());
+
+    // the connections were only collected while they were listed
+    assert!(app.shown_system_info_tx.borrow().is_connection_list_shown);
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(!app.shown_system_info_tx.borrow().is_connection_list_shown);
+}
+
+#[test]
+fn network_shows_gateway_dns_and_public_ip() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("GW 10.1.2.1 | DNS 10.1.2.53"));
+    assert!(!text.contains("Public"));
+
+    // the public IP was looked up separately and kept across the refreshes
+    let _ = app.public_ip_tx.send("203.0.113.7".to_string());
+    let _ = app.tx.send(get_test_sys_info());
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert!(
+        get_rendered_text(&terminal).contains("GW 10.1.2.1 | DNS 10.1.2.53 | Public 203.0.113.7")
+    );
+}
+
+#[test]
+fn data_updates_can_be_paused() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char(' '));
+    assert!(get_rendered_text(&terminal).contains("PAUSED"));
+
+    // the collected info is queued while paused
+    let mut sys_info = get_test_sys_info();
+    sys_info.system.load_average_one = 7.5;
+    let _ = app.tx.send(sys_info);
+    let _ = app.tx.send(get_test_sys_info());
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert_eq!(app.sys_info.system.load_average_one, 1.25);
+    assert_eq!(
+        app.sys_info.networks["testnet0"].current_received_vec.len(),
+        1
+    );
+
+    // all the queued info was applied on resume
+    press_key(&mut app, &mut terminal, KeyCode::Char(' '));
+    assert!(!get_rendered_text(&terminal).contains("PAUSED"));
+    assert_eq!(
+        app.sys_info.networks["testnet0"].current_received_vec.len(),
+        3
+    );
+}
+
+#[test]
+fn all_the_info_queued_during_a_long_pause_is_applied_on_resume() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char(' '));
+
+    for _ in 0..200 {
+        let _ = app.tx.send(get_test_sys_info());
+        app.run_once(&mut terminal, None).unwrap();
+    }
+    // the info was drained from the channels while paused, up to the maximum history length
+    assert_eq!(app.queued_sys_info.len(), 200);
+    assert!(app.queued_sys_info.len() <= get_maximum_data_collection());
+
+    // the changes before a snapshot were dropped, as the snapshot has the full info of all the processes
+    let _ = app.process_tx.send(CProcessesInfo {
+        is_snapshot: false,
+        processes: vec![get_test_process(400, "top", vec!["top"], "alice")],
+        updated_processes: vec![],
+        removed_pids: vec![],
+    });
+    let mut snapshot = get_test_processes_info();
+    snapshot.is_snapshot = true;
+    let _ = app.process_tx.send(snapshot);
+    // htop was started while paused, only its change was sent
+    let _ = app.process_tx.send(CProcessesInfo {
+        is_snapshot: false,
+        processes: vec![get_test_process(300, "htop", vec!["htop"], "alice")],
+        updated_processes: vec![],
+        removed_pids: vec![],
+    });
+    redraw(&mut app, &mut terminal);
+    assert_eq!(app.queued_process_info.len(), 2);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char(' '));
+    assert!(app.queued_sys_info.is_empty());
+    assert!(app.queued_process_info.is_empty());
+    assert_eq!(
+        app.sys_info.networks["testnet0"].current_received_vec.len(),
+        201
+    );
+    assert!(app.process_info.processes.contains_key("300"));
+}
+
+#[test]
+fn graph_history_can_be_panned_back() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    for _ in 0..30 {
+        let _ = app.tx.send(get_test_sys_info());
+        app.run_once(&mut terminal, None).unwrap();
+    }
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('{'));
+    assert_eq!(app.network_graph_offset, 10);
+    assert_eq!(app.cpu_graph_offset, 0);
+    assert!(get_rendered_text(&terminal).contains("{ -10 }"));
+
+    // the offset can't go beyond the oldest point or after the latest point
+    for _ in 0..5 {
+        press_key(&mut app, &mut terminal, KeyCode::Char('{'));
+    }
+    assert_eq!(
+        app.network_graph_offset,
+        app.sys_info.cpus[0].usage_history_vec.len() - 1
+    );
+    for _ in 0..5 {
+        press_key(&mut app, &mut terminal, KeyCode::Char('}'));
+    }
+    assert_eq!(app.network_graph_offset, 0);
+    assert!(!get_rendered_text(&terminal).contains("{ -"));
+}
+
+#[test]
+fn panned_history_drops_the_latest_points() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let mut sys_info = get_test_sys_info();
+    sys_info.networks[0].current_received = 4096.0;
+    let _ = app.tx.send(sys_info);
+    app.run_once(&mut terminal, None).unwrap();
+    let network_data = &app.sys_info.networks["testnet0"];
+    assert_eq!(network_data.current_received_vec[..], [512.0, 4096.0]);
+    assert_eq!(network_data.current_received_vec.get_panned(1), [512.0]);
+    // the oldest point was always kept
+    assert_eq!(network_data.current_received_vec.get_panned(5), [512.0]);
+}
+
+#[test]
+fn graph_style_can_be_switched_per_container() {
+    assert_eq!(GraphStyle::from_config("Dot-Line"), GraphStyle::DotLine);
+    assert_eq!(GraphStyle::from_config("unknown"), GraphStyle::BrailleBar);
+    assert_eq!(GraphStyle::DotLine.get_next(), GraphStyle::BrailleBar);
+
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let memory_graph_style = app.memory_graph_style;
+    press_key(&mut app, &mut terminal, KeyCode::Char('c'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('w'));
+    let cpu_graph_style = app.cpu_graph_style;
+    assert_eq!(app.memory_graph_style, memory_graph_style);
+    assert!(get_rendered_text(&terminal)
+        .contains(&format!("CPU graph style: {}", cpu_graph_style.get_name())));
+    assert_eq!(cpu_graph_style.get_graph_type(), GraphType::Line);
+}
+
+#[test]
+fn graph_gradient_colors_the_points_by_value() {
+    let graph_gradient: GraphGradient =
+        serde_json::from_str(r#"{ "low": "Green", "medium": "Yellow", "high": "Red" }"#).unwrap();
+    assert_eq!(graph_gradient.get_color(10.0), Color::Green);
+    assert_eq!(graph_gradient.get_color(65.0), Color::Yellow);
+    assert_eq!(graph_gradient.get_color(95.0), Color::Red);
+
+    let points = vec![(0.0, 10.0), (1.0, 90.0), (2.0, 20.0)];
+    let gradient_points = get_gradient_points(
+        &points,
+        Color::Blue,
+        Some(&graph_gradient),
+        GraphStyle::BrailleBar,
+    );
+    assert_eq!(gradient_points[0].0, Color::Green);
+    assert_eq!(gradient_points[0].1, vec![(0.0, 10.0), (2.0, 20.0)]);
+    assert_eq!(gradient_points[2].0, Color::Red);
+    assert_eq!(gradient_points[2].1, vec![(1.0, 90.0)]);
+    // the line graph was colored by the latest value, the points were borrowed as they weren't split
+    let line_gradient_points = get_gradient_points(
+        &points,
+        Color::Blue,
+        Some(&graph_gradient),
+        GraphStyle::DotLine,
+    );
+    assert_eq!(line_gradient_points.len(), 1);
+    assert_eq!(line_gradient_points[0].0, Color::Green);
+    assert!(matches!(line_gradient_points[0].1, Cow::Borrowed(_)));
+    let base_gradient_points =
+        get_gradient_points(&points, Color::Blue, None, GraphStyle::BrailleBar);
+    assert_eq!(base_gradient_points.len(), 1);
+    assert_eq!(base_gradient_points[0].0, Color::Blue);
+    assert_eq!(base_gradient_points[0].1, points);
+
+    // the gradient was kept when the theme was saved and loaded
+    let mut app_color_info = DEFAULT;
+    app_color_info.graph_gradient = Some(graph_gradient);
+    let theme_json = serde_json::to_string(&app_color_info).unwrap();
+    let loaded_app_color_info: AppColorInfo = serde_json::from_str(&theme_json).unwrap();
+    assert_eq!(loaded_app_color_info.graph_gradient, Some(graph_gradient));
+}
+
+#[test]
+fn process_rows_are_colored_by_the_state_and_the_usage() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    let mut processes_info = get_test_processes_info();
+    processes_info.processes[0].status = "Runnable".to_string();
+    processes_info.processes[1].status = "Zombie".to_string();
+    let _ = app.process_tx.send(processes_info);
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+
+    // the color of the first character of the process name in the process list
+    let get_name_color = |terminal: &Terminal<TestBackend>, name: &str| -> Color {
+        let text = get_rendered_text(terminal);
+        let (y, line) = text
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.contains(name) && !line.contains("Top CPU:"))
+            .unwrap();
+        let x = line[..line.find(name).unwrap()].chars().count();
+        return terminal.backend().buffer()[(x as u16, y as u16)].fg;
+    };
+    assert_eq!(get_name_color(&terminal, "initd"), Color::Green);
+    assert_eq!(get_name_color(&terminal, "bash"), Color::Red);
+    assert_eq!(get_name_color(&terminal, "vim"), DEFAULT.process_text_color);
+
+    // the thresholds and the colors can be set by the theme
+    let process_heat: ProcessHeat = serde_json::from_str(
+        r#"{ "running": "Cyan", "stopped": "Magenta", "zombie": "Red", "medium_percentage": 20.0,
+            "high_percentage": 40.0, "medium": "Blue", "high": "LightRed" }"#,
+    )
+    .unwrap();
+    assert_eq!(process_heat.get_usage_color(10.0), None);
+    assert_eq!(process_heat.get_usage_color(25.0), Some(Color::Blue));
+    assert_eq!(process_heat.get_usage_color(45.0), Some(Color::LightRed));
+    assert_eq!(
+        process_heat.get_state_color("Stopped"),
+        Some(Color::Magenta)
+    );
+    assert_eq!(process_heat.get_state_color("Sleeping"), None);
+}
+
+#[test]
+fn graph_axis_labels_and_grid_lines_can_be_shown() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("┄"));
+
+    app.is_graph_axis_shown = true;
+    redraw(&mut app, &mut terminal);
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(rendered_text.contains("100%"));
+    assert!(rendered_text.contains("75%"));
+    assert!(rendered_text.contains("┄"));
+
+    assert!(get_scaled_axis_labels(false, 2048.0, |value| format!("{}", value)).is_empty());
+    assert_eq!(
+        get_scaled_axis_labels(true, 2048.0, |value| format!("{}", value)),
+        vec!["0", "1024", "2048"]
+    );
+}
+
+#[test]
+fn throughput_graphs_label_the_peak_of_the_shown_window() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains("peak "));
+
+    // the peak was shown by the axis labels instead
+    app.is_graph_axis_shown = true;
+    redraw(&mut app, &mut terminal);
+    assert!(!get_rendered_text(&terminal).contains("peak "));
+}
+
+#[test]
+fn containers_can_be_rearranged_by_the_layout() {
+    let layout: ContainerLayout = serde_json::from_str(
+        r#"{
+            "direction": "horizontal",
+            "children": [
+                { "container": "process", "size": 70 },
+                { "children": [{ "container": "cpu" }, { "container": "memory" }, { "container": "disk" }] }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let container_areas = get_container_areas(&layout, &[], Rect::new(0, 0, 100, 30));
+    assert_eq!(
+        container_areas[0],
+        (SelectedContainer::Process, Rect::new(0, 0, 70, 30))
+    );
+    assert_eq!(
+        container_areas[1],
+        (SelectedContainer::Cpu, Rect::new(70, 0, 30, 10))
+    );
+    assert_eq!(
+        get_container_area(&container_areas, SelectedContainer::Network),
+        None
+    );
+    // the default layout kept the original arrangement
+    let default_areas = get_container_areas(
+        &ContainerLayout::default_layout(),
+        &[],
+        Rect::new(0, 0, 100, 100),
+    );
+    assert_eq!(
+        get_container_area(&default_areas, SelectedContainer::Cpu),
+        Some(Rect::new(0, 0, 100, 30))
+    );
+
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains("Download"));
+    app.tabs[0].layout = layout;
+    redraw(&mut app, &mut terminal);
+    assert!(!get_rendered_text(&terminal).contains("Download"));
+
+    // the hidden network container can't be selected
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    assert!(app.selected_container == SelectedContainer::None);
+}
+
+#[test]
+fn containers_can_be_hidden_and_shown() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(get_rendered_text(&terminal).contains("WRITE:"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('D'));
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(!rendered_text.contains("WRITE:"));
+    assert!(rendered_text.contains("Disk container hidden"));
+    // the hidden disk container was unselected and the memory container took up its space
+    assert!(app.selected_container == SelectedContainer::None);
+    let container_areas = get_container_areas(
+        &app.tabs[app.selected_tab].layout,
+        &app.hidden_containers,
+        Rect::new(0, 0, 100, 100),
+    );
+    assert_eq!(
+        get_container_area(&container_areas, SelectedContainer::Memory).map(|area| area.width),
+        Some(45)
+    );
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('D'));
+    assert!(get_rendered_text(&terminal).contains("WRITE:"));
+}
+
+#[test]
+fn tabs_can_be_switched_with_the_number_keys() {
+    let tabs: Vec<LayoutTab> = serde_json::from_str(
+        r#"[
+            { "name": "overview", "layout": { "children": [{ "container": "cpu" }, { "container": "network" }] } },
+            { "name": "processes", "layout": { "container": "process" } }
+        ]"#,
+    )
+    .unwrap();
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.tabs = tabs;
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(rendered_text.contains("1 overview"));
+    assert!(rendered_text.contains("2 processes"));
+    assert!(rendered_text.contains("Download"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('2'));
+    assert_eq!(app.selected_tab, 1);
+    assert!(app.selected_container == SelectedContainer::None);
+    assert!(!get_rendered_text(&terminal).contains("Download"));
+
+    // there is no third tab
+    press_key(&mut app, &mut terminal, KeyCode::Char('3'));
+    assert_eq!(app.selected_tab, 1);
+
+    // the selected container of the first tab was restored
+    press_key(&mut app, &mut terminal, KeyCode::Char('1'));
+    assert!(app.selected_container == SelectedContainer::Network);
+}
+
+#[test]
+fn layout_presets_can_be_cycled() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('0'));
+    assert!(get_rendered_text(&terminal).contains("Layout: full dashboard"));
+
+    // the network container was left out of the process focused preset
+    press_key(&mut app, &mut terminal, KeyCode::Char('0'));
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(rendered_text.contains("Layout: process focused"));
+    assert!(!rendered_text.contains("Download"));
+    assert!(app.selected_container == SelectedContainer::None);
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('0'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('0'));
+    assert_eq!(app.selected_layout_preset, None);
+    assert!(get_rendered_text(&terminal).contains("Layout: overview"));
+}
+
+#[test]
+fn app_can_start_in_a_full_screen_container() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.start_in_view(SelectedContainer::from_name("Network").unwrap());
+    redraw(&mut app, &mut terminal);
+    assert!(app.selected_container == SelectedContainer::Network);
+    assert!(app.container_full_screen);
+    // the other containers were not rendered in full screen
+    assert!(!get_rendered_text(&terminal).contains("WRITE:"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(!app.container_full_screen);
+    assert!(get_rendered_text(&terminal).contains("WRITE:"));
+}
+
+#[test]
+fn unparsable_settings_file_is_not_overwritten() {
+    let config_directory =
+        std::env::temp_dir().join(format!("rtop-test-settings-{}", std::process::id()));
+    std::fs::create_dir_all(&config_directory).unwrap();
+    let settings_filepath = config_directory.join("settings.json");
+    std::fs::write(&settings_filepath, "{ \"theme\": \"paper\", ").unwrap();
+
+    assert!(read_theme_config(Some(&config_directory)).is_err());
+    assert!(set_theme(&config_directory, "default".to_string()).is_err());
+    assert!(save_tabs_config(&config_directory, &[]).is_err());
+
+    // the error was shown when the file was reloaded, and the current settings were kept
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.config_directory = Some(config_directory.clone());
+    app.reload_theme_if_changed();
+    redraw(&mut app, &mut terminal);
+    assert!(get_rendered_text(&terminal).contains("Failed to read the settings"));
+    assert_eq!(app.theme_config.theme, "default");
+
+    // switching the theme doesn't write the defaults over the file
+    app.save_theme("paper".to_string());
+    redraw(&mut app, &mut terminal);
+    assert!(get_rendered_text(&terminal).contains("Failed to save the theme"));
+    assert_eq!(
+        std::fs::read_to_string(&settings_filepath).unwrap(),
+        "{ \"theme\": \"paper\", "
+    );
+    std::fs::remove_dir_all(&config_directory).unwrap();
+}
+
+#[test]
+fn failed_settings_save_is_shown_as_a_toast() {
+    // the settings directory can't be created in /proc
+    let (mut app, tick_rx, process_tick_rx) = new_app(
+        ThemeConfig::new("default"),
+        Some(PathBuf::from("/proc/rtop-test")),
+        None,
+    );
+    app.start_collectors(
+        &MockMetricsSource {
+            sys_info: get_test_sys_info,
+            processes_info: get_test_processes_info,
+        },
+        tick_rx,
+        process_tick_rx,
+    );
+    app.wait_for_initial_info();
+    let mut terminal = Terminal::new(TestBackend::new(TEST_WIDTH, TEST_HEIGHT)).unwrap();
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    press_key(&mut app, &mut terminal, KeyCode::Char('.'));
+    assert!(get_rendered_text(&terminal).contains("Failed to save the layout"));
+}
+
+#[test]
+fn container_share_can_be_resized() {
+    let mut layout = ContainerLayout::default_layout();
+    assert!(layout.resize_container(SelectedContainer::Process, 5));
+    let container_areas = get_container_areas(&layout, &[], Rect::new(0, 0, 100, 100));
+    assert_eq!(
+        get_container_area(&container_areas, SelectedContainer::Process).map(|area| area.width),
+        Some(60)
+    );
+    assert_eq!(
+        get_container_area(&container_areas, SelectedContainer::Memory).map(|area| area.width),
+        Some(20)
+    );
+
+    // the container can't be shrunk below the minimum share
+    for _ in 0..20 {
+        layout.resize_container(SelectedContainer::Process, -5);
+    }
+    assert!(!layout.resize_container(SelectedContainer::Process, -5));
+
+    // the parent split was resized when the container was the only child
+    let mut layout: ContainerLayout = serde_json::from_str(
+        r#"{ "direction": "horizontal", "children": [{ "children": [{ "container": "cpu" }] }, { "container": "process" }] }"#,
+    )
+    .unwrap();
+    assert!(layout.resize_container(SelectedContainer::Cpu, 10));
+    assert_eq!(layout.children[0].size, Some(60));
+    assert_eq!(layout.children[1].size, Some(40));
+    assert!(!layout.resize_container(SelectedContainer::Network, 10));
+}
+
+#[test]
+fn status_bar_shows_the_key_hints_of_the_context() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("c m d n p select"));
+
+    app.is_status_bar_shown = true;
+    redraw(&mut app, &mut terminal);
+    assert!(get_rendered_text(&terminal).contains("c m d n p select"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('p'));
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(rendered_text.contains("Enter details"));
+    assert!(rendered_text.contains("Tab full screen"));
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('f'));
+    assert!(get_rendered_text(&terminal).contains("Enter apply filter"));
+}
+
+#[test]
+fn summary_line_shows_uptime_users_and_load_average() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("2 users"));
+    // the users were only counted while the summary line was shown
+    assert!(!app.shown_system_info_tx.borrow().is_summary_line_shown);
+
+    app.is_summary_line_shown = true;
+    redraw(&mut app, &mut terminal);
+    assert!(app.shown_system_info_tx.borrow().is_summary_line_shown);
+    let rendered_text = get_rendered_text(&terminal);
+    assert!(rendered_text.contains("up 2d 3h │ 2 users │ load average: 1.25, 0.50, 0.25"));
+}
+
+#[test]
+fn signal_menu_without_signal_state_does_not_panic() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.state = AppState::Popup;
+    app.pop_up_type = AppPopUpType::SignalMenu;
+    app.current_process_signal_state_data = None;
+
+    press_key(&mut app, &mut terminal, KeyCode::Char('1'));
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    press_key(&mut app, &mut terminal, KeyCode::Enter);
+    assert!(app.state == AppState::View);
+    assert!(app.pop_up_type == AppPopUpType::None);
+}
+
+#[test]
+fn signal_menu_ignores_out_of_range_signal_id() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.state = AppState::Popup;
+    app.pop_up_type = AppPopUpType::SignalMenu;
+    app.current_process_signal_state_data = Some(CurrentProcessSignalStateData {
+        pid: "100".to_string(),
+        name: "bash".to_string(),
+        signal: None,
+        signal_id: None,
+        yes_confirmation: true,
+        no_confirmation: false,
+        tree_confirmation: false,
+        signal_name_input: String::new(),
+    });
+    let get_signal_id = |app: &App| {
+        app.current_process_signal_state_data
+            .as_ref()
+            .and_then(|signal_state_data| signal_state_data.signal_id)
+    };
+
+    type_text(&mut app, &mut terminal, "15");
+    assert_eq!(get_signal_id(&app), Some(15));
+    type_text(&mut app, &mut terminal, "9");
+    assert_eq!(get_signal_id(&app), Some(15));
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    assert_eq!(get_signal_id(&app), Some(1));
+    press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    assert_eq!(get_signal_id(&app), None);
+
+    press_key(&mut app, &mut terminal, KeyCode::Esc);
+    assert!(app.state == AppState::View);
+}
+
+#[test]
+fn signal_menu_completes_the_typed_signal_name() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.state = AppState::Popup;
+    app.pop_up_type = AppPopUpType::SignalMenu;
+    app.current_process_signal_state_data = Some(CurrentProcessSignalStateData {
+        pid: "100".to_string(),
+        name: "bash".to_string(),
+        signal: None,
+        signal_id: None,
+        yes_confirmation: true,
+        no_confirmation: false,
+        tree_confirmation: false,
+        signal_name_input: String::new(),
+    });
+    let get_signal = |app: &App| {
+        let signal_state_data = app.current_process_signal_state_data.as_ref().unwrap();
+        return (
+            signal_state_data.signal_name_input.clone(),
+            signal_state_data.signal,
+        );
+    };
+
+    type_text(&mut app, &mut terminal, "hu");
+    assert_eq!(get_signal(&app), ("HU".to_string(), Some(Signal::Hangup)));
+    assert!(get_rendered_text(&terminal).contains("Enter Signal ID or Name: HUP_"));
+    press_key(&mut app, &mut terminal, KeyCode::Tab);
+    assert_eq!(get_signal(&app).0, "SIGHUP");
+
+    // the letters of the name were not taken as the yes and no shortcuts
+    for _ in 0..6 {
+        press_key(&mut app, &mut terminal, KeyCode::Backspace);
+    }
+    type_text(&mut app, &mut terminal, "sy");
+    assert!(app.state == AppState::Popup);
+    assert_eq!(get_signal(&app), ("SY".to_string(), Some(Signal::Sys)));
+    type_text(&mut app, &mut terminal, "n");
+    assert_eq!(get_signal(&app), ("SYN".to_string(), None));
+    assert!(get_rendered_text(&terminal).contains("( no match )"));
+
+    // typing the id starts over
+    type_text(&mut app, &mut terminal, "9");
+    assert_eq!(get_signal(&app), (String::new(), Some(Signal::Kill)));
+
+    assert_eq!(get_signal_id_from_name("sigter"), Some(15));
+    assert_eq!(get_signal_id_from_name("s"), Some(11));
+}
+
+#[test]
+fn signal_menu_lists_the_signals_selectable_with_arrows() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.state = AppState::Popup;
+    app.pop_up_type = AppPopUpType::SignalMenu;
+    app.current_process_signal_state_data = Some(CurrentProcessSignalStateData {
+        pid: "100".to_string(),
+        name: "bash".to_string(),
+        signal: None,
+        signal_id: None,
+        yes_confirmation: true,
+        no_confirmation: false,
+        tree_confirmation: false,
+        signal_name_input: String::new(),
+    });
+    let get_signal_id = |app: &App| {
+        app.current_process_signal_state_data
+            .as_ref()
+            .and_then(|signal_state_data| signal_state_data.signal_id)
+    };
+
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert_eq!(get_signal_id(&app), Some(0));
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("SIGHUP"));
+    assert!(text.contains("Hangup, often reloads the config"));
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert_eq!(get_signal_id(&app), Some(1));
+    press_key(&mut app, &mut terminal, KeyCode::Up);
+    press_key(&mut app, &mut terminal, KeyCode::Up);
+    assert_eq!(get_signal_id(&app), Some(0));
+
+    // the list scrolls to the selected signal
+    type_text(&mut app, &mut terminal, "30");
+    assert_eq!(get_signal_id(&app), Some(30));
+    assert!(get_rendered_text(&terminal).contains("Bad system call"));
+    press_key(&mut app, &mut terminal, KeyCode::Down);
+    assert_eq!(get_signal_id(&app), Some(30));
+}
+
+#[test]
+fn renders_placeholder_without_disks_or_networks() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.sys_info.disks.clear();
+    app.sys_info.networks.clear();
+    redraw(&mut app, &mut terminal);
+    let text = get_rendered_text(&terminal);
+
+    assert!(text.contains("No disk found"));
+    assert!(text.contains("No network interface found"));
+    assert!(text.contains("Test CPU"));
+    assert!(text.contains("bash"));
+
+    // switching between the entries with nothing to switch to
+    press_key(&mut app, &mut terminal, KeyCode::Char('d'));
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    press_key(&mut app, &mut terminal, KeyCode::Right);
+    press_key(&mut app, &mut terminal, KeyCode::Char('n'));
+    press_key(&mut app, &mut terminal, KeyCode::Left);
+    press_key(&mut app, &mut terminal, KeyCode::Right);
+    assert!(get_rendered_text(&terminal).contains("No network interface found"));
+}
+
+// the plugin that draw its data as it is, the data was sent to the app directly in the tests
+struct TestPlugin;
+
+impl WidgetPlugin for TestPlugin {
+    fn name(&self) -> String {
+        return "Test Plugin".to_string();
+    }
+
+    fn collect(&self) -> Vec<String> {
+        return vec![];
+    }
+}
+
+#[test]
+fn plugin_panel_is_placed_below_the_layout() {
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    assert!(!get_rendered_text(&terminal).contains("Test Plugin"));
+
+    app.plugins = vec![Arc::new(TestPlugin)];
+    redraw(&mut app, &mut terminal);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("Test Plugin"));
+    assert!(text.contains("loading..."));
+
+    let _ = app
+        .plugin_tx
+        .send((0, vec!["connected_clients 42".to_string()]));
+    redraw(&mut app, &mut terminal);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("connected_clients 42"));
+    // the other containers were still shown above it
+    assert!(text.contains("testdisk"));
+}
+
+fn get_test_script_config() -> ScriptConfig {
+    return ScriptConfig {
+        columns: vec![
+            NamedScript {
+                name: "MB".to_string(),
+                script: "memory / 1000000.0".to_string(),
+            },
+            NamedScript {
+                name: "Broken".to_string(),
+                script: "memory +".to_string(),
+            },
+        ],
+        alerts: vec![NamedScript {
+            name: "vim running".to_string(),
+            script: "processes.some(|p| p.name == \"vim\")".to_string(),
+        }],
+    };
+}
+
+#[test]
+fn scripts_compute_custom_columns() {
+    let (script_engine, script_errors) = ScriptEngine::new(&get_test_script_config());
+    // the broken script was left out and its error was reported
+    assert_eq!(script_errors.len(), 1);
+    assert!(script_errors[0].contains("Broken"));
+    assert_eq!(script_engine.get_column_names(), vec!["MB".to_string()]);
+
+    let (mut app, mut terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+    app.custom_column_names = script_engine.get_column_names();
+    app.script_engine = script_engine;
+    let pids: Vec<String> = app.process_info.processes.keys().cloned().collect();
+    app.queue_custom_column_evaluation(pids);
+    // a process was only queued once
+    app.queue_custom_column_evaluation(vec!["200".to_string()]);
+    assert_eq!(
+        app.custom_column_queue.len(),
+        app.process_info.processes.len()
+    );
+    app.update_custom_column_values();
+    assert_eq!(app.custom_column_values["200"], vec!["1.00".to_string()]);
+    assert!(app.custom_column_queue.is_empty());
+
+    // only the changed processes were evaluated again, the values of the exited processes were removed
+    let mut processes_info = get_test_processes_info();
+    processes_info
+        .processes
+        .retain(|process| process.pid == 200);
+    processes_info.is_snapshot = true;
+    processes_info.processes[0].memory = 2_000_000.0;
+    let _ = app.process_tx.send(processes_info);
+    redraw(&mut app, &mut terminal);
+    assert_eq!(app.custom_column_values["200"], vec!["2.00".to_string()]);
+    assert_eq!(app.custom_column_values.len(), 1);
+
+    redraw(&mut app, &mut terminal);
+    let text = get_rendered_text(&terminal);
+    assert!(text.contains("MB"));
+    assert!(text.contains("1.00"));
+}
+
+#[test]
+fn script_alert_is_only_raised_once() {
+    let (mut script_engine, _) = ScriptEngine::new(&get_test_script_config());
+    let (app, _terminal) = start_test_app(TEST_WIDTH, TEST_HEIGHT);
+
+    let raised_alerts = script_engine.evaluate_alerts(&app.sys_info, &app.process_info.processes);
+    assert_eq!(raised_alerts, vec!["vim running".to_string()]);
+    // still raised, so it wasn't reported again
+    let raised_alerts = script_engine.evaluate_alerts(&app.sys_info, &app.process_info.processes);
+    assert!(raised_alerts.is_empty());
+    // raised again after the condition was cleared
+    let mut processes = app.process_info.processes.clone();
+    processes.retain(|_, process| process.name != "vim");
+    assert!(script_engine
+        .evaluate_alerts(&app.sys_info, &processes)
+        .is_empty());
+    let raised_alerts = script_engine.evaluate_alerts(&app.sys_info, &app.process_info.processes);
+    assert_eq!(raised_alerts, vec!["vim running".to_string()]);
+}
+
+#[test]
+fn collector_task_sends_at_the_watched_tick() {
+    let runtime = Runtime::new().unwrap();
+    let (tick_tx, tick_rx) = watch::channel(10);
+    let (tx, mut rx) = unbounded_channel();
+    runtime.spawn(run_collector(
+        "counter",
+        0,
+        |count: &mut u32| {
+            *count += 1;
+            return *count;
+        },
+        tick_rx,
+        tx,
+    ));
+
+    runtime.block_on(async {
+        assert_eq!(rx.recv().await.unwrap(), 1);
+        assert_eq!(rx.recv().await.unwrap(), 2);
+
+        // the collector picked up the new tick, nothing was collected until it passed
+        let _ = tick_tx.send(60_000);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        while rx.try_recv().is_ok() {}
+        let next_collected = tokio::time::timeout(Duration::from_millis(200), rx.recv()).await;
+        assert!(next_collected.is_err());
+    });
+    runtime.shutdown_background();
+}
+
+#[test]
+fn graph_points_are_filled_into_the_reused_buffer() {
+    let mut data_points = Vec::with_capacity(100);
+    let buffer = data_points.as_ptr();
+
+    // the latest point was aligned to the right edge of the graph
+    fill_graph_data_points(&mut data_points, &[1.0, 2.0, 3.0], 100, |value| value * 2.0);
+    assert_eq!(data_points, vec![(97.0, 2.0), (98.0, 4.0), (99.0, 6.0)]);
+
+    // only the shown range of the history was filled, without allocating a new buffer
+    let history: Vec<f32> = (0..150).map(|value| value as f32).collect();
+    fill_graph_data_points(&mut data_points, &history, 100, |value| value as f64);
+    assert_eq!(data_points.len(), 100);
+    assert_eq!(data_points[0], (0.0, 50.0));
+    assert_eq!(data_points[99], (99.0, 149.0));
+    assert_eq!(data_points.as_ptr(), buffer);
+}
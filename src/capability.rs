@@ -0,0 +1,223 @@
+use std::{env, path::Path};
+
+use crate::components::theme::get_system_appearance;
+
+// the cargo features that this binary was compiled with
+pub const FEATURES: [(&str, bool); 10] = [
+    ("container-runtime", cfg!(feature = "container-runtime")),
+    ("wireguard", cfg!(feature = "wireguard")),
+    ("system-theme", cfg!(feature = "system-theme")),
+    ("redis-plugin", cfg!(feature = "redis-plugin")),
+    ("smart", cfg!(feature = "smart")),
+    ("sensors", cfg!(feature = "sensors")),
+    ("storage-pools", cfg!(feature = "storage-pools")),
+    ("ping", cfg!(feature = "ping")),
+    ("public-ip", cfg!(feature = "public-ip")),
+    ("connections", cfg!(feature = "connections")),
+];
+
+// an optional subsystem and whether it can be used on this machine
+pub struct Capability {
+    pub name: String,
+    pub feature: Option<String>, // the cargo feature required by this capability, None if it was always compiled
+    pub is_available: bool,
+    pub detail: String, // the reason why it was ( or wasn't ) available
+}
+
+// the version with the compiled features, shown in `--version`
+pub fn get_long_version() -> String {
+    let features: Vec<String> = FEATURES
+        .iter()
+        .map(|(feature, is_enabled)| format!("{}{}", if *is_enabled { "+" } else { "-" }, feature))
+        .collect();
+    return format!(
+        "{}\nfeatures: {}",
+        env!("CARGO_PKG_VERSION"),
+        features.join(" ")
+    );
+}
+
+fn is_feature_enabled(feature: &str) -> bool {
+    return FEATURES
+        .iter()
+        .any(|(name, is_enabled)| *name == feature && *is_enabled);
+}
+
+// check if the command can be found in PATH
+fn is_command_available(command: &str) -> bool {
+    let Some(paths) = env::var_os("PATH") else {
+        return false;
+    };
+    return env::split_paths(&paths).any(|path| {
+        path.join(command).is_file() || path.join(format!("{}.exe", command)).is_file()
+    });
+}
+
+fn get_capability(
+    name: &str,
+    feature: Option<&str>,
+    runtime_check: impl Fn() -> (bool, String),
+) -> Capability {
+    if let Some(feature) = feature {
+        if !is_feature_enabled(feature) {
+            return Capability {
+                name: name.to_string(),
+                feature: Some(feature.to_string()),
+                is_available: false,
+                detail: format!("not compiled ( enable the `{}` feature )", feature),
+            };
+        }
+    }
+
+    let (is_available, detail) = runtime_check();
+    return Capability {
+        name: name.to_string(),
+        feature: feature.map(|feature| feature.to_string()),
+        is_available,
+        detail,
+    };
+}
+
+pub fn get_capabilities() -> Vec<Capability> {
+    let is_linux = cfg!(target_os = "linux");
+
+    return vec![
+        get_capability("Container metadata", Some("container-runtime"), || {
+            if !is_linux {
+                return (false, "only supported on Linux".to_string());
+            }
+            let runtimes: Vec<&str> = ["docker", "podman", "crictl"]
+                .into_iter()
+                .filter(|runtime| is_command_available(runtime))
+                .collect();
+            if runtimes.is_empty() {
+                return (
+                    false,
+                    "no container runtime found in PATH ( docker, podman, crictl )".to_string(),
+                );
+            }
+            return (true, format!("using {}", runtimes.join(", ")));
+        }),
+        get_capability("WireGuard peer info", Some("wireguard"), || {
+            if is_command_available("wg") {
+                return (
+                    true,
+                    "`wg` found in PATH ( usually require root privilege )".to_string(),
+                );
+            }
+            return (false, "`wg` not found in PATH".to_string());
+        }),
+        get_capability(
+            "OS light/dark theme",
+            Some("system-theme"),
+            || match get_system_appearance() {
+                Some(appearance) => (true, format!("detected {:?} appearance", appearance)),
+                None => (false, "the OS preference can't be detected".to_string()),
+            },
+        ),
+        get_capability("Redis panel plugin", Some("redis-plugin"), || {
+            if is_command_available("redis-cli") {
+                return (true, "`redis-cli` found in PATH".to_string());
+            }
+            return (false, "`redis-cli` not found in PATH".to_string());
+        }),
+        get_capability("SMART disk health", Some("smart"), || {
+            if is_command_available("smartctl") {
+                return (
+                    true,
+                    "`smartctl` found in PATH ( usually require root privilege )".to_string(),
+                );
+            }
+            return (false, "`smartctl` not found in PATH".to_string());
+        }),
+        get_capability("Disk temperature sensors", Some("sensors"), || {
+            if !is_linux {
+                return (false, "only supported on Linux".to_string());
+            }
+            if Path::new("/sys/class/hwmon").is_dir() {
+                return (true, "/sys/class/hwmon found".to_string());
+            }
+            return (false, "/sys/class/hwmon not found".to_string());
+        }),
+        get_capability("btrfs / ZFS pools", Some("storage-pools"), || {
+            let is_btrfs_available = Path::new("/sys/fs/btrfs").is_dir();
+            let is_zpool_available = is_command_available("zpool");
+            return match (is_btrfs_available, is_zpool_available) {
+                (true, true) => (true, "btrfs and `zpool` found".to_string()),
+                (true, false) => (true, "btrfs found".to_string()),
+                (false, true) => (true, "`zpool` found in PATH".to_string()),
+                (false, false) => (
+                    false,
+                    "neither /sys/fs/btrfs nor `zpool` was found".to_string(),
+                ),
+            };
+        }),
+        get_capability("Ping", Some("ping"), || {
+            if is_command_available("ping") {
+                return (
+                    true,
+                    "`ping` found in PATH ( set `ping_target` in the settings to enable )"
+                        .to_string(),
+                );
+            }
+            return (false, "`ping` not found in PATH".to_string());
+        }),
+        get_capability("Public IP", Some("public-ip"), || {
+            if is_command_available("curl") {
+                return (
+                    true,
+                    "`curl` found in PATH ( set `public_ip_lookup` in the settings to enable )"
+                        .to_string(),
+                );
+            }
+            return (false, "`curl` not found in PATH".to_string());
+        }),
+        get_capability("Connection throughput", Some("connections"), || {
+            if !is_linux {
+                return (false, "only supported on Linux".to_string());
+            }
+            if is_command_available("ss") {
+                return (true, "`ss` found in PATH".to_string());
+            }
+            return (false, "`ss` not found in PATH".to_string());
+        }),
+        get_capability("Delay accounting", None, || {
+            #[cfg(target_os = "linux")]
+            {
+                if crate::get_sys_info::is_linux_delay_accounting_enabled() {
+                    return (true, "kernel.task_delayacct is enabled".to_string());
+                }
+                return (
+                    false,
+                    "block io wait is unavailable, kernel.task_delayacct is disabled".to_string(),
+                );
+            }
+            #[cfg(not(target_os = "linux"))]
+            return (false, "only supported on Linux".to_string());
+        }),
+        get_capability("Cgroup CPU throttling", None, || {
+            if Path::new("/sys/fs/cgroup").is_dir() {
+                return (true, "/sys/fs/cgroup is mounted".to_string());
+            }
+            return (false, "/sys/fs/cgroup is not mounted".to_string());
+        }),
+    ];
+}
+
+// print the capability report for `rtop doctor`
+pub fn print_doctor_report() {
+    println!("rtop {}", get_long_version());
+    println!();
+    for capability in get_capabilities() {
+        println!(
+            "[{}] {:<24} {}{}",
+            if capability.is_available { "ok" } else { "--" },
+            capability.name,
+            capability.detail,
+            match capability.feature.as_ref() {
+                Some(feature) => format!(" ( feature: {} )", feature),
+                None => "".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Paragraph, RenderDirection, Sparkline},
+    Frame,
+};
+
+use crate::{
+    format::format_bytes,
+    types::{AppColorInfo, CpuData, MemoryData, ProcessData},
+};
+
+// width of the label on the left of the sparklines, eg: "CPU  100.0%"
+const LABEL_WIDTH: u16 = 13;
+const PID_WIDTH: usize = 8;
+const CPU_USAGE_WIDTH: usize = 7;
+const MEMORY_WIDTH: usize = 11;
+
+// the single panel layout when the terminal is too small for the containers
+// it only shows the cpu and memory usage with a sparkline and the processes that use the most cpu
+pub fn draw_compact_view(
+    cpus: &[CpuData],
+    memory: &MemoryData,
+    processes: &HashMap<String, ProcessData>,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let compact_block = Block::bordered()
+        .title(
+            Line::from(" rtop ( compact ) ")
+                .style(app_color_info.app_title_color)
+                .bold()
+                .left_aligned(),
+        )
+        .style(app_color_info.cpu_main_block_color)
+        .border_set(border::ROUNDED);
+    let inner_area = compact_block.inner(area);
+    frame.render_widget(compact_block, area);
+
+    let [cpu_layout, memory_layout, _, process_layout] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(inner_area);
+
+    // the first entry was the average cpu
+    if let Some(avg_cpu) = cpus.first() {
+        let cpu_usage_history: Vec<f64> = avg_cpu
+            .usage_history_vec
+            .iter()
+            .map(|usage| *usage as f64)
+            .collect();
+        draw_usage_sparkline(
+            "CPU",
+            avg_cpu.usage as f64,
+            &cpu_usage_history,
+            cpu_layout,
+            frame,
+            Style::default().fg(app_color_info.cpu_base_graph_color),
+            app_color_info,
+        );
+    }
+
+    let memory_usage_history: Vec<f64> = memory
+        .used_memory_vec
+        .iter()
+        .map(|used_memory| {
+            if memory.total_memory > 0.0 {
+                used_memory / memory.total_memory * 100.0
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    draw_usage_sparkline(
+        "MEM",
+        memory_usage_history.last().copied().unwrap_or(0.0),
+        &memory_usage_history,
+        memory_layout,
+        frame,
+        Style::default().fg(app_color_info.used_memory_base_graph_color),
+        app_color_info,
+    );
+
+    // the processes that use the most cpu, as many as the height allows
+    let mut top_processes: Vec<&ProcessData> = processes.values().collect();
+    top_processes.sort_by(|a, b| {
+        let a_cpu_usage = a.cpu_usage.last().copied().unwrap_or(0.0);
+        let b_cpu_usage = b.cpu_usage.last().copied().unwrap_or(0.0);
+        b_cpu_usage.total_cmp(&a_cpu_usage)
+    });
+
+    let name_width = (process_layout.width as usize)
+        .saturating_sub(PID_WIDTH + CPU_USAGE_WIDTH + MEMORY_WIDTH)
+        .max(1);
+    let mut process_lines = vec![Line::from(format!(
+        "{:<pid_width$}{:<name_width$}{:>cpu_width$}{:>memory_width$}",
+        "PID",
+        "NAME",
+        "CPU%",
+        "MEM",
+        pid_width = PID_WIDTH,
+        name_width = name_width,
+        cpu_width = CPU_USAGE_WIDTH,
+        memory_width = MEMORY_WIDTH,
+    ))
+    .style(app_color_info.app_title_color)
+    .bold()];
+    process_lines.extend(
+        top_processes
+            .iter()
+            .take((process_layout.height as usize).saturating_sub(1))
+            .map(|process| {
+                let name: String = process.name.chars().take(name_width - 1).collect();
+                Line::from(format!(
+                    "{:<pid_width$}{:<name_width$}{:>cpu_width$.1}{:>memory_width$}",
+                    process.pid,
+                    name,
+                    process.cpu_usage.last().copied().unwrap_or(0.0),
+                    format_bytes(process.memory.last().copied().unwrap_or(0.0)),
+                    pid_width = PID_WIDTH,
+                    name_width = name_width,
+                    cpu_width = CPU_USAGE_WIDTH,
+                    memory_width = MEMORY_WIDTH,
+                ))
+                .style(app_color_info.base_app_text_color)
+            }),
+    );
+    frame.render_widget(Paragraph::new(process_lines), process_layout);
+}
+
+// the label with the latest usage on the left, and the sparkline of the usage history filling up the rest of the row
+fn draw_usage_sparkline(
+    label: &str,
+    usage: f64,
+    usage_history: &[f64],
+    area: Rect,
+    frame: &mut Frame,
+    graph_style: Style,
+    app_color_info: &AppColorInfo,
+) {
+    let [label_layout, graph_layout] =
+        Layout::horizontal([Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)]).areas(area);
+
+    let usage_label = Line::from(vec![
+        Span::styled(
+            format!("{:<5}", label),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        Span::styled(
+            format!("{:>6.1}% ", usage),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+
+    // the newest usage will be at the right edge of the graph, so the data was reversed and rendered from right to left
+    let num_points_to_display = (graph_layout.width as usize).min(usage_history.len());
+    let start_idx = usage_history.len() - num_points_to_display;
+    let data_points: Vec<u64> = usage_history[start_idx..]
+        .iter()
+        .rev()
+        .map(|usage| (*usage * 100.0) as u64)
+        .collect();
+    let sparkline = Sparkline::default()
+        .data(&data_points)
+        .max(100 * 100)
+        .direction(RenderDirection::RightToLeft)
+        .style(graph_style.bg(app_color_info.background_color));
+
+    frame.render_widget(usage_label, label_layout);
+    frame.render_widget(sparkline, graph_layout);
+}
@@ -1,27 +1,265 @@
+use std::borrow::Cow;
+
 use chrono::Local;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Style, Stylize},
-    symbols::{border, Marker},
+    style::{Color, Style, Stylize},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Axis, Block, Chart, Dataset, GraphType, List, ListItem, ListState},
+    widgets::{Axis, Block, Chart, Dataset, List, ListItem, ListState, RenderDirection, Sparkline},
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, CpuData},
-    utils::get_tick_line_ui,
+    types::{AppColorInfo, CpuData, CpuTimeBreakdown, GraphStyle, PressureData, SystemData},
+    utils::{
+        draw_graph_grid_lines, draw_pressure_graph, fill_graph_data_points, format_duration,
+        get_gradient_datasets, get_gradient_points, get_graph_offset_line_ui, get_graph_y_axis,
+        get_percentage_axis_labels, get_tick_line_ui,
+    },
 };
 
+const LOAD_AVERAGE_MIN_WIDTH: u16 = 70;
+// the minimum width of each graph in the grid view
+const GRID_CELL_MIN_WIDTH: u16 = 24;
+
+// an entry of the cpu list, it could be a logical cpu or a physical core with its hyperthread siblings aggregated
+// the history of a logical cpu was borrowed, only the aggregated history of the siblings was owned
+struct CpuListEntry<'a> {
+    name: Cow<'a, str>,
+    usage: f32,
+    usage_history_vec: Cow<'a, [f32]>,
+    time_breakdown_vec: Cow<'a, [CpuTimeBreakdown]>,
+}
+
+// the name, the value getter and the graph color of a cpu time state
+type CpuTimeState = (&'static str, fn(&CpuTimeBreakdown) -> f32, Color);
+
+// the states of the cpu time breakdown from the top of the stacked graph to the bottom,
+// each state reuse one of the graph colors of the theme so every theme works without new color fields
+fn get_cpu_time_states(app_color_info: &AppColorInfo) -> [CpuTimeState; 5] {
+    return [
+        (
+            "st",
+            |breakdown| breakdown.steal,
+            app_color_info.process_base_graph_color,
+        ),
+        (
+            "io",
+            |breakdown| breakdown.iowait,
+            app_color_info.disk_bytes_read_base_graph_color,
+        ),
+        (
+            "irq",
+            |breakdown| breakdown.irq,
+            app_color_info.network_received_base_graph_color,
+        ),
+        (
+            "sys",
+            |breakdown| breakdown.system,
+            app_color_info.cached_memory_base_graph_color,
+        ),
+        (
+            "usr",
+            |breakdown| breakdown.user,
+            app_color_info.cpu_base_graph_color,
+        ),
+    ];
+}
+
+// the average cpu will always be the first entry, followed by the logical cpus grouped by socket and physical core
+// if the topology was detected, when aggregated, the hyperthread siblings of a physical core will be shown as one entry
+// the histories were borrowed without the latest graph_offset points, to show an older window when it was panned
+fn get_cpu_list_entries(
+    cpus: &Vec<CpuData>,
+    is_ht_aggregated: bool,
+    graph_offset: usize,
+) -> Vec<CpuListEntry<'_>> {
+    let mut entries = Vec::new();
+    let Some((avg_cpu, logical_cpus)) = cpus.split_first() else {
+        return entries;
+    };
+    entries.push(CpuListEntry {
+        name: Cow::Borrowed(&avg_cpu.id),
+        usage: avg_cpu.usage,
+        usage_history_vec: Cow::Borrowed(avg_cpu.usage_history_vec.get_panned(graph_offset)),
+        time_breakdown_vec: Cow::Borrowed(avg_cpu.time_breakdown_vec.get_panned(graph_offset)),
+    });
+
+    if !is_cpu_topology_detected(cpus) {
+        for cpu in logical_cpus {
+            entries.push(CpuListEntry {
+                name: Cow::Borrowed(&cpu.id),
+                usage: cpu.usage,
+                usage_history_vec: Cow::Borrowed(cpu.usage_history_vec.get_panned(graph_offset)),
+                time_breakdown_vec: Cow::Borrowed(cpu.time_breakdown_vec.get_panned(graph_offset)),
+            });
+        }
+        return entries;
+    }
+
+    // the sort is stable, so the logical cpus of the same physical core will stay in their original order
+    let mut grouped_cpus: Vec<&CpuData> = logical_cpus.iter().collect();
+    grouped_cpus.sort_by_key(|cpu| cpu.topology);
+
+    if !is_ht_aggregated {
+        for cpu in grouped_cpus {
+            let (socket_id, core_id) = cpu.topology.unwrap();
+            entries.push(CpuListEntry {
+                name: Cow::Owned(format!("S{}C{} {}", socket_id, core_id, cpu.id)),
+                usage: cpu.usage,
+                usage_history_vec: Cow::Borrowed(cpu.usage_history_vec.get_panned(graph_offset)),
+                time_breakdown_vec: Cow::Borrowed(cpu.time_breakdown_vec.get_panned(graph_offset)),
+            });
+        }
+        return entries;
+    }
+
+    for siblings in grouped_cpus.chunk_by(|a, b| a.topology == b.topology) {
+        let (socket_id, core_id) = siblings[0].topology.unwrap();
+        let usage = siblings.iter().map(|cpu| cpu.usage).sum::<f32>() / siblings.len() as f32;
+
+        // the siblings were updated together, but we still align their history from the most recent one to be safe
+        let history_len = siblings
+            .iter()
+            .map(|cpu| cpu.usage_history_vec.get_panned(graph_offset).len())
+            .min()
+            .unwrap_or(0);
+        let usage_history_vec: Vec<f32> = (0..history_len)
+            .map(|i| {
+                siblings
+                    .iter()
+                    .map(|cpu| {
+                        let history = cpu.usage_history_vec.get_panned(graph_offset);
+                        history[history.len() - history_len + i]
+                    })
+                    .sum::<f32>()
+                    / siblings.len() as f32
+            })
+            .collect();
+
+        let breakdown_len = siblings
+            .iter()
+            .map(|cpu| cpu.time_breakdown_vec.get_panned(graph_offset).len())
+            .min()
+            .unwrap_or(0);
+        let time_breakdown_vec: Vec<CpuTimeBreakdown> = (0..breakdown_len)
+            .map(|i| {
+                let mut breakdown = CpuTimeBreakdown::default();
+                for cpu in siblings {
+                    let history = cpu.time_breakdown_vec.get_panned(graph_offset);
+                    let sibling_breakdown = history[history.len() - breakdown_len + i];
+                    breakdown.user += sibling_breakdown.user / siblings.len() as f32;
+                    breakdown.system += sibling_breakdown.system / siblings.len() as f32;
+                    breakdown.iowait += sibling_breakdown.iowait / siblings.len() as f32;
+                    breakdown.irq += sibling_breakdown.irq / siblings.len() as f32;
+                    breakdown.steal += sibling_breakdown.steal / siblings.len() as f32;
+                }
+                breakdown
+            })
+            .collect();
+
+        entries.push(CpuListEntry {
+            name: Cow::Owned(format!("S{}C{} x{}", socket_id, core_id, siblings.len())),
+            usage,
+            usage_history_vec: Cow::Owned(usage_history_vec),
+            time_breakdown_vec: Cow::Owned(time_breakdown_vec),
+        });
+    }
+    return entries;
+}
+
+// render a small graph for each of the entries in a grid, the number of columns depends on the available width
+fn draw_cpu_grid(
+    entries: &[CpuListEntry],
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    app_color_info: &AppColorInfo,
+) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let columns = ((area.width / GRID_CELL_MIN_WIDTH) as usize).clamp(1, entries.len());
+    let rows = entries.len().div_ceil(columns);
+
+    let row_layouts = Layout::vertical(vec![Constraint::Fill(1); rows]).split(area);
+    for (row_index, row_layout) in row_layouts.iter().enumerate() {
+        let cell_layouts =
+            Layout::horizontal(vec![Constraint::Fill(1); columns]).split(*row_layout);
+        for (column_index, cell_layout) in cell_layouts.iter().enumerate() {
+            let Some(entry) = entries.get(row_index * columns + column_index) else {
+                break;
+            };
+
+            let cell_block = Block::bordered()
+                .title(
+                    Line::from(format!(" {} ", entry.name))
+                        .style(app_color_info.base_app_text_color)
+                        .left_aligned(),
+                )
+                .title(
+                    Line::from(format!(" {:.1}% ", entry.usage))
+                        .style(app_color_info.cpu_text_color)
+                        .bold()
+                        .right_aligned(),
+                )
+                .style(app_color_info.cpu_info_block_color)
+                .border_set(border::ROUNDED);
+            let graph_layout = cell_block.inner(*cell_layout);
+
+            // the newest usage will be at the right edge of the graph, so the data was reversed and rendered from right to left
+            let num_points_to_display = (graph_layout.width as usize)
+                .min(graph_show_range)
+                .min(entry.usage_history_vec.len());
+            let start_idx = entry.usage_history_vec.len() - num_points_to_display;
+            let data_points: Vec<u64> = entry.usage_history_vec[start_idx..]
+                .iter()
+                .rev()
+                .map(|usage| (*usage * 100.0) as u64)
+                .collect();
+            let sparkline = Sparkline::default()
+                .data(&data_points)
+                .max(100 * 100)
+                .direction(RenderDirection::RightToLeft)
+                .style(
+                    Style::default()
+                        .fg(app_color_info.cpu_base_graph_color)
+                        .bg(app_color_info.background_color),
+                );
+
+            frame.render_widget(cell_block, *cell_layout);
+            frame.render_widget(sparkline, graph_layout);
+        }
+    }
+}
+
+// the topology was only used when every logical cpu has it
+pub fn is_cpu_topology_detected(cpus: &Vec<CpuData>) -> bool {
+    return cpus.len() > 1 && cpus[1..].iter().all(|cpu| cpu.topology.is_some());
+}
+
 pub fn draw_cpu_info(
     tick: u64,
     cpus: &Vec<CpuData>,
     size: Rect,
     frame: &mut Frame,
     cpu_selected_state: &mut ListState,
+    cpu_selectable_entries: &mut usize,
+    is_ht_aggregated: bool, // to show the hyperthread siblings of a physical core as one entry
     graph_show_range: usize,
+    graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    graph_style: GraphStyle, // the graph type and marker of the charts
+    is_graph_axis_shown: bool, // show the y axis labels and grid lines of the charts
     is_selected: bool,
     app_color_info: &AppColorInfo,
+    is_full_screen: bool,
+    is_grid_view: bool, // to show a small graph for each core instead of the selected cpu graph, only in full screen
+    session_duration: u64, // how long the app has been monitoring, in seconds
+    system: &SystemData,
+    pressure: &Option<PressureData>, // None if PSI isn't available
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graphs, owned by the app
 ) {
     let local_time = Local::now();
 
@@ -41,11 +279,52 @@ pub fn draw_cpu_info(
         Span::styled("pu ", Style::default().fg(app_color_info.app_title_color)).bold(),
     ]);
 
+    let load_average = Line::from(vec![
+        Span::styled("load ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled(
+            format!(
+                "{:.2} {:.2} {:.2} ",
+                system.load_average_one, system.load_average_five, system.load_average_fifteen
+            ),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+    let uptime = Line::from(vec![
+        Span::styled(" up ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled(
+            format!("{} ", format_duration(system.uptime)),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
     // The main block for CPU info
     let mut main_block = Block::bordered()
         .title(title.centered())
-        .title(select_instruction.left_aligned())
+        .title(select_instruction.left_aligned());
+    // indicate that an older window of the history was shown
+    if graph_offset > 0 {
+        main_block = main_block
+            .title(get_graph_offset_line_ui(graph_offset, app_color_info).right_aligned());
+    }
+
+    // only show the load average and uptime when there is enough space for them
+    if size.width > LOAD_AVERAGE_MIN_WIDTH {
+        main_block = main_block
+            .title(load_average.left_aligned())
+            .title(uptime.right_aligned());
+    }
+    main_block = main_block
         .title(refresh_tick.right_aligned())
+        .title_bottom(
+            Line::from(format!(
+                " monitoring for {} ",
+                format_duration(session_duration)
+            ))
+            .style(app_color_info.app_title_color)
+            .right_aligned(),
+        )
         .style(app_color_info.cpu_main_block_color)
         .border_set(border::ROUNDED);
     if is_selected {
@@ -54,6 +333,26 @@ pub fn draw_cpu_info(
             .border_set(border::DOUBLE);
     }
 
+    // the grid view can only be toggled in full screen
+    if is_full_screen {
+        let grid_view_instruction = Line::from(vec![
+            Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+            Span::styled("V", Style::default().fg(app_color_info.key_text_color))
+                .bold()
+                .underlined(),
+            Span::styled(
+                if is_grid_view {
+                    "iew chart "
+                } else {
+                    "iew grid "
+                },
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+        ]);
+        main_block = main_block.title_bottom(grid_view_instruction.left_aligned());
+    }
+
     // Constrain the block to have space at the right and left
     let [_, cpu_block, _] = Layout::horizontal([
         Constraint::Length(2),
@@ -62,6 +361,34 @@ pub fn draw_cpu_info(
     ])
     .areas(size);
 
+    let cpu_list_entries = get_cpu_list_entries(cpus, is_ht_aggregated, graph_offset);
+    *cpu_selectable_entries = cpu_list_entries.len();
+    // the entries could be changed when the hyperthread aggregation was toggled, so we make sure the selection is still valid
+    if cpu_selected_state.selected().unwrap_or(0) >= cpu_list_entries.len() {
+        cpu_selected_state.select(Some(0));
+    }
+
+    // the grid of every core will replace both the selected cpu graph and the cpu list
+    if is_full_screen && is_grid_view {
+        let [_, cpu_grid_layout, _] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(cpu_block);
+
+        frame.render_widget(main_block, size);
+        // the first entry was the average cpu, which was already shown in the title of the main block
+        draw_cpu_grid(
+            &cpu_list_entries[1.min(cpu_list_entries.len())..],
+            cpu_grid_layout,
+            frame,
+            graph_show_range,
+            app_color_info,
+        );
+        return;
+    }
+
     // Split into cpu_graph_layout and cpu_info_layout (cpu name and usage info)
     let [cpu_graph_layout, cpu_info_layout] =
         Layout::horizontal([Constraint::Fill(7), Constraint::Fill(3)]).areas(cpu_block);
@@ -86,49 +413,80 @@ pub fn draw_cpu_info(
     // Rendering for CPU usage history graph on the left
     // --------------------------------------------------
 
+    // the cpu pressure graph will take the bottom part of the cpu usage graph if it was available
+    let [cpu_usage_graph_layout, cpu_pressure_layout] = Layout::vertical([
+        Constraint::Fill(3),
+        Constraint::Fill(if pressure.is_some() { 1 } else { 0 }),
+    ])
+    .areas(constraint_inner_cpu_graph_layout);
+
     // first get the current selected cpu usage history
-    let cpu_usage_history = cpus[cpu_selected_state.selected().unwrap()]
-        .usage_history_vec
-        .clone();
+    let selected_cpu_entry = &cpu_list_entries[cpu_selected_state.selected().unwrap_or(0)];
+    fill_graph_data_points(
+        graph_data_points,
+        &selected_cpu_entry.usage_history_vec,
+        graph_show_range,
+        |usage| usage as f64,
+    );
 
-    // Determine the number of points to display based on zoom level
-    let num_points_to_display = graph_show_range.min(cpu_usage_history.len());
-    let start_idx = cpu_usage_history
+    // when the time breakdown is available, the usage will be shown as a stacked graph of each cpu state
+    // each state was drawn as a bar from 0 to the sum of itself and the states below it,
+    // the states below will be drawn later on top of it, so only its own portion keeps its color
+    let cpu_time_states = get_cpu_time_states(app_color_info);
+    let time_breakdown_history = &selected_cpu_entry.time_breakdown_vec;
+    let breakdown_start_idx = time_breakdown_history
         .len()
-        .saturating_sub(num_points_to_display);
-    let mut data_points: Vec<(f64, f64)> = cpu_usage_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            // X-axis: Usage (0.0 to 100.0)
-            // Y-axis: Time (most recent at the bottom)
-            // Map the index to a y-value from 0.0 (oldest) to num_points_to_display (newest)
-            let x = i as f64;
-            let y = usage as f64;
-            (x, y)
+        .saturating_sub(graph_show_range.min(time_breakdown_history.len()));
+    let stacked_data_points: Vec<Vec<(f64, f64)>> = (0..cpu_time_states.len())
+        .map(|state_index| {
+            let shown_breakdowns = &time_breakdown_history[breakdown_start_idx..];
+            shown_breakdowns
+                .iter()
+                .enumerate()
+                .map(|(i, breakdown)| {
+                    let x = graph_show_range as f64 - (shown_breakdowns.len() - i) as f64;
+                    let y: f32 = cpu_time_states[state_index..]
+                        .iter()
+                        .map(|(_, get_value, _)| get_value(breakdown))
+                        .sum();
+                    (x, y as f64)
+                })
+                .collect()
         })
         .collect();
 
-    data_points = data_points
-        .iter()
-        .map(|(x, y)| (graph_show_range as f64 - (data_points.len() as f64 - x), *y))
-        .collect();
-
     // Create the dataset for the chart
-    let dataset = Dataset::default()
-        .name("")
-        .data(&data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
-        .style(Style::default().fg(app_color_info.cpu_base_graph_color));
+    // the gradient of the theme will take over the stacked graph as it colors the usage by the value
+    let gradient_points = get_gradient_points(
+        graph_data_points,
+        app_color_info.cpu_base_graph_color,
+        app_color_info.graph_gradient.as_ref(),
+        graph_style,
+    );
+    let datasets = if time_breakdown_history.is_empty() || app_color_info.graph_gradient.is_some() {
+        get_gradient_datasets(&gradient_points, graph_style)
+    } else {
+        stacked_data_points
+            .iter()
+            .zip(cpu_time_states.iter())
+            .map(|(state_data_points, (_, _, color))| {
+                Dataset::default()
+                    .data(state_data_points)
+                    .graph_type(graph_style.get_graph_type())
+                    .marker(graph_style.get_marker())
+                    .style(Style::default().fg(*color))
+            })
+            .collect()
+    };
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
     // Define the x-axis (CPU Usage) and y-axis (Time)
-    let y_axis = Axis::default().bounds([0.0, 100.0]);
+    let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+    let y_axis = get_graph_y_axis(100.0, &y_axis_labels, app_color_info);
 
     // Create the chart widget
-    let chart = Chart::new(vec![dataset])
+    let chart = Chart::new(datasets)
         .x_axis(x_axis)
         .y_axis(y_axis)
         .bg(app_color_info.background_color);
@@ -141,10 +499,29 @@ pub fn draw_cpu_info(
     let cpu_brand = Line::from(format!(" {} ", cpus[0].brand))
         .style(app_color_info.app_title_color)
         .bold();
-    let inner_right_block = Block::bordered()
+    let mut inner_right_block = Block::bordered()
         .title(cpu_brand.left_aligned())
         .style(app_color_info.cpu_info_block_color)
         .border_set(border::ROUNDED);
+    // the hyperthread aggregation is only available when the topology was detected
+    if is_cpu_topology_detected(cpus) {
+        let aggregate_instruction = Line::from(vec![
+            Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+            Span::styled("A", Style::default().fg(app_color_info.key_text_color))
+                .bold()
+                .underlined(),
+            Span::styled(
+                if is_ht_aggregated {
+                    "ll threads "
+                } else {
+                    "ggregate HT "
+                },
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+        ]);
+        inner_right_block = inner_right_block.title_bottom(aggregate_instruction.right_aligned());
+    }
 
     // split the cpu name and usage info into two parts
     let [_, cpu_info_inner_container, _] = Layout::horizontal([
@@ -159,10 +536,10 @@ pub fn draw_cpu_info(
     let usage_width = cpu_info_inner_container.width as usize / 2;
 
     // Prepare the combined CPU info list
-    let cpu_info_items: Vec<ListItem> = cpus
+    let cpu_info_items: Vec<ListItem> = cpu_list_entries
         .iter()
         .map(|cpu| {
-            let name = format!("{}", cpu.id);
+            let name = &cpu.name;
             let usage = format!("{:.2}%", cpu.usage);
 
             // Pad the name to take up 48% of the width
@@ -206,10 +583,50 @@ pub fn draw_cpu_info(
     // Render the main cpu block container
     frame.render_widget(main_block, size);
     // Render the chart in the left area
-    frame.render_widget(chart, constraint_inner_cpu_graph_layout);
+    if let Some(latest_breakdown) = time_breakdown_history.last() {
+        // the legend of the stacked graph with the latest percentage of each state, from the bottom state to the top
+        let [legend_layout, stacked_graph_layout] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
+                .areas(cpu_usage_graph_layout);
+        let legend = Line::from(
+            cpu_time_states
+                .iter()
+                .rev()
+                .map(|(name, get_value, color)| {
+                    Span::styled(
+                        format!("{} {:.1}%  ", name, get_value(latest_breakdown)),
+                        Style::default().fg(*color),
+                    )
+                })
+                .collect::<Vec<Span>>(),
+        );
+        frame.render_widget(legend, legend_layout);
+        draw_graph_grid_lines(stacked_graph_layout, &y_axis_labels, frame, app_color_info);
+        frame.render_widget(chart, stacked_graph_layout);
+    } else {
+        draw_graph_grid_lines(
+            cpu_usage_graph_layout,
+            &y_axis_labels,
+            frame,
+            app_color_info,
+        );
+        frame.render_widget(chart, cpu_usage_graph_layout);
+    }
+    if let Some(pressure) = pressure.as_ref() {
+        draw_pressure_graph(
+            "Pressure:",
+            pressure,
+            cpu_pressure_layout,
+            frame,
+            graph_show_range,
+            graph_offset,
+            graph_style,
+            app_color_info.cpu_base_graph_color,
+            app_color_info.cpu_main_block_color,
+            app_color_info,
+            graph_data_points,
+        );
+    }
     // Render the combined list with state
     frame.render_stateful_widget(cpu_info_list, cpu_info_inner_container, cpu_selected_state);
-
-    drop(data_points);
-    drop(cpu_usage_history);
 }
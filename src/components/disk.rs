@@ -1,31 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Local};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Style, Stylize},
-    symbols::{border, Marker},
+    style::{Color, Style, Stylize},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, LineGauge, Paragraph, RenderDirection, Sparkline,
+    },
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, DiskData},
-    utils::{get_tick_line_ui, process_to_kib_mib_gib},
+    format::{format_bytes, format_bytes_per_second},
+    types::{
+        AppColorInfo, DiskData, DiskFreeThresholds, DiskIoStats, GraphStyle, PressureData,
+        RaidArray, SmartInfo, StoragePool,
+    },
+    utils::{
+        draw_graph_grid_lines, draw_graph_peak_label, draw_pressure_graph, fill_graph_data_points,
+        get_graph_offset_line_ui, get_graph_y_axis, get_ordered_disks, get_scaled_axis_labels,
+        get_session_line_ui, get_tick_line_ui,
+    },
 };
 
 // width smaller than this will be consider small width for the disk container
 const SMALL_WIDTH: u16 = 20;
 const GRAPH_PERCENTAGE: f64 = 100.0;
+// the y bound of the throughput graphs when nothing was transferred in the shown window
+const GRAPH_MIN_SCALE: f64 = 1.0;
+// the temperature sparkline will be scaled to this, which is above the usual drive operating limit
+const TEMPERATURE_GRAPH_MAX: u64 = 80;
 
 pub fn draw_disk_info(
     tick: u64,
     disk_data: &DiskData,
+    disks: &HashMap<String, DiskData>, // all the disks, used for the list view
+    disk_selected_entry: usize,
+    disk_free_thresholds: &DiskFreeThresholds, // the disks below the threshold will be highlighted in red
     area: Rect,
     frame: &mut Frame,
     graph_show_range: usize,
+    graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    graph_style: GraphStyle, // the graph type and marker of the charts
+    is_graph_axis_shown: bool, // show the y axis labels and grid lines of the charts
     is_selected: bool,
     app_color_info: &AppColorInfo,
     is_full_screen: bool,
+    session_since: &DateTime<Local>, // the time the disk session counters was started or reset
+    is_list_view: bool,              // to list all the disks above the selected disk
+    smart_info: Option<&SmartInfo>,  // None if SMART isn't available for this disk
+    raid_arrays: &[RaidArray],       // the software RAID arrays, empty if there is none
+    storage_pool: Option<&StoragePool>, // the btrfs or ZFS pool of this disk, None if it isn't in a pool
+    pressure: &Option<PressureData>,    // the io pressure, None if PSI isn't available
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graphs, owned by the app
 ) {
+    // the graphs will show an older window of the history when it was panned
+    let bytes_written_vec = disk_data.bytes_written_vec.get_panned(graph_offset);
+    let bytes_read_vec = disk_data.bytes_read_vec.get_panned(graph_offset);
+    let io_stats_vec = disk_data.io_stats_vec.get_panned(graph_offset);
+    // the SMART rows and temperature were only shown with the smart feature
+    let smart_info = smart_info.filter(|_| cfg!(feature = "smart"));
+
     let mut disk_name = disk_data.name.clone();
     if area.width <= SMALL_WIDTH + 5 {
         let extension = if disk_name.len() > 8 { ".." } else { "" };
@@ -75,6 +112,40 @@ pub fn draw_disk_info(
         main_block = main_block.title(refresh_tick.right_aligned())
     }
 
+    // indicate that an older window of the history was shown
+    if graph_offset > 0 {
+        main_block = main_block
+            .title(get_graph_offset_line_ui(graph_offset, app_color_info).right_aligned());
+    }
+    if is_selected {
+        let list_view_instruction = Line::from(vec![
+            Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+            Span::styled("L", Style::default().fg(app_color_info.key_text_color))
+                .bold()
+                .underlined(),
+            Span::styled(
+                if is_list_view {
+                    "ist hide "
+                } else {
+                    "ist all "
+                },
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+        ]);
+        main_block = main_block.title_bottom(list_view_instruction.left_aligned());
+    }
+    if area.width > SMALL_WIDTH + 40 {
+        let session_line = get_session_line_ui(
+            session_since,
+            disk_data.session_bytes_written,
+            disk_data.session_bytes_read,
+            app_color_info,
+        );
+
+        main_block = main_block.title_bottom(session_line.right_aligned())
+    }
+
     // bottom border will be the space where the statistics for used, available space, total bytes written and read etc... will be displayed
     let [_, disk_block, _] = Layout::vertical([
         Constraint::Length(1),
@@ -91,6 +162,24 @@ pub fn draw_disk_info(
     ])
     .areas(disk_block);
 
+    // the list of all disks will take the top of the container in the list view
+    let (disk_list_layout, padded_disk_block) = if is_list_view {
+        // a row for each physical device and an extra row to separate the list from the selected disk
+        let device_count = disks
+            .values()
+            .map(|disk| &disk.physical_device)
+            .collect::<HashSet<&String>>()
+            .len();
+        let disk_list_height =
+            ((disks.len() + device_count) as u16 + 1).min(padded_disk_block.height / 2);
+        let [new_disk_list_layout, new_padded_disk_block] =
+            Layout::vertical([Constraint::Length(disk_list_height), Constraint::Fill(1)])
+                .areas(padded_disk_block);
+        (new_disk_list_layout, new_padded_disk_block)
+    } else {
+        (Rect::default(), padded_disk_block)
+    };
+
     // top label will be the label for total disk space
     // bottom disk info blocks will be the statistics for used, available space, total bytes written and read etc...
     let [top_label, bottom_disk_info_blocks] =
@@ -99,7 +188,7 @@ pub fn draw_disk_info(
     let total_disk_space_label = Line::from("Total:")
         .style(app_color_info.app_title_color)
         .bold();
-    let total_disk_space = Line::from(process_to_kib_mib_gib(disk_data.total_space))
+    let total_disk_space = Line::from(format_bytes(disk_data.total_space))
         .style(app_color_info.app_title_color)
         .bold();
     let top_inner_block = Block::new()
@@ -111,24 +200,111 @@ pub fn draw_disk_info(
     frame.render_widget(main_block, area);
     frame.render_widget(top_inner_block, top_label);
 
+    if is_list_view {
+        draw_disk_list(
+            tick,
+            disks,
+            disk_selected_entry,
+            disk_free_thresholds,
+            disk_list_layout,
+            frame,
+            app_color_info,
+        );
+    }
+
+    // ----------------------------------------
+    //
+    //      FOR DISK TEMPERATURE LAYOUT
+    //
+    // ----------------------------------------
+    // prefer the hwmon temperature as it was collected every tick, fallback to the SMART temperature
+    let temperature = disk_data
+        .temperature
+        .or(smart_info.and_then(|smart_info| smart_info.temperature));
+    if let Some(temperature) = temperature {
+        let [_, temperature_layout] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(top_label);
+        let temperature_text = if temperature_layout.width < SMALL_WIDTH {
+            format!("{:.0}°C ", temperature)
+        } else {
+            format!("Temp: {:.0}°C ", temperature)
+        };
+        let [temperature_label_layout, temperature_graph_layout] = Layout::horizontal([
+            Constraint::Length(temperature_text.chars().count() as u16),
+            Constraint::Fill(1),
+        ])
+        .areas(temperature_layout);
+
+        let temperature_label = Block::new()
+            .title(
+                Line::from(temperature_text)
+                    .style(app_color_info.disk_text_color)
+                    .bold(),
+            )
+            .style(app_color_info.disk_main_block_color)
+            .borders(Borders::NONE);
+        frame.render_widget(temperature_label, temperature_label_layout);
+
+        // the newest temperature will be at the right edge of the graph, so the data was reversed and rendered from right to left
+        let num_points_to_display = (temperature_graph_layout.width as usize)
+            .min(graph_show_range)
+            .min(disk_data.temperature_vec.len());
+        let start_idx = disk_data.temperature_vec.len() - num_points_to_display;
+        let temperature_data_points: Vec<u64> = disk_data.temperature_vec[start_idx..]
+            .iter()
+            .rev()
+            .map(|temperature| temperature.max(0.0) as u64)
+            .collect();
+        let temperature_sparkline = Sparkline::default()
+            .data(&temperature_data_points)
+            .max(TEMPERATURE_GRAPH_MAX)
+            .direction(RenderDirection::RightToLeft)
+            .style(
+                Style::default()
+                    .fg(app_color_info.disk_bytes_written_base_graph_color)
+                    .bg(app_color_info.background_color),
+            );
+        frame.render_widget(temperature_sparkline, temperature_graph_layout);
+    }
+
     // bottom block will be in the follwing order:
     // used space
     // available space
+    // inode usage [bar] ( only if the file system report inodes )
     // file system
     // mount point
     // kind
+    // SMART health ( only if SMART is available )
+    // RAID array state, one row for each array ( only if there is any )
+    // pool, pool usage and scrub status ( only if the disk is in a btrfs or ZFS pool )
     // current written bytes [graph]
     // current read bytes [graph]
+    // io utilization, await and queue depth [graph] ( only in full screen and if /proc/diskstats is available )
+    // io pressure [graph] ( only if PSI is available )
 
-    let [used_space_layout, available_space_layout, file_system_layout, mount_point_layout, disk_kind_layout, current_bytes_written_layout, current_bytes_read_layout] =
+    let [used_space_layout, available_space_layout, inode_usage_layout, file_system_layout, mount_point_layout, disk_kind_layout, smart_layout, raid_layout, storage_pool_layout, current_bytes_written_layout, current_bytes_read_layout, io_stats_layout, io_pressure_layout] =
         Layout::vertical([
             Constraint::Length(1),
             Constraint::Length(1),
+            Constraint::Length(if disk_data.inodes.is_some() { 1 } else { 0 }),
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Length(1),
+            Constraint::Length(if smart_info.is_some() { 1 } else { 0 }),
+            Constraint::Length(raid_arrays.len() as u16),
+            Constraint::Length(match storage_pool {
+                Some(storage_pool) if storage_pool.scrub_status.is_some() => 3,
+                Some(_) => 2,
+                None => 0,
+            }),
             Constraint::Fill(1),
             Constraint::Fill(1),
+            Constraint::Fill(if is_full_screen && !io_stats_vec.is_empty() {
+                1
+            } else {
+                0
+            }),
+            Constraint::Fill(if pressure.is_some() { 1 } else { 0 }),
         ])
         .areas(bottom_disk_info_blocks);
 
@@ -149,7 +325,7 @@ pub fn draw_disk_info(
         Line::from("Used:").style(app_color_info.base_app_text_color)
     };
 
-    let used_space_usage = Line::from(process_to_kib_mib_gib(disk_data.used_space))
+    let used_space_usage = Line::from(format_bytes(disk_data.used_space))
         .style(app_color_info.disk_text_color)
         .bold();
     let used_space_block = Block::bordered()
@@ -171,8 +347,14 @@ pub fn draw_disk_info(
         Line::from("Available:").style(app_color_info.base_app_text_color)
     };
 
-    let available_space_usage = Line::from(process_to_kib_mib_gib(disk_data.available_space))
-        .style(app_color_info.disk_text_color)
+    // the available space will be in red when it drop below the free space threshold
+    let available_space_color = if disk_free_thresholds.is_low_on_space(disk_data) {
+        Color::Red
+    } else {
+        app_color_info.disk_text_color
+    };
+    let available_space_usage = Line::from(format_bytes(disk_data.available_space))
+        .style(available_space_color)
         .bold();
     let available_space_block = Block::bordered()
         .title(available_space_label.left_aligned())
@@ -182,6 +364,34 @@ pub fn draw_disk_info(
 
     frame.render_widget(available_space_block, available_space_layout);
 
+    // ----------------------------------------
+    //
+    //      FOR DISK INODE USAGE LAYOUT
+    //
+    // ----------------------------------------
+    if let Some((total_inodes, free_inodes)) = disk_data.inodes {
+        let inode_usage_ratio =
+            (total_inodes.saturating_sub(free_inodes) as f64 / total_inodes as f64).clamp(0.0, 1.0);
+        let inode_usage_label = if inode_usage_layout.width < SMALL_WIDTH + 10 {
+            format!("I {:.0}% ", inode_usage_ratio * 100.0)
+        } else {
+            format!("Inodes: {:.1}% ", inode_usage_ratio * 100.0)
+        };
+
+        let inode_usage_gauge = LineGauge::default()
+            .label(Line::from(inode_usage_label).style(app_color_info.base_app_text_color))
+            .ratio(inode_usage_ratio)
+            .filled_style(Style::default().fg(app_color_info.disk_text_color))
+            .unfilled_style(
+                Style::default()
+                    .fg(app_color_info.base_app_text_color)
+                    .dim(),
+            )
+            .style(app_color_info.disk_main_block_color);
+
+        frame.render_widget(inode_usage_gauge, inode_usage_layout);
+    }
+
     // ----------------------------------------
     //
     //     FOR DISK FILE SYSTEM LAYOUT
@@ -274,6 +484,184 @@ pub fn draw_disk_info(
 
     frame.render_widget(disk_kind_block, disk_kind_layout);
 
+    // ----------------------------------------
+    //
+    //        FOR SMART HEALTH LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(smart_info) = smart_info {
+        let smart_label = if smart_layout.width < SMALL_WIDTH {
+            Line::from("H").style(app_color_info.base_app_text_color)
+        } else {
+            Line::from("SMART:").style(app_color_info.base_app_text_color)
+        };
+
+        let mut smart_values = vec![match smart_info.is_healthy {
+            Some(true) => "PASSED".to_string(),
+            Some(false) => "FAILED".to_string(),
+            None => "?".to_string(),
+        }];
+        // the details will only be shown when there is enough space
+        if smart_layout.width >= SMALL_WIDTH + 10 {
+            if let Some(reallocated_sectors) = smart_info.reallocated_sectors {
+                smart_values.push(format!("realloc {}", reallocated_sectors));
+            }
+            if let Some(wear_level) = smart_info.wear_level {
+                smart_values.push(format!("wear {}%", wear_level));
+            }
+        }
+        let smart_usage = Line::from(smart_values.join(" "))
+            .style(app_color_info.disk_text_color)
+            .bold();
+        let smart_block = Block::bordered()
+            .title(smart_label.left_aligned())
+            .title(smart_usage.right_aligned())
+            .style(app_color_info.disk_main_block_color)
+            .borders(border_type);
+
+        frame.render_widget(smart_block, smart_layout);
+    }
+
+    // ----------------------------------------
+    //
+    //         FOR RAID ARRAYS LAYOUT
+    //
+    // ----------------------------------------
+    let raid_row_layouts =
+        Layout::vertical(vec![Constraint::Length(1); raid_arrays.len()]).split(raid_layout);
+    for (raid_array, raid_row_layout) in raid_arrays.iter().zip(raid_row_layouts.iter()) {
+        let raid_label =
+            Line::from(format!("{}:", raid_array.name)).style(app_color_info.base_app_text_color);
+
+        let mut raid_values = vec![if raid_array.is_degraded() {
+            "DEGRADED".to_string()
+        } else {
+            raid_array.state.clone()
+        }];
+        if let Some((action, progress)) = raid_array.sync_progress.as_ref() {
+            raid_values.push(format!("{} {:.1}%", action, progress));
+        }
+        // the details will only be shown when there is enough space
+        if raid_row_layout.width >= SMALL_WIDTH + 10 {
+            raid_values.insert(0, raid_array.level.clone());
+            if raid_array.device_count.0 > 0 {
+                raid_values.push(format!(
+                    "[{}/{}]",
+                    raid_array.device_count.0, raid_array.device_count.1
+                ));
+            }
+        }
+        if raid_row_layout.width >= SMALL_WIDTH + 40 {
+            raid_values.push(raid_array.members.join(" "));
+        }
+        // the degraded array was highlighted
+        let raid_style = if raid_array.is_degraded() {
+            app_color_info.key_text_color
+        } else {
+            app_color_info.disk_text_color
+        };
+        let raid_usage = Line::from(raid_values.join(" ")).style(raid_style).bold();
+        let raid_block = Block::bordered()
+            .title(raid_label.left_aligned())
+            .title(raid_usage.right_aligned())
+            .style(app_color_info.disk_main_block_color)
+            .borders(border_type);
+
+        frame.render_widget(raid_block, *raid_row_layout);
+    }
+
+    // ----------------------------------------
+    //
+    //         FOR STORAGE POOL LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(storage_pool) = storage_pool {
+        let [pool_layout, pool_usage_layout, scrub_layout] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(if storage_pool.scrub_status.is_some() {
+                1
+            } else {
+                0
+            }),
+        ])
+        .areas(storage_pool_layout);
+
+        let pool_label = if pool_layout.width < SMALL_WIDTH {
+            Line::from("P").style(app_color_info.base_app_text_color)
+        } else {
+            Line::from("Pool:").style(app_color_info.base_app_text_color)
+        };
+        let mut pool_values = vec![storage_pool.name.clone()];
+        // the details will only be shown when there is enough space
+        if pool_layout.width >= SMALL_WIDTH + 10 {
+            pool_values.push(storage_pool.raid_level.clone());
+        }
+        if let Some(health) = storage_pool.health.as_ref() {
+            pool_values.push(health.clone());
+        }
+        let pool_usage = Line::from(pool_values.join(" "))
+            .style(app_color_info.disk_text_color)
+            .bold();
+        let pool_block = Block::bordered()
+            .title(pool_label.left_aligned())
+            .title(pool_usage.right_aligned())
+            .style(app_color_info.disk_main_block_color)
+            .borders(border_type);
+        frame.render_widget(pool_block, pool_layout);
+
+        // the pool usage was the raw space of all the devices, including the redundant copies
+        let pool_usage_label = if pool_usage_layout.width < SMALL_WIDTH + 10 {
+            Line::from("PA").style(app_color_info.base_app_text_color)
+        } else {
+            Line::from("Allocated:").style(app_color_info.base_app_text_color)
+        };
+        let pool_usage_value = if pool_usage_layout.width >= SMALL_WIDTH + 20 {
+            format!(
+                "{} / {} free {}",
+                format_bytes(storage_pool.allocated),
+                format_bytes(storage_pool.size),
+                format_bytes(storage_pool.get_free())
+            )
+        } else {
+            format!(
+                "{} / {}",
+                format_bytes(storage_pool.allocated),
+                format_bytes(storage_pool.size)
+            )
+        };
+        let pool_usage_block = Block::bordered()
+            .title(pool_usage_label.left_aligned())
+            .title(
+                Line::from(pool_usage_value)
+                    .style(app_color_info.disk_text_color)
+                    .bold()
+                    .right_aligned(),
+            )
+            .style(app_color_info.disk_main_block_color)
+            .borders(border_type);
+        frame.render_widget(pool_usage_block, pool_usage_layout);
+
+        if let Some(scrub_status) = storage_pool.scrub_status.as_ref() {
+            let scrub_label = if scrub_layout.width < SMALL_WIDTH {
+                Line::from("S").style(app_color_info.base_app_text_color)
+            } else {
+                Line::from("Scrub:").style(app_color_info.base_app_text_color)
+            };
+            let scrub_block = Block::bordered()
+                .title(scrub_label.left_aligned())
+                .title(
+                    Line::from(scrub_status.clone())
+                        .style(app_color_info.disk_text_color)
+                        .bold()
+                        .right_aligned(),
+                )
+                .style(app_color_info.disk_main_block_color)
+                .borders(border_type);
+            frame.render_widget(scrub_block, scrub_layout);
+        }
+    }
+
     // ----------------------------------------
     //
     //          FOR BYTES WRITTEN LAYOUT
@@ -287,12 +675,12 @@ pub fn draw_disk_info(
         Line::from("WRITE:").style(app_color_info.base_app_text_color)
     };
 
-    let actual_bytes = disk_data.bytes_written_vec[disk_data.bytes_written_vec.len() - 1];
+    let actual_bytes = bytes_written_vec[bytes_written_vec.len() - 1];
 
     let bytes_written_usage = Line::from(format!(
         "{} {}",
         if actual_bytes > 0.0 { "▲" } else { "" },
-        process_to_kib_mib_gib(actual_bytes),
+        format_bytes_per_second(actual_bytes, tick),
     ))
     .style(app_color_info.disk_text_color)
     .bold();
@@ -303,50 +691,33 @@ pub fn draw_disk_info(
         .style(app_color_info.disk_main_block_color)
         .borders(border_type);
 
-    let bytes_written_history = disk_data.bytes_written_vec.clone();
-    let num_points_to_display = graph_show_range.min(bytes_written_history.len());
-    let start_idx = bytes_written_history
-        .len()
-        .saturating_sub(num_points_to_display);
-
-    let mut current_max_written_bytes: f64 = 0.0;
-    bytes_written_history[start_idx..].iter().for_each(|usage| {
-        current_max_written_bytes = current_max_written_bytes.max(*usage);
-    });
-
-    let mut bytes_written_data_points: Vec<(f64, f64)> = bytes_written_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            let x = i as f64;
-            let y = if usage > 0.0 {
-                (usage / current_max_written_bytes) * GRAPH_PERCENTAGE as f64
-            } else {
-                0.0
-            };
-            (x, y)
-        })
-        .collect();
-
-    bytes_written_data_points = bytes_written_data_points
+    fill_graph_data_points(
+        graph_data_points,
+        bytes_written_vec,
+        graph_show_range,
+        |usage| usage,
+    );
+    let current_max_written_bytes = graph_data_points
         .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (bytes_written_data_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+        .fold(0.0, |current_max: f64, (_, usage)| current_max.max(*usage));
 
     let dataset = Dataset::default()
-        .data(&bytes_written_data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.disk_bytes_written_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels =
+        get_scaled_axis_labels(is_graph_axis_shown, current_max_written_bytes, |value| {
+            format_bytes_per_second(value, tick)
+        });
+    let y_axis = get_graph_y_axis(
+        current_max_written_bytes.max(GRAPH_MIN_SCALE),
+        &y_axis_labels,
+        app_color_info,
+    );
 
     let bytes_written_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -354,10 +725,17 @@ pub fn draw_disk_info(
         .bg(app_color_info.background_color);
 
     frame.render_widget(bytes_written_block, current_bytes_written_layout);
+    draw_graph_grid_lines(bytes_written_graph, &y_axis_labels, frame, app_color_info);
     frame.render_widget(bytes_written_chart, bytes_written_graph);
-
-    drop(bytes_written_history);
-    drop(bytes_written_data_points);
+    // label the peak of the shown window, the axis labels will show it instead when it was shown
+    if !is_graph_axis_shown && current_max_written_bytes > 0.0 {
+        draw_graph_peak_label(
+            bytes_written_graph,
+            format_bytes_per_second(current_max_written_bytes, tick),
+            frame,
+            app_color_info,
+        );
+    }
 
     // ----------------------------------------
     //
@@ -372,12 +750,12 @@ pub fn draw_disk_info(
         Line::from("READ:").style(app_color_info.base_app_text_color)
     };
 
-    let actual_bytes = disk_data.bytes_read_vec[disk_data.bytes_read_vec.len() - 1];
+    let actual_bytes = bytes_read_vec[bytes_read_vec.len() - 1];
 
     let bytes_read_usage = Line::from(format!(
         "{} {}",
         if actual_bytes > 0.0 { "▲" } else { "" },
-        process_to_kib_mib_gib(actual_bytes)
+        format_bytes_per_second(actual_bytes, tick)
     ))
     .style(app_color_info.disk_text_color)
     .bold();
@@ -388,50 +766,33 @@ pub fn draw_disk_info(
         .style(app_color_info.disk_main_block_color)
         .borders(border_type);
 
-    let bytes_read_history = disk_data.bytes_read_vec.clone();
-    let num_points_to_display = graph_show_range.min(bytes_read_history.len());
-    let start_idx = bytes_read_history
-        .len()
-        .saturating_sub(num_points_to_display);
-
-    let mut current_max_read_bytes: f64 = 0.0;
-    bytes_read_history[start_idx..].iter().for_each(|usage| {
-        current_max_read_bytes = current_max_read_bytes.max(*usage);
-    });
-
-    let mut bytes_read_data_points: Vec<(f64, f64)> = bytes_read_history[start_idx..]
+    fill_graph_data_points(
+        graph_data_points,
+        bytes_read_vec,
+        graph_show_range,
+        |usage| usage,
+    );
+    let current_max_read_bytes = graph_data_points
         .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            let x = i as f64;
-            let y = if usage > 0.0 {
-                (usage / current_max_read_bytes) * GRAPH_PERCENTAGE
-            } else {
-                0.0
-            };
-            (x, y)
-        })
-        .collect();
-
-    bytes_read_data_points = bytes_read_data_points
-        .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (bytes_read_data_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+        .fold(0.0, |current_max: f64, (_, usage)| current_max.max(*usage));
 
     let dataset = Dataset::default()
-        .data(&bytes_read_data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.disk_bytes_read_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels =
+        get_scaled_axis_labels(is_graph_axis_shown, current_max_read_bytes, |value| {
+            format_bytes_per_second(value, tick)
+        });
+    let y_axis = get_graph_y_axis(
+        current_max_read_bytes.max(GRAPH_MIN_SCALE),
+        &y_axis_labels,
+        app_color_info,
+    );
 
     let bytes_read_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -439,8 +800,365 @@ pub fn draw_disk_info(
         .bg(app_color_info.background_color);
 
     frame.render_widget(bytes_read_block, current_bytes_read_layout);
+    draw_graph_grid_lines(bytes_read_graph, &y_axis_labels, frame, app_color_info);
     frame.render_widget(bytes_read_chart, bytes_read_graph);
+    // label the peak of the shown window, the axis labels will show it instead when it was shown
+    if !is_graph_axis_shown && current_max_read_bytes > 0.0 {
+        draw_graph_peak_label(
+            bytes_read_graph,
+            format_bytes_per_second(current_max_read_bytes, tick),
+            frame,
+            app_color_info,
+        );
+    }
+
+    // ----------------------------------------
+    //
+    //         FOR IO STATS LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(latest_io_stats) = io_stats_vec.last() {
+        if io_stats_layout.height > 0 {
+            let [utilization_layout, await_layout, queue_depth_layout] = Layout::horizontal([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+            .areas(io_stats_layout);
+
+            draw_io_stats_graph(
+                "UTIL:",
+                format!("{:.1}%", latest_io_stats.utilization),
+                io_stats_vec,
+                |io_stats| io_stats.utilization,
+                Some(GRAPH_PERCENTAGE),
+                utilization_layout,
+                frame,
+                graph_show_range,
+                graph_style,
+                app_color_info.disk_bytes_written_base_graph_color,
+                border_type,
+                app_color_info,
+                graph_data_points,
+            );
+            draw_io_stats_graph(
+                "AWAIT:",
+                format!("{:.2}ms", latest_io_stats.await_ms),
+                io_stats_vec,
+                |io_stats| io_stats.await_ms,
+                None,
+                await_layout,
+                frame,
+                graph_show_range,
+                graph_style,
+                app_color_info.disk_bytes_read_base_graph_color,
+                border_type,
+                app_color_info,
+                graph_data_points,
+            );
+            draw_io_stats_graph(
+                "QUEUE:",
+                format!("{:.2}", latest_io_stats.queue_depth),
+                io_stats_vec,
+                |io_stats| io_stats.queue_depth,
+                None,
+                queue_depth_layout,
+                frame,
+                graph_show_range,
+                graph_style,
+                app_color_info.disk_bytes_written_base_graph_color,
+                border_type,
+                app_color_info,
+                graph_data_points,
+            );
+        }
+    }
+
+    // ----------------------------------------
+    //
+    //          FOR IO PRESSURE LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(pressure) = pressure.as_ref() {
+        draw_pressure_graph(
+            "IO PRESSURE:",
+            pressure,
+            io_pressure_layout,
+            frame,
+            graph_show_range,
+            graph_offset,
+            graph_style,
+            app_color_info.disk_bytes_written_base_graph_color,
+            app_color_info.disk_main_block_color,
+            app_color_info,
+            graph_data_points,
+        );
+    }
+}
+
+// the placeholder when there is no disk to show, eg: in containers or VMs that don't report any disk
+pub fn draw_empty_disk_info(
+    area: Rect,
+    frame: &mut Frame,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("D", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled("isk ", Style::default().fg(app_color_info.app_title_color)).bold(),
+    ]);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .style(app_color_info.disk_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.disk_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    let inner_area = main_block.inner(area);
+    let [_, message_layout, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(inner_area);
+    let empty_message = Paragraph::new(Line::from(Span::styled(
+        "No disk found",
+        Style::default().fg(app_color_info.base_app_text_color),
+    )))
+    .centered();
+
+    frame.render_widget(main_block, area);
+    frame.render_widget(empty_message, message_layout);
+}
+
+// draw a graph of the io stats with the latest value on the title
+// the graph will be scaled to the max value shown if the max isn't provided
+fn draw_io_stats_graph(
+    label: &str,
+    value: String,
+    history: &[DiskIoStats],
+    get_value: fn(DiskIoStats) -> f64, // the stat of the graph
+    max: Option<f64>,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    graph_style: GraphStyle,
+    graph_color: Color,
+    border_type: Borders,
+    app_color_info: &AppColorInfo,
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graph
+) {
+    let [_, io_stats_graph] =
+        Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(area);
+
+    let io_stats_block = Block::new()
+        .title(
+            Line::from(label.to_string())
+                .style(app_color_info.base_app_text_color)
+                .left_aligned(),
+        )
+        .title(
+            Line::from(value)
+                .style(app_color_info.disk_text_color)
+                .bold()
+                .right_aligned(),
+        )
+        .style(app_color_info.disk_main_block_color)
+        .borders(border_type);
+
+    let start_idx = history.len().saturating_sub(graph_show_range);
+    let max = max.unwrap_or(
+        history[start_idx..]
+            .iter()
+            .fold(0.0, |current_max: f64, io_stats| {
+                current_max.max(get_value(*io_stats))
+            }),
+    );
+
+    fill_graph_data_points(graph_data_points, history, graph_show_range, |io_stats| {
+        let value = get_value(io_stats);
+        if max > 0.0 {
+            (value.min(max) / max) * GRAPH_PERCENTAGE
+        } else {
+            0.0
+        }
+    });
+
+    let dataset = Dataset::default()
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
+        .style(Style::default().fg(graph_color));
+
+    let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
+
+    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+
+    let io_stats_chart = Chart::new(vec![dataset])
+        .x_axis(x_axis)
+        .y_axis(y_axis)
+        .bg(app_color_info.background_color);
+
+    frame.render_widget(io_stats_block, area);
+    frame.render_widget(io_stats_chart, io_stats_graph);
+}
+
+// list all the disks grouped under their physical device with their usage bar and current write / read rate,
+// the device row shows the total write / read rate of its partitions, the selected disk will be marked with >>
+// the list will be scrolled to keep the selected disk visible
+fn draw_disk_list(
+    tick: u64,
+    disks: &HashMap<String, DiskData>,
+    disk_selected_entry: usize,
+    disk_free_thresholds: &DiskFreeThresholds,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    // the last row was left empty to separate the list from the selected disk
+    let visible_rows = area.height.saturating_sub(1) as usize;
+    if visible_rows == 0 {
+        return;
+    }
 
-    drop(bytes_read_history);
-    drop(bytes_read_data_points);
+    // the rows of the list, a device row ( None ) was inserted before the partitions of each physical device
+    let ordered_disks = get_ordered_disks(disks);
+    let mut rows: Vec<(&str, Option<(usize, &DiskData)>)> = Vec::new();
+    let mut selected_row = 0;
+    for (index, disk) in ordered_disks.iter().enumerate() {
+        if rows
+            .last()
+            .is_none_or(|(device, _)| *device != disk.physical_device)
+        {
+            rows.push((&disk.physical_device, None));
+        }
+        if index == disk_selected_entry {
+            selected_row = rows.len();
+        }
+        rows.push((&disk.physical_device, Some((index, disk))));
+    }
+
+    let start_idx = selected_row.saturating_sub(visible_rows - 1);
+    let row_layouts = Layout::vertical(vec![Constraint::Length(1); visible_rows]).split(area);
+
+    // the rates will only be shown when there is enough space
+    let is_rate_shown = area.width > SMALL_WIDTH + 40;
+    let name_width = (area.width / 3).max(4);
+    let rate_width = if is_rate_shown { 30 } else { 0 };
+
+    for ((device, row), row_layout) in rows.iter().skip(start_idx).zip(row_layouts.iter()) {
+        let [name_layout, usage_layout, rate_layout] = Layout::horizontal([
+            Constraint::Length(name_width),
+            Constraint::Fill(1),
+            Constraint::Length(rate_width),
+        ])
+        .areas(*row_layout);
+
+        let (name_line, bytes_written, bytes_read) = match row {
+            // the device row, the rate is the sum of all its partitions
+            None => {
+                let partitions: Vec<&&DiskData> = ordered_disks
+                    .iter()
+                    .filter(|disk| disk.physical_device == *device)
+                    .collect();
+                let name_line = Line::from(format!(
+                    "{} ({})",
+                    device.strip_prefix("/dev/").unwrap_or(device),
+                    partitions.len()
+                ))
+                .style(Style::default().fg(app_color_info.disk_text_color).bold());
+                let bytes_written: f64 = partitions
+                    .iter()
+                    .map(|disk| disk.bytes_written_vec[disk.bytes_written_vec.len() - 1])
+                    .sum();
+                let bytes_read: f64 = partitions
+                    .iter()
+                    .map(|disk| disk.bytes_read_vec[disk.bytes_read_vec.len() - 1])
+                    .sum();
+                (name_line, bytes_written, bytes_read)
+            }
+            Some((index, disk)) => {
+                let is_disk_selected = *index == disk_selected_entry;
+                // the disk that is low on space will be in red
+                let name_color = if disk_free_thresholds.is_low_on_space(disk) {
+                    Color::Red
+                } else if is_disk_selected {
+                    app_color_info.key_text_color
+                } else {
+                    app_color_info.base_app_text_color
+                };
+                let name_style = if is_disk_selected {
+                    Style::default().fg(name_color).bold()
+                } else {
+                    Style::default().fg(name_color)
+                };
+                let name_line = Line::from(format!(
+                    "{}{}",
+                    if is_disk_selected { ">> " } else { "   " },
+                    disk.mount_point
+                ))
+                .style(name_style);
+
+                let usage_ratio = if disk.total_space > 0.0 {
+                    (disk.used_space / disk.total_space).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let usage_gauge = LineGauge::default()
+                    .label(
+                        Line::from(format!(" {:>5.1}% ", usage_ratio * 100.0))
+                            .style(app_color_info.base_app_text_color),
+                    )
+                    .ratio(usage_ratio)
+                    .filled_style(Style::default().fg(app_color_info.disk_text_color))
+                    .unfilled_style(
+                        Style::default()
+                            .fg(app_color_info.base_app_text_color)
+                            .dim(),
+                    )
+                    .style(app_color_info.disk_main_block_color);
+                frame.render_widget(usage_gauge, usage_layout);
+
+                (
+                    name_line,
+                    disk.bytes_written_vec[disk.bytes_written_vec.len() - 1],
+                    disk.bytes_read_vec[disk.bytes_read_vec.len() - 1],
+                )
+            }
+        };
+
+        let disk_name = Block::new()
+            .title(name_line)
+            .style(app_color_info.disk_main_block_color)
+            .borders(Borders::NONE);
+        // the device row doesn't have a usage bar, so its name can take the space of the bar
+        if row.is_none() {
+            frame.render_widget(disk_name, name_layout.union(usage_layout));
+        } else {
+            frame.render_widget(disk_name, name_layout);
+        }
+
+        if is_rate_shown {
+            let disk_rate = Block::new()
+                .title(
+                    Line::from(format!(
+                        "W {} R {}",
+                        format_bytes_per_second(bytes_written, tick),
+                        format_bytes_per_second(bytes_read, tick)
+                    ))
+                    .style(app_color_info.disk_text_color)
+                    .right_aligned(),
+                )
+                .style(app_color_info.disk_main_block_color)
+                .borders(Borders::NONE);
+            frame.render_widget(disk_rate, rate_layout);
+        }
+    }
 }
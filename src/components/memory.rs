@@ -1,21 +1,28 @@
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Style, Stylize},
-    symbols::{border, Marker},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType},
+    widgets::{Axis, Block, Borders, Chart, Dataset},
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, MemoryData},
-    utils::{get_tick_line_ui, process_to_kib_mib_gib},
+    format::format_memory,
+    types::{AppColorInfo, GraphStyle, MemoryData, MemoryUnit, PressureData},
+    utils::{
+        draw_graph_grid_lines, draw_pressure_graph, fill_graph_data_points, get_gradient_datasets,
+        get_gradient_points, get_graph_offset_line_ui, get_graph_y_axis,
+        get_percentage_axis_labels, get_tick_line_ui,
+    },
 };
 
 // width smaller than this will be consider small width for the memory container
 const SMALL_WIDTH: u16 = 20;
 const MEDIUM_HEIGHT: u16 = 16;
 const LARGE_HEIGHT: u16 = 21;
+// buffers, slab, dirty, writeback and huge pages
+const MEMORY_BREAKDOWN_ROWS: u16 = 5;
 const GRAPH_PERCENTAGE: f64 = 100.0;
 
 pub fn draw_memory_info(
@@ -24,10 +31,24 @@ pub fn draw_memory_info(
     area: Rect,
     frame: &mut Frame,
     graph_show_range: usize,
+    graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    graph_style: GraphStyle, // the graph type and marker of the charts
+    is_graph_axis_shown: bool, // show the y axis labels and grid lines of the charts
     is_selected: bool,
     app_color_info: &AppColorInfo,
     is_full_screen: bool,
+    memory_unit: &MemoryUnit,
+    pressure: &Option<PressureData>, // None if PSI isn't available
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graphs, owned by the app
 ) {
+    // the graphs will show an older window of the history when it was panned
+    let used_memory_vec = memory.used_memory_vec.get_panned(graph_offset);
+    let available_memory_vec = memory.available_memory_vec.get_panned(graph_offset);
+    let free_memory_vec = memory.free_memory_vec.get_panned(graph_offset);
+    let used_swap_vec = memory.used_swap_vec.get_panned(graph_offset);
+    let cached_memory_vec = memory.cached_memory_vec.get_panned(graph_offset);
+    let zfs_arc_size_vec = memory.zfs_arc_size_vec.get_panned(graph_offset);
+
     let select_instruction = Line::from(vec![
         Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
         Span::styled("M", Style::default().fg(app_color_info.key_text_color))
@@ -55,6 +76,25 @@ pub fn draw_memory_info(
         main_block = main_block.title(refresh_tick.right_aligned());
     }
 
+    // indicate that an older window of the history was shown
+    if graph_offset > 0 {
+        main_block = main_block
+            .title(get_graph_offset_line_ui(graph_offset, app_color_info).right_aligned());
+    }
+
+    let memory_unit_instruction = Line::from(vec![
+        Span::styled(" Un", Style::default().fg(app_color_info.app_title_color)).bold(),
+        Span::styled("i", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            format!("t {} ", memory_unit.get_label()),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+    main_block = main_block.title_bottom(memory_unit_instruction.right_aligned());
+
     // this will be the layout for the memory usage graph
     let [_, memory_block, _] = Layout::vertical([
         Constraint::Length(1),
@@ -79,7 +119,8 @@ pub fn draw_memory_info(
     let total_memory_label = Line::from("Total:")
         .style(app_color_info.app_title_color)
         .bold();
-    let total_memory = Line::from(process_to_kib_mib_gib(memory.total_memory))
+    // the total memory will always be shown in bytes
+    let total_memory = Line::from(format_memory(memory.total_memory, 0.0, memory_unit))
         .style(app_color_info.app_title_color)
         .bold();
     let top_inner_block = Block::new()
@@ -91,6 +132,48 @@ pub fn draw_memory_info(
     frame.render_widget(main_block, area);
     frame.render_widget(top_inner_block, top_label);
 
+    // the memory pressure graph will take the bottom of the memory container if it was available
+    let [bottom_graphs, memory_pressure_layout] = Layout::vertical([
+        Constraint::Fill(4),
+        Constraint::Fill(if pressure.is_some() { 1 } else { 0 }),
+    ])
+    .areas(bottom_graphs);
+
+    // the detailed memory stats will be shown above the graphs in the large height layout
+    let (memory_breakdown_layout, bottom_graphs) =
+        if area.height >= LARGE_HEIGHT && memory.breakdown.is_some() {
+            let [new_memory_breakdown_layout, new_bottom_graphs] = Layout::vertical([
+                Constraint::Length(MEMORY_BREAKDOWN_ROWS),
+                Constraint::Fill(1),
+            ])
+            .areas(bottom_graphs);
+            (new_memory_breakdown_layout, new_bottom_graphs)
+        } else {
+            (Rect::default(), bottom_graphs)
+        };
+
+    // the compressed swap stats will be shown as an extra row when zram or zswap was active
+    let (compressed_swap_layout, bottom_graphs) =
+        if area.height >= MEDIUM_HEIGHT && memory.compressed_swap.is_some() {
+            let [new_compressed_swap_layout, new_bottom_graphs] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(bottom_graphs);
+            (new_compressed_swap_layout, new_bottom_graphs)
+        } else {
+            (Rect::default(), bottom_graphs)
+        };
+
+    // the ZFS ARC will take the same share as the other memory series at the bottom when it was present
+    let (zfs_arc_layout, bottom_graphs) =
+        if area.height >= MEDIUM_HEIGHT && memory.zfs_arc.is_some() {
+            let series_count = if area.height >= LARGE_HEIGHT { 5 } else { 4 };
+            let [new_bottom_graphs, new_zfs_arc_layout] =
+                Layout::vertical([Constraint::Fill(series_count), Constraint::Fill(1)])
+                    .areas(bottom_graphs);
+            (new_zfs_arc_layout, new_bottom_graphs)
+        } else {
+            (Rect::default(), bottom_graphs)
+        };
+
     // we will show the metrics baseed on the height of the terminal
     // so that the rendering will fit nicely
     let mut cached_memory_layout = Rect::default();
@@ -153,8 +236,10 @@ pub fn draw_memory_info(
         Line::from("Used:").style(app_color_info.base_app_text_color)
     };
 
-    let used_memory_usage = Line::from(process_to_kib_mib_gib(
-        memory.used_memory_vec[memory.used_memory_vec.len() - 1],
+    let used_memory_usage = Line::from(format_memory(
+        used_memory_vec[used_memory_vec.len() - 1],
+        memory.total_memory,
+        memory_unit,
     ))
     .style(app_color_info.memory_text_color)
     .bold();
@@ -165,52 +250,35 @@ pub fn draw_memory_info(
         .style(app_color_info.memory_main_block_color)
         .borders(border_type);
 
-    let used_memory_history = memory.used_memory_vec.clone();
-    let num_points_to_display = graph_show_range.min(used_memory_history.len());
-    let start_idx = used_memory_history
-        .len()
-        .saturating_sub(num_points_to_display);
-    let mut used_memory_data_points: Vec<(f64, f64)> = used_memory_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            let x = i as f64;
-            let y = (usage / memory.total_memory) * GRAPH_PERCENTAGE as f64;
-            (x, y)
-        })
-        .collect();
-
-    used_memory_data_points = used_memory_data_points
-        .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (used_memory_data_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
-
-    let dataset = Dataset::default()
-        .data(&used_memory_data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
-        .style(Style::default().fg(app_color_info.used_memory_base_graph_color));
+    fill_graph_data_points(
+        graph_data_points,
+        used_memory_vec,
+        graph_show_range,
+        |usage| (usage / memory.total_memory) * GRAPH_PERCENTAGE as f64,
+    );
+
+    let gradient_points = get_gradient_points(
+        graph_data_points,
+        app_color_info.used_memory_base_graph_color,
+        app_color_info.graph_gradient.as_ref(),
+        graph_style,
+    );
+    let datasets = get_gradient_datasets(&gradient_points, graph_style);
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+    let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
 
-    let used_memory_chart = Chart::new(vec![dataset])
+    let used_memory_chart = Chart::new(datasets)
         .x_axis(x_axis)
         .y_axis(y_axis)
         .bg(app_color_info.background_color);
 
     frame.render_widget(used_memory_block, used_memory_layout);
+    draw_graph_grid_lines(used_memory_graph, &y_axis_labels, frame, app_color_info);
     frame.render_widget(used_memory_chart, used_memory_graph);
 
-    drop(used_memory_history);
-    drop(used_memory_data_points);
-
     // ----------------------------------------
     //
     //      FOR AVAILABLE MEMORY LAYOUT
@@ -225,8 +293,10 @@ pub fn draw_memory_info(
         Line::from("Available:").style(app_color_info.base_app_text_color)
     };
 
-    let available_memory_usage = Line::from(process_to_kib_mib_gib(
-        memory.available_memory_vec[memory.available_memory_vec.len() - 1],
+    let available_memory_usage = Line::from(format_memory(
+        available_memory_vec[available_memory_vec.len() - 1],
+        memory.total_memory,
+        memory_unit,
     ))
     .style(app_color_info.memory_text_color)
     .bold();
@@ -237,40 +307,23 @@ pub fn draw_memory_info(
         .style(app_color_info.memory_main_block_color)
         .borders(border_type);
 
-    let available_memory_history = memory.available_memory_vec.clone();
-    let num_points_to_display = graph_show_range.min(available_memory_history.len());
-    let start_idx = available_memory_history
-        .len()
-        .saturating_sub(num_points_to_display);
-    let mut available_memory_data_points: Vec<(f64, f64)> = available_memory_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &remain)| {
-            let x = i as f64;
-            let y = (remain / memory.total_memory) * GRAPH_PERCENTAGE as f64;
-            (x, y)
-        })
-        .collect();
-
-    available_memory_data_points = available_memory_data_points
-        .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (available_memory_data_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+    fill_graph_data_points(
+        graph_data_points,
+        available_memory_vec,
+        graph_show_range,
+        |remain| (remain / memory.total_memory) * GRAPH_PERCENTAGE as f64,
+    );
 
     let dataset = Dataset::default()
-        .data(&available_memory_data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.available_memory_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+    let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
 
     let available_memory_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -278,11 +331,14 @@ pub fn draw_memory_info(
         .bg(app_color_info.background_color);
 
     frame.render_widget(available_memory_block, available_memory_layout);
+    draw_graph_grid_lines(
+        available_memory_graph,
+        &y_axis_labels,
+        frame,
+        app_color_info,
+    );
     frame.render_widget(available_memory_chart, available_memory_graph);
 
-    drop(available_memory_history);
-    drop(available_memory_data_points);
-
     // ----------------------------------------
     //
     //        FOR FREE MEMORY LAYOUT
@@ -296,8 +352,10 @@ pub fn draw_memory_info(
         Line::from("Free:").style(app_color_info.base_app_text_color)
     };
 
-    let free_memory_usage = Line::from(process_to_kib_mib_gib(
-        memory.free_memory_vec[memory.free_memory_vec.len() - 1],
+    let free_memory_usage = Line::from(format_memory(
+        free_memory_vec[free_memory_vec.len() - 1],
+        memory.total_memory,
+        memory_unit,
     ))
     .style(app_color_info.memory_text_color)
     .bold();
@@ -308,40 +366,23 @@ pub fn draw_memory_info(
         .style(app_color_info.memory_main_block_color)
         .borders(border_type);
 
-    let free_memory_history = memory.free_memory_vec.clone();
-    let num_points_to_display = graph_show_range.min(free_memory_history.len());
-    let start_idx = free_memory_history
-        .len()
-        .saturating_sub(num_points_to_display);
-    let mut free_memory_data_points: Vec<(f64, f64)> = free_memory_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &free)| {
-            let x = i as f64;
-            let y = (free / memory.total_memory) * GRAPH_PERCENTAGE as f64;
-            (x, y)
-        })
-        .collect();
-
-    free_memory_data_points = free_memory_data_points
-        .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (free_memory_data_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+    fill_graph_data_points(
+        graph_data_points,
+        free_memory_vec,
+        graph_show_range,
+        |free| (free / memory.total_memory) * GRAPH_PERCENTAGE as f64,
+    );
 
     let dataset = Dataset::default()
-        .data(&free_memory_data_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.free_memory_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+    let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
 
     let free_memory_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -349,11 +390,9 @@ pub fn draw_memory_info(
         .bg(app_color_info.background_color);
 
     frame.render_widget(free_memory_block, free_memory_layout);
+    draw_graph_grid_lines(free_memory_graph, &y_axis_labels, frame, app_color_info);
     frame.render_widget(free_memory_chart, free_memory_graph);
 
-    drop(free_memory_history);
-    drop(free_memory_data_points);
-
     // ----------------------------------------
     //
     //        FOR SWAP MEMORY LAYOUT
@@ -362,14 +401,23 @@ pub fn draw_memory_info(
     if swap_memory_layout.height > 0 {
         let [_, swap_memory_graph] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
             .areas(swap_memory_layout);
+
+        // each swap device will take a row above the aggregated swap graph if there is space for it
+        let swap_device_count = (memory.swap_devices.len() as u16).min(swap_memory_graph.height);
+        let [swap_devices_layout, swap_memory_graph] =
+            Layout::vertical([Constraint::Length(swap_device_count), Constraint::Fill(1)])
+                .areas(swap_memory_graph);
         let swap_memory_label = if swap_memory_layout.width < SMALL_WIDTH {
             Line::from("S").style(app_color_info.base_app_text_color)
         } else {
             Line::from("Swap:").style(app_color_info.base_app_text_color)
         };
 
-        let swap_memory_usage = Line::from(process_to_kib_mib_gib(
-            memory.used_swap_vec[memory.used_swap_vec.len() - 1],
+        // the total swap isn't collected, so the swap will be shown in bytes for percentage
+        let swap_memory_usage = Line::from(format_memory(
+            used_swap_vec[used_swap_vec.len() - 1],
+            0.0,
+            memory_unit,
         ))
         .style(app_color_info.memory_text_color)
         .bold();
@@ -380,41 +428,20 @@ pub fn draw_memory_info(
             .style(app_color_info.memory_main_block_color)
             .borders(border_type);
 
-        let swap_memory_history = memory.used_swap_vec.clone();
-        let num_points_to_display = graph_show_range.min(swap_memory_history.len());
-        let start_idx = swap_memory_history
-            .len()
-            .saturating_sub(num_points_to_display);
-        let mut swap_memory_data_points: Vec<(f64, f64)> = swap_memory_history[start_idx..]
-            .iter()
-            .enumerate()
-            .map(|(i, &swap)| {
-                let x = i as f64;
-                let y =
-                    (swap.min(memory.total_memory) / memory.total_memory) * GRAPH_PERCENTAGE as f64;
-                (x, y)
-            })
-            .collect();
-
-        swap_memory_data_points = swap_memory_data_points
-            .iter()
-            .map(|(x, y)| {
-                (
-                    graph_show_range as f64 - (swap_memory_data_points.len() as f64 - x),
-                    *y,
-                )
-            })
-            .collect();
+        fill_graph_data_points(graph_data_points, used_swap_vec, graph_show_range, |swap| {
+            (swap.min(memory.total_memory) / memory.total_memory) * GRAPH_PERCENTAGE as f64
+        });
 
         let dataset = Dataset::default()
-            .data(&swap_memory_data_points)
-            .graph_type(GraphType::Bar)
-            .marker(Marker::Braille)
+            .data(graph_data_points)
+            .graph_type(graph_style.get_graph_type())
+            .marker(graph_style.get_marker())
             .style(Style::default().fg(app_color_info.swap_memory_base_graph_color));
 
         let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-        let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+        let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+        let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
 
         let swap_memory_chart = Chart::new(vec![dataset])
             .x_axis(x_axis)
@@ -422,10 +449,44 @@ pub fn draw_memory_info(
             .bg(app_color_info.background_color);
 
         frame.render_widget(swap_memory_block, swap_memory_layout);
+        draw_graph_grid_lines(swap_memory_graph, &y_axis_labels, frame, app_color_info);
         frame.render_widget(swap_memory_chart, swap_memory_graph);
 
-        drop(swap_memory_history);
-        drop(swap_memory_data_points);
+        let swap_device_layouts =
+            Layout::vertical(vec![Constraint::Length(1); swap_device_count as usize])
+                .split(swap_devices_layout);
+        for (swap_device, swap_device_layout) in
+            memory.swap_devices.iter().zip(swap_device_layouts.iter())
+        {
+            // only the file name of the device or swap file will be shown, eg: /dev/sda2 -> sda2
+            let swap_device_name = swap_device
+                .name
+                .rsplit('/')
+                .next()
+                .unwrap_or(&swap_device.name)
+                .to_string();
+            let swap_device_label = if swap_device_layout.width < SMALL_WIDTH * 2 {
+                Line::from(format!(" {}", swap_device_name))
+            } else {
+                Line::from(format!(
+                    " {} ({} p{})",
+                    swap_device_name, swap_device.kind, swap_device.priority
+                ))
+            }
+            .style(app_color_info.base_app_text_color);
+            let swap_device_usage = Line::from(format!(
+                "{} / {}",
+                format_memory(swap_device.used, swap_device.size, memory_unit),
+                format_memory(swap_device.size, 0.0, memory_unit)
+            ))
+            .style(app_color_info.memory_text_color);
+            let swap_device_block = Block::new()
+                .title(swap_device_label.left_aligned())
+                .title(swap_device_usage.right_aligned())
+                .style(app_color_info.memory_main_block_color)
+                .borders(Borders::NONE);
+            frame.render_widget(swap_device_block, *swap_device_layout);
+        }
     }
 
     // ----------------------------------------
@@ -443,8 +504,10 @@ pub fn draw_memory_info(
             Line::from("Cached:").style(app_color_info.base_app_text_color)
         };
 
-        let cached_memory_usage = Line::from(process_to_kib_mib_gib(
-            memory.cached_memory_vec[memory.cached_memory_vec.len() - 1],
+        let cached_memory_usage = Line::from(format_memory(
+            cached_memory_vec[cached_memory_vec.len() - 1],
+            memory.total_memory,
+            memory_unit,
         ))
         .style(app_color_info.memory_text_color)
         .bold();
@@ -455,41 +518,25 @@ pub fn draw_memory_info(
             .style(app_color_info.memory_main_block_color)
             .borders(border_type);
 
-        let cached_memory_history = memory.cached_memory_vec.clone();
-        let num_points_to_display = graph_show_range.min(cached_memory_history.len());
-        let start_idx = cached_memory_history
-            .len()
-            .saturating_sub(num_points_to_display);
-        let mut cached_memory_data_points: Vec<(f64, f64)> = cached_memory_history[start_idx..]
-            .iter()
-            .enumerate()
-            .map(|(i, &cached)| {
-                let x = i as f64;
-                let y = (cached.min(memory.total_memory) / memory.total_memory)
-                    * GRAPH_PERCENTAGE as f64;
-                (x, y)
-            })
-            .collect();
-
-        cached_memory_data_points = cached_memory_data_points
-            .iter()
-            .map(|(x, y)| {
-                (
-                    graph_show_range as f64 - (cached_memory_data_points.len() as f64 - x),
-                    *y,
-                )
-            })
-            .collect();
+        fill_graph_data_points(
+            graph_data_points,
+            cached_memory_vec,
+            graph_show_range,
+            |cached| {
+                (cached.min(memory.total_memory) / memory.total_memory) * GRAPH_PERCENTAGE as f64
+            },
+        );
 
         let dataset = Dataset::default()
-            .data(&cached_memory_data_points)
-            .graph_type(GraphType::Bar)
-            .marker(Marker::Braille)
+            .data(graph_data_points)
+            .graph_type(graph_style.get_graph_type())
+            .marker(graph_style.get_marker())
             .style(Style::default().fg(app_color_info.cached_memory_base_graph_color));
 
         let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-        let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+        let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+        let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
 
         let cached_memory_chart = Chart::new(vec![dataset])
             .x_axis(x_axis)
@@ -497,9 +544,194 @@ pub fn draw_memory_info(
             .bg(app_color_info.background_color);
 
         frame.render_widget(cached_memory_block, cached_memory_layout);
+        draw_graph_grid_lines(cached_memory_graph, &y_axis_labels, frame, app_color_info);
         frame.render_widget(cached_memory_chart, cached_memory_graph);
+    }
 
-        drop(cached_memory_history);
-        drop(cached_memory_data_points);
+    // ----------------------------------------
+    //
+    //         FOR ZFS ARC LAYOUT
+    //
+    // ----------------------------------------
+    if zfs_arc_layout.height > 0 {
+        if let Some(zfs_arc) = memory.zfs_arc.as_ref() {
+            let [_, zfs_arc_graph] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
+                .areas(zfs_arc_layout);
+            let zfs_arc_label = if zfs_arc_layout.width < SMALL_WIDTH {
+                Line::from("Z").style(app_color_info.base_app_text_color)
+            } else {
+                Line::from("ARC:").style(app_color_info.base_app_text_color)
+            };
+
+            // the target size and hit rate will only be shown when there is enough space
+            let zfs_arc_usage = if zfs_arc_layout.width < SMALL_WIDTH * 2 {
+                Line::from(format_memory(
+                    zfs_arc.size,
+                    memory.total_memory,
+                    memory_unit,
+                ))
+            } else {
+                Line::from(format!(
+                    "{} / {} hit {:.1}%",
+                    format_memory(zfs_arc.size, memory.total_memory, memory_unit),
+                    format_memory(zfs_arc.target_size, memory.total_memory, memory_unit),
+                    zfs_arc.hit_rate
+                ))
+            }
+            .style(app_color_info.memory_text_color)
+            .bold();
+
+            let zfs_arc_block = Block::new()
+                .title(zfs_arc_label.left_aligned())
+                .title(zfs_arc_usage.right_aligned())
+                .style(app_color_info.memory_main_block_color)
+                .borders(border_type);
+
+            fill_graph_data_points(
+                graph_data_points,
+                zfs_arc_size_vec,
+                graph_show_range,
+                |size| (size.min(memory.total_memory) / memory.total_memory) * GRAPH_PERCENTAGE,
+            );
+
+            let dataset = Dataset::default()
+                .data(graph_data_points)
+                .graph_type(graph_style.get_graph_type())
+                .marker(graph_style.get_marker())
+                .style(Style::default().fg(app_color_info.cached_memory_base_graph_color));
+
+            let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
+
+            let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+            let y_axis = get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
+
+            let zfs_arc_chart = Chart::new(vec![dataset])
+                .x_axis(x_axis)
+                .y_axis(y_axis)
+                .bg(app_color_info.background_color);
+
+            frame.render_widget(zfs_arc_block, zfs_arc_layout);
+            draw_graph_grid_lines(zfs_arc_graph, &y_axis_labels, frame, app_color_info);
+            frame.render_widget(zfs_arc_chart, zfs_arc_graph);
+        }
+    }
+
+    // ----------------------------------------
+    //
+    //        FOR MEMORY BREAKDOWN LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(breakdown) = memory.breakdown.as_ref() {
+        let huge_pages = if breakdown.huge_pages_total > 0.0 {
+            format!(
+                "{} / {}",
+                format_memory(
+                    breakdown.huge_pages_total - breakdown.huge_pages_free,
+                    memory.total_memory,
+                    memory_unit
+                ),
+                format_memory(breakdown.huge_pages_total, memory.total_memory, memory_unit)
+            )
+        } else {
+            "none".to_string()
+        };
+        let breakdown_rows = [
+            (
+                "Buffers:",
+                format_memory(breakdown.buffers, memory.total_memory, memory_unit),
+            ),
+            (
+                "Slab:",
+                format_memory(breakdown.slab, memory.total_memory, memory_unit),
+            ),
+            (
+                "Dirty:",
+                format_memory(breakdown.dirty, memory.total_memory, memory_unit),
+            ),
+            (
+                "Writeback:",
+                format_memory(breakdown.writeback, memory.total_memory, memory_unit),
+            ),
+            ("HugePages:", huge_pages),
+        ];
+        let breakdown_row_layouts =
+            Layout::vertical([Constraint::Length(1); MEMORY_BREAKDOWN_ROWS as usize])
+                .split(memory_breakdown_layout);
+        for ((label, value), row_layout) in
+            breakdown_rows.into_iter().zip(breakdown_row_layouts.iter())
+        {
+            let breakdown_block = Block::new()
+                .title(
+                    Line::from(label)
+                        .style(app_color_info.base_app_text_color)
+                        .left_aligned(),
+                )
+                .title(
+                    Line::from(value)
+                        .style(app_color_info.memory_text_color)
+                        .bold()
+                        .right_aligned(),
+                )
+                .style(app_color_info.memory_main_block_color)
+                .borders(Borders::NONE);
+            frame.render_widget(breakdown_block, *row_layout);
+        }
+    }
+
+    // ----------------------------------------
+    //
+    //        FOR COMPRESSED SWAP LAYOUT
+    //
+    // ----------------------------------------
+    if compressed_swap_layout.height > 0 {
+        if let Some(compressed_swap) = memory.compressed_swap.as_ref() {
+            // only show the ratio when the width is too small for the sizes
+            let compressed_swap_value = if compressed_swap_layout.width > SMALL_WIDTH * 2 {
+                format!(
+                    "{} -> {} ({:.1}x)",
+                    format_memory(compressed_swap.original_size, 0.0, memory_unit),
+                    format_memory(compressed_swap.compressed_size, 0.0, memory_unit),
+                    compressed_swap.get_compression_ratio()
+                )
+            } else {
+                format!("{:.1}x", compressed_swap.get_compression_ratio())
+            };
+            let compressed_swap_block = Block::new()
+                .title(
+                    Line::from(format!("{}:", compressed_swap.kind))
+                        .style(app_color_info.base_app_text_color)
+                        .left_aligned(),
+                )
+                .title(
+                    Line::from(compressed_swap_value)
+                        .style(app_color_info.memory_text_color)
+                        .bold()
+                        .right_aligned(),
+                )
+                .style(app_color_info.memory_main_block_color)
+                .borders(Borders::NONE);
+            frame.render_widget(compressed_swap_block, compressed_swap_layout);
+        }
+    }
+
+    // ----------------------------------------
+    //
+    //        FOR MEMORY PRESSURE LAYOUT
+    //
+    // ----------------------------------------
+    if let Some(pressure) = pressure.as_ref() {
+        draw_pressure_graph(
+            "Pressure:",
+            pressure,
+            memory_pressure_layout,
+            frame,
+            graph_show_range,
+            graph_offset,
+            graph_style,
+            app_color_info.used_memory_base_graph_color,
+            app_color_info.memory_main_block_color,
+            app_color_info,
+            graph_data_points,
+        );
     }
 }
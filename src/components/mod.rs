@@ -1,7 +1,11 @@
+pub mod compact;
 pub mod cpu;
 pub mod disk;
 pub mod memory;
 pub mod network;
+pub mod plugin;
 pub mod process;
+pub mod status_bar;
+pub mod system;
 pub mod theme;
 pub mod themes;
@@ -1,20 +1,34 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Local};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Style, Stylize},
-    symbols::{border, Marker},
+    style::{Color, Style, Stylize},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph, RenderDirection, Sparkline},
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, NetworkData},
-    utils::{get_tick_line_ui, process_to_kib_mib_gib},
+    format::{format_bytes, format_network_rate},
+    types::{
+        AppColorInfo, ConnectionThroughput, GraphStyle, NetworkConnectivity, NetworkData,
+        NetworkUnit, PingData,
+    },
+    utils::{
+        draw_graph_grid_lines, draw_graph_peak_label, fill_graph_data_points, format_seconds,
+        get_graph_offset_line_ui, get_graph_y_axis, get_scaled_axis_labels, get_session_line_ui,
+        get_tick_line_ui, redact_ip,
+    },
 };
 
 // width smaller than this will be consider small width for the network container
 const SMALL_WIDTH: u16 = 40;
-const GRAPH_PERCENTAGE: f64 = 100.0;
+// the y bound of the throughput graphs when nothing was transferred in the shown window
+const GRAPH_MIN_SCALE: f64 = 1.0;
+// the packet loss was calculated from this many latest pings
+const PING_LOSS_SAMPLE_COUNT: usize = 60;
 
 pub fn draw_network_info(
     tick: u64,
@@ -22,10 +36,27 @@ pub fn draw_network_info(
     area: Rect,
     frame: &mut Frame,
     graph_show_range: usize,
+    graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    graph_style: GraphStyle, // the graph type and marker of the charts
+    is_graph_axis_shown: bool, // show the y axis labels and grid lines of the charts
     is_selected: bool,
     app_color_info: &AppColorInfo,
     is_full_screen: bool,
+    is_redacted: bool,               // to hide the ip address and the vpn endpoint
+    session_since: &DateTime<Local>, // the time the network session counters was started or reset
+    network_unit: &NetworkUnit,      // the unit of the download and upload rates
+    ip_selected_entry: usize,        // the address to show when the interface has more than one
+    ping_data: Option<&PingData>,    // the continuous ping, None if it wasn't enabled
+    connections: &[ConnectionThroughput], // the established TCP connections, sorted by the throughput
+    connectivity: &NetworkConnectivity,   // the gateway, DNS and public IP shown in the header
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graphs, owned by the app
 ) {
+    // the graphs will show an older window of the history when it was panned
+    let current_received_vec = network_data.current_received_vec.get_panned(graph_offset);
+    let current_transmitted_vec = network_data
+        .current_transmitted_vec
+        .get_panned(graph_offset);
+
     let mut network_name = network_data.interface_name.clone();
     if area.width <= SMALL_WIDTH + 5 {
         let extension = if network_name.len() > 16 { ".." } else { "" };
@@ -50,6 +81,11 @@ pub fn draw_network_info(
         ),
     ]);
 
+    // mark the interface as a vpn tunnel
+    if network_data.vpn_info.is_some() {
+        network_name = format!("{} [VPN]", network_name);
+    }
+
     let network_switch_instruction = Line::from(vec![
         Span::styled("  ", Style::default().fg(app_color_info.app_title_color)),
         Span::styled("<", Style::default().fg(app_color_info.key_text_color)).bold(),
@@ -67,12 +103,32 @@ pub fn draw_network_info(
         .style(app_color_info.network_main_block_color)
         .border_set(border::ROUNDED);
 
-    if network_data.ip_network.is_some() {
+    if !network_data.ip_networks.is_empty() {
+        // the selected entry could be out of range after switching to an interface with fewer addresses
+        let ip_index = ip_selected_entry % network_data.ip_networks.len();
+        let ip_count = if network_data.ip_networks.len() > 1 {
+            format!(" ({}/{})", ip_index + 1, network_data.ip_networks.len())
+        } else {
+            "".to_string()
+        };
+        main_block = main_block.title(
+            Line::from(format!(
+                " {}{} ",
+                redact_ip(&network_data.ip_networks[ip_index], is_redacted),
+                ip_count
+            ))
+            .fg(app_color_info.network_text_color)
+            .bold()
+            .centered(),
+        )
+    }
+
+    if let Some(vpn_info) = network_data.vpn_info.as_ref() {
         main_block = main_block.title(
-            Line::from(format!(" {} ", network_data.ip_network.as_ref().unwrap()))
+            Line::from(format!(" VPN: {} ", vpn_info.kind))
                 .fg(app_color_info.network_text_color)
                 .bold()
-                .centered(),
+                .right_aligned(),
         )
     }
 
@@ -87,6 +143,39 @@ pub fn draw_network_info(
         main_block = main_block.title(refresh_tick.right_aligned())
     }
 
+    // indicate that an older window of the history was shown
+    if graph_offset > 0 {
+        main_block = main_block
+            .title(get_graph_offset_line_ui(graph_offset, app_color_info).right_aligned());
+    }
+    if is_selected {
+        let network_unit_instruction = Line::from(vec![
+            Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+            Span::styled("b", Style::default().fg(app_color_info.key_text_color))
+                .bold()
+                .underlined(),
+            Span::styled(
+                match network_unit {
+                    NetworkUnit::Bytes => "yte/s ",
+                    NetworkUnit::Bits => "it/s ",
+                },
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+        ]);
+        main_block = main_block.title_bottom(network_unit_instruction.left_aligned());
+    }
+    if area.width > SMALL_WIDTH + 20 {
+        let session_line = get_session_line_ui(
+            session_since,
+            network_data.session_transmitted,
+            network_data.session_received,
+            app_color_info,
+        );
+
+        main_block = main_block.title_bottom(session_line.right_aligned())
+    }
+
     frame.render_widget(main_block, area);
 
     // this will be the layout for the network block for graph and info
@@ -105,11 +194,281 @@ pub fn draw_network_info(
     ])
     .areas(network_block);
 
+    // for WireGuard tunnel, we will show the endpoint and latest handshake if it was accessible
+    let mut vpn_detail_info: Vec<String> = Vec::new();
+    if let Some(vpn_info) = network_data.vpn_info.as_ref() {
+        if let Some(endpoint) = vpn_info.endpoint.as_ref() {
+            vpn_detail_info.push(format!("Endpoint: {}", redact_ip(endpoint, is_redacted)));
+        }
+        if let Some(latest_handshake) = vpn_info.latest_handshake {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or(latest_handshake);
+            vpn_detail_info.push(format!(
+                "Handshake: {} ago",
+                format_seconds(now.saturating_sub(latest_handshake))
+            ));
+        }
+    }
+
+    // the gateway, DNS and public IP, only the available ones will be shown
+    let mut connectivity_info: Vec<String> = Vec::new();
+    if let Some(gateway) = connectivity.gateway.as_ref() {
+        connectivity_info.push(format!("GW {}", redact_ip(gateway, is_redacted)));
+    }
+    if let Some(dns) = connectivity.dns.as_ref() {
+        connectivity_info.push(format!("DNS {}", redact_ip(dns, is_redacted)));
+    }
+    if let Some(public_ip) = connectivity.public_ip.as_ref() {
+        connectivity_info.push(format!("Public {}", redact_ip(public_ip, is_redacted)));
+    }
+
+    let [connectivity_layout, vpn_detail_layout, interface_details_layout, packet_stats_layout, ping_layout, padded_network_block] =
+        Layout::vertical([
+            Constraint::Length(if connectivity_info.is_empty() { 0 } else { 1 }),
+            Constraint::Length(if vpn_detail_info.is_empty() { 0 } else { 1 }),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(if ping_data.is_some() { 1 } else { 0 }),
+            Constraint::Fill(1),
+        ])
+        .areas(padded_network_block);
+
+    if !connectivity_info.is_empty() {
+        let connectivity_line = Line::from(connectivity_info.join(" | "))
+            .style(app_color_info.network_text_color)
+            .centered();
+        frame.render_widget(connectivity_line, connectivity_layout);
+    }
+
+    if !vpn_detail_info.is_empty() {
+        let vpn_detail_line = Line::from(vpn_detail_info.join(" | "))
+            .style(app_color_info.network_text_color)
+            .bold()
+            .centered();
+        frame.render_widget(vpn_detail_line, vpn_detail_layout);
+    }
+
+    // ----------------------------------------
+    //
+    //    FOR NETWORK INTERFACE DETAILS LAYOUT
+    //
+    // ----------------------------------------
+    // the MAC address will be hidden for interface without one ( eg: loopback or tunnel ) or in privacy mode
+    let interface_details = &network_data.interface_details;
+    let mut interface_details_info: Vec<String> = Vec::new();
+    if !interface_details.mac_address.is_empty()
+        && interface_details.mac_address != "00:00:00:00:00:00"
+    {
+        interface_details_info.push(format!(
+            "MAC {}",
+            redact_ip(&interface_details.mac_address, is_redacted)
+        ));
+    }
+    if interface_details.mtu > 0 {
+        interface_details_info.push(format!("MTU {}", interface_details.mtu));
+    }
+    // the link speed and duplex will only be shown when there is enough space
+    if interface_details_layout.width >= SMALL_WIDTH + 20 {
+        match (interface_details.speed, interface_details.duplex.as_ref()) {
+            (Some(speed), Some(duplex)) => {
+                interface_details_info.push(format!("{} Mb/s {}", speed, duplex))
+            }
+            (Some(speed), None) => interface_details_info.push(format!("{} Mb/s", speed)),
+            _ => {}
+        }
+    }
+    let interface_details_line = Line::from(interface_details_info.join(" | "))
+        .style(app_color_info.network_text_color)
+        .centered();
+    frame.render_widget(interface_details_line, interface_details_layout);
+
+    // ----------------------------------------
+    //
+    //       FOR NETWORK PACKET STATS LAYOUT
+    //
+    // ----------------------------------------
+    // the packets, errors and drops since the last refresh, the errors and drops will be in red when there is any
+    let packet_stats = &network_data.packet_stats;
+    let get_counter_style = |received: u64, transmitted: u64| {
+        if received > 0 || transmitted > 0 {
+            Style::default().fg(Color::Red).bold()
+        } else {
+            Style::default().fg(app_color_info.network_text_color)
+        }
+    };
+    let mut packet_stats_spans = vec![
+        Span::styled(
+            if packet_stats_layout.width < SMALL_WIDTH + 20 {
+                "Pkt "
+            } else {
+                "Packets "
+            },
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        Span::styled(
+            format!(
+                "▼ {} ▲ {}",
+                packet_stats.packets_received, packet_stats.packets_transmitted
+            ),
+            Style::default().fg(app_color_info.network_text_color),
+        ),
+        Span::styled(
+            " | Err ",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        Span::styled(
+            format!(
+                "{}/{}",
+                packet_stats.errors_received, packet_stats.errors_transmitted
+            ),
+            get_counter_style(
+                packet_stats.errors_received,
+                packet_stats.errors_transmitted,
+            ),
+        ),
+    ];
+    if let Some((dropped_received, dropped_transmitted)) = packet_stats.drops {
+        packet_stats_spans.push(Span::styled(
+            " | Drop ",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ));
+        packet_stats_spans.push(Span::styled(
+            format!("{}/{}", dropped_received, dropped_transmitted),
+            get_counter_style(dropped_received, dropped_transmitted),
+        ));
+    }
+    frame.render_widget(
+        Line::from(packet_stats_spans).centered(),
+        packet_stats_layout,
+    );
+
+    // the connections will be listed on the right of the graphs in full screen
+    let is_connections_shown = is_full_screen && !connections.is_empty();
+    let [padded_network_block, connections_layout] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(if is_connections_shown {
+            (padded_network_block.width / 3).max(SMALL_WIDTH)
+        } else {
+            0
+        }),
+    ])
+    .areas(padded_network_block);
+
     let [network_received_layout, network_transmitted_layout] =
         Layout::vertical([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
             .areas(padded_network_block);
 
+    // ----------------------------------------
+    //
+    //        FOR NETWORK CONNECTIONS LAYOUT
+    //
+    // ----------------------------------------
+    if is_connections_shown {
+        let connections_block = Block::bordered()
+            .title(
+                Line::from(" Connections ")
+                    .style(app_color_info.app_title_color)
+                    .bold(),
+            )
+            .style(app_color_info.network_main_block_color)
+            .borders(Borders::LEFT | Borders::TOP);
+        let connections_inner_layout = connections_block.inner(connections_layout);
+        frame.render_widget(connections_block, connections_layout);
+
+        let connection_row_layouts = Layout::vertical(vec![
+            Constraint::Length(1);
+            connections_inner_layout.height as usize
+        ])
+        .split(connections_inner_layout);
+        for (connection, connection_row_layout) in
+            connections.iter().zip(connection_row_layouts.iter())
+        {
+            let connection_rate = format!(
+                "▲ {} ▼ {} ",
+                format_network_rate(connection.sent, tick, network_unit),
+                format_network_rate(connection.received, tick, network_unit)
+            );
+            let connection_block = Block::new()
+                .title(
+                    Line::from(format!(" {}", redact_ip(&connection.remote, is_redacted)))
+                        .style(app_color_info.base_app_text_color)
+                        .left_aligned(),
+                )
+                .title(
+                    Line::from(connection_rate)
+                        .style(app_color_info.network_text_color)
+                        .right_aligned(),
+                )
+                .borders(Borders::NONE);
+            frame.render_widget(connection_block, *connection_row_layout);
+        }
+    }
+
     // render the network received graph
+    // ----------------------------------------
+    //
+    //            FOR PING LAYOUT
+    //
+    // ----------------------------------------
+    // the latest round trip time and the packet loss, followed by the round trip time graph
+    if let Some(ping_data) = ping_data {
+        let latest_rtt = match ping_data.rtt_vec.last() {
+            Some(Some(rtt_ms)) => format!("{:.1} ms", rtt_ms),
+            Some(None) => "timeout".to_string(),
+            None => "-".to_string(),
+        };
+        let ping_loss = ping_data.get_loss(PING_LOSS_SAMPLE_COUNT);
+        let ping_text = if ping_layout.width < SMALL_WIDTH + 20 {
+            format!("Ping {} loss {:.0}% ", latest_rtt, ping_loss)
+        } else {
+            format!(
+                "Ping {}: {} loss {:.0}% ",
+                redact_ip(&ping_data.target, is_redacted),
+                latest_rtt,
+                ping_loss
+            )
+        };
+        let [ping_label_layout, ping_graph_layout] = Layout::horizontal([
+            Constraint::Length(ping_text.chars().count() as u16),
+            Constraint::Fill(1),
+        ])
+        .areas(ping_layout);
+
+        // the loss will be in red when there is any
+        let ping_style = if ping_loss > 0.0 {
+            Style::default().fg(Color::Red).bold()
+        } else {
+            Style::default()
+                .fg(app_color_info.network_text_color)
+                .bold()
+        };
+        frame.render_widget(Line::from(ping_text).style(ping_style), ping_label_layout);
+
+        // the newest ping will be at the right edge of the graph, the lost pings were left empty
+        let num_points_to_display = (ping_graph_layout.width as usize)
+            .min(graph_show_range)
+            .min(ping_data.rtt_vec.len());
+        let start_idx = ping_data.rtt_vec.len() - num_points_to_display;
+        let ping_data_points: Vec<Option<u64>> = ping_data.rtt_vec[start_idx..]
+            .iter()
+            .rev()
+            .map(|rtt_ms| rtt_ms.map(|rtt_ms| rtt_ms.ceil() as u64))
+            .collect();
+        let ping_sparkline = Sparkline::default()
+            .data(ping_data_points)
+            .direction(RenderDirection::RightToLeft)
+            .absent_value_symbol("x")
+            .absent_value_style(Style::default().fg(Color::Red))
+            .style(
+                Style::default()
+                    .fg(app_color_info.network_received_base_graph_color)
+                    .bg(app_color_info.background_color),
+            );
+        frame.render_widget(ping_sparkline, ping_graph_layout);
+    }
+
     // ----------------------------------------
     //
     //       FOR NETWORK RECEIVED LAYOUT
@@ -119,8 +478,15 @@ pub fn draw_network_info(
     let current_network_received_bytes_info = Line::from(format!(
         "Download: {} {}",
         "▼",
-        process_to_kib_mib_gib(
-            network_data.current_received_vec[network_data.current_received_vec.len() - 1]
+        format_network_rate(
+            // the entry for all the interfaces has no history when there is only the loopback interface
+            network_data
+                .current_received_vec
+                .last()
+                .copied()
+                .unwrap_or(0.0),
+            tick,
+            network_unit
         )
     ))
     .style(app_color_info.network_text_color)
@@ -129,7 +495,7 @@ pub fn draw_network_info(
     let total_network_received_bytes_info = Line::from(format!(
         "{} Total: {} ",
         "▼",
-        process_to_kib_mib_gib(network_data.total_received)
+        format_bytes(network_data.total_received)
     ))
     .style(app_color_info.network_text_color)
     .bold();
@@ -145,52 +511,33 @@ pub fn draw_network_info(
         .borders(Borders::NONE);
 
     // network received graph
-    let network_received_history = network_data.current_received_vec.clone();
-    let num_points_to_display = graph_show_range.min(network_received_history.len());
-    let start_idx = network_received_history
-        .len()
-        .saturating_sub(num_points_to_display);
-
-    let mut current_max_network_received: f64 = 0.0;
-    network_received_history[start_idx..]
-        .iter()
-        .for_each(|usage| {
-            current_max_network_received = current_max_network_received.max(*usage);
-        });
-
-    let mut network_received_points: Vec<(f64, f64)> = network_received_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            let x = i as f64;
-            let y = if usage > 0.0 {
-                (usage / current_max_network_received) * GRAPH_PERCENTAGE as f64
-            } else {
-                0.0
-            };
-            (x, y)
-        })
-        .collect();
-
-    network_received_points = network_received_points
+    fill_graph_data_points(
+        graph_data_points,
+        current_received_vec,
+        graph_show_range,
+        |usage| usage,
+    );
+    let current_max_network_received = graph_data_points
         .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (network_received_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+        .fold(0.0, |current_max: f64, (_, usage)| current_max.max(*usage));
 
     let dataset = Dataset::default()
-        .data(&network_received_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.network_received_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels =
+        get_scaled_axis_labels(is_graph_axis_shown, current_max_network_received, |value| {
+            format_network_rate(value, tick, network_unit)
+        });
+    let y_axis = get_graph_y_axis(
+        current_max_network_received.max(GRAPH_MIN_SCALE),
+        &y_axis_labels,
+        app_color_info,
+    );
 
     let network_received_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -201,7 +548,22 @@ pub fn draw_network_info(
         network_received_info_block,
         network_received_padded_info_layout,
     );
+    draw_graph_grid_lines(
+        network_received_padded_graph_layout,
+        &y_axis_labels,
+        frame,
+        app_color_info,
+    );
     frame.render_widget(network_received_chart, network_received_padded_graph_layout);
+    // label the peak of the shown window, the axis labels will show it instead when it was shown
+    if !is_graph_axis_shown && current_max_network_received > 0.0 {
+        draw_graph_peak_label(
+            network_received_padded_graph_layout,
+            format_network_rate(current_max_network_received, tick, network_unit),
+            frame,
+            app_color_info,
+        );
+    }
 
     // render the network transmitted graph
     // ----------------------------------------
@@ -213,8 +575,14 @@ pub fn draw_network_info(
     let current_network_transmitted_bytes_info = Line::from(format!(
         "Upload: {} {}",
         "▲",
-        process_to_kib_mib_gib(
-            network_data.current_transmitted_vec[network_data.current_transmitted_vec.len() - 1]
+        format_network_rate(
+            network_data
+                .current_transmitted_vec
+                .last()
+                .copied()
+                .unwrap_or(0.0),
+            tick,
+            network_unit
         )
     ))
     .style(app_color_info.network_text_color)
@@ -223,7 +591,7 @@ pub fn draw_network_info(
     let total_network_transmitted_bytes_info = Line::from(format!(
         "{} Total: {}",
         "▲",
-        process_to_kib_mib_gib(network_data.total_transmitted)
+        format_bytes(network_data.total_transmitted)
     ))
     .style(app_color_info.network_text_color)
     .bold();
@@ -239,52 +607,34 @@ pub fn draw_network_info(
         .borders(Borders::NONE);
 
     // network received graph
-    let network_transmitted_history = network_data.current_transmitted_vec.clone();
-    let num_points_to_display = graph_show_range.min(network_transmitted_history.len());
-    let start_idx = network_transmitted_history
-        .len()
-        .saturating_sub(num_points_to_display);
-
-    let mut current_max_network_transmitted: f64 = 0.0;
-    network_transmitted_history[start_idx..]
-        .iter()
-        .for_each(|usage| {
-            current_max_network_transmitted = current_max_network_transmitted.max(*usage);
-        });
-
-    let mut network_transmitted_points: Vec<(f64, f64)> = network_transmitted_history[start_idx..]
-        .iter()
-        .enumerate()
-        .map(|(i, &usage)| {
-            let x = i as f64;
-            let y = if usage > 0.0 {
-                (usage / current_max_network_transmitted) * GRAPH_PERCENTAGE as f64
-            } else {
-                0.0
-            };
-            (x, y)
-        })
-        .collect();
-
-    network_transmitted_points = network_transmitted_points
+    fill_graph_data_points(
+        graph_data_points,
+        current_transmitted_vec,
+        graph_show_range,
+        |usage| usage,
+    );
+    let current_max_network_transmitted = graph_data_points
         .iter()
-        .map(|(x, y)| {
-            (
-                graph_show_range as f64 - (network_transmitted_points.len() as f64 - x),
-                *y,
-            )
-        })
-        .collect();
+        .fold(0.0, |current_max: f64, (_, usage)| current_max.max(*usage));
 
     let dataset = Dataset::default()
-        .data(&network_transmitted_points)
-        .graph_type(GraphType::Bar)
-        .marker(Marker::Braille)
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
         .style(Style::default().fg(app_color_info.network_transmitted_base_graph_color));
 
     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
-    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+    let y_axis_labels = get_scaled_axis_labels(
+        is_graph_axis_shown,
+        current_max_network_transmitted,
+        |value| format_network_rate(value, tick, network_unit),
+    );
+    let y_axis = get_graph_y_axis(
+        current_max_network_transmitted.max(GRAPH_MIN_SCALE),
+        &y_axis_labels,
+        app_color_info,
+    );
 
     let network_transmitted_chart = Chart::new(vec![dataset])
         .x_axis(x_axis)
@@ -295,8 +645,69 @@ pub fn draw_network_info(
         network_transmitted_info_block,
         network_transmitted_padded_info_layout,
     );
+    draw_graph_grid_lines(
+        network_transmitted_padded_graph_layout,
+        &y_axis_labels,
+        frame,
+        app_color_info,
+    );
     frame.render_widget(
         network_transmitted_chart,
         network_transmitted_padded_graph_layout,
     );
+    // label the peak of the shown window, the axis labels will show it instead when it was shown
+    if !is_graph_axis_shown && current_max_network_transmitted > 0.0 {
+        draw_graph_peak_label(
+            network_transmitted_padded_graph_layout,
+            format_network_rate(current_max_network_transmitted, tick, network_unit),
+            frame,
+            app_color_info,
+        );
+    }
+}
+
+// the placeholder when there is no network interface to show, eg: in containers or VMs that don't report any interface
+pub fn draw_empty_network_info(
+    area: Rect,
+    frame: &mut Frame,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("N", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "etwork ",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .style(app_color_info.network_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.network_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    let inner_area = main_block.inner(area);
+    let [_, message_layout, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(inner_area);
+    let empty_message = Paragraph::new(Line::from(Span::styled(
+        "No network interface found",
+        Style::default().fg(app_color_info.base_app_text_color),
+    )))
+    .centered();
+
+    frame.render_widget(main_block, area);
+    frame.render_widget(empty_message, message_layout);
 }
@@ -0,0 +1,52 @@
+use std::{collections::HashMap, sync::Arc};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::Block,
+    Frame,
+};
+
+use crate::{plugins::WidgetPlugin, types::AppColorInfo};
+
+// the panels of the plugins side by side, each plugin draws its latest data inside its own panel
+pub fn draw_plugin_panels(
+    plugins: &[Arc<dyn WidgetPlugin>],
+    plugin_data: &HashMap<usize, Vec<String>>, // the latest data of each plugin, keyed by the index of the plugin
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let panel_areas = Layout::horizontal(plugins.iter().map(|_| Constraint::Fill(1))).split(area);
+
+    for (index, (plugin, panel_area)) in plugins.iter().zip(panel_areas.iter()).enumerate() {
+        let panel_block = Block::bordered()
+            .title(
+                Line::from(Span::styled(
+                    format!(" {} ", plugin.name()),
+                    Style::default().fg(app_color_info.app_title_color),
+                ))
+                .bold()
+                .left_aligned(),
+            )
+            .style(app_color_info.cpu_main_block_color)
+            .border_set(border::ROUNDED);
+        let inner_area = panel_block.inner(*panel_area);
+        frame.render_widget(panel_block, *panel_area);
+
+        // the data of the plugin wasn't collected yet
+        let Some(data) = plugin_data.get(&index) else {
+            frame.render_widget(
+                Line::from(Span::styled(
+                    "loading...",
+                    Style::default().fg(app_color_info.base_app_text_color),
+                )),
+                inner_area,
+            );
+            continue;
+        };
+        plugin.draw(data, inner_area, frame, app_color_info);
+    }
+}
@@ -1,19 +1,26 @@
-use std::collections::HashMap;
+use std::{cmp::Ordering, collections::HashMap};
 
+use chrono::Local;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Modifier, Style, Stylize},
-    symbols::{border, Marker},
+    symbols::border,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState},
+    widgets::{Axis, Block, Borders, Chart, List, ListItem, ListState},
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, ProcessData, ProcessSortType},
+    format::{format_bytes, format_bytes_per_second, format_memory},
+    types::{
+        AppColorInfo, GraphStyle, MemoryUnit, ProcessData, ProcessFilter, ProcessSortType,
+        ThreadData,
+    },
     utils::{
-        break_line_into_vectors_of_string, format_seconds, get_tick_line_ui,
-        process_to_kib_mib_gib, round_to_2_decimal, sort_process,
+        draw_graph_grid_lines, fill_graph_data_points, format_duration, format_nanoseconds,
+        format_start_time, get_child_pids, get_gradient_datasets, get_gradient_points,
+        get_graph_offset_line_ui, get_graph_y_axis, get_percentage_axis_labels, get_start_time,
+        get_tick_line_ui, redact_command, redact_user, round_to_2_decimal, sort_process, wrap_text,
     },
 };
 
@@ -22,7 +29,12 @@ const GRAPH_PERCENTAGE: f64 = 100.0;
 const MEDIUM_WIDTH: u16 = 60;
 const LARGE_WIDTH: u16 = 80;
 const X_LARGE_WIDTH: u16 = 100;
+// how many of the top cpu and memory consumers were shown, and the width of their names
+const TOP_CONSUMER_COUNT: usize = 3;
+const TOP_CONSUMER_NAME_WIDTH: usize = 12;
 const XX_LARGE_WIDTH: u16 = 120;
+// the width of each script column, they were only shown when the width is above MEDIUM_WIDTH
+const CUSTOM_COLUMN_WIDTH: usize = 10;
 
 // following is the process detail container required space percentage in different window height and also the height definetion
 const MEDIUM_HEIGHT: u16 = 15;
@@ -35,26 +47,75 @@ const LARGE_HEIGHT_FILL: u16 = 4;
 const X_LARGE_HEIGHT_FILL: u16 = 3;
 const XX_LARGE_HEIGHT_FILL: u16 = 3;
 
-pub fn draw_process_info(
-    tick: u64,
-    process_data: &HashMap<String, ProcessData>,
-    process_current_list: &mut Vec<ProcessData>,
-    process_selectable_entries: &mut usize,
-    process_selected_state: &mut ListState,
-    process_sort_type: &ProcessSortType,
-    process_sort_is_reversed: bool,
-    process_filter: String,
-    process_show_detail: bool,
-    current_showing_process_detail: &Option<HashMap<String, ProcessData>>,
-    total_memory: f64,
-    is_filtering: bool, // to indicate if the app enter typing state for process filtering
-    area: Rect,
-    frame: &mut Frame,
-    graph_show_range: usize,
-    is_selected: bool,
-    app_color_info: &AppColorInfo,
-    is_full_screen: bool,
-) {
+// the state of the process container that was drawn by draw_process_info, the lists and the selection were updated
+// while drawing
+pub struct ProcessView<'a> {
+    pub tick: u64,
+    pub process_data: &'a HashMap<String, ProcessData>,
+    pub process_current_list: &'a mut Vec<u32>, // the pids of the processes in the shown order
+    pub process_header_columns: &'a mut Vec<(Rect, ProcessSortType)>, // the area of each sortable header, for sorting by clicking
+    pub process_selectable_entries: &'a mut usize,
+    pub process_selected_state: &'a mut ListState,
+    pub process_sort_type: &'a ProcessSortType,
+    pub process_sort_is_reversed: bool,
+    pub process_command_scroll_offset: usize,
+    pub process_detail_command_scroll_offset: &'a mut usize, // clamped to the number of the wrapped command lines that don't fit
+    pub process_filter: String,
+    pub process_user_filter: &'a Option<String>,
+    pub process_show_detail: bool,
+    pub current_showing_process_detail: &'a Option<HashMap<String, ProcessData>>,
+    pub process_threads: Option<&'a [ThreadData]>, // the threads of the process in the detail, shown in place of the memory usage
+    pub total_memory: f64,
+    pub memory_unit: &'a MemoryUnit,
+    pub is_filtering: bool, // to indicate if the app enter typing state for process filtering
+    pub graph_show_range: usize,
+    pub graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    pub graph_style: GraphStyle, // the graph type and marker of the charts
+    pub is_graph_axis_shown: bool, // show the y axis labels and grid lines of the charts
+    pub is_selected: bool,
+    pub app_color_info: &'a AppColorInfo,
+    pub is_full_screen: bool,
+    pub is_redacted: bool, // to hide sensitive values like user and command arguments
+    pub is_presentation_mode: bool, // to render the process list with larger spacing
+    pub custom_columns: &'a [String], // the names of the script columns, shown after the cpu usage
+    pub custom_column_values: &'a HashMap<String, Vec<String>>, // the values of the script columns, keyed by the pid
+    pub graph_data_points: &'a mut Vec<(f64, f64)>, // the reused buffer for the points of the graphs, owned by the app
+}
+
+pub fn draw_process_info(process_view: ProcessView, area: Rect, frame: &mut Frame) {
+    let ProcessView {
+        tick,
+        process_data,
+        process_current_list,
+        process_header_columns,
+        process_selectable_entries,
+        process_selected_state,
+        process_sort_type,
+        process_sort_is_reversed,
+        process_command_scroll_offset,
+        process_detail_command_scroll_offset,
+        process_filter,
+        process_user_filter,
+        process_show_detail,
+        current_showing_process_detail,
+        process_threads,
+        total_memory,
+        memory_unit,
+        is_filtering,
+        graph_show_range,
+        graph_offset,
+        graph_style,
+        is_graph_axis_shown,
+        is_selected,
+        app_color_info,
+        is_full_screen,
+        is_redacted,
+        is_presentation_mode,
+        custom_columns,
+        custom_column_values,
+        graph_data_points,
+    } = process_view;
+
     let select_instruction = Line::from(vec![
         Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
         Span::styled("P", Style::default().fg(app_color_info.key_text_color))
@@ -89,8 +150,15 @@ pub fn draw_process_info(
 
     let mut process_filter_without_underscore_extension: String = process_filter
         .chars()
-        .take(process_filter.len() - 1)
+        .take(process_filter.len().saturating_sub(1))
         .collect();
+    // the full filter input was kept for the filtering, as the one shown in the title could be truncated
+    let process_filter_input = process_filter_without_underscore_extension.clone();
+    let process_filter_matcher = ProcessFilter::new(&process_filter_input);
+    let invalid_filter_indicator = match process_filter_matcher.as_ref() {
+        Some(matcher) if matcher.is_invalid() => "(invalid regex) ",
+        _ => "",
+    };
 
     // for process filtering input width takeup space
     process_filter_without_underscore_extension =
@@ -151,6 +219,10 @@ pub fn draw_process_info(
                 format!(" {}_ ", process_filter_without_underscore_extension),
                 Style::default().fg(app_color_info.app_title_color).bold(),
             ),
+            Span::styled(
+                invalid_filter_indicator,
+                Style::default().fg(app_color_info.key_text_color),
+            ),
             Span::styled("↵ ", Style::default().fg(app_color_info.key_text_color)).bold(),
         ])
     } else {
@@ -175,6 +247,10 @@ pub fn draw_process_info(
                     format!(" {} ", process_filter_without_underscore_extension),
                     Style::default().fg(app_color_info.app_title_color).bold(),
                 ),
+                Span::styled(
+                    invalid_filter_indicator,
+                    Style::default().fg(app_color_info.key_text_color),
+                ),
                 Span::styled("← ", Style::default().fg(app_color_info.key_text_color)).bold(),
             ])
         }
@@ -252,13 +328,52 @@ pub fn draw_process_info(
         ])
     };
 
+    // the command column was only shown when there is enough width
+    let process_command_scroll_instruction = if area.width > MEDIUM_WIDTH {
+        Line::from(vec![
+            Span::styled(" < ", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                "scroll cmd",
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+            Span::styled(" > ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        ])
+    } else {
+        Line::from("")
+    };
+
+    // the user picked from the user menu
+    let process_user_filter_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("U", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            match process_user_filter.as_ref() {
+                Some(user) => format!("ser: {} ", redact_user(user, is_redacted)),
+                None => "ser ".to_string(),
+            },
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+    ]);
+
+    // the number of the processes in each state, eg: "312 total, 2 running, 1 zombie"
+    let process_state_summary = Line::from(Span::styled(
+        format!(" {} ", get_process_state_summary(process_data)),
+        Style::default().fg(app_color_info.app_title_color),
+    ));
+
     let mut main_block = Block::bordered()
         .title(select_instruction.left_aligned())
         .title(process_filter_instruction.left_aligned())
+        .title(process_user_filter_instruction.left_aligned())
+        .title(process_state_summary.left_aligned())
         .title(process_sort_is_reversed_intruction.right_aligned())
         .title(process_sort_select_instruction.right_aligned())
         .title_bottom(process_list_selection_instruction.left_aligned())
         .title_bottom(able_show_info.left_aligned())
+        .title_bottom(process_command_scroll_instruction.right_aligned())
         .style(app_color_info.process_main_block_color)
         .border_set(border::ROUNDED);
 
@@ -273,6 +388,22 @@ pub fn draw_process_info(
         main_block = main_block.title(refresh_tick.right_aligned())
     }
 
+    // indicate that an older window of the history was shown
+    if graph_offset > 0 {
+        main_block = main_block
+            .title(get_graph_offset_line_ui(graph_offset, app_color_info).right_aligned());
+    }
+    // indicate that the sensitive values are currently being hidden
+    if is_redacted {
+        main_block = main_block.title_bottom(
+            Line::from(Span::styled(
+                " redacted ",
+                Style::default().fg(app_color_info.key_text_color),
+            ))
+            .centered(),
+        );
+    }
+
     frame.render_widget(main_block, area);
 
     // padded the inner container
@@ -290,6 +421,14 @@ pub fn draw_process_info(
     ])
     .areas(padded_vertical_inner);
 
+    // the top consumers were always shown above the process list, regardless of the sort and the filter
+    let [top_consumers_layout, process_block] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(process_block);
+    frame.render_widget(
+        get_top_consumers_line(process_data, total_memory, memory_unit, app_color_info),
+        top_consumers_layout,
+    );
+
     let [mut title_layout, mut process_list_layout] =
         Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(process_block);
 
@@ -322,6 +461,9 @@ pub fn draw_process_info(
             Some(hashmap) => {
                 if let Some((_, value)) = hashmap.iter().next() {
                     let process_detail = value;
+                    // the graphs will show an older window of the history when it was panned
+                    let cpu_usage_history = process_detail.cpu_usage.get_panned(graph_offset);
+                    let memory_history = process_detail.memory.get_panned(graph_offset);
 
                     let [process_detail_graph_layout, process_detail_info_layout] =
                         Layout::horizontal([Constraint::Fill(3), Constraint::Fill(7)])
@@ -504,18 +646,54 @@ pub fn draw_process_info(
                         ])
                     };
 
+                    // dim the parent and child navigation triggers if there is no process to navigate to
+                    let has_parent = process_detail.parent != "-";
+                    let child_count =
+                        get_child_pids(&process_detail.pid.to_string(), process_data).len();
+                    let get_navigation_instruction = |text: String, key: &str, is_enabled: bool| {
+                        let mut instruction = Line::from(vec![
+                            Span::styled(text, Style::default().fg(app_color_info.app_title_color))
+                                .bold(),
+                            Span::styled(
+                                key.to_string(),
+                                Style::default().fg(app_color_info.key_text_color),
+                            )
+                            .bold(),
+                        ]);
+                        if is_user_navigating_process_list || !is_enabled {
+                            instruction = instruction.patch_style(Modifier::DIM);
+                        }
+                        instruction
+                    };
+                    let parent_instruction =
+                        get_navigation_instruction("Parent ".to_string(), "g", has_parent);
+                    let child_instruction = get_navigation_instruction(
+                        format!("Children({}) ", child_count),
+                        "j",
+                        child_count > 0,
+                    );
+
                     let process_detail_info_block = if area.width < MEDIUM_WIDTH {
                         Block::bordered()
                             .borders(Borders::NONE)
                             .title(termination_instruction.left_aligned())
                             .title(signal_instruction.left_aligned())
                             .title(hide_instruction.right_aligned())
+                    } else if area.width <= X_LARGE_WIDTH {
+                        Block::bordered()
+                            .borders(Borders::NONE)
+                            .title(termination_instruction.left_aligned())
+                            .title(kill_instruction.left_aligned())
+                            .title(signal_instruction.left_aligned())
+                            .title(hide_instruction.right_aligned())
                     } else {
                         Block::bordered()
                             .borders(Borders::NONE)
                             .title(termination_instruction.left_aligned())
                             .title(kill_instruction.left_aligned())
                             .title(signal_instruction.left_aligned())
+                            .title(parent_instruction.right_aligned())
+                            .title(child_instruction.right_aligned())
                             .title(hide_instruction.right_aligned())
                     };
 
@@ -549,54 +727,30 @@ pub fn draw_process_info(
                     .areas(detail_graph_naming_layout);
 
                     // get the process cpu usage history
-                    let process_cpu_usage_history = process_detail.cpu_usage.clone();
-
-                    // Determine the number of points to display based on zoom level
-                    let num_points_to_display =
-                        graph_show_range.min(process_cpu_usage_history.len());
-                    let start_idx = process_cpu_usage_history
-                        .len()
-                        .saturating_sub(num_points_to_display);
-                    let mut process_cpu_usage_points: Vec<(f64, f64)> = process_cpu_usage_history
-                        [start_idx..]
-                        .iter()
-                        .enumerate()
-                        .map(|(i, &usage)| {
-                            // X-axis: Usage (0.0 to 100.0)
-                            // Y-axis: Time (most recent at the bottom)
-                            // Map the index to a y-value from 0.0 (oldest) to num_points_to_display (newest)
-                            let x = i as f64;
-                            let y = usage as f64;
-                            (x, y)
-                        })
-                        .collect();
-
-                    process_cpu_usage_points = process_cpu_usage_points
-                        .iter()
-                        .map(|(x, y)| {
-                            (
-                                graph_show_range as f64
-                                    - (process_cpu_usage_points.len() as f64 - x),
-                                *y,
-                            )
-                        })
-                        .collect();
+                    fill_graph_data_points(
+                        graph_data_points,
+                        cpu_usage_history,
+                        graph_show_range,
+                        |usage| usage as f64,
+                    );
 
                     // Create the dataset for the chart
-                    let dataset = Dataset::default()
-                        .name("")
-                        .data(&process_cpu_usage_points)
-                        .graph_type(GraphType::Bar)
-                        .marker(Marker::Braille)
-                        .style(Style::default().fg(app_color_info.cpu_base_graph_color));
+                    let gradient_points = get_gradient_points(
+                        graph_data_points,
+                        app_color_info.cpu_base_graph_color,
+                        app_color_info.graph_gradient.as_ref(),
+                        graph_style,
+                    );
+                    let datasets = get_gradient_datasets(&gradient_points, graph_style);
 
                     let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
 
                     // Define the x-axis (CPU Usage) and y-axis (Time)
-                    let y_axis = Axis::default().bounds([0.0, 100.0]);
+                    let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+                    let y_axis = get_graph_y_axis(100.0, &y_axis_labels, app_color_info);
 
                     // Create the chart widget
-                    let process_cpu_usage_chart = Chart::new(vec![dataset])
+                    let process_cpu_usage_chart = Chart::new(datasets)
                         .x_axis(x_axis)
                         .y_axis(y_axis)
                         .bg(app_color_info.background_color);
@@ -610,6 +764,12 @@ pub fn draw_process_info(
                     )
                     .bold()]);
 
+                    draw_graph_grid_lines(
+                        padded_detail_graph_layout,
+                        &y_axis_labels,
+                        frame,
+                        app_color_info,
+                    );
                     frame.render_widget(process_cpu_usage_chart, padded_detail_graph_layout);
                     frame.render_widget(
                         process_cpu_usage_graph_naming,
@@ -623,9 +783,24 @@ pub fn draw_process_info(
                         Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
                             .areas(process_detail_info_layout);
 
-                    let [process_info_layout, process_memory_usage_layout, process_cmd_layout] =
+                    // additional info lines that are only available on some platform or process
+                    let process_additional_info_lines = get_process_additional_info_lines(
+                        value,
+                        total_memory,
+                        memory_unit,
+                        app_color_info,
+                    );
+                    let process_additional_info_height = if process_additional_info_lines.is_empty()
+                    {
+                        0
+                    } else {
+                        process_additional_info_lines.len() as u16 + 1
+                    };
+
+                    let [process_info_layout, process_additional_info_layout, process_memory_usage_layout, process_cmd_layout] =
                         Layout::vertical(vec![
                             Constraint::Length(3),
+                            Constraint::Length(process_additional_info_height),
                             Constraint::Fill(1),
                             Constraint::Length(3),
                         ])
@@ -834,29 +1009,32 @@ pub fn draw_process_info(
                     frame.render_widget(process_info_title, process_info_title_layout);
 
                     let status_detail = value.status.clone();
-                    let elapsed_detail = format_seconds(value.elapsed);
+                    let elapsed_detail = format_duration(value.elapsed);
+                    let now = Local::now();
+                    let start_time_detail =
+                        format_start_time(&get_start_time(value.elapsed, &now), &now); // this will be render at the extra detail row
                     let current_io_read_detail = format!(
                         "{} /",
-                        process_to_kib_mib_gib(value.current_read_disk_usage as f64)
+                        format_bytes_per_second(value.current_read_disk_usage as f64, tick)
                     );
-                    let total_io_read_detail = format!(
-                        "{}",
-                        process_to_kib_mib_gib(value.total_read_disk_usage as f64)
-                    ); // this will be render at the extra detail row
+                    let total_io_read_detail =
+                        format!("{}", format_bytes(value.total_read_disk_usage as f64)); // this will be render at the extra detail row
                     let current_io_write_detail = format!(
                         "{} /",
-                        process_to_kib_mib_gib(value.current_write_disk_usage as f64)
+                        format_bytes_per_second(value.current_write_disk_usage as f64, tick)
                     );
-                    let total_io_write_detail = format!(
-                        "{}",
-                        process_to_kib_mib_gib(value.total_write_disk_usage as f64)
-                    ); // this will be render at the extra detail row
-                    let user_detail = value.user.clone();
+                    let total_io_write_detail =
+                        format!("{}", format_bytes(value.total_write_disk_usage as f64)); // this will be render at the extra detail row
+                    let user_detail = redact_user(&value.user, is_redacted);
                     let parent_detail = match process_data.get(&value.parent) {
                         Some(p_d) => p_d.name.clone(),
                         None => "-".to_string(),
                     };
-                    let thread_detail = value.thread_count.to_string();
+                    // a process always has a thread, 0 means it wasn't collected yet ( eg: a new process on Windows )
+                    let thread_detail = match value.thread_count {
+                        0 => "-".to_string(),
+                        thread_count => thread_count.to_string(),
+                    };
 
                     let padded_status_detail = if status_detail.len() < status_width {
                         format!("{:^width$}", status_detail, width = status_width)
@@ -972,7 +1150,14 @@ pub fn draw_process_info(
                             Style::default().fg(app_color_info.process_text_color),
                         ),
                         Span::styled(
-                            format!("{:^width$}", "", width = elapsed_width),
+                            format!(
+                                "{:^width$}",
+                                start_time_detail
+                                    .chars()
+                                    .take(elapsed_width)
+                                    .collect::<String>(),
+                                width = elapsed_width
+                            ),
                             Style::default().fg(app_color_info.base_app_text_color),
                         ),
                         Span::styled(
@@ -1001,124 +1186,160 @@ pub fn draw_process_info(
                     frame.render_widget(process_info_detail_extra, extra_detail_layout);
 
                     // ------------------------------------------------------------
-                    // Memory Usage Metrics and graph on the middle
+                    // Additional process info ( delay, container, ... )
                     // ------------------------------------------------------------
-
-                    let [process_memory_usage_percentage_layout, process_memory_usage_graph_layout, process_memory_usage_bytes_layout] =
-                        Layout::horizontal(vec![
-                            Constraint::Fill(3),
-                            Constraint::Fill(4),
-                            Constraint::Fill(3),
-                        ])
-                        .areas(process_memory_usage_layout);
+                    if !process_additional_info_lines.is_empty() {
+                        let [_, process_additional_info_lines_layout] =
+                            Layout::vertical(vec![Constraint::Length(1), Constraint::Fill(1)])
+                                .areas(process_additional_info_layout);
+                        let process_additional_info_line_layouts = Layout::vertical(
+                            process_additional_info_lines
+                                .iter()
+                                .map(|_| Constraint::Length(1))
+                                .collect::<Vec<Constraint>>(),
+                        )
+                        .split(process_additional_info_lines_layout);
+
+                        for (line, line_layout) in process_additional_info_lines
+                            .into_iter()
+                            .zip(process_additional_info_line_layouts.iter())
+                        {
+                            frame.render_widget(line, *line_layout);
+                        }
+                    }
 
                     // ------------------------------------------------------------
-                    // Memory Usage Percentage on the right side of the memory usage graph
+                    // the threads of the process in place of the memory usage when the threads were shown
                     // ------------------------------------------------------------
-
-                    let process_memory_usage_percentage: f64 =
-                        ((process_detail.memory[process_detail.memory.len() - 1]) / total_memory)
-                            * 100.0;
-                    let process_memory_usage_percentage_formatting = if area.width < LARGE_WIDTH {
-                        format!("M: {:.2}%", process_memory_usage_percentage)
+                    if let Some(process_threads) = process_threads {
+                        draw_process_thread_list(
+                            process_threads,
+                            process_memory_usage_layout,
+                            frame,
+                            app_color_info,
+                        );
                     } else {
-                        format!("MEMORY: {:.2}%", process_memory_usage_percentage)
-                    };
-
-                    let [_, process_memory_usage_percentage_layout, _] = Layout::horizontal(vec![
-                        Constraint::Fill(1),
-                        Constraint::Length(process_memory_usage_percentage_formatting.len() as u16),
-                        Constraint::Fill(1),
-                    ])
-                    .areas(process_memory_usage_percentage_layout);
-                    let [_, padded_process_memory_usage_percentage_layout] =
-                        Layout::vertical(vec![Constraint::Fill(1), Constraint::Length(1)])
-                            .areas(process_memory_usage_percentage_layout);
-
-                    let process_memory_usage_percentage_line = Line::from(vec![Span::styled(
-                        process_memory_usage_percentage_formatting,
-                        Style::default().fg(app_color_info.process_title_color),
-                    )
-                    .bold()]);
+                        // ------------------------------------------------------------
+                        // Memory Usage Metrics and graph on the middle
+                        // ------------------------------------------------------------
 
-                    frame.render_widget(
-                        process_memory_usage_percentage_line,
-                        padded_process_memory_usage_percentage_layout,
-                    );
-
-                    // get the process memory history
-                    let process_memory = process_detail.memory.clone();
-                    let num_points_to_display = graph_show_range.min(process_memory.len());
-                    let start_idx = process_memory.len().saturating_sub(num_points_to_display);
-
-                    let mut process_memory_points: Vec<(f64, f64)> = process_memory[start_idx..]
-                        .iter()
-                        .enumerate()
-                        .map(|(i, &usage)| {
-                            let x = i as f64;
-                            let y = if usage > 0.0 {
-                                (usage / total_memory) * GRAPH_PERCENTAGE as f64
-                            } else {
-                                0.0
-                            };
-                            (x, y)
-                        })
-                        .collect();
-
-                    process_memory_points = process_memory_points
-                        .iter()
-                        .map(|(x, y)| {
-                            (
-                                graph_show_range as f64 - (process_memory_points.len() as f64 - x),
-                                *y,
-                            )
-                        })
-                        .collect();
-
-                    let dataset = Dataset::default()
-                        .data(&process_memory_points)
-                        .graph_type(GraphType::Bar)
-                        .marker(Marker::Braille)
-                        .style(Style::default().fg(app_color_info.used_memory_base_graph_color));
-
-                    let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
-
-                    let y_axis = Axis::default().bounds([0.0, GRAPH_PERCENTAGE]);
+                        let [process_memory_usage_percentage_layout, process_memory_usage_graph_layout, process_memory_usage_bytes_layout] =
+                            Layout::horizontal(vec![
+                                Constraint::Fill(3),
+                                Constraint::Fill(4),
+                                Constraint::Fill(3),
+                            ])
+                            .areas(process_memory_usage_layout);
 
-                    let process_memory_chart = Chart::new(vec![dataset])
-                        .x_axis(x_axis)
-                        .y_axis(y_axis)
-                        .bg(app_color_info.background_color);
+                        // ------------------------------------------------------------
+                        // Memory Usage Percentage on the right side of the memory usage graph
+                        // ------------------------------------------------------------
 
-                    frame.render_widget(process_memory_chart, process_memory_usage_graph_layout);
+                        let process_memory_usage_percentage: f64 =
+                            ((memory_history[memory_history.len() - 1]) / total_memory) * 100.0;
+                        let process_memory_usage_percentage_formatting = if area.width < LARGE_WIDTH
+                        {
+                            format!("M: {:.2}%", process_memory_usage_percentage)
+                        } else {
+                            format!("MEMORY: {:.2}%", process_memory_usage_percentage)
+                        };
 
-                    // ------------------------------------------------------------
-                    // Memory Usage Bytes on the left side of the memory usage graph
-                    // ------------------------------------------------------------
-                    let process_memory_usage_bytes_formatting = process_to_kib_mib_gib(
-                        process_detail.memory[process_detail.memory.len() - 1],
-                    );
+                        let [_, process_memory_usage_percentage_layout, _] =
+                            Layout::horizontal(vec![
+                                Constraint::Fill(1),
+                                Constraint::Length(
+                                    process_memory_usage_percentage_formatting.len() as u16,
+                                ),
+                                Constraint::Fill(1),
+                            ])
+                            .areas(process_memory_usage_percentage_layout);
+                        let [_, padded_process_memory_usage_percentage_layout] =
+                            Layout::vertical(vec![Constraint::Fill(1), Constraint::Length(1)])
+                                .areas(process_memory_usage_percentage_layout);
 
-                    let [_, process_memory_usage_bytes_layout, _] = Layout::horizontal(vec![
-                        Constraint::Fill(1),
-                        Constraint::Length(process_memory_usage_bytes_formatting.len() as u16),
-                        Constraint::Fill(1),
-                    ])
-                    .areas(process_memory_usage_bytes_layout);
+                        let process_memory_usage_percentage_line = Line::from(vec![Span::styled(
+                            process_memory_usage_percentage_formatting,
+                            Style::default().fg(app_color_info.process_title_color),
+                        )
+                        .bold()]);
 
-                    let [_, padded_process_memory_usage_bytes_layout] =
-                        Layout::vertical(vec![Constraint::Fill(1), Constraint::Length(1)])
-                            .areas(process_memory_usage_bytes_layout);
-                    let process_memory_usage_bytes_line = Line::from(vec![Span::styled(
-                        process_memory_usage_bytes_formatting,
-                        Style::default().fg(app_color_info.process_title_color),
-                    )
-                    .bold()]);
+                        frame.render_widget(
+                            process_memory_usage_percentage_line,
+                            padded_process_memory_usage_percentage_layout,
+                        );
+
+                        // get the process memory history
+                        fill_graph_data_points(
+                            graph_data_points,
+                            memory_history,
+                            graph_show_range,
+                            |usage| {
+                                if usage > 0.0 {
+                                    (usage / total_memory) * GRAPH_PERCENTAGE as f64
+                                } else {
+                                    0.0
+                                }
+                            },
+                        );
+
+                        let gradient_points = get_gradient_points(
+                            graph_data_points,
+                            app_color_info.used_memory_base_graph_color,
+                            app_color_info.graph_gradient.as_ref(),
+                            graph_style,
+                        );
+                        let datasets = get_gradient_datasets(&gradient_points, graph_style);
+
+                        let x_axis = Axis::default().bounds([0.0, graph_show_range as f64]);
+
+                        let y_axis_labels = get_percentage_axis_labels(is_graph_axis_shown);
+                        let y_axis =
+                            get_graph_y_axis(GRAPH_PERCENTAGE, &y_axis_labels, app_color_info);
+
+                        let process_memory_chart = Chart::new(datasets)
+                            .x_axis(x_axis)
+                            .y_axis(y_axis)
+                            .bg(app_color_info.background_color);
+
+                        draw_graph_grid_lines(
+                            process_memory_usage_graph_layout,
+                            &y_axis_labels,
+                            frame,
+                            app_color_info,
+                        );
+                        frame
+                            .render_widget(process_memory_chart, process_memory_usage_graph_layout);
+
+                        // ------------------------------------------------------------
+                        // Memory Usage Bytes on the left side of the memory usage graph
+                        // ------------------------------------------------------------
+                        let process_memory_usage_bytes_formatting = format_memory(
+                            memory_history[memory_history.len() - 1],
+                            total_memory,
+                            memory_unit,
+                        );
+
+                        let [_, process_memory_usage_bytes_layout, _] = Layout::horizontal(vec![
+                            Constraint::Fill(1),
+                            Constraint::Length(process_memory_usage_bytes_formatting.len() as u16),
+                            Constraint::Fill(1),
+                        ])
+                        .areas(process_memory_usage_bytes_layout);
+
+                        let [_, padded_process_memory_usage_bytes_layout] =
+                            Layout::vertical(vec![Constraint::Fill(1), Constraint::Length(1)])
+                                .areas(process_memory_usage_bytes_layout);
+                        let process_memory_usage_bytes_line = Line::from(vec![Span::styled(
+                            process_memory_usage_bytes_formatting,
+                            Style::default().fg(app_color_info.process_title_color),
+                        )
+                        .bold()]);
 
-                    frame.render_widget(
-                        process_memory_usage_bytes_line,
-                        padded_process_memory_usage_bytes_layout,
-                    );
+                        frame.render_widget(
+                            process_memory_usage_bytes_line,
+                            padded_process_memory_usage_bytes_layout,
+                        );
+                    }
 
                     // ------------------------------------------------------------
                     // CMD command on the bottom
@@ -1127,19 +1348,13 @@ pub fn draw_process_info(
                         Layout::horizontal(vec![Constraint::Fill(2), Constraint::Fill(8)])
                             .areas(process_cmd_layout);
 
-                    let [_, process_cmd_title_layout, _] = Layout::vertical(vec![
-                        Constraint::Length(1),
-                        Constraint::Length(1),
-                        Constraint::Length(1),
-                    ])
-                    .areas(process_cmd_title_layout);
-                    let [upper_process_cmd_layout, mid_process_cmd_layout, bottom_process_cmd_layout] =
+                    let [_, process_cmd_title_layout, process_cmd_scroll_layout] =
                         Layout::vertical(vec![
                             Constraint::Length(1),
                             Constraint::Length(1),
                             Constraint::Length(1),
                         ])
-                        .areas(process_cmd_info_layout);
+                        .areas(process_cmd_title_layout);
 
                     let process_cmd_title_line = Line::from(vec![Span::styled(
                         format!(
@@ -1151,78 +1366,62 @@ pub fn draw_process_info(
                     )
                     .bold()]);
 
-                    let seperated_cmd_line_vec = break_line_into_vectors_of_string(
-                        process_detail.cmd.join(""),
-                        upper_process_cmd_layout.width as usize,
-                        3,
+                    // the full command was wrapped, the lines that don't fit can be scrolled with shift + up/down
+                    let process_cmd_lines = wrap_text(
+                        &redact_command(&process_detail.cmd, " ", is_redacted),
+                        process_cmd_info_layout.width as usize,
                     );
-                    if seperated_cmd_line_vec.len() == 1 {
-                        let first_line = Line::from(vec![Span::styled(
-                            format!(
-                                "{:^width$}",
-                                seperated_cmd_line_vec[0],
-                                width = mid_process_cmd_layout.width as usize
-                            ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
+                    let shown_line_count = process_cmd_info_layout.height as usize;
+                    *process_detail_command_scroll_offset = (*process_detail_command_scroll_offset)
+                        .min(process_cmd_lines.len().saturating_sub(shown_line_count));
 
-                        frame.render_widget(first_line, mid_process_cmd_layout);
-                    } else if seperated_cmd_line_vec.len() == 2 {
-                        let first_line = Line::from(vec![Span::styled(
-                            format!(
-                                "{:^width$}",
-                                seperated_cmd_line_vec[0],
-                                width = upper_process_cmd_layout.width as usize
-                            ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
-                        let second_line = Line::from(vec![Span::styled(
-                            format!(
-                                "{:^width$}",
-                                seperated_cmd_line_vec[1],
-                                width = mid_process_cmd_layout.width as usize
-                            ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
+                    // a single line was shown in the middle like the title
+                    let process_cmd_info_layout = if process_cmd_lines.len() == 1 {
+                        let [_, mid_process_cmd_layout, _] = Layout::vertical(vec![
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                        ])
+                        .areas(process_cmd_info_layout);
+                        mid_process_cmd_layout
+                    } else {
+                        process_cmd_info_layout
+                    };
+                    let shown_process_cmd_lines: Vec<Line> = process_cmd_lines
+                        .iter()
+                        .skip(*process_detail_command_scroll_offset)
+                        .take(shown_line_count)
+                        .map(|line| {
+                            Line::from(vec![Span::styled(
+                                format!(
+                                    "{:^width$}",
+                                    line,
+                                    width = process_cmd_info_layout.width as usize
+                                ),
+                                Style::default().fg(app_color_info.base_app_text_color),
+                            )
+                            .bold()])
+                        })
+                        .collect();
+                    frame
+                        .render_widget(List::new(shown_process_cmd_lines), process_cmd_info_layout);
 
-                        frame.render_widget(first_line, upper_process_cmd_layout);
-                        frame.render_widget(second_line, mid_process_cmd_layout);
-                    }
-                    if seperated_cmd_line_vec.len() == 3 {
-                        let first_line = Line::from(vec![Span::styled(
-                            format!(
-                                "{:^width$}",
-                                seperated_cmd_line_vec[0],
-                                width = upper_process_cmd_layout.width as usize
-                            ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
-                        let second_line = Line::from(vec![Span::styled(
+                    // the position of the shown lines, only when the command doesn't fit
+                    if process_cmd_lines.len() > shown_line_count {
+                        let process_cmd_scroll_line = Line::from(vec![Span::styled(
                             format!(
                                 "{:^width$}",
-                                seperated_cmd_line_vec[1],
-                                width = mid_process_cmd_layout.width as usize
+                                format!(
+                                    "↕ {}-{}/{}",
+                                    *process_detail_command_scroll_offset + 1,
+                                    *process_detail_command_scroll_offset + shown_line_count,
+                                    process_cmd_lines.len()
+                                ),
+                                width = process_cmd_scroll_layout.width as usize
                             ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
-                        let third_line = Line::from(vec![Span::styled(
-                            format!(
-                                "{:^width$}",
-                                seperated_cmd_line_vec[2],
-                                width = bottom_process_cmd_layout.width as usize
-                            ),
-                            Style::default().fg(app_color_info.base_app_text_color),
-                        )
-                        .bold()]);
-
-                        frame.render_widget(first_line, upper_process_cmd_layout);
-                        frame.render_widget(second_line, mid_process_cmd_layout);
-                        frame.render_widget(third_line, bottom_process_cmd_layout);
+                            Style::default().fg(app_color_info.key_text_color),
+                        )]);
+                        frame.render_widget(process_cmd_scroll_line, process_cmd_scroll_layout);
                     }
 
                     frame.render_widget(process_cmd_title_line, process_cmd_title_layout);
@@ -1236,6 +1435,18 @@ pub fn draw_process_info(
         };
     }
 
+    // the script columns have a fixed width at the right, the other columns will share the rest
+    let custom_columns: &[String] = if area.width > MEDIUM_WIDTH {
+        custom_columns
+    } else {
+        &[]
+    };
+    let [columns_layout, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length((custom_columns.len() * CUSTOM_COLUMN_WIDTH) as u16),
+    ])
+    .areas(title_layout);
+
     // for each column of different info of process
     let [pid, program, user, memory, cpu_usage] = Layout::horizontal([
         // Constraint::Ratio(15, 100),
@@ -1249,13 +1460,18 @@ pub fn draw_process_info(
         Constraint::Fill(2),
         Constraint::Fill(1),
     ])
-    .areas(title_layout);
+    .areas(columns_layout);
 
     let mut pid_width = pid.width as usize;
     let mut program_width = program.width as usize;
     let mut command_width = 0;
     let mut thread_width = 0;
+    let mut nice_width = 0;
     let mut user_width = user.width as usize;
+    let mut container_width = 0;
+    let mut start_time_width = 0;
+    let mut elapsed_width = 0;
+    let mut cpu_time_width = 0;
     let mut memory_width = memory.width as usize;
     let mut cpu_usage_width = cpu_usage.width as usize;
 
@@ -1268,14 +1484,14 @@ pub fn draw_process_info(
             Constraint::Fill(1),
             Constraint::Fill(1),
         ])
-        .areas(title_layout);
+        .areas(columns_layout);
         pid_width = pid.width as usize;
         program_width = program.width as usize;
         command_width = command.width as usize;
         user_width = user.width as usize;
         memory_width = memory.width as usize;
         cpu_usage_width = cpu_usage.width as usize;
-    } else if area.width > LARGE_WIDTH {
+    } else if area.width > LARGE_WIDTH && area.width <= X_LARGE_WIDTH {
         let [pid, program, command, thread, user, memory, cpu_usage] = Layout::horizontal([
             Constraint::Fill(1),
             Constraint::Fill(1),
@@ -1285,12 +1501,41 @@ pub fn draw_process_info(
             Constraint::Fill(1),
             Constraint::Fill(1),
         ])
-        .areas(title_layout);
+        .areas(columns_layout);
+        pid_width = pid.width as usize;
+        program_width = program.width as usize;
+        command_width = command.width as usize;
+        thread_width = thread.width as usize;
+        user_width = user.width as usize;
+        memory_width = memory.width as usize;
+        cpu_usage_width = cpu_usage.width as usize;
+    } else if area.width > X_LARGE_WIDTH {
+        let [pid, program, command, thread, nice, user, container, start_time, elapsed, cpu_time, memory, cpu_usage] =
+            Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ])
+            .areas(columns_layout);
         pid_width = pid.width as usize;
         program_width = program.width as usize;
         command_width = command.width as usize;
         thread_width = thread.width as usize;
+        nice_width = nice.width as usize;
         user_width = user.width as usize;
+        container_width = container.width as usize;
+        start_time_width = start_time.width as usize;
+        elapsed_width = elapsed.width as usize;
+        cpu_time_width = cpu_time.width as usize;
         memory_width = memory.width as usize;
         cpu_usage_width = cpu_usage.width as usize;
     }
@@ -1298,9 +1543,18 @@ pub fn draw_process_info(
     // Pad the string to take up respective width
     let pid_title = String::from("Pid: ");
     let program_title = String::from("Program: ");
-    let command_title = String::from("Command: ");
+    let command_title = if process_command_scroll_offset > 0 {
+        format!("Command: (+{}) ", process_command_scroll_offset)
+    } else {
+        String::from("Command: ")
+    };
     let thread_title = String::from("Threads: ");
+    let nice_title = String::from("Nice: ");
     let user_title = String::from("User: ");
+    let container_title = String::from("Container: ");
+    let start_time_title = String::from("Start: ");
+    let elapsed_title = String::from("Elapsed: ");
+    let cpu_time_title = String::from("CPU Time: ");
     let memory_title = String::from("Mem: ");
     let cpu_usage_title = String::from("Cpu%: ");
 
@@ -1334,12 +1588,54 @@ pub fn draw_process_info(
         thread_title.chars().take(thread_width).collect::<String>()
     };
 
+    let padded_nice_title = if nice_title.len() < nice_width {
+        format!("{:width$}", nice_title, width = nice_width)
+    } else {
+        nice_title.chars().take(nice_width).collect::<String>()
+    };
+
     let padded_user_title = if user_title.len() < user_width {
         format!("{:width$}", user_title, width = user_width)
     } else {
         user_title.chars().take(user_width).collect::<String>()
     };
 
+    let padded_container_title = if container_title.len() < container_width {
+        format!("{:width$}", container_title, width = container_width)
+    } else {
+        container_title
+            .chars()
+            .take(container_width)
+            .collect::<String>()
+    };
+
+    let padded_start_time_title = if start_time_title.len() < start_time_width {
+        format!("{:width$}", start_time_title, width = start_time_width)
+    } else {
+        start_time_title
+            .chars()
+            .take(start_time_width)
+            .collect::<String>()
+    };
+
+    let padded_elapsed_title = if elapsed_title.len() < elapsed_width {
+        format!("{:width$}", elapsed_title, width = elapsed_width)
+    } else {
+        elapsed_title
+            .chars()
+            .take(elapsed_width)
+            .collect::<String>()
+    };
+
+    let padded_cpu_time_title = if cpu_time_title.len() < cpu_time_width {
+        format!("{:width$}", cpu_time_title, width = cpu_time_width)
+    } else {
+        cpu_time_title
+            .chars()
+            .take(cpu_time_width)
+            .collect::<String>()
+    };
+
     let padded_memory_title = if memory_title.len() < memory_width {
         format!("{:width$}", memory_title, width = memory_width)
     } else {
@@ -1355,61 +1651,76 @@ pub fn draw_process_info(
             .collect::<String>()
     };
 
-    let process_title = Line::from(vec![
-        Span::styled(
-            padded_pid_title,
+    // the header of the current sort was highlighted, and the area of each sortable header was kept
+    // so the list can be sorted by clicking on it
+    let padded_titles = vec![
+        (padded_pid_title, Some(ProcessSortType::Pid)),
+        (padded_program_title, Some(ProcessSortType::Name)),
+        (padded_command_title, Some(ProcessSortType::Command)),
+        (padded_thread_title, Some(ProcessSortType::Thread)),
+        (padded_nice_title, Some(ProcessSortType::Nice)),
+        (padded_user_title, Some(ProcessSortType::User)),
+        (padded_container_title, None),
+        (padded_start_time_title, Some(ProcessSortType::StartTime)),
+        (padded_elapsed_title, Some(ProcessSortType::Elapsed)),
+        (padded_cpu_time_title, Some(ProcessSortType::CpuTime)),
+        (padded_memory_title, Some(ProcessSortType::Memory)),
+        (padded_cpu_usage_title, Some(ProcessSortType::Cpu)),
+    ];
+    let mut process_title = Line::default();
+    let mut header_x = columns_layout.x;
+    for (padded_title, sort_type) in padded_titles {
+        let header_width = padded_title.chars().count() as u16;
+        let header_style = if sort_type.as_ref() == Some(process_sort_type) {
             Style::default()
-                .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_program_title,
-            Style::default()
-                .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_command_title,
-            Style::default()
-                .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_thread_title,
-            Style::default()
-                .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_user_title,
-            Style::default()
-                .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_memory_title,
+                .fg(app_color_info.key_text_color)
+                .bold()
+                .underlined()
+        } else {
             Style::default()
                 .fg(app_color_info.process_title_color)
-                .bold(),
-        ),
-        Span::styled(
-            padded_cpu_usage_title,
+                .bold()
+        };
+        process_title.push_span(Span::styled(padded_title, header_style));
+        if let Some(sort_type) = sort_type {
+            if header_width > 0 {
+                process_header_columns.push((
+                    Rect::new(header_x, title_layout.y, header_width, 1),
+                    sort_type,
+                ));
+            }
+        }
+        header_x += header_width;
+    }
+    for custom_column in custom_columns {
+        process_title.push_span(Span::styled(
+            format!(
+                "{:width$}",
+                format!("{}: ", custom_column)
+                    .chars()
+                    .take(CUSTOM_COLUMN_WIDTH)
+                    .collect::<String>(),
+                width = CUSTOM_COLUMN_WIDTH
+            ),
             Style::default()
                 .fg(app_color_info.process_title_color)
                 .bold(),
-        ),
-    ]);
+        ));
+    }
 
     frame.render_widget(process_title, title_layout);
 
     let sorted_process = sort_process(
         process_sort_type.clone(),
         process_sort_is_reversed,
-        process_filter_without_underscore_extension,
+        process_filter_input,
+        process_user_filter,
         process_data,
     );
 
-    *process_current_list = sorted_process.clone();
+    *process_current_list = sorted_process.iter().map(|process| process.pid).collect();
+
+    let now = Local::now();
 
     let process_list: Vec<ListItem> = sorted_process
         .iter()
@@ -1418,24 +1729,64 @@ pub fn draw_process_info(
             let pid = format!("{}", value.pid);
             let program = value.name.clone();
             let command = if value.cmd.len() > 0 {
-                value.cmd.join(" ")
+                redact_command(&value.cmd, " ", is_redacted)
             } else {
                 value.name.clone()
             };
-            #[cfg(target_os = "windows")]
-            // due to unoptimized way of getting thread count on window platform which hurt performence,
-            // will not support this till a solution is found
-            let thread = "?".to_string();
-
-            #[cfg(any(target_os = "macos", target_os = "linux"))]
-            let thread = value.thread_count.to_string();
+            // the match indices were from the untruncated text, so it will be shifted by the scroll offset when highlighting
+            let match_indices = |text: &str| -> Vec<usize> {
+                match process_filter_matcher.as_ref() {
+                    Some(matcher) => matcher.find_match_indices(text).unwrap_or_default(),
+                    None => vec![],
+                }
+            };
+            let program_match_indices = match_indices(&program);
+            let command_match_indices = match_indices(&command);
+            // skip the scrolled characters, so the part that is truncated can be seen
+            let command: String = command
+                .chars()
+                .skip(process_command_scroll_offset)
+                .collect();
+            // a process always has a thread, 0 means it wasn't collected yet ( eg: a new process on Windows )
+            let thread = match value.thread_count {
+                0 => "-".to_string(),
+                thread_count => thread_count.to_string(),
+            };
+            let nice = match value.nice {
+                Some(nice) => nice.to_string(),
+                None => "-".to_string(),
+            };
 
-            let user = value.user.clone();
-            let memory = process_to_kib_mib_gib(value.memory[value.memory.len() - 1]);
+            let user = redact_user(&value.user, is_redacted);
+            let user_match_indices = match_indices(&user);
+            let container = value.get_container_name();
+            let container_match_indices = match_indices(&container);
+            let start_time = format_start_time(&get_start_time(value.elapsed, &now), &now);
+            let elapsed = format_duration(value.elapsed);
+            let cpu_time = format_duration(value.cpu_time / 1000);
+            let memory = format_memory(
+                value.memory[value.memory.len() - 1],
+                total_memory,
+                memory_unit,
+            );
+            // flag the process that is heavily throttled by its cgroup cpu quota with "!"
+            let is_heavily_throttled = value.is_heavily_throttled();
             let cpu_usage = format!(
-                "{:.2}%",
-                round_to_2_decimal(value.cpu_usage[value.cpu_usage.len() - 1])
+                "{:.2}%{}",
+                round_to_2_decimal(value.cpu_usage[value.cpu_usage.len() - 1]),
+                if is_heavily_throttled { "!" } else { "" }
             );
+            // the pid and the program were colored by the state, the usage cells were tinted by their value
+            let process_heat = app_color_info.get_process_heat();
+            let state_color = process_heat.get_state_color(&value.status);
+            let cpu_usage_color =
+                process_heat.get_usage_color(value.cpu_usage[value.cpu_usage.len() - 1] as f64);
+            let memory_color = if total_memory > 0.0 {
+                process_heat
+                    .get_usage_color(value.memory[value.memory.len() - 1] / total_memory * 100.0)
+            } else {
+                None
+            };
 
             let padded_pid = if pid.len() < pid_width {
                 format!("{:width$}", pid, width = pid_width)
@@ -1465,6 +1816,12 @@ pub fn draw_process_info(
                 thread.chars().take(thread_width).collect::<String>()
             };
 
+            let padded_nice = if nice.len() < nice_width {
+                format!("{:width$}", nice, width = nice_width)
+            } else {
+                nice.chars().take(nice_width).collect::<String>()
+            };
+
             let padded_user = if user.len() < user_width {
                 format!("{:width$}", user, width = user_width)
             } else {
@@ -1473,6 +1830,38 @@ pub fn draw_process_info(
                 user
             };
 
+            let padded_container = if container.len() < container_width {
+                format!("{:width$}", container, width = container_width)
+            } else {
+                let mut container = container
+                    .chars()
+                    .take(container_width.saturating_sub(2))
+                    .collect::<String>();
+                container.push_str("  ");
+                container
+            };
+
+            let padded_start_time = if start_time.len() < start_time_width {
+                format!("{:width$}", start_time, width = start_time_width)
+            } else {
+                start_time
+                    .chars()
+                    .take(start_time_width)
+                    .collect::<String>()
+            };
+
+            let padded_elapsed = if elapsed.len() < elapsed_width {
+                format!("{:width$}", elapsed, width = elapsed_width)
+            } else {
+                elapsed.chars().take(elapsed_width).collect::<String>()
+            };
+
+            let padded_cpu_time = if cpu_time.len() < cpu_time_width {
+                format!("{:width$}", cpu_time, width = cpu_time_width)
+            } else {
+                cpu_time.chars().take(cpu_time_width).collect::<String>()
+            };
+
             let padded_memory = if memory.len() < memory_width {
                 format!("{:width$}", memory, width = memory_width)
             } else {
@@ -1485,55 +1874,110 @@ pub fn draw_process_info(
                 cpu_usage.chars().take(cpu_usage_width).collect::<String>()
             };
 
-            let mut process_inline_content_vec = vec![
-                Span::styled(
-                    padded_pid,
+            // the characters that matched the process filter will be highlighted
+            let highlight_style = Style::default()
+                .fg(app_color_info.key_text_color)
+                .bold()
+                .underlined();
+
+            let mut process_inline_content_vec = vec![Span::styled(
+                padded_pid,
+                Style::default().fg(state_color.unwrap_or(app_color_info.base_app_text_color)),
+            )];
+            process_inline_content_vec.extend(get_highlighted_spans(
+                padded_program,
+                &program_match_indices,
+                0,
+                Style::default().fg(state_color.unwrap_or(app_color_info.process_text_color)),
+                highlight_style,
+            ));
+            if area.width > MEDIUM_WIDTH {
+                process_inline_content_vec.extend(get_highlighted_spans(
+                    padded_command,
+                    &command_match_indices,
+                    process_command_scroll_offset,
                     Style::default().fg(app_color_info.base_app_text_color),
-                ),
-                Span::styled(
-                    padded_program,
+                    highlight_style,
+                ));
+            }
+            if area.width > LARGE_WIDTH {
+                process_inline_content_vec.push(Span::styled(
+                    padded_thread,
                     Style::default().fg(app_color_info.process_text_color),
-                ),
-                Span::styled(
-                    padded_user,
+                ));
+            }
+            if area.width > X_LARGE_WIDTH {
+                process_inline_content_vec.push(Span::styled(
+                    padded_nice,
                     Style::default().fg(app_color_info.base_app_text_color),
-                ),
-                Span::styled(
-                    padded_memory,
+                ));
+            }
+            process_inline_content_vec.extend(get_highlighted_spans(
+                padded_user,
+                &user_match_indices,
+                0,
+                Style::default().fg(app_color_info.base_app_text_color),
+                highlight_style,
+            ));
+            if area.width > X_LARGE_WIDTH {
+                process_inline_content_vec.extend(get_highlighted_spans(
+                    padded_container,
+                    &container_match_indices,
+                    0,
                     Style::default().fg(app_color_info.process_text_color),
-                ),
-                Span::styled(
-                    padded_cpu_usage,
+                    highlight_style,
+                ));
+                process_inline_content_vec.push(Span::styled(
+                    padded_start_time,
+                    Style::default().fg(app_color_info.process_text_color),
+                ));
+                process_inline_content_vec.push(Span::styled(
+                    padded_elapsed,
                     Style::default().fg(app_color_info.base_app_text_color),
-                ),
-            ];
-            if area.width > MEDIUM_WIDTH && area.width <= LARGE_WIDTH {
-                process_inline_content_vec.insert(
-                    2,
-                    Span::styled(
-                        padded_command,
-                        Style::default().fg(app_color_info.base_app_text_color),
-                    ),
-                );
-            } else if area.width > LARGE_WIDTH {
-                process_inline_content_vec.insert(
-                    2,
-                    Span::styled(
-                        padded_command,
-                        Style::default().fg(app_color_info.base_app_text_color),
-                    ),
-                );
-                process_inline_content_vec.insert(
-                    3,
-                    Span::styled(
-                        padded_thread,
-                        Style::default().fg(app_color_info.process_text_color),
+                ));
+                process_inline_content_vec.push(Span::styled(
+                    padded_cpu_time,
+                    Style::default().fg(app_color_info.process_text_color),
+                ));
+            }
+            process_inline_content_vec.push(Span::styled(
+                padded_memory,
+                Style::default().fg(memory_color.unwrap_or(app_color_info.process_text_color)),
+            ));
+            process_inline_content_vec.push(Span::styled(
+                padded_cpu_usage,
+                if is_heavily_throttled {
+                    Style::default().fg(app_color_info.key_text_color).bold()
+                } else {
+                    Style::default()
+                        .fg(cpu_usage_color.unwrap_or(app_color_info.base_app_text_color))
+                },
+            ));
+            let values = custom_column_values.get(&value.pid.to_string());
+            for index in 0..custom_columns.len() {
+                let custom_value = values
+                    .and_then(|values| values.get(index))
+                    .map(|custom_value| custom_value.as_str())
+                    .unwrap_or("");
+                process_inline_content_vec.push(Span::styled(
+                    format!(
+                        "{:width$}",
+                        custom_value
+                            .chars()
+                            .take(CUSTOM_COLUMN_WIDTH - 1)
+                            .collect::<String>(),
+                        width = CUSTOM_COLUMN_WIDTH
                     ),
-                );
+                    Style::default().fg(app_color_info.process_text_color),
+                ));
             }
 
             let process = Line::from(process_inline_content_vec);
 
+            // in presentation mode, each process will be followed by an empty line for larger spacing
+            if is_presentation_mode {
+                return ListItem::new(vec![process, Line::from("")]);
+            }
             ListItem::new(process)
         })
         .collect();
@@ -1554,3 +1998,407 @@ pub fn draw_process_info(
         process_selected_state,
     );
 }
+
+// the threads of the process in the process detail, the busiest threads first
+// only the threads that fit in the area were shown
+fn draw_process_thread_list(
+    threads: &[ThreadData],
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let [title_layout, thread_list_layout] =
+        Layout::vertical(vec![Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+    let title_style = Style::default()
+        .fg(app_color_info.process_title_color)
+        .bold();
+    let text_style = Style::default().fg(app_color_info.base_app_text_color);
+
+    if threads.is_empty() {
+        frame.render_widget(
+            Line::from(Span::styled("No thread info available", text_style)),
+            title_layout,
+        );
+        return;
+    }
+
+    // the name takes the remaining width
+    let tid_width: usize = 12;
+    let state_width: usize = 12;
+    let cpu_usage_width: usize = 8;
+    let name_width =
+        (area.width as usize).saturating_sub(tid_width + state_width + cpu_usage_width);
+    let get_columns = |tid: String, name: String, state: String, cpu_usage: String| -> String {
+        return format!(
+            "{:<tid_width$}{:<name_width$}{:<state_width$}{:>cpu_usage_width$}",
+            tid.chars().take(tid_width - 1).collect::<String>(),
+            name.chars()
+                .take(name_width.saturating_sub(1))
+                .collect::<String>(),
+            state,
+            cpu_usage,
+            tid_width = tid_width,
+            name_width = name_width,
+            state_width = state_width,
+            cpu_usage_width = cpu_usage_width,
+        );
+    };
+
+    frame.render_widget(
+        Line::from(Span::styled(
+            get_columns(
+                "TID:".to_string(),
+                format!("Name ( {} threads ):", threads.len()),
+                "State:".to_string(),
+                "CPU:".to_string(),
+            ),
+            title_style,
+        )),
+        title_layout,
+    );
+
+    let mut sorted_threads: Vec<&ThreadData> = threads.iter().collect();
+    sorted_threads.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage).then(a.tid.cmp(&b.tid)));
+    let thread_lines: Vec<ListItem> = sorted_threads
+        .into_iter()
+        .take(thread_list_layout.height as usize)
+        .map(|thread| {
+            ListItem::new(Line::from(Span::styled(
+                get_columns(
+                    thread.tid.to_string(),
+                    thread.name.clone(),
+                    thread.state.clone(),
+                    format!("{:.1}%", thread.cpu_usage),
+                ),
+                text_style,
+            )))
+        })
+        .collect();
+    frame.render_widget(List::new(thread_lines), thread_list_layout);
+}
+
+// split the displayed text into spans, the characters matched by the process filter will be highlighted
+// index_offset is the number of characters skipped from the original text ( eg: the scrolled command )
+fn get_highlighted_spans(
+    text: String,
+    match_indices: &[usize],
+    index_offset: usize,
+    style: Style,
+    highlight_style: Style,
+) -> Vec<Span<'static>> {
+    if match_indices.is_empty() {
+        return vec![Span::styled(text, style)];
+    }
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut current_text = String::new();
+    let mut is_current_highlighted = false;
+    for (index, c) in text.chars().enumerate() {
+        // the padding spaces will not be highlighted
+        let is_highlighted = !c.is_whitespace() && match_indices.contains(&(index + index_offset));
+        if is_highlighted != is_current_highlighted && !current_text.is_empty() {
+            spans.push(Span::styled(
+                current_text.clone(),
+                if is_current_highlighted {
+                    highlight_style
+                } else {
+                    style
+                },
+            ));
+            current_text.clear();
+        }
+        is_current_highlighted = is_highlighted;
+        current_text.push(c);
+    }
+    if !current_text.is_empty() {
+        spans.push(Span::styled(
+            current_text,
+            if is_current_highlighted {
+                highlight_style
+            } else {
+                style
+            },
+        ));
+    }
+    return spans;
+}
+
+// build the additional info lines for the process detail view, each line is a list of title and value
+// the top 3 processes by the current cpu usage and by the current memory usage
+// eg: "Top CPU: spinner 99.00%  bash 1.20%  vim 0.50%   Top Mem: firefox 1.2 GiB  ..."
+fn get_top_consumers_line(
+    process_data: &HashMap<String, ProcessData>,
+    total_memory: f64,
+    memory_unit: &MemoryUnit,
+    app_color_info: &AppColorInfo,
+) -> Line<'static> {
+    let mut processes: Vec<&ProcessData> = process_data.values().collect();
+    let title_style = Style::default()
+        .fg(app_color_info.process_title_color)
+        .bold();
+    let name_style = Style::default().fg(app_color_info.process_text_color);
+    let value_style = Style::default().fg(app_color_info.base_app_text_color);
+
+    let mut spans = vec![Span::styled("Top CPU: ", title_style)];
+    processes.sort_by(|a, b| {
+        let a_cpu_usage = a.cpu_usage.last().copied().unwrap_or(0.0);
+        let b_cpu_usage = b.cpu_usage.last().copied().unwrap_or(0.0);
+        b_cpu_usage
+            .partial_cmp(&a_cpu_usage)
+            .unwrap_or(Ordering::Equal)
+            .then(a.pid.cmp(&b.pid))
+    });
+    for process in processes.iter().take(TOP_CONSUMER_COUNT) {
+        spans.push(Span::styled(
+            format!("{} ", truncate_name(&process.name)),
+            name_style,
+        ));
+        spans.push(Span::styled(
+            format!(
+                "{:.2}%  ",
+                round_to_2_decimal(process.cpu_usage.last().copied().unwrap_or(0.0))
+            ),
+            value_style,
+        ));
+    }
+
+    spans.push(Span::styled(" Top Mem: ", title_style));
+    processes.sort_by(|a, b| {
+        let a_memory = a.memory.last().copied().unwrap_or(0.0);
+        let b_memory = b.memory.last().copied().unwrap_or(0.0);
+        b_memory
+            .partial_cmp(&a_memory)
+            .unwrap_or(Ordering::Equal)
+            .then(a.pid.cmp(&b.pid))
+    });
+    for process in processes.iter().take(TOP_CONSUMER_COUNT) {
+        spans.push(Span::styled(
+            format!("{} ", truncate_name(&process.name)),
+            name_style,
+        ));
+        spans.push(Span::styled(
+            format!(
+                "{}  ",
+                format_memory(
+                    process.memory.last().copied().unwrap_or(0.0),
+                    total_memory,
+                    memory_unit
+                )
+            ),
+            value_style,
+        ));
+    }
+    return Line::from(spans);
+}
+
+// the stopped and zombie processes were only counted in the summary when there is any of them,
+// the process that was killed while its detail was showing wasn't counted
+fn get_process_state_summary(process_data: &HashMap<String, ProcessData>) -> String {
+    let mut total_count = 0;
+    let mut running_count = 0;
+    let mut stopped_count = 0;
+    let mut zombie_count = 0;
+    for process in process_data.values() {
+        let status = process.status.to_lowercase();
+        if status == "killed" {
+            continue;
+        }
+        total_count += 1;
+        if status.starts_with("run") {
+            running_count += 1;
+        } else if status.starts_with("stop") {
+            stopped_count += 1;
+        } else if status.starts_with("zombie") {
+            zombie_count += 1;
+        }
+    }
+
+    let mut summary = format!("{} total, {} running", total_count, running_count);
+    if stopped_count > 0 {
+        summary.push_str(&format!(", {} stopped", stopped_count));
+    }
+    if zombie_count > 0 {
+        summary.push_str(&format!(", {} zombie", zombie_count));
+    }
+    return summary;
+}
+
+// the long process name was cut so the strip can fit more of the top consumers
+fn truncate_name(name: &str) -> String {
+    if name.chars().count() > TOP_CONSUMER_NAME_WIDTH {
+        let mut truncated_name: String = name.chars().take(TOP_CONSUMER_NAME_WIDTH - 1).collect();
+        truncated_name.push('…');
+        return truncated_name;
+    }
+    return name.to_string();
+}
+
+fn get_process_additional_info_lines(
+    process: &ProcessData,
+    total_memory: f64,
+    memory_unit: &MemoryUnit,
+    app_color_info: &AppColorInfo,
+) -> Vec<Line<'static>> {
+    let mut additional_info: Vec<Vec<(String, String)>> = Vec::new();
+
+    // the resident memory was already shown in the memory usage graph
+    additional_info.push(vec![
+        (
+            "Virtual Memory:".to_string(),
+            format_memory(process.virtual_memory, total_memory, memory_unit),
+        ),
+        (
+            "Shared Memory:".to_string(),
+            match process.shared_memory {
+                Some(shared_memory) => format_memory(shared_memory, total_memory, memory_unit),
+                None => "N/A".to_string(),
+            },
+        ),
+    ]);
+
+    // the average cpu usage over the lifetime of the process, to tell a long running hog from a short spike
+    let average_cpu_usage = if process.elapsed > 0 {
+        process.cpu_time as f64 / 1000.0 / process.elapsed as f64 * 100.0
+    } else {
+        0.0
+    };
+    additional_info.push(vec![
+        (
+            "CPU Time:".to_string(),
+            format_duration(process.cpu_time / 1000),
+        ),
+        (
+            "Average CPU:".to_string(),
+            format!("{:.2}%", average_cpu_usage),
+        ),
+    ]);
+
+    if let Some((oom_score, oom_score_adj)) = process.oom_score {
+        additional_info.push(vec![
+            ("OOM Score:".to_string(), format!("{}", oom_score)),
+            ("OOM Score Adj:".to_string(), format!("{}", oom_score_adj)),
+        ]);
+    }
+
+    // delay accounting ( waiting for CPU / waiting for IO ) was only available on Linux
+    if let Some(delay_info) = process.delay_info.as_ref() {
+        let current_cpu_wait_time = match process.current_delay_info.as_ref() {
+            Some(current_delay_info) => {
+                format!(
+                    " (+{})",
+                    format_nanoseconds(current_delay_info.cpu_wait_time)
+                )
+            }
+            None => "".to_string(),
+        };
+        let current_io_wait_time = match process.current_delay_info.as_ref() {
+            Some(current_delay_info) => match current_delay_info.io_wait_time {
+                Some(io_wait_time) => format!(" (+{})", format_nanoseconds(io_wait_time)),
+                None => "".to_string(),
+            },
+            None => "".to_string(),
+        };
+        let io_wait_time = match delay_info.io_wait_time {
+            Some(io_wait_time) => format_nanoseconds(io_wait_time),
+            None => "N/A".to_string(),
+        };
+
+        additional_info.push(vec![
+            (
+                "Waiting for CPU:".to_string(),
+                format!(
+                    "{}{}",
+                    format_nanoseconds(delay_info.cpu_wait_time),
+                    current_cpu_wait_time
+                ),
+            ),
+            (
+                "Waiting for IO:".to_string(),
+                format!("{}{}", io_wait_time, current_io_wait_time),
+            ),
+        ]);
+    }
+
+    // cpu throttling of the cgroup, the percentage is the throttled periods since the last refresh
+    if let Some(cpu_throttle_info) = process.cpu_throttle_info.as_ref() {
+        let current_throttled = match process.current_cpu_throttle_info.as_ref() {
+            Some(current_cpu_throttle_info) if current_cpu_throttle_info.nr_periods > 0 => format!(
+                " ({:.2}% of periods)",
+                current_cpu_throttle_info.nr_throttled as f64
+                    / current_cpu_throttle_info.nr_periods as f64
+                    * 100.0
+            ),
+            _ => "".to_string(),
+        };
+
+        additional_info.push(vec![
+            (
+                if process.is_heavily_throttled() {
+                    "CPU Throttled (!):".to_string()
+                } else {
+                    "CPU Throttled:".to_string()
+                },
+                format!(
+                    "{}/{} periods{}",
+                    cpu_throttle_info.nr_throttled, cpu_throttle_info.nr_periods, current_throttled
+                ),
+            ),
+            (
+                "Throttled Time:".to_string(),
+                format_nanoseconds(cpu_throttle_info.throttled_time),
+            ),
+        ]);
+    }
+
+    // container metadata, the container id will be shorten to 12 characters like `docker ps`
+    if let Some(container_info) = process.container_info.as_ref() {
+        additional_info.push(vec![
+            (
+                "Container:".to_string(),
+                format!(
+                    "{} ({})",
+                    container_info.id.get(..12).unwrap_or(&container_info.id),
+                    container_info.runtime
+                ),
+            ),
+            (
+                "Name:".to_string(),
+                container_info.name.clone().unwrap_or("-".to_string()),
+            ),
+            (
+                "Image:".to_string(),
+                container_info.image.clone().unwrap_or("-".to_string()),
+            ),
+            (
+                "Restarts:".to_string(),
+                match container_info.restart_count {
+                    Some(restart_count) => restart_count.to_string(),
+                    None => "-".to_string(),
+                },
+            ),
+        ]);
+    }
+
+    return additional_info
+        .into_iter()
+        .map(|items| {
+            let mut spans = Vec::new();
+            for (i, (title, value)) in items.into_iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw("    "));
+                }
+                spans.push(Span::styled(
+                    format!("{} ", title),
+                    Style::default()
+                        .fg(app_color_info.process_title_color)
+                        .bold(),
+                ));
+                spans.push(Span::styled(
+                    value,
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ));
+            }
+            Line::from(spans).centered()
+        })
+        .collect();
+}
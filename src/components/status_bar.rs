@@ -0,0 +1,33 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    Frame,
+};
+
+use crate::types::AppColorInfo;
+
+// the one line footer with the key hints of the current context, the hints that don't fit will be cut off
+pub fn draw_status_bar(
+    hints: &[(&str, &str)], // ( key, description )
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let mut status_bar = Line::default();
+    for (key, description) in hints {
+        status_bar.push_span(
+            Span::styled(
+                format!(" {}", key),
+                Style::default().fg(app_color_info.key_text_color),
+            )
+            .bold(),
+        );
+        status_bar.push_span(Span::styled(
+            format!(" {} ", description),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ));
+    }
+
+    frame.render_widget(status_bar, area);
+}
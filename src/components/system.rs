@@ -0,0 +1,146 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, StaticSystemInfo, SystemData},
+    utils::{format_duration, REDACTED_PLACEHOLDER},
+};
+
+// the slim header on top of the app showing which machine is being monitored
+pub fn draw_system_info_header(
+    static_info: &StaticSystemInfo,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+    is_redacted: bool,    // to hide the host name
+    is_paused: bool,      // to indicate the data updates were paused with space
+    tab_names: &[String], // the tabs will only be shown on the left when there is more than one tab
+    selected_tab: usize,
+) {
+    let separator = Span::styled(" │ ", Style::default().fg(app_color_info.key_text_color));
+
+    let host_name = if is_redacted {
+        REDACTED_PLACEHOLDER.to_string()
+    } else {
+        static_info.host_name.clone()
+    };
+
+    let mut header = Line::from(vec![
+        Span::styled(
+            host_name,
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        separator.clone(),
+        Span::styled(
+            static_info.os_name.clone(),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        separator.clone(),
+        Span::styled(
+            format!("kernel {}", static_info.kernel_version),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        separator.clone(),
+        Span::styled(
+            static_info.architecture.clone(),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+
+    if is_paused {
+        header.push_span(separator);
+        header.push_span(
+            Span::styled("PAUSED", Style::default().fg(app_color_info.key_text_color)).bold(),
+        );
+    }
+
+    if tab_names.len() > 1 {
+        let mut tabs = Line::default();
+        for (i, tab_name) in tab_names.iter().enumerate() {
+            let tab_style = if i == selected_tab {
+                Style::default()
+                    .fg(app_color_info.app_title_color)
+                    .bold()
+                    .reversed()
+            } else {
+                Style::default().fg(app_color_info.base_app_text_color)
+            };
+            tabs.push_span(Span::styled(
+                format!("{}", i + 1),
+                Style::default().fg(app_color_info.key_text_color),
+            ));
+            tabs.push_span(Span::styled(format!(" {} ", tab_name), tab_style));
+            tabs.push_span(Span::raw(" "));
+        }
+        let [tabs_area, header_area] =
+            Layout::horizontal([Constraint::Length(tabs.width() as u16), Constraint::Fill(1)])
+                .areas(area);
+        frame.render_widget(tabs, tabs_area);
+        frame.render_widget(
+            Paragraph::new(header).alignment(Alignment::Center),
+            header_area,
+        );
+        return;
+    }
+
+    frame.render_widget(Paragraph::new(header).alignment(Alignment::Center), area);
+}
+
+// the summary line like the first line of top, eg: "14:03:22 up 2d 3h │ 2 users │ load average: 1.25, 0.50, 0.25"
+pub fn draw_system_summary_line(
+    system: &SystemData,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let separator = Span::styled(" │ ", Style::default().fg(app_color_info.key_text_color));
+
+    let mut summary_line = Line::from(vec![
+        Span::styled(
+            Local::now().format("%H:%M:%S").to_string(),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        Span::styled(
+            format!(" up {}", format_duration(system.uptime)),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+
+    if let Some(logged_in_users) = system.logged_in_users {
+        summary_line.push_span(separator.clone());
+        summary_line.push_span(Span::styled(
+            format!(
+                "{} {}",
+                logged_in_users,
+                if logged_in_users == 1 {
+                    "user"
+                } else {
+                    "users"
+                }
+            ),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ));
+    }
+
+    summary_line.push_span(separator);
+    summary_line.push_span(Span::styled(
+        format!(
+            "load average: {:.2}, {:.2}, {:.2}",
+            system.load_average_one, system.load_average_five, system.load_average_fifteen
+        ),
+        Style::default().fg(app_color_info.base_app_text_color),
+    ));
+
+    frame.render_widget(
+        Paragraph::new(summary_line).alignment(Alignment::Center),
+        area,
+    );
+}
@@ -1,4 +1,13 @@
-use std::fs::{File, OpenOptions};
+use std::{
+    env,
+    fs::{self, metadata, File},
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::SystemTime,
+};
+
+use log::error;
 
 use crate::{
     components::themes::{
@@ -22,80 +31,664 @@ use crate::{
         solarized::{SOLARIZED_DARK, SOLARIZED_LIGHT},
         tokyo::{TOKYO_NIGHT, TOKYO_STORM},
     },
-    types::{AppColorInfo, ThemeConfig},
+    types::{
+        AppColorInfo, ContainerLayout, DiskFilter, DiskFreeThresholds, GraphStyle, LayoutTab,
+        MemoryUnit, NetworkFilter, NetworkUnit, ScriptConfig, SelectedContainer, ThemeAppearance,
+        ThemeConfig,
+    },
     utils::{create_file_with_dirs, get_user_directory},
 };
 
-pub fn get_and_return_app_color_info() -> AppColorInfo {
-    let theme_config_filepath = get_user_directory().join(".rtop/settings.json");
-    if !theme_config_filepath.exists() {
-        let theme_config = ThemeConfig {
-            theme: "default".to_string(),
-        };
+// the settings, the user created themes and the plugins were kept in this directory of the home directory
+const CONFIG_DIRECTORY: &str = ".rtop";
+const SETTINGS_FILE: &str = "settings.json";
+// user created themes are saved as json files of AppColorInfo in this directory of the config directory
+const CUSTOM_THEME_DIRECTORY: &str = "themes";
+
+// the theme that will follow the terminal background, see `resolve_auto_theme`
+pub const AUTO_THEME: &str = "auto";
+// the theme that will follow the OS light/dark preference, see `resolve_system_theme`
+pub const SYSTEM_THEME: &str = "system";
+const DEFAULT_LIGHT_THEME: &str = "paper";
+const DEFAULT_DARK_THEME: &str = "default";
+
+// the refresh tick in ms, adjustable with - and + within the range
+const DEFAULT_REFRESH_TICK: u32 = 1000;
+pub const MIN_REFRESH_TICK: u32 = 100;
+pub const MAX_REFRESH_TICK: u32 = 10000;
+
+// the terminal background is only detected once before the app take over the terminal
+static TERMINAL_BACKGROUND: OnceLock<ThemeAppearance> = OnceLock::new();
+
+// all the available themes, the order here will be the order shown in the theme selection
+pub const THEMES: [&str; 28] = [
+    AUTO_THEME,
+    SYSTEM_THEME,
+    "default",
+    "dracula",
+    "gruvbox_dark",
+    "gruvbox_light",
+    "gruvbox_mat_dark",
+    "ayu",
+    "everforest_dark",
+    "everforest_light",
+    "flatremix",
+    "flatremix_light",
+    "grayscale",
+    "horizon",
+    "kanagawa_wave",
+    "kanagawa_lotus",
+    "monokai",
+    "onedark",
+    "nightowl",
+    "rosepine",
+    "matcha_dark_sea",
+    "paper",
+    "solarized_dark",
+    "solarized_light",
+    "tokyo_night",
+    "tokyo_storm",
+    "catppuccin_mocha",
+    "github_dark",
+];
 
-        create_file_with_dirs(theme_config_filepath.to_str().unwrap());
-        let file = OpenOptions::new()
-            .write(true)
-            .create(true) // Create the file if it doesn't exist
-            .truncate(true) // Truncate the file to ensure it's empty before writing
-            .open(&theme_config_filepath)
-            .unwrap();
+// the directory of the settings file, the user created themes and the plugins
+// None when the home directory is not set, the settings won't be saved then
+pub fn get_config_directory() -> Option<PathBuf> {
+    get_user_directory().map(|user_directory| user_directory.join(CONFIG_DIRECTORY))
+}
 
-        // write the data into the json file
-        let _ = serde_json::to_writer(file, &theme_config);
+// read the settings file, it will be created with the auto theme if it doesn't exist yet
+// the auto theme will only be kept in memory if the file can't be created or there is no config directory
+// an error is returned if the existing file can't be read or parsed, so it won't be overwritten by the defaults
+pub fn read_theme_config(config_directory: Option<&Path>) -> io::Result<ThemeConfig> {
+    let Some(config_directory) = config_directory else {
+        return Ok(ThemeConfig::new(AUTO_THEME));
+    };
+    let theme_config_filepath = config_directory.join(SETTINGS_FILE);
+    if !theme_config_filepath.exists() {
+        let theme_config = ThemeConfig::new(AUTO_THEME);
+        if let Err(e) = write_theme_config(config_directory, &theme_config) {
+            error!("Failed to create the settings file: {}", e);
+        }
 
-        return DEFAULT;
+        return Ok(theme_config);
     }
+
     // read the json file to configure the settings instead if it exist
-    let file = File::open(theme_config_filepath).unwrap();
-    let theme_config: ThemeConfig = serde_json::from_reader(file).unwrap();
+    let theme_config = File::open(&theme_config_filepath).and_then(|file| {
+        serde_json::from_reader::<File, ThemeConfig>(file).map_err(io::Error::from)
+    });
+    if let Err(e) = &theme_config {
+        error!(
+            "Failed to read the settings file {}: {}",
+            theme_config_filepath.display(),
+            e
+        );
+    }
+    theme_config
+}
+
+// read the settings file, the auto theme will be used if it can't be read, for the places that can't show the error
+pub fn read_theme_config_or_default(config_directory: Option<&Path>) -> ThemeConfig {
+    read_theme_config(config_directory).unwrap_or_else(|_| ThemeConfig::new(AUTO_THEME))
+}
+
+fn write_theme_config(config_directory: &Path, theme_config: &ThemeConfig) -> io::Result<()> {
+    let file = create_file_with_dirs(&config_directory.join(SETTINGS_FILE))?;
+
+    // write the data into the json file
+    serde_json::to_writer(file, theme_config)?;
+    Ok(())
+}
+
+// return the memory unit that is saved in the settings file, auto if it wasn't set
+pub fn get_memory_unit_config(theme_config: &ThemeConfig) -> MemoryUnit {
+    match theme_config.memory_unit.as_deref() {
+        Some(memory_unit) => MemoryUnit::from_config(memory_unit),
+        None => MemoryUnit::Auto,
+    }
+}
+
+// return the network unit that is saved in the settings file, bytes if it wasn't set
+pub fn get_network_unit_config(theme_config: &ThemeConfig) -> NetworkUnit {
+    match theme_config.network_unit.as_deref() {
+        Some(network_unit) => NetworkUnit::from_config(network_unit),
+        None => NetworkUnit::Bytes,
+    }
+}
+
+// return the ping target that is saved in the settings file, None if the ping wasn't enabled
+pub fn get_ping_target_config(theme_config: &ThemeConfig) -> Option<String> {
+    theme_config
+        .ping_target
+        .clone()
+        .filter(|ping_target| !ping_target.trim().is_empty())
+}
+
+// return true if the public IP lookup was enabled in the settings file
+pub fn get_public_ip_lookup_config(theme_config: &ThemeConfig) -> bool {
+    theme_config.public_ip_lookup.unwrap_or(false)
+}
+
+// return true if the mouse capture was enabled in the settings file
+pub fn get_mouse_capture_config(theme_config: &ThemeConfig) -> bool {
+    theme_config.mouse_capture.unwrap_or(false)
+}
+
+// return the ( system, process ) refresh tick in ms that is saved in the settings file
+// the tick was rounded to 100ms as it was adjusted by 100ms with - and +
+pub fn get_refresh_tick_config(theme_config: &ThemeConfig) -> (u32, u32) {
+    let refresh_tick = theme_config
+        .refresh_tick
+        .unwrap_or(DEFAULT_REFRESH_TICK)
+        .clamp(MIN_REFRESH_TICK, MAX_REFRESH_TICK)
+        / 100
+        * 100;
+    let process_refresh_tick = theme_config
+        .process_refresh_tick
+        .map(|process_refresh_tick| {
+            process_refresh_tick.clamp(MIN_REFRESH_TICK, MAX_REFRESH_TICK) / 100 * 100
+        })
+        .unwrap_or(refresh_tick);
+    (refresh_tick, process_refresh_tick)
+}
+
+// return the number of points kept in the graph history that is saved in the settings file
+pub fn get_max_history_config(theme_config: &ThemeConfig) -> Option<usize> {
+    theme_config.max_history
+}
+
+// return the graph style of the container that is saved in the settings file, fallback to the "*" graph style
+pub fn get_graph_style_config(theme_config: &ThemeConfig, container: &str) -> GraphStyle {
+    let graph_styles = theme_config.graph_style.clone().unwrap_or_default();
+    match graph_styles.get(container).or(graph_styles.get("*")) {
+        Some(graph_style) => GraphStyle::from_config(graph_style),
+        None => GraphStyle::BrailleBar,
+    }
+}
+
+// return if the y axis labels and grid lines of the graphs should be shown, which is saved in the settings file
+pub fn get_graph_axis_config(theme_config: &ThemeConfig) -> bool {
+    theme_config.graph_axis.unwrap_or(false)
+}
+
+// return the arrangement of the containers that is saved in the settings file, fallback to the default arrangement
+// when it doesn't contain any container
+pub fn get_layout_config(theme_config: &ThemeConfig) -> ContainerLayout {
+    match &theme_config.layout {
+        Some(layout) if !layout.get_containers().is_empty() => layout.clone(),
+        _ => ContainerLayout::default_layout(),
+    }
+}
+
+// return if the status bar with the key hints should be shown, which is saved in the settings file
+pub fn get_status_bar_config(theme_config: &ThemeConfig) -> bool {
+    theme_config.status_bar.unwrap_or(false)
+}
+
+// return if the summary line should be shown below the header, which is saved in the settings file
+pub fn get_summary_line_config(theme_config: &ThemeConfig) -> bool {
+    theme_config.summary_line.unwrap_or(false)
+}
+
+// return the custom column and alert scripts that are saved in the settings file, empty if there is none
+pub fn get_script_config(theme_config: &ThemeConfig) -> ScriptConfig {
+    theme_config.scripts.clone().unwrap_or_default()
+}
+
+// return the container to start in full screen that is saved in the settings file
+pub fn get_view_config(theme_config: &ThemeConfig) -> Option<SelectedContainer> {
+    theme_config
+        .view
+        .as_deref()
+        .and_then(SelectedContainer::from_name)
+}
+
+// the maximum number of tabs, they were switched with the number keys 1 - 9
+pub const MAX_TAB_COUNT: usize = 9;
+
+// return the tabs that is saved in the settings file, the tabs without any container will be skipped
+// the layout in the settings file will be the only tab when there isn't any tab
+pub fn get_tabs_config(theme_config: &ThemeConfig) -> Vec<LayoutTab> {
+    let tabs: Vec<LayoutTab> = theme_config
+        .tabs
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|tab| !tab.layout.get_containers().is_empty())
+        .take(MAX_TAB_COUNT)
+        .collect();
+    if tabs.is_empty() {
+        return vec![LayoutTab {
+            name: "overview".to_string(),
+            layout: get_layout_config(theme_config),
+        }];
+    }
+    tabs
+}
+
+// return the disk include / exclude patterns that is saved in the settings file
+pub fn get_disk_filter_config(theme_config: &ThemeConfig) -> DiskFilter {
+    DiskFilter::new(
+        theme_config.disk_include.as_deref().unwrap_or_default(),
+        theme_config.disk_exclude.as_deref().unwrap_or_default(),
+    )
+}
+
+// return the network interface include / exclude patterns that is saved in the settings file
+pub fn get_network_filter_config(theme_config: &ThemeConfig) -> NetworkFilter {
+    NetworkFilter::new(
+        theme_config.network_include.as_deref().unwrap_or_default(),
+        theme_config.network_exclude.as_deref().unwrap_or_default(),
+    )
+}
+
+// return the free space thresholds of the disks that is saved in the settings file
+pub fn get_disk_free_thresholds_config(theme_config: &ThemeConfig) -> DiskFreeThresholds {
+    DiskFreeThresholds::new(
+        theme_config
+            .disk_free_thresholds
+            .clone()
+            .unwrap_or_default(),
+    )
+}
+
+// the last modified time of the settings file, used to hot reload the theme when the file was changed outside of the app
+pub fn get_theme_config_modified_time(config_directory: &Path) -> Option<SystemTime> {
+    let theme_config_filepath = config_directory.join(SETTINGS_FILE);
+    metadata(theme_config_filepath)
+        .ok()
+        .and_then(|m| m.modified().ok())
+}
+
+// the user created themes will only be looked up when there is a settings directory
+// the "system" theme was resolved with the last known OS light/dark preference, as checking it can be slow
+pub fn get_app_color_info_from_theme_name(
+    theme_str: &str,
+    theme_config: &ThemeConfig,
+    config_directory: Option<&Path>,
+    system_appearance: Option<ThemeAppearance>,
+) -> AppColorInfo {
+    if theme_str == AUTO_THEME {
+        return get_app_color_info_from_theme_name(
+            &resolve_auto_theme(theme_config),
+            theme_config,
+            config_directory,
+            system_appearance,
+        );
+    }
+    if theme_str == SYSTEM_THEME {
+        return get_app_color_info_from_theme_name(
+            &resolve_system_theme(theme_config, system_appearance),
+            theme_config,
+            config_directory,
+            system_appearance,
+        );
+    }
 
-    let theme_str: &str = &theme_config.theme;
     match theme_str {
-        "default" => return DEFAULT,
-        "dracula" => return DRACULA,
-        "gruvbox_dark" => return GRUVBOX_DARK,
-        "gruvbox_light" => return GRUVBOX_LIGHT,
-        "gruvbox_mat_dark" => return GRUVBOX_MAT_DARK,
-        "ayu" => return AYU,
-        "everforest_dark" => return EVERFOREST_DARK,
-        "everforest_light" => return EVERFOREST_LIGHT,
-        "flatremix" => return FLATREMIX,
-        "flatremix_light" => return FLATREMIX_LIGHT,
-        "grayscale" => return GRAYSCALE,
-        "horizon" => return HORIZON,
-        "kanagawa_wave" => return KANAGAWA_WAVE,
-        "kanagawa_lotus" => return KANAGAWA_LOTUS,
-        "monokai" => return MONOKAI,
-        "onedark" => return ONEDARK,
-        "nightowl" => return NIGHT_OWL,
-        "rosepine" => return ROSE_PINE,
-        "matcha_dark_sea" => return MATCHA_DARK_SEA,
-        "paper" => return PAPER,
-        "solarized_dark" => return SOLARIZED_DARK,
-        "solarized_light" => return SOLARIZED_LIGHT,
-        "tokyo_night" => return TOKYO_NIGHT,
-        "tokyo_storm" => return TOKYO_STORM,
-        "catppuccin_mocha" => return CATPPUCCIN_MOCHA,
-        "github_dark" => return GITHUB_DARK,
-        _ => return DEFAULT,
-    }
-}
-
-pub fn set_theme(theme_string: String) {
-    let theme_config_filepath = get_user_directory().join(".rtop/settings.json");
-    let theme_config = ThemeConfig {
-        theme: theme_string,
+        "default" => DEFAULT,
+        "dracula" => DRACULA,
+        "gruvbox_dark" => GRUVBOX_DARK,
+        "gruvbox_light" => GRUVBOX_LIGHT,
+        "gruvbox_mat_dark" => GRUVBOX_MAT_DARK,
+        "ayu" => AYU,
+        "everforest_dark" => EVERFOREST_DARK,
+        "everforest_light" => EVERFOREST_LIGHT,
+        "flatremix" => FLATREMIX,
+        "flatremix_light" => FLATREMIX_LIGHT,
+        "grayscale" => GRAYSCALE,
+        "horizon" => HORIZON,
+        "kanagawa_wave" => KANAGAWA_WAVE,
+        "kanagawa_lotus" => KANAGAWA_LOTUS,
+        "monokai" => MONOKAI,
+        "onedark" => ONEDARK,
+        "nightowl" => NIGHT_OWL,
+        "rosepine" => ROSE_PINE,
+        "matcha_dark_sea" => MATCHA_DARK_SEA,
+        "paper" => PAPER,
+        "solarized_dark" => SOLARIZED_DARK,
+        "solarized_light" => SOLARIZED_LIGHT,
+        "tokyo_night" => TOKYO_NIGHT,
+        "tokyo_storm" => TOKYO_STORM,
+        "catppuccin_mocha" => CATPPUCCIN_MOCHA,
+        "github_dark" => GITHUB_DARK,
+        _ => config_directory
+            .and_then(|config_directory| get_custom_theme(config_directory, theme_str))
+            .unwrap_or(DEFAULT),
+    }
+}
+
+// all the built in themes followed by the user created themes
+pub fn get_all_theme_names(config_directory: Option<&Path>) -> Vec<String> {
+    let mut theme_names: Vec<String> = THEMES.iter().map(|theme| theme.to_string()).collect();
+    let Some(config_directory) = config_directory else {
+        return theme_names;
+    };
+    for custom_theme_name in get_custom_theme_names(config_directory) {
+        if !theme_names.contains(&custom_theme_name) {
+            theme_names.push(custom_theme_name);
+        }
+    }
+    theme_names
+}
+
+fn get_custom_theme_names(config_directory: &Path) -> Vec<String> {
+    let custom_theme_directory = config_directory.join(CUSTOM_THEME_DIRECTORY);
+    let mut custom_theme_names: Vec<String> = match fs::read_dir(custom_theme_directory) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "json")
+            })
+            .filter_map(|path| {
+                path.file_stem()
+                    .map(|file_stem| file_stem.to_string_lossy().to_string())
+            })
+            .collect(),
+        Err(_) => Vec::new(),
     };
+    custom_theme_names.sort();
+    custom_theme_names
+}
+
+fn get_custom_theme(config_directory: &Path, theme_name: &str) -> Option<AppColorInfo> {
+    let custom_theme_filepath = config_directory
+        .join(CUSTOM_THEME_DIRECTORY)
+        .join(format!("{}.json", theme_name));
+    let file = File::open(custom_theme_filepath).ok()?;
+    serde_json::from_reader::<File, AppColorInfo>(file).ok()
+}
+
+// save the colors as a new theme file, the theme name will be the file name
+pub fn save_custom_theme(
+    config_directory: &Path,
+    theme_name: &str,
+    app_color_info: &AppColorInfo,
+) -> Result<(), String> {
+    if theme_name.is_empty() {
+        return Err("Theme name can't be empty".to_string());
+    }
+    if THEMES.contains(&theme_name) {
+        return Err(format!("\"{}\" is a built in theme", theme_name));
+    }
+
+    let custom_theme_filepath = config_directory
+        .join(CUSTOM_THEME_DIRECTORY)
+        .join(format!("{}.json", theme_name));
+    let file = create_file_with_dirs(&custom_theme_filepath)
+        .map_err(|e| format!("Fail to save theme: {}", e))?;
+
+    serde_json::to_writer_pretty(file, app_color_info)
+        .map_err(|e| format!("Fail to save theme: {}", e))
+}
+
+// save the tabs after their layout were resized, the first tab will be saved as the layout when there isn't any tab
+// in the settings file
+pub fn save_tabs_config(config_directory: &Path, tabs: &[LayoutTab]) -> io::Result<()> {
+    // keep the other settings in the file untouched, the file isn't written if it can't be parsed
+    let mut theme_config = read_theme_config(Some(config_directory))?;
+    if theme_config.tabs.is_some() {
+        theme_config.tabs = Some(tabs.to_vec());
+    } else if let Some(tab) = tabs.first() {
+        theme_config.layout = Some(tab.layout.clone());
+    }
+    write_theme_config(config_directory, &theme_config)
+}
+
+pub fn set_theme(config_directory: &Path, theme_string: String) -> io::Result<()> {
+    // keep the other settings in the file untouched, the file isn't written if it can't be parsed
+    let mut theme_config = read_theme_config(Some(config_directory))?;
+    theme_config.theme = theme_string;
+    write_theme_config(config_directory, &theme_config)
+}
+
+// pick the light or dark theme based on the settings override or the detected terminal background
+fn resolve_auto_theme(theme_config: &ThemeConfig) -> String {
+    resolve_paired_theme(theme_config, get_terminal_background())
+}
+
+// pick the light or dark theme based on the settings override or the OS preference,
+// fallback to the terminal background if the OS preference can't be detected
+fn resolve_system_theme(
+    theme_config: &ThemeConfig,
+    system_appearance: Option<ThemeAppearance>,
+) -> String {
+    resolve_paired_theme(
+        theme_config,
+        system_appearance.unwrap_or(get_terminal_background()),
+    )
+}
+
+fn get_terminal_background() -> ThemeAppearance {
+    *TERMINAL_BACKGROUND.get_or_init(|| ThemeAppearance::Dark)
+}
+
+fn resolve_paired_theme(
+    theme_config: &ThemeConfig,
+    detected_appearance: ThemeAppearance,
+) -> String {
+    let background = match theme_config.background.as_deref() {
+        Some("light") => ThemeAppearance::Light,
+        Some("dark") => ThemeAppearance::Dark,
+        _ => detected_appearance,
+    };
+
+    let theme = match background {
+        ThemeAppearance::Light => theme_config
+            .light_theme
+            .clone()
+            .unwrap_or(DEFAULT_LIGHT_THEME.to_string()),
+        ThemeAppearance::Dark => theme_config
+            .dark_theme
+            .clone()
+            .unwrap_or(DEFAULT_DARK_THEME.to_string()),
+    };
+
+    // prevent "auto" and "system" from resolving to itself
+    if theme == AUTO_THEME || theme == SYSTEM_THEME {
+        return match background {
+            ThemeAppearance::Light => DEFAULT_LIGHT_THEME.to_string(),
+            ThemeAppearance::Dark => DEFAULT_DARK_THEME.to_string(),
+        };
+    }
+    theme
+}
+
+// the OS light/dark preference, None if it can't be detected or the system-theme feature was disabled
+pub fn get_system_appearance() -> Option<ThemeAppearance> {
+    #[cfg(feature = "system-theme")]
+    return query_system_appearance();
+    #[cfg(not(feature = "system-theme"))]
+    return None;
+}
 
-    create_file_with_dirs(theme_config_filepath.to_str().unwrap());
-    let file = OpenOptions::new()
+#[cfg(feature = "system-theme")]
+fn query_system_appearance() -> Option<ThemeAppearance> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    {
+        // AppleInterfaceStyle only exist ( as "Dark" ) when dark mode is on
+        let output = Command::new("defaults")
+            .args(["read", "-g", "AppleInterfaceStyle"])
+            .output()
+            .ok()?;
+        if String::from_utf8_lossy(&output.stdout).trim() == "Dark" {
+            return Some(ThemeAppearance::Dark);
+        }
+        return Some(ThemeAppearance::Light);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let output = Command::new("reg")
+            .args([
+                "query",
+                "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+                "/v",
+                "AppsUseLightTheme",
+            ])
+            .output()
+            .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.contains("0x0") {
+            return Some(ThemeAppearance::Dark);
+        } else if stdout.contains("0x1") {
+            return Some(ThemeAppearance::Light);
+        }
+        return None;
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        // GNOME 42+ ( and desktop that follow the same setting ) expose the preference as color-scheme
+        if let Ok(output) = Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "color-scheme"])
+            .output()
+        {
+            if output.status.success() {
+                let color_scheme = String::from_utf8_lossy(&output.stdout);
+                if color_scheme.contains("prefer-dark") {
+                    return Some(ThemeAppearance::Dark);
+                } else if color_scheme.contains("prefer-light") || color_scheme.contains("default")
+                {
+                    return Some(ThemeAppearance::Light);
+                }
+            }
+        }
+
+        // older desktop only have the gtk theme, dark variant usually end with "-dark"
+        if let Ok(output) = Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "gtk-theme"])
+            .output()
+        {
+            if output.status.success() {
+                let gtk_theme = String::from_utf8_lossy(&output.stdout).to_lowercase();
+                if gtk_theme.contains("dark") {
+                    return Some(ThemeAppearance::Dark);
+                }
+                return Some(ThemeAppearance::Light);
+            }
+        }
+
+        return None;
+    }
+}
+
+// detect the terminal background, this need to be called before the app enter the alternate screen
+// as the terminal response of the OSC 11 query will otherwise be read as key input
+pub fn detect_terminal_background() {
+    let background = query_terminal_background_color()
+        .or_else(get_terminal_background_from_env)
+        .unwrap_or(ThemeAppearance::Dark);
+    let _ = TERMINAL_BACKGROUND.set(background);
+}
+
+// COLORFGBG is set by some terminal ( rxvt, konsole, ... ) in the format of "<foreground>;<background>"
+fn get_terminal_background_from_env() -> Option<ThemeAppearance> {
+    let colorfgbg = env::var("COLORFGBG").ok()?;
+    let background = colorfgbg.rsplit(';').next()?.parse::<u8>().ok()?;
+
+    // ansi color 7 ( white ) and 9-15 ( bright colors ) are considered light, except 8 ( bright black )
+    if background == 7 || (9..=15).contains(&background) {
+        Some(ThemeAppearance::Light)
+    } else {
+        Some(ThemeAppearance::Dark)
+    }
+}
+
+// ask the terminal for its background color with OSC 11, the terminal will reply with
+// "\x1b]11;rgb:RRRR/GGGG/BBBB" terminated by BEL or ST, terminal that doesn't support it won't reply
+#[cfg(unix)]
+fn query_terminal_background_color() -> Option<ThemeAppearance> {
+    use libc::{poll, pollfd, POLLIN};
+    use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled};
+    use std::{
+        fs::OpenOptions,
+        io::{Read, Write},
+        os::fd::AsRawFd,
+        time::{Duration, Instant},
+    };
+
+    const RESPONSE_TIMEOUT: Duration = Duration::from_millis(100);
+
+    let mut tty = OpenOptions::new()
+        .read(true)
         .write(true)
-        .create(true) // Create the file if it doesn't exist
-        .truncate(true) // Truncate the file to ensure it's empty before writing
-        .open(&theme_config_filepath)
-        .unwrap();
+        .open("/dev/tty")
+        .ok()?;
 
-    // write the data into the json file
-    let _ = serde_json::to_writer(file, &theme_config);
+    let was_raw_mode_enabled = is_raw_mode_enabled().unwrap_or(false);
+    if !was_raw_mode_enabled {
+        enable_raw_mode().ok()?;
+    }
+
+    let mut response: Vec<u8> = Vec::new();
+    if tty.write_all(b"\x1b]11;?\x07").is_ok() && tty.flush().is_ok() {
+        let started = Instant::now();
+        let mut buffer = [0u8; 64];
+        while started.elapsed() < RESPONSE_TIMEOUT {
+            let remaining = RESPONSE_TIMEOUT.saturating_sub(started.elapsed());
+            let mut poll_fd = pollfd {
+                fd: tty.as_raw_fd(),
+                events: POLLIN,
+                revents: 0,
+            };
+            let ready = unsafe { poll(&mut poll_fd, 1, remaining.as_millis() as i32) };
+            if ready <= 0 {
+                break;
+            }
+            match tty.read(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => response.extend_from_slice(&buffer[..n]),
+            }
+            // stop reading once the response was terminated with BEL or ST
+            if response.ends_with(b"\x07") || response.ends_with(b"\x1b\\") {
+                break;
+            }
+        }
+    }
+
+    if !was_raw_mode_enabled {
+        let _ = disable_raw_mode();
+    }
+
+    parse_osc_11_response(&String::from_utf8_lossy(&response))
+}
+
+#[cfg(not(unix))]
+fn query_terminal_background_color() -> Option<ThemeAppearance> {
+    None
+}
+
+fn parse_osc_11_response(response: &str) -> Option<ThemeAppearance> {
+    let rgb = response.split("rgb:").nth(1)?;
+    let rgb = rgb
+        .trim_end_matches(['\x07', '\\'])
+        .trim_end_matches('\x1b');
+
+    // each channel can be 1 to 4 hex digits, normalize them into 0.0 to 1.0
+    let channels: Vec<f64> = rgb
+        .split('/')
+        .filter_map(|channel| {
+            let value = u32::from_str_radix(channel, 16).ok()?;
+            let max = (1u32 << (channel.len() * 4)) - 1;
+            Some(value as f64 / max as f64)
+        })
+        .collect();
+    if channels.len() != 3 {
+        return None;
+    }
+
+    // relative luminance, ITU-R BT.709
+    let luminance = 0.2126 * channels[0] + 0.7152 * channels[1] + 0.0722 * channels[2];
+    if luminance > 0.5 {
+        Some(ThemeAppearance::Light)
+    } else {
+        Some(ThemeAppearance::Dark)
+    }
 }
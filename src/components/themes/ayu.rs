@@ -48,4 +48,7 @@ pub const AYU: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(223, 191, 255),
     process_selected_color_bg: Color::Rgb(230, 180, 80),
     process_selected_color_fg: Color::Rgb(248, 248, 242),
+
+    graph_gradient: None,
+    process_heat: None,
 };
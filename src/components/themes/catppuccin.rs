@@ -48,4 +48,7 @@ pub const CATPPUCCIN_MOCHA: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(166, 173, 200),
     process_selected_color_bg: Color::Rgb(203, 166, 247),
     process_selected_color_fg: Color::Rgb(30, 30, 46),
+
+    graph_gradient: None,
+    process_heat: None,
 };
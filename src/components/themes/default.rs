@@ -48,4 +48,7 @@ pub const DEFAULT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(94, 129, 172),
     process_selected_color_bg: Color::Rgb(76, 86, 106),
     process_selected_color_fg: Color::Rgb(236, 239, 244),
+
+    graph_gradient: None,
+    process_heat: None,
 };
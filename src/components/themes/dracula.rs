@@ -48,4 +48,7 @@ pub const DRACULA: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(189, 147, 249),
     process_selected_color_bg: Color::Rgb(255, 121, 198),
     process_selected_color_fg: Color::Rgb(248, 248, 242),
+
+    graph_gradient: None,
+    process_heat: None,
 };
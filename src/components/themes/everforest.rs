@@ -48,6 +48,9 @@ pub const EVERFOREST_DARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(167, 192, 128),
     process_selected_color_bg: Color::Rgb(55, 65, 69),
     process_selected_color_fg: Color::Rgb(219, 188, 127),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const EVERFOREST_LIGHT: AppColorInfo = AppColorInfo {
@@ -96,4 +99,7 @@ pub const EVERFOREST_LIGHT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(141, 161, 1),
     process_selected_color_bg: Color::Rgb(79, 88, 94),
     process_selected_color_fg: Color::Rgb(223, 160, 0),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -48,6 +48,9 @@ pub const FLATREMIX: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(54, 123, 240),
     process_selected_color_bg: Color::Rgb(184, 23, 76),
     process_selected_color_fg: Color::Rgb(255, 255, 255),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const FLATREMIX_LIGHT: AppColorInfo = AppColorInfo {
@@ -96,4 +99,7 @@ pub const FLATREMIX_LIGHT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(54, 123, 240),
     process_selected_color_bg: Color::Rgb(184, 23, 76),
     process_selected_color_fg: Color::Rgb(255, 255, 255),
+
+    graph_gradient: None,
+    process_heat: None,
 };
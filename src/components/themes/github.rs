@@ -48,4 +48,7 @@ pub const GITHUB_DARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(201, 209, 217),
     process_selected_color_bg: Color::Rgb(88, 166, 255),
     process_selected_color_fg: Color::Rgb(240, 246, 252),
+
+    graph_gradient: None,
+    process_heat: None,
 };
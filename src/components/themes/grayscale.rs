@@ -48,4 +48,7 @@ pub const GRAYSCALE: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(144, 144, 144),
     process_selected_color_bg: Color::Rgb(255, 255, 255),
     process_selected_color_fg: Color::Rgb(0, 0, 0),
+
+    graph_gradient: None,
+    process_heat: None,
 };
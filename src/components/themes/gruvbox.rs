@@ -48,6 +48,9 @@ pub const GRUVBOX_DARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(152, 151, 26),
     process_selected_color_bg: Color::Rgb(40, 40, 40),
     process_selected_color_fg: Color::Rgb(250, 189, 47),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const GRUVBOX_LIGHT: AppColorInfo = AppColorInfo {
@@ -96,6 +99,9 @@ pub const GRUVBOX_LIGHT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(152, 151, 26),
     process_selected_color_bg: Color::Rgb(242, 229, 188),
     process_selected_color_fg: Color::Rgb(143, 63, 113),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const GRUVBOX_MAT_DARK: AppColorInfo = AppColorInfo {
@@ -144,4 +150,7 @@ pub const GRUVBOX_MAT_DARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(169, 182, 101),
     process_selected_color_bg: Color::Rgb(216, 166, 87),
     process_selected_color_fg: Color::Rgb(40, 40, 40),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -48,4 +48,7 @@ pub const HORIZON: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(39, 215, 150),
     process_selected_color_bg: Color::Rgb(40, 43, 55),
     process_selected_color_fg: Color::Rgb(248, 248, 242),
+
+    graph_gradient: None,
+    process_heat: None,
 };
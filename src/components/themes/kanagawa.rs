@@ -48,6 +48,9 @@ pub const KANAGAWA_LOTUS: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(89, 123, 117),
     process_selected_color_bg: Color::Rgb(201, 203, 209),
     process_selected_color_fg: Color::Rgb(67, 67, 108),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const KANAGAWA_WAVE: AppColorInfo = AppColorInfo {
@@ -96,4 +99,7 @@ pub const KANAGAWA_WAVE: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(122, 168, 159),
     process_selected_color_bg: Color::Rgb(34, 50, 73),
     process_selected_color_fg: Color::Rgb(220, 165, 97),
+
+    graph_gradient: None,
+    process_heat: None,
 };
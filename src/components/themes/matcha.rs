@@ -48,4 +48,7 @@ pub const MATCHA_DARK_SEA: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(51, 177, 101),
     process_selected_color_bg: Color::Rgb(13, 73, 61),
     process_selected_color_fg: Color::Rgb(248, 248, 242),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -14,6 +14,7 @@ pub mod monokai;
 pub mod nightowl;
 pub mod onedark;
 pub mod paper;
+pub mod presentation;
 pub mod rosepine;
 pub mod solarized;
 pub mod tokyo;
@@ -48,4 +48,7 @@ pub const MONOKAI: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(166, 226, 46),
     process_selected_color_bg: Color::Rgb(122, 17, 55),
     process_selected_color_fg: Color::Rgb(248, 248, 242),
+
+    graph_gradient: None,
+    process_heat: None,
 };
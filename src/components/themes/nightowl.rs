@@ -48,4 +48,7 @@ pub const NIGHT_OWL: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(34, 218, 110),
     process_selected_color_bg: Color::Rgb(0, 0, 0),
     process_selected_color_fg: Color::Rgb(255, 235, 149),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -48,4 +48,7 @@ pub const ONEDARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(97, 175, 239),
     process_selected_color_bg: Color::Rgb(44, 49, 60),
     process_selected_color_fg: Color::Rgb(171, 178, 191),
+
+    graph_gradient: None,
+    process_heat: None,
 };
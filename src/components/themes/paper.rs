@@ -48,4 +48,7 @@ pub const PAPER: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(0, 0, 0),
     process_selected_color_bg: Color::Rgb(216, 213, 199),
     process_selected_color_fg: Color::Rgb(0, 0, 0),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -0,0 +1,55 @@
+use ratatui::style::Color;
+
+use crate::types::AppColorInfo;
+
+// simplified high contrast colors used by the presentation mode, it was not listed as a selectable theme
+pub const PRESENTATION: AppColorInfo = AppColorInfo {
+    background_color: Color::Rgb(0, 0, 0),
+    base_app_text_color: Color::Rgb(255, 255, 255),
+    key_text_color: Color::Rgb(255, 215, 0),
+    app_title_color: Color::Rgb(255, 255, 255),
+    pop_up_color: Color::Rgb(255, 255, 255),
+    pop_up_selected_color_bg: Color::Rgb(64, 64, 64),
+    pop_up_blur_bg: Color::Rgb(32, 32, 32),
+
+    cpu_container_selected_color: Color::Rgb(255, 215, 0),
+    cpu_main_block_color: Color::Rgb(128, 128, 128),
+    cpu_selected_color: Color::Rgb(255, 215, 0),
+    cpu_base_graph_color: Color::Rgb(255, 255, 255),
+    cpu_info_block_color: Color::Rgb(128, 128, 128),
+    cpu_text_color: Color::Rgb(255, 255, 255),
+
+    memory_container_selected_color: Color::Rgb(255, 215, 0),
+    memory_main_block_color: Color::Rgb(128, 128, 128),
+    used_memory_base_graph_color: Color::Rgb(255, 255, 255),
+    available_memory_base_graph_color: Color::Rgb(255, 255, 255),
+    free_memory_base_graph_color: Color::Rgb(255, 255, 255),
+    cached_memory_base_graph_color: Color::Rgb(255, 255, 255),
+    swap_memory_base_graph_color: Color::Rgb(255, 255, 255),
+    memory_text_color: Color::Rgb(255, 255, 255),
+
+    disk_container_selected_color: Color::Rgb(255, 215, 0),
+    disk_main_block_color: Color::Rgb(128, 128, 128),
+    disk_bytes_written_base_graph_color: Color::Rgb(255, 255, 255),
+    disk_bytes_read_base_graph_color: Color::Rgb(255, 255, 255),
+    disk_text_color: Color::Rgb(255, 255, 255),
+
+    network_container_selected_color: Color::Rgb(255, 215, 0),
+    network_main_block_color: Color::Rgb(128, 128, 128),
+    network_received_base_graph_color: Color::Rgb(255, 255, 255),
+    network_transmitted_base_graph_color: Color::Rgb(255, 255, 255),
+    network_info_block_color: Color::Rgb(128, 128, 128),
+    network_text_color: Color::Rgb(255, 255, 255),
+
+    process_container_selected_color: Color::Rgb(255, 215, 0),
+    process_main_block_color: Color::Rgb(128, 128, 128),
+    process_base_graph_color: Color::Rgb(255, 255, 255),
+    process_info_block_color: Color::Rgb(128, 128, 128),
+    process_title_color: Color::Rgb(255, 215, 0),
+    process_text_color: Color::Rgb(255, 255, 255),
+    process_selected_color_bg: Color::Rgb(255, 215, 0),
+    process_selected_color_fg: Color::Rgb(0, 0, 0),
+
+    graph_gradient: None,
+    process_heat: None,
+};
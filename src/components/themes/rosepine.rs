@@ -59,4 +59,7 @@ pub const ROSE_PINE: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(122, 162, 247),               // Rosé Pine Iris
     process_selected_color_bg: Color::Rgb(43, 42, 51),           // Rosé Pine Surface
     process_selected_color_fg: Color::Rgb(224, 222, 244),        // Rosé Pine Text
+
+    graph_gradient: None,
+    process_heat: None,
 };
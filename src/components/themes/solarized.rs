@@ -48,6 +48,9 @@ pub const SOLARIZED_DARK: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(133, 153, 0),
     process_selected_color_bg: Color::Rgb(7, 54, 66),
     process_selected_color_fg: Color::Rgb(214, 162, 0),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const SOLARIZED_LIGHT: AppColorInfo = AppColorInfo {
@@ -96,4 +99,7 @@ pub const SOLARIZED_LIGHT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(211, 54, 130),
     process_selected_color_bg: Color::Rgb(238, 232, 213),
     process_selected_color_fg: Color::Rgb(181, 137, 0),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -48,6 +48,9 @@ pub const TOKYO_NIGHT: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(125, 207, 255),
     process_selected_color_bg: Color::Rgb(65, 72, 104),
     process_selected_color_fg: Color::Rgb(207, 201, 194),
+
+    graph_gradient: None,
+    process_heat: None,
 };
 
 pub const TOKYO_STORM: AppColorInfo = AppColorInfo {
@@ -96,4 +99,7 @@ pub const TOKYO_STORM: AppColorInfo = AppColorInfo {
     process_text_color: Color::Rgb(125, 207, 255),
     process_selected_color_bg: Color::Rgb(65, 72, 104),
     process_selected_color_fg: Color::Rgb(207, 201, 194),
+
+    graph_gradient: None,
+    process_heat: None,
 };
@@ -0,0 +1,83 @@
+use crate::types::{MemoryUnit, NetworkUnit};
+
+// the binary units for the bytes, the value will be scaled up till it is below 1024 of the unit
+const BYTE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+// the decimal units for the bits, network speeds are 1000 based
+const BIT_UNITS: [&str; 5] = ["bps", "Kbps", "Mbps", "Gbps", "Tbps"];
+// all the bytes and rates are shown with the same precision so the width won't jump around
+const BYTE_DECIMAL_PLACES: usize = 2;
+
+// scale the bytes into the largest unit that keep the value below 1024
+fn scale_bytes(value: f64) -> (f64, &'static str) {
+    let mut value = value;
+    let mut unit_index = 0;
+    while value.abs() >= 1024.0 && unit_index < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit_index += 1;
+    }
+    return (value, BYTE_UNITS[unit_index]);
+}
+
+// format the bytes with auto-scaling units, eg: 512.00 B, 1.50 GiB
+pub fn format_bytes(value: f64) -> String {
+    let (value, unit) = scale_bytes(value);
+    return format!("{:.*} {}", BYTE_DECIMAL_PLACES, value, unit);
+}
+
+// format the bytes transferred within a refresh interval as bytes per second, eg: 1.50 MiB/s
+pub fn format_bytes_per_second(bytes: f64, interval_ms: u64) -> String {
+    let bytes_per_second = if interval_ms > 0 {
+        bytes * 1000.0 / interval_ms as f64
+    } else {
+        bytes
+    };
+    let (value, unit) = scale_bytes(bytes_per_second);
+    return format!("{:.*} {}/s", BYTE_DECIMAL_PLACES, value, unit);
+}
+
+// format the bytes transferred within a refresh interval as bits per second, eg: 12.00 Mbps
+pub fn format_bits_per_second(bytes: f64, interval_ms: u64) -> String {
+    let mut bits_per_second = if interval_ms > 0 {
+        bytes * 8.0 * 1000.0 / interval_ms as f64
+    } else {
+        bytes * 8.0
+    };
+    let mut unit_index = 0;
+    while bits_per_second.abs() >= 1000.0 && unit_index < BIT_UNITS.len() - 1 {
+        bits_per_second /= 1000.0;
+        unit_index += 1;
+    }
+    return format!(
+        "{:.*} {}",
+        BYTE_DECIMAL_PLACES, bits_per_second, BIT_UNITS[unit_index]
+    );
+}
+
+// format the network rate with the selected unit
+pub fn format_network_rate(bytes: f64, interval_ms: u64, network_unit: &NetworkUnit) -> String {
+    match network_unit {
+        NetworkUnit::Bytes => format_bytes_per_second(bytes, interval_ms),
+        NetworkUnit::Bits => format_bits_per_second(bytes, interval_ms),
+    }
+}
+
+// format the memory value with the selected unit, the percentage is based on the total,
+// it will fallback to the auto unit for percentage if the total is 0 ( eg: the total memory itself or swap )
+pub fn format_memory(value: f64, total: f64, memory_unit: &MemoryUnit) -> String {
+    match memory_unit {
+        MemoryUnit::Auto => format_bytes(value),
+        MemoryUnit::MiB => format!("{:.*} MiB", BYTE_DECIMAL_PLACES, value / 1024.0 / 1024.0),
+        MemoryUnit::GiB => format!(
+            "{:.*} GiB",
+            BYTE_DECIMAL_PLACES,
+            value / 1024.0 / 1024.0 / 1024.0
+        ),
+        MemoryUnit::Percent => {
+            if total > 0.0 {
+                format!("{:.*}%", BYTE_DECIMAL_PLACES, (value / total) * 100.0)
+            } else {
+                format_bytes(value)
+            }
+        }
+    }
+}
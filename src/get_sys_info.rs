@@ -1,331 +1,2563 @@
 use std::{
-    collections::HashMap,
-    sync::mpsc::{Receiver, RecvTimeoutError, Sender},
-    thread,
+    collections::{HashMap, HashSet},
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
     time::{Duration, Instant},
 };
 
+use crate::components::theme::{
+    get_config_directory, get_disk_filter_config, get_network_filter_config, get_system_appearance,
+    read_theme_config_or_default,
+};
 use crate::types::{
-    CCpuData, CDiskData, CMemoryData, CNetworkData, CProcessData, CProcessesInfo, CSysInfo,
+    CCpuData, CDiskData, CMemoryData, CNetworkData, CPressureData, CPressureInfo, CProcessData,
+    CProcessUpdate, CProcessesInfo, CSysInfo, CSystemData, CompressedSwapInfo,
+    ConnectionThroughput, ContainerInfo, CpuThrottleInfo, CpuTimeBreakdown, DiskFilter,
+    DiskIoStats, MemoryBreakdown, NetworkConnectivity, NetworkFilter, NetworkInterfaceDetails,
+    NetworkPacketStats, PingResult, ProcessDelayInfo, RaidArray, ShownSystemInfo, SmartInfo,
+    StaticSystemInfo, StoragePool, SwapDevice, ThemeAppearance, ThreadData, VpnInfo, ZfsArcInfo,
+};
+use log::{debug, error};
+use sysinfo::{Disks, Networks, Pid, Process, ProcessesToUpdate, System, Uid, Users};
+use tokio::{
+    sync::{mpsc::UnboundedSender, watch},
+    task, time,
 };
-use sysinfo::{Disks, Networks, Process, ProcessesToUpdate, System, Users};
 
-pub fn spawn_system_info_collector(
-    tick_receiver: Receiver<u32>,
-    tx: Sender<CSysInfo>,
-    default_tick: u32,
-) {
-    // Spawn a worker thread to gather CPU info
-    thread::spawn(move || {
-        let mut sys = System::new_all();
-        let mut disks = Disks::new();
-        let mut networks = Networks::new();
-        let mut last_refresh = Instant::now();
-        let mut tick_value = default_tick; // Current tick in ms
+// how long should the container metadata queried from the container runtime be cached
+const CONTAINER_INFO_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+// how often should the thread counts of the processes be refreshed on windows
+#[cfg(target_os = "windows")]
+const WIN_THREAD_COUNT_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+// how long should the peer info of the WireGuard interfaces be cached, as it was queried by running `wg`
+const WIREGUARD_PEER_INFO_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+// the ( endpoint, latest handshake ) of each WireGuard interface and the time it was queried
+type WireguardPeerInfoCache = HashMap<String, (Instant, (Option<String>, Option<u64>))>;
+// how long should the logged in user count be cached, as it was counted by running `who`
+const LOGGED_IN_USER_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+// the full info of all the processes was sent once every this many process info collections
+const PROCESS_SNAPSHOT_INTERVAL: u32 = 30;
+
+// ------------------------------------
+//
+// metrics source
+//
+// ------------------------------------
+// the source of the collected info, each collector sends what it collected to the main thread through its channel
+// the app only depends on this trait, so it can be driven with synthetic info instead of the real system
+//
+// the collectors were tasks of the tokio runtime started by the app, so an additional source shares the same runtime
+// instead of spawning its own thread, the blocking collection itself was run on the blocking thread pool
+// the system and process info were sent through unbounded channels, as the app was their only consumer and
+// it can't miss any of them ( the process info only has the changes since the previous collection )
+// and the collect interval ( tick ) was watched, the collector picks up the latest tick
+pub trait MetricsSource {
+    fn spawn_system_info_collector(
+        &self,
+        tick_receiver: watch::Receiver<u32>,
+        shown_receiver: watch::Receiver<ShownSystemInfo>,
+        tx: UnboundedSender<CSysInfo>,
+    );
+
+    fn spawn_process_info_collector(
+        &self,
+        tick_receiver: watch::Receiver<u32>,
+        tx: UnboundedSender<CProcessesInfo>,
+    );
+
+    // the optional collectors don't collect anything unless the source provides them
+    fn spawn_smart_info_collector(&self, _tx: Sender<HashMap<String, SmartInfo>>) {}
+
+    fn spawn_storage_pool_collector(&self, _tx: Sender<Vec<StoragePool>>) {}
+
+    fn spawn_ping_collector(&self, _target: String, _tx: Sender<PingResult>) {}
+
+    fn spawn_public_ip_collector(&self, _tx: Sender<String>) {}
+
+    // the threads of the watched pid, None while the threads aren't shown
+    fn spawn_thread_info_collector(
+        &self,
+        _pid_receiver: watch::Receiver<Option<u32>>,
+        _tx: Sender<(u32, Vec<ThreadData>)>,
+    ) {
+    }
+
+    // the OS light/dark preference, only checked while the watched theme is "system"
+    fn spawn_system_appearance_collector(
+        &self,
+        _is_system_theme_receiver: watch::Receiver<bool>,
+        _tx: Sender<Option<ThemeAppearance>>,
+    ) {
+    }
+}
+
+// the collectors backed by sysinfo and the system commands
+pub struct SysinfoMetricsSource;
+
+impl MetricsSource for SysinfoMetricsSource {
+    fn spawn_system_info_collector(
+        &self,
+        tick_receiver: watch::Receiver<u32>,
+        shown_receiver: watch::Receiver<ShownSystemInfo>,
+        tx: UnboundedSender<CSysInfo>,
+    ) {
+        spawn_system_info_collector(tick_receiver, shown_receiver, tx);
+    }
+
+    fn spawn_process_info_collector(
+        &self,
+        tick_receiver: watch::Receiver<u32>,
+        tx: UnboundedSender<CProcessesInfo>,
+    ) {
+        spawn_process_info_collector(tick_receiver, tx);
+    }
+
+    // the SMART health was only collected with the smart feature
+    #[cfg(feature = "smart")]
+    fn spawn_smart_info_collector(&self, tx: Sender<HashMap<String, SmartInfo>>) {
+        spawn_smart_info_collector(tx);
+    }
+
+    #[cfg(feature = "storage-pools")]
+    fn spawn_storage_pool_collector(&self, tx: Sender<Vec<StoragePool>>) {
+        spawn_storage_pool_collector(tx);
+    }
+
+    #[cfg(feature = "ping")]
+    fn spawn_ping_collector(&self, target: String, tx: Sender<PingResult>) {
+        spawn_ping_collector(target, tx);
+    }
+
+    #[cfg(feature = "public-ip")]
+    fn spawn_public_ip_collector(&self, tx: Sender<String>) {
+        spawn_public_ip_collector(tx);
+    }
+
+    fn spawn_thread_info_collector(
+        &self,
+        pid_receiver: watch::Receiver<Option<u32>>,
+        tx: Sender<(u32, Vec<ThreadData>)>,
+    ) {
+        spawn_thread_info_collector(pid_receiver, tx);
+    }
+
+    fn spawn_system_appearance_collector(
+        &self,
+        is_system_theme_receiver: watch::Receiver<bool>,
+        tx: Sender<Option<ThemeAppearance>>,
+    ) {
+        spawn_system_appearance_collector(is_system_theme_receiver, tx);
+    }
+}
+
+// run the blocking collection every tick and send what it collected, until the app was closed
+// it can be reused by the other metrics sources for their own collectors
+// a new tick restarts the wait, so the collector doesn't need to wait for the old tick to pass first
+pub async fn run_collector<C, T>(
+    name: &'static str,
+    mut collector: C,
+    collect: fn(&mut C) -> T,
+    mut tick_receiver: watch::Receiver<u32>,
+    tx: UnboundedSender<T>,
+) where
+    C: Send + 'static,
+    T: Send + 'static,
+{
+    let mut last_refresh = time::Instant::now();
+    loop {
+        let tick = Duration::from_millis((*tick_receiver.borrow_and_update()).into());
+        tokio::select! {
+            is_changed = tick_receiver.changed() => {
+                // the app was closed
+                if is_changed.is_err() {
+                    break;
+                }
+                continue; // don't collect this cycle, just updated tick
+            }
+            _ = time::sleep_until(last_refresh + tick) => {}
+        }
+
+        let collection_start = Instant::now();
+        let collected = task::spawn_blocking(move || {
+            let info = collect(&mut collector);
+            return (collector, info);
+        })
+        .await;
+        let Ok((returned_collector, info)) = collected else {
+            error!("The {} collection was aborted", name);
+            break;
+        };
+        collector = returned_collector;
+        debug!(
+            "{} collected in {} ms",
+            name,
+            collection_start.elapsed().as_millis()
+        );
+
+        // Send the data to the main thread
+        if tx.send(info).is_err() {
+            error!("Failed to send the {}, the channel was disconnected", name);
+            break; // Exit loop if channel is disconnected
+        }
+
+        // Reset the last refresh time
+        last_refresh = time::Instant::now();
+    }
+}
+
+// the state kept between the system info collections, most of the rates were calculated from the difference
+// with the counters of the previous collection
+struct SystemInfoCollector {
+    sys: System,
+    disks: Disks,
+    networks: Networks,
+    // the cpu topology won't change while running, so it was only detected once
+    cpu_topology: Vec<Option<(u32, u32)>>,
+    // the cpu time counters of the previous collection, the breakdown was calculated from the difference
+    previous_cpu_times: Vec<[u64; 8]>,
+    // the ARC hit rate was calculated from the difference of the previous collection
+    previous_zfs_arc: Option<ZfsArcInfo>,
+    // the disk io latency and utilization was calculated from the difference of the previous collection
+    previous_disk_stats: DiskStatsCounters,
+    previous_disk_stats_time: Instant,
+    // the dropped packets are cumulative counters, so the count of each refresh was calculated from the difference
+    previous_network_drops: HashMap<String, (u64, u64)>,
+    // the connection throughput was calculated from the difference of the socket counters
+    previous_connection_counters: HashMap<(String, String), (u64, u64)>,
+    // the slow parts of the system info were only collected while the app shows them
+    shown_receiver: watch::Receiver<ShownSystemInfo>,
+    // the logged in user count and the time it was counted, the logins rarely change
+    logged_in_user_count: Option<(Instant, Option<usize>)>,
+    // the peer info of the WireGuard interfaces was only queried again once it was outdated
+    wireguard_peer_info_cache: WireguardPeerInfoCache,
+    // the disks that were filtered out by the settings won't be collected at all
+    disk_filter: DiskFilter,
+    // the same for the network interfaces ( eg: docker0, veth* )
+    network_filter: NetworkFilter,
+}
+
+impl SystemInfoCollector {
+    fn new(shown_receiver: watch::Receiver<ShownSystemInfo>) -> SystemInfoCollector {
+        let mut sys = System::new_all();
+        let mut disks = Disks::new();
+        let mut networks = Networks::new();
+        sys.refresh_all();
+        disks.refresh(true);
+        networks.refresh(true);
+
+        let cpu_topology = (0..sys.cpus().len()).map(get_cpu_topology).collect();
+        let theme_config = read_theme_config_or_default(get_config_directory().as_deref());
+        return SystemInfoCollector {
+            sys,
+            disks,
+            networks,
+            cpu_topology,
+            previous_cpu_times: get_cpu_times(),
+            previous_zfs_arc: get_zfs_arc_info(&None),
+            previous_disk_stats: get_disk_stats_counters(),
+            previous_disk_stats_time: Instant::now(),
+            previous_network_drops: get_network_drop_counters(),
+            previous_connection_counters: HashMap::new(),
+            shown_receiver,
+            logged_in_user_count: None,
+            wireguard_peer_info_cache: HashMap::new(),
+            disk_filter: get_disk_filter_config(&theme_config),
+            network_filter: get_network_filter_config(&theme_config),
+        };
+    }
+
+    // the logged in user count, `who` was only run again once the cached count was outdated
+    fn get_cached_logged_in_user_count(&mut self) -> Option<usize> {
+        if let Some((last_counted, logged_in_user_count)) = self.logged_in_user_count {
+            if last_counted.elapsed() < LOGGED_IN_USER_REFRESH_INTERVAL {
+                return logged_in_user_count;
+            }
+        }
+        let logged_in_user_count = get_logged_in_user_count();
+        self.logged_in_user_count = Some((Instant::now(), logged_in_user_count));
+        return logged_in_user_count;
+    }
+
+    // the blocking collection, it was run on the blocking thread pool of the runtime
+    fn collect(&mut self) -> CSysInfo {
+        // -------------------------------------------
+        //
+        //             CPU DATA COLLECTION
+        //
+        // -------------------------------------------
+
+        // Refresh CPU data
+        self.sys.refresh_cpu_all();
+        let cpus = self.sys.cpus();
+        let current_cpu_times = get_cpu_times();
+        let cpu_time_breakdowns =
+            get_cpu_time_breakdowns(&self.previous_cpu_times, &current_cpu_times);
+        self.previous_cpu_times = current_cpu_times;
+
+        // Gather CPU data
+        let mut cpu_data: Vec<CCpuData> = cpus
+            .iter()
+            .enumerate()
+            .map(|(index, cpu)| CCpuData {
+                id: index as i8,
+                brand: cpu.brand().to_string(),
+                usage: cpu.cpu_usage(),
+                topology: self.cpu_topology.get(index).copied().flatten(),
+                // the first entry of the breakdown was for all the cpus
+                time_breakdown: cpu_time_breakdowns.get(index + 1).copied().flatten(),
+            })
+            .collect();
+
+        // we later add cpu avg info as the first entry of the collected cpu info vector
+        let avg_cpu_data = CCpuData {
+            id: -1 as i8,
+            brand: cpu_data[0].brand.clone(),
+            usage: self.sys.global_cpu_usage(),
+            topology: None,
+            time_breakdown: cpu_time_breakdowns.first().copied().flatten(),
+        };
+        cpu_data.insert(0, avg_cpu_data);
+
+        // -------------------------------------------
+        //
+        //          RAM MEMORY DATA COLLECTION
+        //
+        // -------------------------------------------
+
+        self.sys.refresh_memory();
+        let total_memory = self.sys.total_memory() as f64;
+        let available_memory = self.sys.available_memory() as f64;
+        let used_memory = self.sys.used_memory() as f64;
+        let used_swap = self.sys.used_swap() as f64;
+        let free_memory = self.sys.free_memory() as f64;
+        let cached_memory = get_cached_memory();
+        let zfs_arc = get_zfs_arc_info(&self.previous_zfs_arc);
+        self.previous_zfs_arc = zfs_arc.clone();
+
+        let memory_data = CMemoryData {
+            total_memory,
+            available_memory,
+            used_memory,
+            used_swap,
+            free_memory,
+            cached_memory,
+            breakdown: get_memory_breakdown(),
+            compressed_swap: get_compressed_swap_info(),
+            swap_devices: get_swap_devices(),
+            zfs_arc,
+        };
+
+        // -------------------------------------------
+        //
+        //            DISK DATA COLLECTION
+        //
+        // -------------------------------------------
+        self.disks.refresh(true);
+        let current_disk_stats = get_disk_stats_counters();
+        let disk_stats_elapsed_ms = self.previous_disk_stats_time.elapsed().as_millis() as f64;
+        let mut disk_data = Vec::new();
+        for disk in &self.disks {
+            if !self.disk_filter.is_disk_shown(
+                &disk.name().to_string_lossy(),
+                &disk.mount_point().to_string_lossy(),
+                &disk.file_system().to_string_lossy(),
+            ) {
+                continue;
+            }
+            let total_space = disk.total_space() as f64;
+            let available_space = disk.available_space() as f64;
+            let data = CDiskData {
+                name: disk.name().to_string_lossy().to_string(),
+                total_space,
+                available_space,
+                used_space: total_space - available_space,
+                bytes_written: disk.usage().written_bytes as f64,
+                bytes_read: disk.usage().read_bytes as f64,
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                kind: disk.kind().to_string(),
+                inodes: get_inodes(disk.mount_point()),
+                temperature: get_disk_temperature(&disk.name().to_string_lossy()),
+                io_stats: get_disk_io_stats(
+                    &disk.name().to_string_lossy(),
+                    &self.previous_disk_stats,
+                    &current_disk_stats,
+                    disk_stats_elapsed_ms,
+                ),
+                physical_device: get_physical_device(&disk.name().to_string_lossy()),
+            };
+
+            disk_data.push(data);
+        }
+        self.previous_disk_stats = current_disk_stats;
+        self.previous_disk_stats_time = Instant::now();
+
+        // -------------------------------------------
+        //
+        //          NETWORK DATA COLLECTION
+        //
+        // -------------------------------------------
+        self.networks.refresh(true);
+        let current_network_drops = get_network_drop_counters();
+        let mut networks_data = Vec::new();
+        // remove the outdated peer info, it will be queried again when needed
+        self.wireguard_peer_info_cache
+            .retain(|_, (last_queried, _)| {
+                last_queried.elapsed() < WIREGUARD_PEER_INFO_REFRESH_INTERVAL
+            });
+        for (interface_name, network_data) in &self.networks {
+            if !self.network_filter.is_network_shown(interface_name) {
+                continue;
+            }
+            let data = CNetworkData {
+                interface_name: interface_name.to_string(),
+                ip_networks: {
+                    // the IPv4 addresses were listed first as they are more commonly looked for
+                    let mut ipv4_networks = Vec::new();
+                    let mut ipv6_networks = Vec::new();
+                    for ip in network_data.ip_networks() {
+                        if ip.addr.is_ipv4() {
+                            ipv4_networks.push(ip.addr.to_string());
+                        } else {
+                            ipv6_networks.push(ip.addr.to_string());
+                        }
+                    }
+                    ipv4_networks.extend(ipv6_networks);
+                    ipv4_networks
+                },
+                vpn_info: get_vpn_info(interface_name, &mut self.wireguard_peer_info_cache),
+                current_received: network_data.received() as f64,
+                current_transmitted: network_data.transmitted() as f64,
+                total_received: network_data.total_received() as f64,
+                total_transmitted: network_data.total_transmitted() as f64,
+                packet_stats: NetworkPacketStats {
+                    packets_received: network_data.packets_received(),
+                    packets_transmitted: network_data.packets_transmitted(),
+                    errors_received: network_data.errors_on_received(),
+                    errors_transmitted: network_data.errors_on_transmitted(),
+                    drops: current_network_drops.get(interface_name).map(
+                        |(dropped_received, dropped_transmitted)| {
+                            let (previous_received, previous_transmitted) = self
+                                .previous_network_drops
+                                .get(interface_name)
+                                .copied()
+                                .unwrap_or((*dropped_received, *dropped_transmitted));
+                            (
+                                dropped_received.saturating_sub(previous_received),
+                                dropped_transmitted.saturating_sub(previous_transmitted),
+                            )
+                        },
+                    ),
+                },
+                interface_details: {
+                    let (speed, duplex) = get_network_link_info(interface_name);
+                    NetworkInterfaceDetails {
+                        mac_address: network_data.mac_address().to_string(),
+                        mtu: network_data.mtu(),
+                        speed,
+                        duplex,
+                    }
+                },
+            };
+            networks_data.push(data);
+        }
+        self.previous_network_drops = current_network_drops;
+
+        // -------------------------------------------
+        //
+        //        CONNECTION DATA COLLECTION
+        //
+        // -------------------------------------------
+        // `ss` was only run while the connection list was shown, the throughput will be shown from the second
+        // collection after it was shown, as the first one has no previous counters to compare with
+        let shown_system_info = *self.shown_receiver.borrow();
+        let current_connection_counters = if shown_system_info.is_connection_list_shown {
+            get_connection_counters()
+        } else {
+            HashMap::new()
+        };
+        let mut connections_data = Vec::new();
+        for ((local, remote), (bytes_sent, bytes_received)) in &current_connection_counters {
+            // the connections that were just established have no previous counters to compare with
+            let Some((previous_sent, previous_received)) = self
+                .previous_connection_counters
+                .get(&(local.to_string(), remote.to_string()))
+            else {
+                continue;
+            };
+            connections_data.push(ConnectionThroughput {
+                local: local.to_string(),
+                remote: remote.to_string(),
+                sent: bytes_sent.saturating_sub(*previous_sent) as f64,
+                received: bytes_received.saturating_sub(*previous_received) as f64,
+            });
+        }
+        self.previous_connection_counters = current_connection_counters;
+
+        // -------------------------------------------
+        //
+        //    LOAD AVERAGE AND UPTIME DATA COLLECTION
+        //
+        // -------------------------------------------
+        // load average is not available on windows, it will be all 0
+        let load_average = System::load_average();
+        // the user count was only shown in the summary line, it will be counted again once it was shown again
+        let logged_in_users = if shown_system_info.is_summary_line_shown {
+            self.get_cached_logged_in_user_count()
+        } else {
+            self.logged_in_user_count = None;
+            None
+        };
+        let system_data = CSystemData {
+            load_average_one: load_average.one,
+            load_average_five: load_average.five,
+            load_average_fifteen: load_average.fifteen,
+            uptime: System::uptime(),
+            logged_in_users,
+        };
+
+        // -------------------------------------------
+        //
+        //          PRESSURE DATA COLLECTION
+        //
+        // -------------------------------------------
+        let pressure_data = CPressureInfo {
+            cpu: get_pressure_data("cpu"),
+            memory: get_pressure_data("memory"),
+            io: get_pressure_data("io"),
+        };
+
+        // -------------------------------------------
+        //
+        //          COLLECTED SYSTEM INFO
+        //
+        // -------------------------------------------
+        return CSysInfo {
+            cpus: cpu_data,
+            memory: memory_data,
+            disks: disk_data,
+            networks: networks_data,
+            system: system_data,
+            pressure: pressure_data,
+            raid_arrays: get_raid_arrays(),
+            connections: connections_data,
+            connectivity: NetworkConnectivity {
+                gateway: get_default_gateway(),
+                dns: get_primary_dns(),
+                public_ip: None,
+            },
+        };
+    }
+}
+
+// collect the system info at the tick, the tick can be updated at any time through the watch channel
+pub fn spawn_system_info_collector(
+    tick_receiver: watch::Receiver<u32>,
+    shown_receiver: watch::Receiver<ShownSystemInfo>,
+    tx: UnboundedSender<CSysInfo>,
+) {
+    tokio::spawn(async move {
+        let Ok(collector) =
+            task::spawn_blocking(move || SystemInfoCollector::new(shown_receiver)).await
+        else {
+            error!("Failed to start the system info collector");
+            return;
+        };
+        run_collector(
+            "system info",
+            collector,
+            SystemInfoCollector::collect,
+            tick_receiver,
+            tx,
+        )
+        .await;
+    });
+}
+
+// the static info only need to be retrieved once, so it wasn't part of the system info collector
+// the SMART data barely change and smartctl is slow, so it was collected at a much slower interval
+#[cfg(feature = "smart")]
+const SMART_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+// collect the SMART health of each disk, keyed by the mount point of the disk
+// the task will exit right away if smartctl can't be found
+#[cfg(feature = "smart")]
+pub fn spawn_smart_info_collector(tx: Sender<HashMap<String, SmartInfo>>) {
+    tokio::spawn(async move {
+        let is_smartctl_available = tokio::process::Command::new("smartctl")
+            .arg("--version")
+            .output()
+            .await
+            .is_ok_and(|output| output.status.success());
+        if !is_smartctl_available {
+            log::info!("smartctl isn't available, the SMART info won't be collected");
+            return;
+        }
+
+        loop {
+            // smartctl was called for each device, so it was run on the blocking thread pool
+            let Ok(smart_info_by_disk) = task::spawn_blocking(get_smart_info_by_disk).await else {
+                break;
+            };
+
+            // the app was closed
+            if tx.send(smart_info_by_disk).is_err() {
+                error!("Failed to send SMART Info, the channel was disconnected");
+                break;
+            }
+            time::sleep(SMART_REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+#[cfg(feature = "smart")]
+fn get_smart_info_by_disk() -> HashMap<String, SmartInfo> {
+    let disks = Disks::new_with_refreshed_list();
+    // the partitions of the same device share the same SMART data, so each device was only queried once
+    let mut smart_info_by_device: HashMap<String, Option<SmartInfo>> = HashMap::new();
+    let mut smart_info_by_disk = HashMap::new();
+    for disk in &disks {
+        let device = get_physical_device(&disk.name().to_string_lossy());
+        let smart_info = smart_info_by_device
+            .entry(device.clone())
+            .or_insert_with(|| query_smart_info(&device))
+            .clone();
+        if let Some(smart_info) = smart_info {
+            smart_info_by_disk.insert(disk.mount_point().to_string_lossy().to_string(), smart_info);
+        }
+    }
+    return smart_info_by_disk;
+}
+
+// how often should the btrfs file systems and ZFS pools be collected, `zpool` is too slow to be called every tick
+#[cfg(feature = "storage-pools")]
+const STORAGE_POOL_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+// collect the btrfs file systems and ZFS pools in a separated task
+#[cfg(feature = "storage-pools")]
+pub fn spawn_storage_pool_collector(tx: Sender<Vec<StoragePool>>) {
+    tokio::spawn(async move {
+        loop {
+            let collected = task::spawn_blocking(|| {
+                let mut storage_pools = get_zfs_pools();
+                storage_pools.extend(get_btrfs_pools());
+                return storage_pools;
+            })
+            .await;
+            let Ok(storage_pools) = collected else {
+                break;
+            };
+
+            // the app was closed
+            if tx.send(storage_pools).is_err() {
+                error!("Failed to send Storage Pools, the channel was disconnected");
+                break;
+            }
+            time::sleep(STORAGE_POOL_REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+// how often should the ping target be pinged, this is also the timeout of each ping
+#[cfg(feature = "ping")]
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+// the special ping target that will be resolved into the default gateway
+pub const PING_GATEWAY_TARGET: &str = "gateway";
+
+// ping the target continuously in a separated task using the system `ping` as raw socket require privilege
+// the task will exit right away if the default gateway can't be resolved
+#[cfg(feature = "ping")]
+pub fn spawn_ping_collector(target: String, tx: Sender<PingResult>) {
+    tokio::spawn(async move {
+        use tokio::process::Command;
+
+        let target = if target == PING_GATEWAY_TARGET {
+            match get_default_gateway() {
+                Some(gateway) => gateway,
+                None => {
+                    log::warn!("the default gateway can't be resolved, the ping won't be started");
+                    return;
+                }
+            }
+        } else {
+            target
+        };
+
+        loop {
+            let ping_start = Instant::now();
+            let mut command = Command::new("ping");
+            #[cfg(target_os = "windows")]
+            command.args(["-n", "1", "-w", "1000", &target]);
+            #[cfg(target_os = "macos")]
+            command.args(["-c", "1", "-t", "1", &target]);
+            #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+            command.args(["-c", "1", "-W", "1", &target]);
+            let rtt_ms = command
+                .output()
+                .await
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| parse_ping_rtt(&String::from_utf8_lossy(&output.stdout)));
+
+            let ping_result = PingResult {
+                target: target.clone(),
+                rtt_ms,
+            };
+            // the app was closed
+            if tx.send(ping_result).is_err() {
+                error!("Failed to send Ping Result, the channel was disconnected");
+                break;
+            }
+            time::sleep(PING_INTERVAL.saturating_sub(ping_start.elapsed())).await;
+        }
+    });
+}
+
+// get the round trip time in ms from the output of `ping`, eg: "time=12.3 ms" or "time<1ms" on Windows
+pub fn parse_ping_rtt(output: &str) -> Option<f64> {
+    let (_, rtt) = output
+        .split_once("time=")
+        .or_else(|| output.split_once("time<"))?;
+    let rtt: String = rtt
+        .chars()
+        .take_while(|char| char.is_ascii_digit() || *char == '.')
+        .collect();
+    return rtt.parse::<f64>().ok();
+}
+
+// get the default gateway from /proc/net/route, None on other platforms
+fn get_default_gateway() -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::{fs, net::Ipv4Addr};
+
+        let data = fs::read_to_string("/proc/net/route").ok()?;
+        // Iface Destination Gateway ..., the addresses are in little endian hex and the first line is the header
+        for line in data.lines().skip(1) {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 3 || parts[1] != "00000000" {
+                continue;
+            }
+            let gateway = u32::from_str_radix(parts[2], 16).ok()?;
+            return Some(Ipv4Addr::from(gateway.swap_bytes()).to_string());
+        }
+        return None;
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return None;
+}
+
+// how often should the public IP be looked up, to not spam the external service
+#[cfg(feature = "public-ip")]
+const PUBLIC_IP_LOOKUP_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+// look up the public IP in a separated task using the system `curl` as there is no http client in the dependencies
+// only the valid IP address will be send back, so a failed lookup will keep the previous one
+#[cfg(feature = "public-ip")]
+pub fn spawn_public_ip_collector(tx: Sender<String>) {
+    tokio::spawn(async move {
+        use std::net::IpAddr;
+        use tokio::process::Command;
+
+        loop {
+            let public_ip = Command::new("curl")
+                .args(["-s", "--max-time", "5", "https://api.ipify.org"])
+                .output()
+                .await
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+                .filter(|public_ip| public_ip.parse::<IpAddr>().is_ok());
+
+            if let Some(public_ip) = public_ip {
+                // the app was closed
+                if tx.send(public_ip).is_err() {
+                    error!("Failed to send Public IP, the channel was disconnected");
+                    break;
+                }
+            } else {
+                log::warn!("the public IP lookup failed");
+            }
+            time::sleep(PUBLIC_IP_LOOKUP_INTERVAL).await;
+        }
+    });
+}
+
+// how often should the threads of the process in the process detail be collected
+const THREAD_INFO_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+// the clock ticks per second of the cpu times in /proc/<pid>/task/<tid>/stat, which is 100 on all the common setups
+#[cfg(target_os = "linux")]
+const CLOCK_TICKS_PER_SECOND: f64 = 100.0;
+
+// collect the threads of the watched pid in a separated task, nothing was collected while the pid was None
+// so the threads were only read while they were shown in the process detail
+pub fn spawn_thread_info_collector(
+    mut pid_receiver: watch::Receiver<Option<u32>>,
+    tx: Sender<(u32, Vec<ThreadData>)>,
+) {
+    tokio::spawn(async move {
+        // the cpu time of each thread at the last collection, to get the cpu usage since then
+        let mut thread_cpu_times: HashMap<u64, u64> = HashMap::new();
+        let mut last_collected = Instant::now();
+
+        loop {
+            let Some(pid) = *pid_receiver.borrow_and_update() else {
+                thread_cpu_times.clear();
+                // the app was closed
+                if pid_receiver.changed().await.is_err() {
+                    break;
+                }
+                continue;
+            };
+
+            let elapsed = last_collected.elapsed();
+            last_collected = Instant::now();
+            let Ok((threads, cpu_times)) =
+                task::spawn_blocking(move || get_thread_info(pid, thread_cpu_times, elapsed)).await
+            else {
+                error!("The thread info collection was aborted");
+                break;
+            };
+            thread_cpu_times = cpu_times;
+
+            // the app was closed
+            if tx.send((pid, threads)).is_err() {
+                error!("Failed to send Thread Info, the channel was disconnected");
+                break;
+            }
+
+            // a new pid will be collected right away
+            tokio::select! {
+                is_changed = pid_receiver.changed() => {
+                    if is_changed.is_err() {
+                        break;
+                    }
+                }
+                _ = time::sleep(THREAD_INFO_REFRESH_INTERVAL) => {}
+            }
+        }
+    });
+}
+
+// how often should the OS light/dark preference be checked while the theme is "system"
+const SYSTEM_APPEARANCE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+// check the OS light/dark preference in a separated task, as the check spawn a command that can block for seconds
+// ( eg: gsettings waiting for dbus in a ssh session ), nothing was checked while the theme isn't "system"
+pub fn spawn_system_appearance_collector(
+    mut is_system_theme_receiver: watch::Receiver<bool>,
+    tx: Sender<Option<ThemeAppearance>>,
+) {
+    tokio::spawn(async move {
+        loop {
+            if !*is_system_theme_receiver.borrow_and_update() {
+                // the app was closed
+                if is_system_theme_receiver.changed().await.is_err() {
+                    break;
+                }
+                continue;
+            }
+
+            let Ok(system_appearance) = task::spawn_blocking(get_system_appearance).await else {
+                error!("The system appearance check was aborted");
+                break;
+            };
+            // the app was closed
+            if tx.send(system_appearance).is_err() {
+                error!("Failed to send System Appearance, the channel was disconnected");
+                break;
+            }
+            time::sleep(SYSTEM_APPEARANCE_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+// the threads of the process and their cpu times, the cpu usage is calculated from the cpu times
+// of the last collection, so a new thread shows 0% until the next collection
+// always empty on windows
+#[allow(unused_variables)]
+fn get_thread_info(
+    pid: u32,
+    last_thread_cpu_times: HashMap<u64, u64>,
+    elapsed: Duration,
+) -> (Vec<ThreadData>, HashMap<u64, u64>) {
+    let mut threads = vec![];
+    let mut thread_cpu_times = HashMap::new();
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(entries) = std::fs::read_dir(format!("/proc/{}/task", pid)) {
+            for entry in entries.flatten() {
+                let Some(tid) = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|tid| tid.parse::<u64>().ok())
+                else {
+                    continue;
+                };
+                let Ok(stat) = std::fs::read_to_string(entry.path().join("stat")) else {
+                    continue;
+                };
+                let Some((name, state, cpu_ticks)) = parse_thread_stat(&stat) else {
+                    continue;
+                };
+
+                let cpu_usage = match last_thread_cpu_times.get(&tid) {
+                    Some(last_cpu_ticks) if elapsed.as_secs_f64() > 0.0 => {
+                        (cpu_ticks.saturating_sub(*last_cpu_ticks) as f64
+                            / CLOCK_TICKS_PER_SECOND
+                            / elapsed.as_secs_f64()
+                            * 100.0) as f32
+                    }
+                    _ => 0.0,
+                };
+                thread_cpu_times.insert(tid, cpu_ticks);
+                threads.push(ThreadData {
+                    tid,
+                    name,
+                    state,
+                    cpu_usage,
+                });
+            }
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use libproc::{
+            proc_pid::{listpidinfo, pidinfo, ListThreads},
+            task_info::TaskInfo,
+            thread_info::ThreadInfo,
+        };
+
+        // the kernel already provides the recent cpu usage of each thread, scaled to 1000
+        if let Ok(task_info) = pidinfo::<TaskInfo>(pid as i32, 0) {
+            let thread_handles =
+                listpidinfo::<ListThreads>(pid as i32, task_info.pti_threadnum as usize)
+                    .unwrap_or_default();
+            for thread_handle in thread_handles {
+                let Ok(thread_info) = pidinfo::<ThreadInfo>(pid as i32, thread_handle) else {
+                    continue;
+                };
+                let name: String = thread_info
+                    .pth_name
+                    .iter()
+                    .take_while(|c| **c != 0)
+                    .map(|c| *c as u8 as char)
+                    .collect();
+                let state = match thread_info.pth_run_state {
+                    1 => "Running",
+                    2 => "Stopped",
+                    3 => "Waiting",
+                    4 => "Uninterruptible",
+                    5 => "Halted",
+                    _ => "Unknown",
+                };
+                threads.push(ThreadData {
+                    tid: thread_handle,
+                    name,
+                    state: state.to_string(),
+                    cpu_usage: thread_info.pth_cpu_usage as f32 / 10.0,
+                });
+            }
+        }
+    }
+
+    return (threads, thread_cpu_times);
+}
+
+// the name, state and cpu time ( utime + stime in clock ticks ) from /proc/<pid>/task/<tid>/stat
+// eg: "1234 (tokio-runtime-w) S 1 1234 1234 0 -1 4194560 110 0 0 0 25 12 0 0 20 0 8 0 ..."
+// the name can contain spaces and parentheses, so the fields were split after the last ')'
+pub fn parse_thread_stat(stat: &str) -> Option<(String, String, u64)> {
+    let name_start = stat.find('(')?;
+    let name_end = stat.rfind(')')?;
+    let name = stat.get(name_start + 1..name_end)?.to_string();
+    let fields: Vec<&str> = stat.get(name_end + 1..)?.split_whitespace().collect();
+
+    let state = match *fields.first()? {
+        "R" => "Running",
+        "S" => "Sleeping",
+        "D" => "Disk Sleep",
+        "Z" => "Zombie",
+        "T" => "Stopped",
+        "t" => "Tracing",
+        "X" => "Dead",
+        "I" => "Idle",
+        _ => "Unknown",
+    };
+    let user_time = fields.get(11)?.parse::<u64>().ok()?;
+    let system_time = fields.get(12)?.parse::<u64>().ok()?;
+
+    return Some((name, state.to_string(), user_time + system_time));
+}
+
+// get the number of login sessions from `who`, None on windows or if `who` isn't available
+fn get_logged_in_user_count() -> Option<usize> {
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::process::Command;
+
+        let output = Command::new("who").output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        return Some(parse_who(&String::from_utf8_lossy(&output.stdout)));
+    }
+
+    #[cfg(target_os = "windows")]
+    return None;
+}
+
+// each line of `who` is a login session, eg: "alice    pts/0        2024-01-01 10:00 (10.0.0.2)"
+pub fn parse_who(data: &str) -> usize {
+    return data.lines().filter(|line| !line.trim().is_empty()).count();
+}
+
+// get the first nameserver in /etc/resolv.conf, None on windows
+fn get_primary_dns() -> Option<String> {
+    #[cfg(not(target_os = "windows"))]
+    {
+        let data = std::fs::read_to_string("/etc/resolv.conf").ok()?;
+        return parse_resolv_conf(&data);
+    }
+
+    #[cfg(target_os = "windows")]
+    return None;
+}
+
+// the first "nameserver 1.1.1.1" line, the comments start with # or ;
+pub fn parse_resolv_conf(data: &str) -> Option<String> {
+    for line in data.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() >= 2 && parts[0] == "nameserver" {
+            return Some(parts[1].to_string());
+        }
+    }
+    return None;
+}
+
+// get the ZFS pools from `zpool list` and `zpool status`, empty if zpool isn't installed
+#[cfg(feature = "storage-pools")]
+fn get_zfs_pools() -> Vec<StoragePool> {
+    use std::process::Command;
+
+    let Ok(output) = Command::new("zpool")
+        .args(["list", "-Hp", "-o", "name,size,alloc,health"])
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let mut storage_pools = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() < 4 {
+            continue;
+        }
+        let (raid_level, scrub_status) =
+            match Command::new("zpool").args(["status", parts[0]]).output() {
+                Ok(status_output) => {
+                    parse_zpool_status(parts[0], &String::from_utf8_lossy(&status_output.stdout))
+                }
+                Err(_) => ("".to_string(), None),
+            };
+        storage_pools.push(StoragePool {
+            name: parts[0].to_string(),
+            kind: "zfs".to_string(),
+            raid_level,
+            devices: Vec::new(),
+            size: parts[1].parse::<f64>().unwrap_or(0.0),
+            allocated: parts[2].parse::<f64>().unwrap_or(0.0),
+            health: Some(parts[3].to_string()),
+            scrub_status,
+        });
+    }
+    return storage_pools;
+}
+
+// get the ( raid level, scrub status ) of a pool from the output of `zpool status`, eg:
+//   scan: scrub repaired 0B in 00:01:02 with 0 errors on Sun Oct 11 00:25:03 2026
+// config:
+//         NAME        STATE     READ WRITE CKSUM
+//         tank        ONLINE       0     0     0
+//           mirror-0  ONLINE       0     0     0
+pub fn parse_zpool_status(pool_name: &str, data: &str) -> (String, Option<String>) {
+    let scrub_status = data.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("scan:")
+            .map(|scan| scan.trim().to_string())
+    });
+
+    // the first vdev under the pool, the disks that are directly under the pool are striped
+    let mut raid_level = "".to_string();
+    let mut lines = data.lines();
+    if lines
+        .by_ref()
+        .any(|line| line.split_whitespace().next() == Some(pool_name))
+    {
+        if let Some(vdev) = lines.next().and_then(|line| line.split_whitespace().next()) {
+            raid_level = if ["mirror", "raidz", "draid"]
+                .iter()
+                .any(|kind| vdev.starts_with(kind))
+            {
+                // mirror-0 -> mirror, raidz2-0 -> raidz2
+                vdev.rsplit_once('-')
+                    .map(|(level, _)| level)
+                    .unwrap_or(vdev)
+                    .to_string()
+            } else {
+                "stripe".to_string()
+            };
+        }
+    }
+    return (raid_level, scrub_status);
+}
+
+// get the btrfs file systems from /sys/fs/btrfs, empty if btrfs isn't used or on other platforms
+#[cfg(feature = "storage-pools")]
+fn get_btrfs_pools() -> Vec<StoragePool> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(entries) = fs::read_dir("/sys/fs/btrfs") else {
+            return Vec::new();
+        };
+        let read_bytes = |path: &Path| -> f64 {
+            fs::read_to_string(path)
+                .ok()
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .unwrap_or(0.0)
+        };
+
+        let mut storage_pools = Vec::new();
+        for entry in entries.flatten() {
+            let fs_path = entry.path();
+            // the file systems are the uuid directories, the others ( features ) are skipped
+            if !fs_path.join("allocation").is_dir() {
+                continue;
+            }
+            let uuid = entry.file_name().to_string_lossy().to_string();
+            let label = fs::read_to_string(fs_path.join("label"))
+                .map(|label| label.trim().to_string())
+                .unwrap_or_default();
+
+            // the member devices, the size of a block device is in 512 bytes sectors
+            let mut devices = Vec::new();
+            let mut size = 0.0;
+            if let Ok(device_entries) = fs::read_dir(fs_path.join("devices")) {
+                for device_entry in device_entries.flatten() {
+                    size += read_bytes(&device_entry.path().join("size")) * 512.0;
+                    devices.push(device_entry.file_name().to_string_lossy().to_string());
+                }
+            }
+
+            // the raw space allocated for data, metadata and system chunks, including the redundant copies
+            let allocated: f64 = ["data", "metadata", "system"]
+                .iter()
+                .map(|chunk| read_bytes(&fs_path.join("allocation").join(chunk).join("disk_total")))
+                .sum();
+
+            // the data profile is a sub directory of the data allocation, eg: allocation/data/raid1
+            let raid_level = fs::read_dir(fs_path.join("allocation/data"))
+                .ok()
+                .and_then(|profile_entries| {
+                    profile_entries
+                        .flatten()
+                        .find(|profile_entry| profile_entry.path().is_dir())
+                        .map(|profile_entry| {
+                            profile_entry.file_name().to_string_lossy().to_string()
+                        })
+                })
+                .unwrap_or_default();
+
+            storage_pools.push(StoragePool {
+                name: if label.is_empty() { uuid } else { label },
+                kind: "btrfs".to_string(),
+                raid_level,
+                devices,
+                size,
+                allocated,
+                health: None,
+                scrub_status: None,
+            });
+        }
+        return storage_pools;
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return Vec::new();
+}
+
+// the physical device of a partition, eg: /dev/sda1 -> /dev/sda, /dev/nvme0n1p2 -> /dev/nvme0n1
+fn get_physical_device(disk_name: &str) -> String {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        if let Some(name) = disk_name.strip_prefix("/dev/") {
+            // the partition is a sub directory of its device in sysfs
+            let sys_path = Path::new("/sys/class/block").join(name);
+            if sys_path.join("partition").exists() {
+                let device = fs::canonicalize(&sys_path).ok().and_then(|path| {
+                    path.parent()
+                        .and_then(|parent| parent.file_name())
+                        .map(|device| device.to_string_lossy().to_string())
+                });
+                if let Some(device) = device {
+                    return format!("/dev/{}", device);
+                }
+            }
+        }
+    }
+
+    return disk_name.to_string();
+}
+
+// the counters from /proc/diskstats keyed by the device name, in the order of
+// ( reads completed, time reading, writes completed, time writing, time doing io, weighted time doing io ), times are in ms
+type DiskStatsCounters = HashMap<String, [u64; 6]>;
+
+fn get_disk_stats_counters() -> DiskStatsCounters {
+    let mut disk_stats = HashMap::new();
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(data) = fs::read_to_string("/proc/diskstats") else {
+            return disk_stats;
+        };
+        for line in data.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 14 {
+                continue;
+            }
+            let mut counters = [0_u64; 6];
+            for (counter, index) in counters.iter_mut().zip([3, 6, 7, 10, 12, 13]) {
+                *counter = fields[index].parse::<u64>().unwrap_or(0);
+            }
+            disk_stats.insert(fields[2].to_string(), counters);
+        }
+    }
+
+    return disk_stats;
+}
+
+// calculate the io latency and utilization of the disk from the counters of the previous and current collection
+// None if the device can't be found in /proc/diskstats ( eg: network file system ) or on other platforms
+fn get_disk_io_stats(
+    disk_name: &str,
+    previous_disk_stats: &DiskStatsCounters,
+    current_disk_stats: &DiskStatsCounters,
+    elapsed_ms: f64,
+) -> Option<DiskIoStats> {
+    if elapsed_ms <= 0.0 {
+        return None;
+    }
+
+    // the device mapper disks are symlinks to the dm-X devices, eg: /dev/mapper/root -> /dev/dm-0
+    let device_path = std::fs::canonicalize(disk_name).unwrap_or(disk_name.into());
+    let device_name = device_path.file_name()?.to_string_lossy().to_string();
+    let previous = previous_disk_stats.get(&device_name)?;
+    let current = current_disk_stats.get(&device_name)?;
+    let delta: Vec<f64> = current
+        .iter()
+        .zip(previous.iter())
+        .map(|(current, previous)| current.saturating_sub(*previous) as f64)
+        .collect();
+
+    let completed_ios = delta[0] + delta[2];
+    let await_ms = if completed_ios > 0.0 {
+        (delta[1] + delta[3]) / completed_ios
+    } else {
+        0.0
+    };
+    return Some(DiskIoStats {
+        await_ms,
+        queue_depth: delta[5] / elapsed_ms,
+        utilization: (delta[4] / elapsed_ms * 100.0).min(100.0),
+    });
+}
+
+// get the software RAID arrays from /proc/mdstat, empty if md isn't used or on other platforms
+fn get_raid_arrays() -> Vec<RaidArray> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(data) = fs::read_to_string("/proc/mdstat") else {
+            return Vec::new();
+        };
+        return parse_mdstat(&data);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return Vec::new();
+}
+
+// each array start with a "md0 : active raid1 sdb1[1] sda1[0]" line, followed by the indented status lines, eg:
+//       1046528 blocks super 1.2 [2/1] [U_]
+//       [==>..................]  recovery = 12.6% (131072/1046528) finish=0.5min speed=26214K/sec
+pub fn parse_mdstat(data: &str) -> Vec<RaidArray> {
+    let mut raid_arrays: Vec<RaidArray> = Vec::new();
+    for line in data.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            let Some((name, detail)) = line.split_once(" : ") else {
+                continue;
+            };
+            if !name.starts_with("md") {
+                continue;
+            }
+            let mut parts: Vec<&str> = detail.split_whitespace().collect();
+            let mut state = if parts.is_empty() {
+                "".to_string()
+            } else {
+                parts.remove(0).to_string()
+            };
+            // eg: active (auto-read-only) raid1 ...
+            while !parts.is_empty() && parts[0].starts_with('(') {
+                state = format!("{} {}", state, parts.remove(0));
+            }
+            // an inactive array doesn't have a level
+            let level = if !parts.is_empty() && !parts[0].contains('[') {
+                parts.remove(0).to_string()
+            } else {
+                "".to_string()
+            };
+            // sda1[0](F) -> sda1(F)
+            let members = parts
+                .iter()
+                .map(|member| {
+                    let (member_name, rest) = member.split_once('[').unwrap_or((member, ""));
+                    let flag = rest.split_once(']').map(|(_, flag)| flag).unwrap_or("");
+                    format!("{}{}", member_name, flag)
+                })
+                .collect();
+            raid_arrays.push(RaidArray {
+                name: name.trim().to_string(),
+                level,
+                state,
+                members,
+                device_count: (0, 0),
+                sync_progress: None,
+            });
+            continue;
+        }
+
+        let Some(raid_array) = raid_arrays.last_mut() else {
+            continue;
+        };
+        // the [total/working] device count
+        if let Some(device_count) = line.split_whitespace().find_map(|part| {
+            let (total, working) = part.strip_prefix('[')?.strip_suffix(']')?.split_once('/')?;
+            Some((total.parse::<u32>().ok()?, working.parse::<u32>().ok()?))
+        }) {
+            raid_array.device_count = device_count;
+        }
+        // the progress of recovery, resync, reshape or check
+        if let Some((action, progress)) = line.split_once(" = ") {
+            let action = action.split_whitespace().last().unwrap_or("").to_string();
+            if let Some(percentage) = progress
+                .split_whitespace()
+                .next()
+                .and_then(|percentage| percentage.strip_suffix('%'))
+                .and_then(|percentage| percentage.parse::<f64>().ok())
+            {
+                raid_array.sync_progress = Some((action, percentage));
+            }
+        }
+    }
+    return raid_arrays;
+}
+
+// the negotiated ( speed in Mb/s, duplex ) of a network interface from /sys/class/net, None on other platforms
+// reading the speed of a virtual interface or an interface that is down will fail or return -1
+fn get_network_link_info(interface_name: &str) -> (Option<u64>, Option<String>) {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let interface_path = Path::new("/sys/class/net").join(interface_name);
+        let speed = fs::read_to_string(interface_path.join("speed"))
+            .ok()
+            .and_then(|speed| speed.trim().parse::<i64>().ok())
+            .filter(|speed| *speed > 0)
+            .map(|speed| speed as u64);
+        let duplex = fs::read_to_string(interface_path.join("duplex"))
+            .ok()
+            .map(|duplex| duplex.trim().to_string())
+            .filter(|duplex| duplex == "full" || duplex == "half");
+        return (speed, duplex);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return (None, None);
+}
+
+// the cumulative ( bytes sent, bytes received ) of each established TCP connection keyed by ( local, remote ),
+// from the tcp_info of `ss`, empty if `ss` isn't available, on other platforms or without the connections feature
+fn get_connection_counters() -> HashMap<(String, String), (u64, u64)> {
+    #[cfg(all(target_os = "linux", feature = "connections"))]
+    {
+        use std::process::Command;
+
+        if let Ok(output) = Command::new("ss")
+            .args(["-tinH", "state", "established"])
+            .output()
+        {
+            if output.status.success() {
+                return parse_ss_connections(&String::from_utf8_lossy(&output.stdout));
+            }
+        }
+    }
+
+    return HashMap::new();
+}
+
+// each connection is a "0 0 192.168.1.2:22 192.168.1.5:51234" line followed by an indented tcp_info line, eg:
+//          cubic wscale:7,7 rto:204 ... bytes_sent:5120 bytes_acked:5120 bytes_received:2048 ...
+// the bytes_sent was added in newer kernel, fallback to bytes_acked
+pub fn parse_ss_connections(data: &str) -> HashMap<(String, String), (u64, u64)> {
+    let mut connection_counters = HashMap::new();
+    let mut current_connection: Option<(String, String)> = None;
+    for line in data.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            current_connection = if parts.len() >= 4 {
+                Some((
+                    parts[parts.len() - 2].to_string(),
+                    parts[parts.len() - 1].to_string(),
+                ))
+            } else {
+                None
+            };
+            continue;
+        }
+
+        let Some(connection) = current_connection.take() else {
+            continue;
+        };
+        let get_counter = |name: &str| -> Option<u64> {
+            line.split_whitespace()
+                .find_map(|part| part.strip_prefix(name))
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+        let bytes_sent = get_counter("bytes_sent:")
+            .or(get_counter("bytes_acked:"))
+            .unwrap_or(0);
+        let bytes_received = get_counter("bytes_received:").unwrap_or(0);
+        connection_counters.insert(connection, (bytes_sent, bytes_received));
+    }
+    return connection_counters;
+}
+
+// the cumulative ( received, transmitted ) dropped packets of each network interface from /sys/class/net, empty on other platforms
+fn get_network_drop_counters() -> HashMap<String, (u64, u64)> {
+    let mut network_drops = HashMap::new();
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(entries) = fs::read_dir("/sys/class/net") else {
+            return network_drops;
+        };
+        let read_counter = |path: &Path| -> Option<u64> {
+            fs::read_to_string(path).ok()?.trim().parse::<u64>().ok()
+        };
+        for entry in entries.flatten() {
+            let statistics_path = entry.path().join("statistics");
+            if let (Some(dropped_received), Some(dropped_transmitted)) = (
+                read_counter(&statistics_path.join("rx_dropped")),
+                read_counter(&statistics_path.join("tx_dropped")),
+            ) {
+                network_drops.insert(
+                    entry.file_name().to_string_lossy().to_string(),
+                    (dropped_received, dropped_transmitted),
+                );
+            }
+        }
+    }
+
+    return network_drops;
+}
+
+// get the drive temperature in celsius from the hwmon of the physical device
+// nvme expose it under device/hwmonX while sata drives need the drivetemp module and expose it under device/hwmon/hwmonX
+// None on other platforms or without the sensors feature
+fn get_disk_temperature(disk_name: &str) -> Option<f64> {
+    #[cfg(all(target_os = "linux", feature = "sensors"))]
+    {
+        use std::fs;
+
+        let device = get_physical_device(disk_name);
+        let device_path = Path::new("/sys/block")
+            .join(device.strip_prefix("/dev/")?)
+            .join("device");
+        for hwmon_parent in [device_path.clone(), device_path.join("hwmon")] {
+            let Ok(entries) = fs::read_dir(&hwmon_parent) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if !entry.file_name().to_string_lossy().starts_with("hwmon") {
+                    continue;
+                }
+                // the temperature is in millidegree celsius
+                if let Some(temperature) = fs::read_to_string(entry.path().join("temp1_input"))
+                    .ok()
+                    .and_then(|temperature| temperature.trim().parse::<f64>().ok())
+                {
+                    return Some(temperature / 1000.0);
+                }
+            }
+        }
+        return None;
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "sensors")))]
+    {
+        let _ = disk_name;
+        return None;
+    }
+}
+
+// query smartctl for the overall health, reallocated sectors and wear level of the device
+// None if the device doesn't support SMART ( eg: virtual disks ) or permission was denied
+#[cfg(feature = "smart")]
+fn query_smart_info(device: &str) -> Option<SmartInfo> {
+    use std::process::Command;
+
+    let output = Command::new("smartctl")
+        .arg("--json")
+        .arg("-H")
+        .arg("-A")
+        .arg(device)
+        .output()
+        .ok()?;
+    // smartctl use the exit status as a bit mask of the problems found, so the json was parsed regardless of it
+    let smart = serde_json::from_slice::<serde_json::Value>(&output.stdout).ok()?;
+
+    let is_healthy = smart["smart_status"]["passed"].as_bool();
+    let mut reallocated_sectors = None;
+    let mut wear_level = None;
+    if let Some(attributes) = smart["ata_smart_attributes"]["table"].as_array() {
+        for attribute in attributes {
+            match attribute["id"].as_u64() {
+                Some(5) => reallocated_sectors = attribute["raw"]["value"].as_u64(),
+                // the normalized value of the wear leveling attributes count down from 100
+                Some(177) | Some(231) | Some(233) => {
+                    if wear_level.is_none() {
+                        wear_level = attribute["value"]
+                            .as_u64()
+                            .map(|value| 100_u64.saturating_sub(value));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    if let Some(percentage_used) =
+        smart["nvme_smart_health_information_log"]["percentage_used"].as_u64()
+    {
+        wear_level = Some(percentage_used);
+    }
+
+    let temperature = smart["temperature"]["current"].as_f64();
+
+    if is_healthy.is_none()
+        && reallocated_sectors.is_none()
+        && wear_level.is_none()
+        && temperature.is_none()
+    {
+        return None;
+    }
+    return Some(SmartInfo {
+        device: device.to_string(),
+        is_healthy,
+        reallocated_sectors,
+        wear_level,
+        temperature,
+    });
+}
+
+pub fn get_static_system_info() -> StaticSystemInfo {
+    return StaticSystemInfo {
+        host_name: System::host_name().unwrap_or("unknown".to_string()),
+        os_name: System::long_os_version()
+            .or(System::name())
+            .unwrap_or("unknown".to_string()),
+        kernel_version: System::kernel_version().unwrap_or("unknown".to_string()),
+        architecture: System::cpu_arch(),
+    };
+}
+
+// what the process was when its full info was last sent, the full info will only be sent again when it changed
+// a different start time means the pid was reused by another process
+#[derive(PartialEq)]
+struct ProcessIdentity {
+    start_time: u64,
+    name: OsString,
+    exe: Option<PathBuf>,
+    cmd: Vec<OsString>,
+    user_id: Option<Uid>,
+    parent: Option<Pid>,
+}
+
+impl ProcessIdentity {
+    fn new(process: &Process) -> ProcessIdentity {
+        return ProcessIdentity {
+            start_time: process.start_time(),
+            name: process.name().to_os_string(),
+            exe: process.exe().map(|exe| exe.to_path_buf()),
+            cmd: process.cmd().to_vec(),
+            user_id: process.user_id().cloned(),
+            parent: process.parent(),
+        };
+    }
+
+    // compared without copying, as this was checked for every process on every collection
+    fn is_same_process(&self, process: &Process) -> bool {
+        return self.start_time == process.start_time()
+            && self.name.as_os_str() == process.name()
+            && self.exe.as_deref() == process.exe()
+            && self.cmd.as_slice() == process.cmd()
+            && self.user_id.as_ref() == process.user_id()
+            && self.parent == process.parent();
+    }
+}
+
+// the user names of the processes, resolved once for each process
+// the users list was only refreshed when a user id that wasn't in it appears ( eg: a user was added )
+struct UserNameCache {
+    users: Users,
+    user_names: HashMap<u32, (Uid, String)>, // pid to the user id and the name it was resolved to
+    unknown_user_ids: HashSet<Uid>, // the user ids that were still unknown after the refresh, so they won't refresh it again
+}
+
+impl UserNameCache {
+    fn new() -> UserNameCache {
+        return UserNameCache {
+            users: Users::new_with_refreshed_list(),
+            user_names: HashMap::new(),
+            unknown_user_ids: HashSet::new(),
+        };
+    }
+
+    // the process without a user, or with an unknown user was shown as root
+    fn get_user_name(&mut self, pid: u32, user_id: Option<&Uid>) -> String {
+        let Some(user_id) = user_id else {
+            return "root".to_string();
+        };
+        if let Some((cached_user_id, user_name)) = self.user_names.get(&pid) {
+            if cached_user_id == user_id {
+                return user_name.clone();
+            }
+        }
+
+        if self.users.get_user_by_id(user_id).is_none() && !self.unknown_user_ids.contains(user_id)
+        {
+            self.users.refresh();
+            self.unknown_user_ids.clear();
+        }
+        let user_name = match self.users.get_user_by_id(user_id) {
+            Some(user) => user.name().to_string(),
+            None => {
+                self.unknown_user_ids.insert(user_id.clone());
+                "root".to_string()
+            }
+        };
+        self.user_names
+            .insert(pid, (user_id.clone(), user_name.clone()));
+        return user_name;
+    }
+
+    fn remove(&mut self, pid: u32) {
+        self.user_names.remove(&pid);
+    }
+}
+
+// the state kept between the process info collections
+struct ProcessInfoCollector {
+    sys: System,
+    user_name_cache: UserNameCache,
+    // the processes whose full info was sent, to only send the changes on the next collection
+    sent_processes: HashMap<u32, ProcessIdentity>,
+    collection_count: u32,
+    // cache of container id to the metadata queried from the container runtime
+    // so we don't need to query the runtime for every process on every refresh
+    #[cfg(target_os = "linux")]
+    container_info_cache: HashMap<String, (Instant, ContainerInfo)>,
+    // the thread counts and base priorities of the processes and the time they were taken from the process snapshot
+    // as they were refreshed at their own interval
+    #[cfg(target_os = "windows")]
+    win_thread_counts: HashMap<u32, u32>,
+    #[cfg(target_os = "windows")]
+    win_base_priorities: HashMap<u32, i32>,
+    #[cfg(target_os = "windows")]
+    win_process_snapshot_last_refreshed: Instant,
+}
+
+impl ProcessInfoCollector {
+    fn new() -> ProcessInfoCollector {
+        let mut sys = System::new_all();
+        sys.refresh_all();
+        #[cfg(target_os = "windows")]
+        let (win_thread_counts, win_base_priorities) = get_win_process_snapshot();
+        return ProcessInfoCollector {
+            sys,
+            user_name_cache: UserNameCache::new(),
+            sent_processes: HashMap::new(),
+            collection_count: 0,
+            #[cfg(target_os = "linux")]
+            container_info_cache: HashMap::new(),
+            #[cfg(target_os = "windows")]
+            win_thread_counts,
+            #[cfg(target_os = "windows")]
+            win_base_priorities,
+            #[cfg(target_os = "windows")]
+            win_process_snapshot_last_refreshed: Instant::now(),
+        };
+    }
+
+    // the blocking collection, it was run on the blocking thread pool of the runtime
+    fn collect(&mut self) -> CProcessesInfo {
+        self.sys.refresh_processes(ProcessesToUpdate::All, true);
+        let mut processes = vec![];
+        let mut updated_processes = vec![];
+
+        // the full info of all the processes was sent every PROCESS_SNAPSHOT_INTERVAL collections,
+        // so the processes will be back in sync even if the full info of a process was never received
+        let is_snapshot = self
+            .collection_count
+            .is_multiple_of(PROCESS_SNAPSHOT_INTERVAL);
+        self.collection_count = self.collection_count.wrapping_add(1);
+
+        #[cfg(target_os = "linux")]
+        let is_delay_accounting_enabled = is_linux_delay_accounting_enabled();
+
+        // remove the outdated container metadata, it will be queried again when needed
+        #[cfg(target_os = "linux")]
+        self.container_info_cache.retain(|_, (last_queried, _)| {
+            last_queried.elapsed() < CONTAINER_INFO_REFRESH_INTERVAL
+        });
+
+        // the thread counts were refreshed less often than the other metrics, a new process that isn't
+        // in the thread counts yet will be shown with a placeholder until the next refresh
+        #[cfg(target_os = "windows")]
+        if self.win_process_snapshot_last_refreshed.elapsed() >= WIN_THREAD_COUNT_REFRESH_INTERVAL {
+            (self.win_thread_counts, self.win_base_priorities) = get_win_process_snapshot();
+            self.win_process_snapshot_last_refreshed = Instant::now();
+        }
+
+        // processes in the same cgroup share the same cpu.stat, so we only read it once per collection
+        #[cfg(target_os = "linux")]
+        let mut cpu_throttle_info_cache: HashMap<String, Option<CpuThrottleInfo>> = HashMap::new();
+        // -------------------------------------------
+        //
+        //          PROCESS INFO COLLECTION
+        //
+        // -------------------------------------------
+        for (pid, process) in self.sys.processes() {
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
+
+            #[cfg(target_os = "windows")]
+            let thread_count =
+                get_thread_count(pid.as_u32() as i32, &process, Some(&self.win_thread_counts));
+
+            let process_disk_usage = process.disk_usage();
+
+            #[cfg(target_os = "linux")]
+            let cgroup = std::fs::read_to_string(format!("/proc/{}/cgroup", pid.as_u32()))
+                .unwrap_or_default();
+            // /proc/<pid>/stat was read once for both the nice value and the block io wait time
+            #[cfg(target_os = "linux")]
+            let stat =
+                std::fs::read_to_string(format!("/proc/{}/stat", pid.as_u32())).unwrap_or_default();
+
+            let process_update = CProcessUpdate {
+                pid: pid.as_u32(),
+                cpu_usage: process.cpu_usage(),
+                thread_count,
+                memory: process.memory() as f64,
+                virtual_memory: process.virtual_memory() as f64,
+                #[cfg(target_os = "linux")]
+                shared_memory: get_linux_process_shared_memory(pid.as_u32()),
+                #[cfg(not(target_os = "linux"))]
+                shared_memory: None,
+                #[cfg(target_os = "linux")]
+                oom_score: get_linux_process_oom_score(pid.as_u32()),
+                #[cfg(not(target_os = "linux"))]
+                oom_score: None,
+                #[cfg(target_os = "linux")]
+                nice: parse_stat_nice(&stat),
+                #[cfg(target_os = "macos")]
+                nice: get_process_nice(pid.as_u32()),
+                #[cfg(target_os = "windows")]
+                nice: get_win_process_nice(pid.as_u32(), &self.win_base_priorities),
+                status: process.status().to_string(),
+                elapsed: process.run_time(),
+                cpu_time: process.accumulated_cpu_time(),
+                current_read_disk_usage: process_disk_usage.read_bytes,
+                total_read_disk_usage: process_disk_usage.total_read_bytes,
+                current_write_disk_usage: process_disk_usage.written_bytes,
+                total_write_disk_usage: process_disk_usage.total_written_bytes,
+                #[cfg(target_os = "linux")]
+                delay_info: get_linux_process_delay_info(
+                    pid.as_u32(),
+                    &stat,
+                    is_delay_accounting_enabled,
+                ),
+                #[cfg(not(target_os = "linux"))]
+                delay_info: None,
+                #[cfg(target_os = "linux")]
+                cpu_throttle_info: get_linux_cgroup_cpu_throttle_info(
+                    &cgroup,
+                    &mut cpu_throttle_info_cache,
+                ),
+                #[cfg(not(target_os = "linux"))]
+                cpu_throttle_info: None,
+            };
+
+            // only the metrics were sent if the process was the same as the one that was sent before
+            let is_sent = match self.sent_processes.get(&pid.as_u32()) {
+                Some(process_identity) => process_identity.is_same_process(process),
+                None => false,
+            };
+            if is_sent && !is_snapshot {
+                updated_processes.push(process_update);
+                continue;
+            }
+
+            let user = self
+                .user_name_cache
+                .get_user_name(pid.as_u32(), process.user_id());
+
+            let process_info = CProcessData {
+                pid: pid.as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                exe_path: if process.exe().is_some() {
+                    Some(process.exe().unwrap().to_string_lossy().to_string())
+                } else {
+                    None
+                },
+                cmd: process
+                    .cmd()
+                    .into_iter()
+                    .map(|osstr| osstr.to_string_lossy().to_string())
+                    .collect(),
+                user,
+                cpu_usage: process_update.cpu_usage,
+                thread_count: process_update.thread_count,
+                memory: process_update.memory,
+                virtual_memory: process_update.virtual_memory,
+                shared_memory: process_update.shared_memory,
+                oom_score: process_update.oom_score,
+                nice: process_update.nice,
+                status: process_update.status,
+                elapsed: process_update.elapsed,
+                cpu_time: process_update.cpu_time,
+                parent: if process.parent().is_some() {
+                    format!("{:?}", process.parent().unwrap().as_u32())
+                } else {
+                    "-".to_string()
+                },
+                current_read_disk_usage: process_update.current_read_disk_usage,
+                total_read_disk_usage: process_update.total_read_disk_usage,
+                current_write_disk_usage: process_update.current_write_disk_usage,
+                total_write_disk_usage: process_update.total_write_disk_usage,
+                delay_info: process_update.delay_info,
+                #[cfg(target_os = "linux")]
+                container_info: get_linux_process_container_info(
+                    &cgroup,
+                    &mut self.container_info_cache,
+                ),
+                #[cfg(not(target_os = "linux"))]
+                container_info: None,
+                #[cfg(target_os = "linux")]
+                cgroup: get_cgroup_name(&cgroup),
+                #[cfg(not(target_os = "linux"))]
+                cgroup: None,
+                cpu_throttle_info: process_update.cpu_throttle_info,
+            };
+
+            if !is_sent {
+                self.sent_processes
+                    .insert(pid.as_u32(), ProcessIdentity::new(process));
+            }
+            processes.push(process_info);
+        }
+
+        // the processes that were sent before but are no longer running
+        let running_processes = self.sys.processes();
+        let removed_pids: Vec<u32> = self
+            .sent_processes
+            .keys()
+            .filter(|pid| !running_processes.contains_key(&Pid::from_u32(**pid)))
+            .copied()
+            .collect();
+        for pid in removed_pids.iter() {
+            self.sent_processes.remove(pid);
+            self.user_name_cache.remove(*pid);
+        }
+
+        return CProcessesInfo {
+            is_snapshot,
+            processes,
+            updated_processes,
+            removed_pids,
+        };
+    }
+}
+
+// collect the process info at its own tick, as it was much slower to collect than the system info
+pub fn spawn_process_info_collector(
+    tick_receiver: watch::Receiver<u32>,
+    tx: UnboundedSender<CProcessesInfo>,
+) {
+    tokio::spawn(async move {
+        let Ok(collector) = task::spawn_blocking(ProcessInfoCollector::new).await else {
+            error!("Failed to start the process info collector");
+            return;
+        };
+        run_collector(
+            "process info",
+            collector,
+            ProcessInfoCollector::collect,
+            tick_receiver,
+            tx,
+        )
+        .await;
+    });
+}
+
+fn get_thread_count(
+    pid: i32,
+    process: &Process,
+    thread_hashmap_win_only: Option<&HashMap<u32, u32>>,
+) -> u32 {
+    let mut thread_count = 0;
+
+    #[cfg(target_os = "macos")]
+    {
+        use libproc::{proc_pid::pidinfo, task_info::TaskInfo};
+        if let Ok(task_info) = pidinfo::<TaskInfo>(pid, 0) {
+            thread_count = task_info.pti_threadnum as u32;
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(tasks) = process.tasks() {
+            thread_count = tasks.len() as u32;
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if thread_hashmap_win_only.is_some() {
+            match thread_hashmap_win_only.unwrap().get(&(pid as u32)) {
+                Some(value) => {
+                    thread_count = *value;
+                }
+                None => {}
+            }
+        }
+    }
+
+    return thread_count;
+}
+
+// read the avg10 of the "some" and "full" lines from /proc/pressure/<resource>
+// the file looks like:
+// some avg10=0.77 avg60=3.07 avg300=3.48 total=87078304
+// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+fn get_pressure_data(resource: &str) -> Option<CPressureData> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let content = fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+        let get_avg10 = |kind: &str| -> Option<f64> {
+            content
+                .lines()
+                .find(|line| line.starts_with(kind))?
+                .split_whitespace()
+                .find_map(|field| field.strip_prefix("avg10="))?
+                .parse::<f64>()
+                .ok()
+        };
+        return Some(CPressureData {
+            some: get_avg10("some")?,
+            // older kernel doesn't have the "full" line for cpu
+            full: get_avg10("full").unwrap_or(0.0),
+        });
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = resource;
+        return None;
+    }
+}
+
+// get the cpu time counters from /proc/stat, the first entry was the total of all cpus followed by each cpu
+// each line looks like:
+// cpu0 user nice system idle iowait irq softirq steal guest guest_nice
+fn get_cpu_times() -> Vec<[u64; 8]> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(content) = fs::read_to_string("/proc/stat") else {
+            return vec![];
+        };
+        return content
+            .lines()
+            .filter(|line| line.starts_with("cpu"))
+            .map(|line| {
+                let mut cpu_times = [0; 8];
+                for (index, value) in line.split_whitespace().skip(1).take(8).enumerate() {
+                    cpu_times[index] = value.parse::<u64>().unwrap_or(0);
+                }
+                cpu_times
+            })
+            .collect();
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return vec![];
+}
+
+// calculate the percentage of each cpu state from the difference of the counters between two collections
+fn get_cpu_time_breakdowns(
+    previous_cpu_times: &[[u64; 8]],
+    current_cpu_times: &[[u64; 8]],
+) -> Vec<Option<CpuTimeBreakdown>> {
+    return current_cpu_times
+        .iter()
+        .enumerate()
+        .map(|(index, current)| {
+            let previous = previous_cpu_times.get(index)?;
+            let mut delta = [0.0; 8];
+            for i in 0..8 {
+                delta[i] = current[i].saturating_sub(previous[i]) as f32;
+            }
+            let total: f32 = delta.iter().sum();
+            if total == 0.0 {
+                return None;
+            }
+            let [user, nice, system, _idle, iowait, irq, softirq, steal] = delta;
+            Some(CpuTimeBreakdown {
+                user: (user + nice) / total * 100.0,
+                system: system / total * 100.0,
+                iowait: iowait / total * 100.0,
+                irq: (irq + softirq) / total * 100.0,
+                steal: steal / total * 100.0,
+            })
+        })
+        .collect();
+}
+
+// get the ( socket id, physical core id ) of the logical cpu from sysfs,
+// logical cpus that share the same physical core are hyperthread siblings
+fn get_cpu_topology(index: usize) -> Option<(u32, u32)> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
 
-        sys.refresh_all();
-        disks.refresh(true);
-        networks.refresh(true);
+        let topology_path = format!("/sys/devices/system/cpu/cpu{}/topology", index);
+        let socket_id = fs::read_to_string(format!("{}/physical_package_id", topology_path))
+            .ok()?
+            .trim()
+            .parse::<i64>()
+            .ok()?;
+        let core_id = fs::read_to_string(format!("{}/core_id", topology_path))
+            .ok()?
+            .trim()
+            .parse::<i64>()
+            .ok()?;
+        // some virtual machines report -1 for the socket id
+        return Some((socket_id.max(0) as u32, core_id.max(0) as u32));
+    }
 
-        loop {
-            let elapsed = last_refresh.elapsed();
-            let sleep_duration = if tick_value > elapsed.as_millis() as u32 {
-                Duration::from_millis((tick_value - elapsed.as_millis() as u32).into())
-            } else {
-                Duration::from_millis(0)
-            };
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = index;
+        return None;
+    }
+}
 
-            match tick_receiver.recv_timeout(sleep_duration) {
-                Ok(new_tick) => {
-                    tick_value = new_tick;
-                    continue; // don't collect this cycle, just updated tick
-                }
-                Err(RecvTimeoutError::Timeout) => {
-                    // -------------------------------------------
-                    //
-                    //             CPU DATA COLLECTION
-                    //
-                    // -------------------------------------------
-
-                    // Refresh CPU data
-                    sys.refresh_cpu_all();
-                    let cpus = sys.cpus();
-
-                    // Gather CPU data
-                    let mut cpu_data: Vec<CCpuData> = cpus
-                        .iter()
-                        .enumerate()
-                        .map(|(index, cpu)| CCpuData {
-                            id: index as i8,
-                            brand: cpu.brand().to_string(),
-                            usage: cpu.cpu_usage(),
-                        })
-                        .collect();
-
-                    // we later add cpu avg info as the first entry of the collected cpu info vector
-                    let avg_cpu_data = CCpuData {
-                        id: -1 as i8,
-                        brand: cpu_data[0].brand.clone(),
-                        usage: sys.global_cpu_usage(),
-                    };
-                    cpu_data.insert(0, avg_cpu_data);
-
-                    // -------------------------------------------
-                    //
-                    //          RAM MEMORY DATA COLLECTION
-                    //
-                    // -------------------------------------------
-
-                    sys.refresh_memory();
-                    let total_memory = sys.total_memory() as f64;
-                    let available_memory = sys.available_memory() as f64;
-                    let used_memory = sys.used_memory() as f64;
-                    let used_swap = sys.used_swap() as f64;
-                    let free_memory = sys.free_memory() as f64;
-                    let cached_memory = get_cached_memory();
-
-                    let memory_data = CMemoryData {
-                        total_memory,
-                        available_memory,
-                        used_memory,
-                        used_swap,
-                        free_memory,
-                        cached_memory,
-                    };
-
-                    // -------------------------------------------
-                    //
-                    //            DISK DATA COLLECTION
-                    //
-                    // -------------------------------------------
-                    disks.refresh(true);
-                    let mut disk_data = Vec::new();
-                    for disk in &disks {
-                        let total_space = disk.total_space() as f64;
-                        let available_space = disk.available_space() as f64;
-                        let data = CDiskData {
-                            name: disk.name().to_string_lossy().to_string(),
-                            total_space,
-                            available_space,
-                            used_space: total_space - available_space,
-                            bytes_written: disk.usage().written_bytes as f64,
-                            bytes_read: disk.usage().read_bytes as f64,
-                            file_system: disk.file_system().to_string_lossy().to_string(),
-                            mount_point: disk.mount_point().to_string_lossy().to_string(),
-                            kind: disk.kind().to_string(),
-                        };
-
-                        disk_data.push(data);
-                    }
+// block io delay will always be 0 if the kernel delay accounting is disabled ( kernel.task_delayacct )
+#[cfg(target_os = "linux")]
+pub fn is_linux_delay_accounting_enabled() -> bool {
+    use std::fs;
 
-                    // -------------------------------------------
-                    //
-                    //          NETWORK DATA COLLECTION
-                    //
-                    // -------------------------------------------
-                    networks.refresh(true);
-                    let mut networks_data = Vec::new();
-                    for (interface_name, network_data) in &networks {
-                        let data = CNetworkData {
-                            interface_name: interface_name.to_string(),
-                            ip_network: if network_data.ip_networks().len() > 0 {
-                                let mut ipv4_networks = Vec::new();
-                                for ip in network_data.ip_networks() {
-                                    if ip.addr.is_ipv4() {
-                                        ipv4_networks.push(ip.addr.to_string());
-                                    }
-                                }
-                                if ipv4_networks.is_empty() {
-                                    None
-                                } else {
-                                    Some(ipv4_networks[0].clone())
-                                }
-                            } else {
-                                None
-                            },
-                            current_received: network_data.received() as f64,
-                            current_transmitted: network_data.transmitted() as f64,
-                            total_received: network_data.total_received() as f64,
-                            total_transmitted: network_data.total_transmitted() as f64,
-                        };
-                        networks_data.push(data);
-                    }
+    match fs::read_to_string("/proc/sys/kernel/task_delayacct") {
+        Ok(value) => value.trim() != "0",
+        // older kernel doesn't have this sysctl, delay accounting is enabled by default there
+        Err(_) => true,
+    }
+}
 
-                    // -------------------------------------------
-                    //
-                    //    SEND COLLECTION DATA TO MAIN THREAD
-                    //
-                    // -------------------------------------------
-                    let sys_info = CSysInfo {
-                        cpus: cpu_data,
-                        memory: memory_data,
-                        disks: disk_data,
-                        networks: networks_data,
-                    };
-
-                    // Send the data to the main thread
-                    if let Err(e) = tx.send(sys_info) {
-                        eprintln!("Failed to send System Info: {}", e);
-                        break; // Exit loop if channel is disconnected
-                    }
+// get the shared memory from the third field of /proc/<pid>/statm, which is in pages
+#[cfg(target_os = "linux")]
+fn get_linux_process_shared_memory(pid: u32) -> Option<f64> {
+    use std::fs;
 
-                    // Reset the last refresh time
-                    last_refresh = Instant::now();
-                }
-                Err(RecvTimeoutError::Disconnected) => {
-                    break;
-                }
+    let statm = fs::read_to_string(format!("/proc/{}/statm", pid)).ok()?;
+    let shared_pages = statm.split_whitespace().nth(2)?.parse::<f64>().ok()?;
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if page_size <= 0 {
+        return None;
+    }
+    return Some(shared_pages * page_size as f64);
+}
+
+// get the nice value of the process from the bsd info, linux parse it from /proc/<pid>/stat instead
+#[cfg(target_os = "macos")]
+fn get_process_nice(pid: u32) -> Option<i32> {
+    use libproc::{bsd_info::BSDInfo, proc_pid::pidinfo};
+    return pidinfo::<BSDInfo>(pid as i32, 0)
+        .ok()
+        .map(|bsd_info| bsd_info.pbi_nice);
+}
+
+// the nice value from the content of /proc/<pid>/stat, it was the 17th field after the name
+// eg: "1234 (bash) S 1 1234 1234 0 -1 4194560 110 0 0 0 25 12 0 0 20 0 1 0 ..."
+pub fn parse_stat_nice(stat: &str) -> Option<i32> {
+    let name_end = stat.rfind(')')?;
+    return stat
+        .get(name_end + 1..)?
+        .split_whitespace()
+        .nth(16)?
+        .parse::<i32>()
+        .ok();
+}
+
+// get the oom score and oom score adj from /proc/<pid>/oom_score and /proc/<pid>/oom_score_adj
+#[cfg(target_os = "linux")]
+fn get_linux_process_oom_score(pid: u32) -> Option<(i32, i32)> {
+    use std::fs;
+
+    let oom_score = fs::read_to_string(format!("/proc/{}/oom_score", pid))
+        .ok()?
+        .trim()
+        .parse::<i32>()
+        .ok()?;
+    let oom_score_adj = fs::read_to_string(format!("/proc/{}/oom_score_adj", pid))
+        .ok()
+        .and_then(|oom_score_adj| oom_score_adj.trim().parse::<i32>().ok())
+        .unwrap_or(0);
+    return Some((oom_score, oom_score_adj));
+}
+
+// get the cpu wait time from /proc/<pid>/schedstat and the block io wait time from the content of /proc/<pid>/stat
+#[cfg(target_os = "linux")]
+fn get_linux_process_delay_info(
+    pid: u32,
+    stat: &str,
+    is_delay_accounting_enabled: bool,
+) -> Option<ProcessDelayInfo> {
+    use libc::{sysconf, _SC_CLK_TCK};
+    use std::fs;
+
+    // schedstat: <time spent on cpu> <time spent waiting on a runqueue> <timeslices run>
+    let schedstat = fs::read_to_string(format!("/proc/{}/schedstat", pid)).ok()?;
+    let cpu_wait_time = schedstat.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+
+    let mut io_wait_time = None;
+    if is_delay_accounting_enabled {
+        // the process name can contain spaces, so we only split the fields after the ')'
+        // delayacct_blkio_ticks is the 42nd field, which is the 40th field after the name
+        if let Some(stat_after_name) = stat.rsplit_once(')').map(|(_, rest)| rest) {
+            if let Some(Ok(blkio_ticks)) = stat_after_name
+                .split_whitespace()
+                .nth(39)
+                .map(|value| value.parse::<u64>())
+            {
+                let clock_ticks = unsafe { sysconf(_SC_CLK_TCK) };
+                let clock_ticks = if clock_ticks <= 0 {
+                    100
+                } else {
+                    clock_ticks as u64
+                };
+                io_wait_time = Some(blkio_ticks * 1_000_000_000 / clock_ticks);
             }
         }
+    }
+
+    return Some(ProcessDelayInfo {
+        cpu_wait_time,
+        io_wait_time,
     });
 }
 
-// dedicate thread to collect process info only
-pub fn spawn_process_info_collector(
-    tick_receiver: Receiver<u32>,
-    tx: Sender<CProcessesInfo>,
-    default_tick: u32,
-) {
-    // Spawn a worker thread to gather CPU info
-    thread::spawn(move || {
-        let mut sys = System::new_all();
-        let mut last_refresh = Instant::now();
-        let mut tick_value = default_tick; // Current tick in ms
+// get the cpu throttling statistics of the cgroup that the process belongs to
+// only cgroup with a cpu quota will be returned, as cgroup without a quota will never be throttled
+#[cfg(target_os = "linux")]
+fn get_linux_cgroup_cpu_throttle_info(
+    cgroup: &str,
+    cpu_throttle_info_cache: &mut HashMap<String, Option<CpuThrottleInfo>>,
+) -> Option<CpuThrottleInfo> {
+    use std::fs;
 
-        sys.refresh_all();
+    // cgroup v2: "0::<path>", cgroup v1: "<id>:cpu,cpuacct:<path>"
+    let mut cgroup_directory: Option<String> = None;
+    let mut is_cgroup_v2 = false;
+    for line in cgroup.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (Some(_), Some(controllers), Some(path)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
 
-        loop {
-            let elapsed = last_refresh.elapsed();
-            let sleep_duration = if tick_value > elapsed.as_millis() as u32 {
-                Duration::from_millis((tick_value - elapsed.as_millis() as u32).into())
+        if controllers.is_empty() {
+            cgroup_directory = Some(format!("/sys/fs/cgroup{}", path));
+            is_cgroup_v2 = true;
+        } else if controllers.split(',').any(|controller| controller == "cpu") {
+            cgroup_directory = Some(format!("/sys/fs/cgroup/{}{}", controllers, path));
+            is_cgroup_v2 = false;
+            break;
+        }
+    }
+    let cgroup_directory = cgroup_directory?;
+
+    if let Some(cpu_throttle_info) = cpu_throttle_info_cache.get(&cgroup_directory) {
+        return cpu_throttle_info.clone();
+    }
+
+    let is_cpu_limited = if is_cgroup_v2 {
+        // cpu.max: "<quota> <period>", quota will be "max" if there is no limit
+        match fs::read_to_string(format!("{}/cpu.max", cgroup_directory)) {
+            Ok(cpu_max) => cpu_max.split_whitespace().next().unwrap_or("max") != "max",
+            Err(_) => false,
+        }
+    } else {
+        // cpu.cfs_quota_us will be -1 if there is no limit
+        match fs::read_to_string(format!("{}/cpu.cfs_quota_us", cgroup_directory)) {
+            Ok(cfs_quota) => cfs_quota.trim() != "-1",
+            Err(_) => false,
+        }
+    };
+
+    let mut cpu_throttle_info = None;
+    if is_cpu_limited {
+        if let Ok(cpu_stat) = fs::read_to_string(format!("{}/cpu.stat", cgroup_directory)) {
+            let mut nr_periods = 0;
+            let mut nr_throttled = 0;
+            let mut throttled_time = 0;
+            for line in cpu_stat.lines() {
+                let Some((key, value)) = line.split_once(' ') else {
+                    continue;
+                };
+                let value = value.trim().parse::<u64>().unwrap_or(0);
+                match key {
+                    "nr_periods" => nr_periods = value,
+                    "nr_throttled" => nr_throttled = value,
+                    // cgroup v2 report in microseconds while cgroup v1 report in nanoseconds
+                    "throttled_usec" => throttled_time = value * 1_000,
+                    "throttled_time" => throttled_time = value,
+                    _ => {}
+                }
+            }
+            cpu_throttle_info = Some(CpuThrottleInfo {
+                nr_periods,
+                nr_throttled,
+                throttled_time,
+            });
+        }
+    }
+
+    cpu_throttle_info_cache.insert(cgroup_directory, cpu_throttle_info.clone());
+    return cpu_throttle_info;
+}
+
+// resolve the container that the process belongs to from the content of /proc/<pid>/cgroup
+// and query the container runtime for the container metadata
+#[cfg(target_os = "linux")]
+fn get_linux_process_container_info(
+    cgroup: &str,
+    container_info_cache: &mut HashMap<String, (Instant, ContainerInfo)>,
+) -> Option<ContainerInfo> {
+    let (runtime, id) = get_container_id_from_cgroup(cgroup)?;
+
+    if let Some((_, container_info)) = container_info_cache.get(&id) {
+        return Some(container_info.clone());
+    }
+
+    // without the container-runtime feature, only the container id from the cgroup will be shown
+    #[cfg(feature = "container-runtime")]
+    let container_info = query_container_runtime(runtime, id.clone());
+    #[cfg(not(feature = "container-runtime"))]
+    let container_info = ContainerInfo {
+        id: id.clone(),
+        runtime: runtime.to_string(),
+        name: None,
+        image: None,
+        restart_count: None,
+    };
+    container_info_cache.insert(id, (Instant::now(), container_info.clone()));
+
+    return Some(container_info);
+}
+
+// the innermost cgroup of the process, the root cgroup was left out as every process belongs to it
+// eg: 0::/system.slice/nginx.service -> nginx.service
+pub fn get_cgroup_name(cgroup: &str) -> Option<String> {
+    // the unified ( cgroup v2 ) hierarchy was preferred, otherwise the first hierarchy that isn't the root
+    let path = cgroup
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .or_else(|| {
+            cgroup
+                .lines()
+                .filter_map(|line| line.splitn(3, ':').nth(2))
+                .find(|path| *path != "/")
+        })?;
+
+    return path
+        .rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .map(|segment| segment.to_string());
+}
+
+// the cgroup path of a container usually contains the container id, eg:
+// 0::/system.slice/docker-<id>.scope
+// 0::/docker/<id>
+// 0::/machine.slice/libpod-<id>.scope
+// 0::/kubepods.slice/kubepods-burstable.slice/.../cri-containerd-<id>.scope
+#[cfg(target_os = "linux")]
+fn get_container_id_from_cgroup(cgroup: &str) -> Option<(&'static str, String)> {
+    for line in cgroup.lines() {
+        let path = match line.splitn(3, ':').nth(2) {
+            Some(path) => path,
+            None => continue,
+        };
+
+        for segment in path.split('/').rev() {
+            let segment = segment.trim_end_matches(".scope");
+            let (runtime, id) = if let Some(id) = segment.strip_prefix("docker-") {
+                ("docker", id)
+            } else if let Some(id) = segment.strip_prefix("libpod-") {
+                ("podman", id)
+            } else if let Some(id) = segment.strip_prefix("cri-containerd-") {
+                ("containerd", id)
+            } else if let Some(id) = segment.strip_prefix("crio-") {
+                ("cri-o", id)
+            } else if path.contains("/docker/") {
+                ("docker", segment)
+            } else if path.contains("kubepods") {
+                ("containerd", segment)
             } else {
-                Duration::from_millis(0)
+                continue;
             };
 
-            match tick_receiver.recv_timeout(sleep_duration) {
-                Ok(new_tick) => {
-                    tick_value = new_tick;
-                    continue; // don't collect this cycle, just updated tick
-                }
-                Err(RecvTimeoutError::Timeout) => {
-                    sys.refresh_processes(ProcessesToUpdate::All, true);
-                    let users = Users::new_with_refreshed_list();
-                    let mut processes = vec![];
-                    // -------------------------------------------
-                    //
-                    //          PROCESS INFO COLLECTION
-                    //
-                    // -------------------------------------------
-                    for (pid, process) in sys.processes() {
-                        let mut user = "root";
-
-                        #[cfg(any(target_os = "linux", target_os = "macos"))]
-                        let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
-
-                        #[cfg(target_os = "windows")]
-                        // let thread_hashmap_win_only = get_win_thread_counts();
-                        #[cfg(target_os = "windows")]
-                        let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
-
-                        if process.user_id().is_some() {
-                            let u = users.get_user_by_id(process.user_id().unwrap());
-                            if u.is_some() {
-                                user = u.unwrap().name();
-                            }
-                        }
-                        let process_disk_usage = process.disk_usage();
-                        let process_info = CProcessData {
-                            pid: pid.as_u32(),
-                            name: process.name().to_string_lossy().to_string(),
-                            exe_path: if process.exe().is_some() {
-                                Some(process.exe().unwrap().to_string_lossy().to_string())
-                            } else {
-                                None
-                            },
-                            cmd: process
-                                .cmd()
-                                .into_iter()
-                                .map(|osstr| osstr.to_string_lossy().to_string())
-                                .collect(),
-                            user: user.to_string(),
-                            cpu_usage: process.cpu_usage(),
-                            thread_count,
-                            memory: process.memory() as f64,
-                            status: process.status().to_string(),
-                            elapsed: process.run_time(),
-                            parent: if process.parent().is_some() {
-                                format!("{:?}", process.parent().unwrap().as_u32())
-                            } else {
-                                "-".to_string()
-                            },
-                            current_read_disk_usage: process_disk_usage.read_bytes,
-                            total_read_disk_usage: process_disk_usage.total_read_bytes,
-                            current_write_disk_usage: process_disk_usage.written_bytes,
-                            total_write_disk_usage: process_disk_usage.total_written_bytes,
-                        };
-
-                        processes.push(process_info);
-                    }
+            if id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Some((runtime, id.to_string()));
+            }
+        }
+    }
 
-                    // -------------------------------------------
-                    //
-                    //  SEND COLLECTED PROCESS INFO TO MAIN THREAD
-                    //
-                    // -------------------------------------------
-                    let process_info = CProcessesInfo { processes };
-
-                    // Send the data to the main thread
-                    if let Err(e) = tx.send(process_info) {
-                        eprintln!("Failed to send Process Info: {}", e);
-                        break; // Exit loop if channel is disconnected
-                    }
+    return None;
+}
+
+// query the container runtime cli for the container name, image and restart count
+// the container id will still be shown if the runtime is not accessible ( not installed / permission denied )
+#[cfg(all(target_os = "linux", feature = "container-runtime"))]
+fn query_container_runtime(runtime: &str, id: String) -> ContainerInfo {
+    use std::process::Command;
+
+    let mut container_info = ContainerInfo {
+        id,
+        runtime: runtime.to_string(),
+        name: None,
+        image: None,
+        restart_count: None,
+    };
 
-                    // Reset the last refresh time
-                    last_refresh = Instant::now();
+    if runtime == "docker" || runtime == "podman" {
+        let output = Command::new(runtime)
+            .arg("inspect")
+            .arg("--format")
+            .arg("{{.Name}}|{{.Config.Image}}|{{.RestartCount}}")
+            .arg(&container_info.id)
+            .output();
+        if let Ok(output) = output {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let fields: Vec<&str> = stdout.trim().split('|').collect();
+                if fields.len() == 3 {
+                    container_info.name = Some(fields[0].trim_start_matches('/').to_string());
+                    container_info.image = Some(fields[1].to_string());
+                    container_info.restart_count = fields[2].parse::<u64>().ok();
                 }
-                Err(RecvTimeoutError::Disconnected) => {
-                    break;
+            }
+        }
+    } else {
+        // containerd and cri-o are usually managed by kubernetes, so we query them through crictl
+        let output = Command::new("crictl")
+            .arg("inspect")
+            .arg(&container_info.id)
+            .output();
+        if let Ok(output) = output {
+            if output.status.success() {
+                if let Ok(inspect) = serde_json::from_slice::<serde_json::Value>(&output.stdout) {
+                    let status = &inspect["status"];
+                    container_info.name = status["metadata"]["name"].as_str().map(String::from);
+                    container_info.image = status["image"]["image"].as_str().map(String::from);
+                    container_info.restart_count = status["metadata"]["attempt"].as_u64();
                 }
             }
         }
+    }
+
+    return container_info;
+}
+
+// detect if the network interface is a vpn tunnel ( tun/tap/wireguard )
+// the peer info cache was only used with the wireguard feature
+#[allow(unused_variables)]
+fn get_vpn_info(
+    interface_name: &str,
+    wireguard_peer_info_cache: &mut WireguardPeerInfoCache,
+) -> Option<VpnInfo> {
+    #[cfg(target_os = "linux")]
+    let mut kind = get_linux_tunnel_kind(interface_name);
+    #[cfg(not(target_os = "linux"))]
+    let mut kind: Option<String> = None;
+
+    // fallback to the naming convention of the interface
+    if kind.is_none() {
+        let name = interface_name.to_lowercase();
+        if name.starts_with("wg") {
+            kind = Some("WireGuard".to_string());
+        } else if name.starts_with("tailscale") {
+            kind = Some("Tailscale".to_string());
+        } else if name.starts_with("tun") || name.starts_with("utun") {
+            kind = Some("TUN".to_string());
+        } else if name.starts_with("tap") {
+            kind = Some("TAP".to_string());
+        } else if name.starts_with("ppp") {
+            kind = Some("PPP".to_string());
+        } else if name.starts_with("ipsec") {
+            kind = Some("IPsec".to_string());
+        }
+    }
+
+    let kind = kind?;
+    // the peer info was only queried with the wireguard feature
+    #[cfg(feature = "wireguard")]
+    let (endpoint, latest_handshake) = if kind == "WireGuard" {
+        match wireguard_peer_info_cache.get(interface_name) {
+            Some((_, peer_info)) => peer_info.clone(),
+            None => {
+                // the failed query ( eg: without root privilege ) was also cached, so `wg` won't be run every tick
+                let peer_info = get_wireguard_peer_info(interface_name).unwrap_or((None, None));
+                wireguard_peer_info_cache.insert(
+                    interface_name.to_string(),
+                    (Instant::now(), peer_info.clone()),
+                );
+                peer_info
+            }
+        }
+    } else {
+        (None, None)
+    };
+    #[cfg(not(feature = "wireguard"))]
+    let (endpoint, latest_handshake) = (None, None);
+
+    return Some(VpnInfo {
+        kind,
+        endpoint,
+        latest_handshake,
     });
 }
 
-fn get_thread_count(
-    pid: i32,
-    process: &Process,
-    thread_hashmap_win_only: Option<HashMap<String, u32>>,
-) -> u32 {
-    let mut thread_count = 0;
+// the kernel expose the tunnel type under /sys/class/net/<interface>
+#[cfg(target_os = "linux")]
+fn get_linux_tunnel_kind(interface_name: &str) -> Option<String> {
+    use std::fs;
 
-    #[cfg(target_os = "macos")]
-    {
-        use libproc::{proc_pid::pidinfo, task_info::TaskInfo};
-        if let Ok(task_info) = pidinfo::<TaskInfo>(pid, 0) {
-            thread_count = task_info.pti_threadnum as u32;
+    let interface_path = format!("/sys/class/net/{}", interface_name);
+    if let Ok(uevent) = fs::read_to_string(format!("{}/uevent", interface_path)) {
+        if uevent
+            .lines()
+            .any(|line| line.trim() == "DEVTYPE=wireguard")
+        {
+            return Some("WireGuard".to_string());
         }
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        if let Some(tasks) = process.tasks() {
-            thread_count = tasks.len() as u32;
+    // tun_flags only exist for tun/tap device, IFF_TAP (0x0002) was set for tap device
+    if let Ok(tun_flags) = fs::read_to_string(format!("{}/tun_flags", interface_path)) {
+        let flags = u32::from_str_radix(tun_flags.trim().trim_start_matches("0x"), 16).unwrap_or(0);
+        if flags & 0x0002 != 0 {
+            return Some("TAP".to_string());
+        } else {
+            return Some("TUN".to_string());
         }
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        if thread_hashmap_win_only.is_some() {
-            match thread_hashmap_win_only.unwrap().get(&format!("{}", pid)) {
-                Some(value) => {
-                    thread_count = *value;
-                }
-                None => {}
+    return None;
+}
+
+// get the endpoint and latest handshake of the most recent peer from `wg show <interface> dump`
+// this usually require root privilege, so we will just return None if it fails
+#[cfg(feature = "wireguard")]
+fn get_wireguard_peer_info(interface_name: &str) -> Option<(Option<String>, Option<u64>)> {
+    use std::process::Command;
+
+    let output = Command::new("wg")
+        .arg("show")
+        .arg(interface_name)
+        .arg("dump")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // the first line is the interface itself, the following lines are the peers:
+    // public-key preshared-key endpoint allowed-ips latest-handshake transfer-rx transfer-tx persistent-keepalive
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut peer_info: Option<(Option<String>, Option<u64>)> = None;
+    for line in stdout.lines().skip(1) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 5 {
+            continue;
+        }
+        let endpoint = if fields[2] == "(none)" {
+            None
+        } else {
+            Some(fields[2].to_string())
+        };
+        let latest_handshake = match fields[4].parse::<u64>() {
+            Ok(0) => None,
+            Ok(handshake) => Some(handshake),
+            Err(_) => None,
+        };
+
+        let is_more_recent = match &peer_info {
+            Some((_, Some(current_handshake))) => {
+                latest_handshake.unwrap_or(0) > *current_handshake
             }
+            _ => true,
+        };
+        if is_more_recent {
+            peer_info = Some((endpoint, latest_handshake));
         }
     }
 
-    return thread_count;
+    return peer_info;
 }
 
 fn get_cached_memory() -> f64 {
@@ -409,6 +2641,220 @@ fn get_linux_cached_memory() -> Option<u64> {
     return None;
 }
 
+// get the detailed memory stats from /proc/meminfo, None on other platforms
+fn get_memory_breakdown() -> Option<MemoryBreakdown> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let data = fs::read_to_string("/proc/meminfo").ok()?;
+        let mut breakdown = MemoryBreakdown::default();
+        let mut huge_pages_total = 0.0;
+        let mut huge_pages_free = 0.0;
+        for line in data.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (Some(key), Some(value)) = (parts.first(), parts.get(1)) else {
+                continue;
+            };
+            let value = value.parse::<f64>().unwrap_or(0.0);
+            // the values are in kB except the huge pages count
+            match *key {
+                "Buffers:" => breakdown.buffers = value * 1024.0,
+                "Slab:" => breakdown.slab = value * 1024.0,
+                "Dirty:" => breakdown.dirty = value * 1024.0,
+                "Writeback:" => breakdown.writeback = value * 1024.0,
+                "HugePages_Total:" => huge_pages_total = value,
+                "HugePages_Free:" => huge_pages_free = value,
+                "Hugepagesize:" => breakdown.huge_page_size = value * 1024.0,
+                _ => {}
+            }
+        }
+        breakdown.huge_pages_total = huge_pages_total * breakdown.huge_page_size;
+        breakdown.huge_pages_free = huge_pages_free * breakdown.huge_page_size;
+        return Some(breakdown);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return None;
+}
+
+// get the zram stats from /sys/block/zram*/mm_stat and the zswap stats from /proc/meminfo
+// None if neither of them was active or on other platforms
+fn get_compressed_swap_info() -> Option<CompressedSwapInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let mut kinds: Vec<&str> = Vec::new();
+        let mut compressed_swap_info = CompressedSwapInfo::default();
+
+        // mm_stat: orig_data_size compr_data_size mem_used_total ... ( in bytes )
+        let mut zram_original_size = 0.0;
+        let mut zram_compressed_size = 0.0;
+        if let Ok(entries) = fs::read_dir("/sys/block") {
+            for entry in entries.flatten() {
+                if !entry.file_name().to_string_lossy().starts_with("zram") {
+                    continue;
+                }
+                let Ok(mm_stat) = fs::read_to_string(entry.path().join("mm_stat")) else {
+                    continue;
+                };
+                let fields: Vec<f64> = mm_stat
+                    .split_whitespace()
+                    .filter_map(|field| field.parse::<f64>().ok())
+                    .collect();
+                if fields.len() >= 2 {
+                    zram_original_size += fields[0];
+                    zram_compressed_size += fields[1];
+                }
+            }
+        }
+        if zram_original_size > 0.0 {
+            kinds.push("zram");
+            compressed_swap_info.original_size += zram_original_size;
+            compressed_swap_info.compressed_size += zram_compressed_size;
+        }
+
+        // Zswap is the compressed size and Zswapped is the original size ( in kB )
+        let mut zswap_original_size = 0.0;
+        let mut zswap_compressed_size = 0.0;
+        if let Ok(data) = fs::read_to_string("/proc/meminfo") {
+            for line in data.lines() {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                let (Some(key), Some(value)) = (parts.first(), parts.get(1)) else {
+                    continue;
+                };
+                let value = value.parse::<f64>().unwrap_or(0.0);
+                match *key {
+                    "Zswap:" => zswap_compressed_size = value * 1024.0,
+                    "Zswapped:" => zswap_original_size = value * 1024.0,
+                    _ => {}
+                }
+            }
+        }
+        if zswap_original_size > 0.0 {
+            kinds.push("zswap");
+            compressed_swap_info.original_size += zswap_original_size;
+            compressed_swap_info.compressed_size += zswap_compressed_size;
+        }
+
+        if kinds.is_empty() {
+            return None;
+        }
+        compressed_swap_info.kind = kinds.join("+");
+        return Some(compressed_swap_info);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return None;
+}
+
+// get the total and free inodes of the file system mounted at the mount point
+// None if the file system doesn't report inodes ( eg: btrfs ) or on windows
+fn get_inodes(mount_point: &Path) -> Option<(u64, u64)> {
+    #[cfg(unix)]
+    {
+        use std::{ffi::CString, mem, os::unix::ffi::OsStrExt};
+
+        let mount_point = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+        let mut stat: libc::statvfs = unsafe { mem::zeroed() };
+        if unsafe { libc::statvfs(mount_point.as_ptr(), &mut stat) } != 0 {
+            return None;
+        }
+        let total_inodes = stat.f_files as u64;
+        if total_inodes == 0 {
+            return None;
+        }
+        return Some((total_inodes, stat.f_ffree as u64));
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = mount_point;
+        return None;
+    }
+}
+
+// get each swap device or file from /proc/swaps, empty on other platforms
+fn get_swap_devices() -> Vec<SwapDevice> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let Ok(data) = fs::read_to_string("/proc/swaps") else {
+            return Vec::new();
+        };
+        // Filename Type Size Used Priority, the sizes are in KiB and the first line is the header
+        return data
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() < 5 {
+                    return None;
+                }
+                return Some(SwapDevice {
+                    name: parts[0].to_string(),
+                    kind: parts[1].to_string(),
+                    size: parts[2].parse::<f64>().ok()? * 1024.0,
+                    used: parts[3].parse::<f64>().ok()? * 1024.0,
+                    priority: parts[4].parse::<i32>().unwrap_or(0),
+                });
+            })
+            .collect();
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return Vec::new();
+}
+
+// get the ZFS ARC stats from /proc/spl/kstat/zfs/arcstats, None if ZFS isn't present or on other platforms
+fn get_zfs_arc_info(previous_zfs_arc: &Option<ZfsArcInfo>) -> Option<ZfsArcInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::fs;
+
+        let data = fs::read_to_string("/proc/spl/kstat/zfs/arcstats").ok()?;
+        let mut zfs_arc = ZfsArcInfo::default();
+        // the first 2 lines are the kstat header, followed by "name type data" rows
+        for line in data.lines().skip(2) {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (Some(name), Some(value)) = (parts.first(), parts.get(2)) else {
+                continue;
+            };
+            match *name {
+                "size" => zfs_arc.size = value.parse::<f64>().unwrap_or(0.0),
+                "c" => zfs_arc.target_size = value.parse::<f64>().unwrap_or(0.0),
+                "hits" => zfs_arc.hits = value.parse::<u64>().unwrap_or(0),
+                "misses" => zfs_arc.misses = value.parse::<u64>().unwrap_or(0),
+                _ => {}
+            }
+        }
+
+        // use the counters since boot for the first collection
+        let (hits, misses) = match previous_zfs_arc {
+            Some(previous_zfs_arc) => (
+                zfs_arc.hits.saturating_sub(previous_zfs_arc.hits),
+                zfs_arc.misses.saturating_sub(previous_zfs_arc.misses),
+            ),
+            None => (zfs_arc.hits, zfs_arc.misses),
+        };
+        zfs_arc.hit_rate = if hits + misses > 0 {
+            hits as f64 / (hits + misses) as f64 * 100.0
+        } else {
+            // no access since the previous collection, keep the previous hit rate
+            previous_zfs_arc
+                .as_ref()
+                .map(|previous_zfs_arc| previous_zfs_arc.hit_rate)
+                .unwrap_or(0.0)
+        };
+        return Some(zfs_arc);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    return None;
+}
+
 #[cfg(target_os = "windows")]
 fn get_window_cached_memory() -> Option<u64> {
     use std::mem;
@@ -427,59 +2873,203 @@ fn get_window_cached_memory() -> Option<u64> {
     }
 }
 
+// the thread count and the base priority of each process, keyed by the pid
+// the entry of each process in a process snapshot already has its thread count, which was much faster
+// than counting the entries of a snapshot of all the threads in the system
 #[cfg(target_os = "windows")]
-fn get_win_thread_counts() -> HashMap<String, u32> {
-    // not very optimize it seems ( not fast enough, tho was tested in VM )
+fn get_win_process_snapshot() -> (HashMap<u32, u32>, HashMap<u32, i32>) {
     use winapi::shared::minwindef::{DWORD, TRUE};
-    use winapi::shared::ntdef::NULL;
-    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE};
     use winapi::um::tlhelp32::{
-        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+        CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+        TH32CS_SNAPPROCESS,
     };
 
-    let mut thread_counts: HashMap<String, u32> = HashMap::new();
-
-    // Create a snapshot of all threads in the system
-    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
-    if snapshot == NULL {
-        // Handle error: Snapshot creation failed.
-        // In a real application, you might want to log this or return a Result.
-        eprintln!("Failed to create thread snapshot.");
-        return thread_counts; // Return an empty HashMap on error
-    }
-
-    let mut thread_entry = THREADENTRY32 {
-        dwSize: std::mem::size_of::<THREADENTRY32>() as DWORD,
-        cntUsage: 0,
-        th32ThreadID: 0,
-        th32OwnerProcessID: 0,
-        tpBasePri: 0,
-        tpDeltaPri: 0,
-        dwFlags: 0,
-    };
+    let mut thread_counts: HashMap<u32, u32> = HashMap::new();
+    let mut base_priorities: HashMap<u32, i32> = HashMap::new();
 
-    // Iterate through threads
-    if unsafe { Thread32First(snapshot, &mut thread_entry) } == TRUE {
-        loop {
-            let pid = thread_entry.th32OwnerProcessID;
-            let pid_str = pid.to_string();
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) };
+    if snapshot == INVALID_HANDLE_VALUE {
+        error!("Failed to create the process snapshot for the thread counts");
+        return (thread_counts, base_priorities);
+    }
 
-            // Increment the thread count for this PID
-            *thread_counts.entry(pid_str).or_insert(0) += 1;
+    let mut process_entry: PROCESSENTRY32W = unsafe { std::mem::zeroed() };
+    process_entry.dwSize = std::mem::size_of::<PROCESSENTRY32W>() as DWORD;
 
-            // Move to the next thread
-            if unsafe { Thread32Next(snapshot, &mut thread_entry) } != TRUE {
+    if unsafe { Process32FirstW(snapshot, &mut process_entry) } == TRUE {
+        loop {
+            thread_counts.insert(process_entry.th32ProcessID, process_entry.cntThreads);
+            base_priorities.insert(process_entry.th32ProcessID, process_entry.pcPriClassBase);
+            if unsafe { Process32NextW(snapshot, &mut process_entry) } != TRUE {
                 break;
             }
         }
     } else {
-        // If Thread32First fails, it might mean no threads were found or an error occurred.
-        // Again, more robust error handling might be needed here.
-        eprintln!("Failed to get the first thread.");
+        error!("Failed to get the first process of the process snapshot");
     }
 
-    // Close the snapshot handle
     unsafe { CloseHandle(snapshot) };
 
-    return thread_counts;
+    return (thread_counts, base_priorities);
+}
+
+// the base priority of the priority class mapped to the nice value that is the closest to it
+// so the processes can be compared the same way on all the platforms
+#[cfg(target_os = "windows")]
+fn get_win_process_nice(pid: u32, base_priorities: &HashMap<u32, i32>) -> Option<i32> {
+    return match *base_priorities.get(&pid)? {
+        // idle
+        ..=4 => Some(19),
+        // below normal
+        5..=6 => Some(10),
+        // normal
+        7..=8 => Some(0),
+        // above normal
+        9..=10 => Some(-5),
+        // high
+        11..=13 => Some(-10),
+        // realtime
+        _ => Some(-20),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cgroup_name_is_the_innermost_cgroup() {
+        assert_eq!(
+            get_cgroup_name("0::/system.slice/nginx.service\n"),
+            Some("nginx.service".to_string())
+        );
+        assert_eq!(get_cgroup_name("0::/\n"), None);
+        // cgroup v1 without the unified hierarchy
+        assert_eq!(
+            get_cgroup_name("12:cpuset:/\n11:memory:/user.slice/user-1000.slice\n"),
+            Some("user-1000.slice".to_string())
+        );
+    }
+
+    #[test]
+    fn thread_stat_is_parsed() {
+        let stat =
+            "1234 (tokio (rt) w) S 1 1234 1234 0 -1 4194560 110 0 0 0 25 12 0 0 20 0 8 0 100";
+        assert_eq!(
+            parse_thread_stat(stat),
+            Some(("tokio (rt) w".to_string(), "Sleeping".to_string(), 37))
+        );
+        assert_eq!(parse_thread_stat("1234 (bash) R 1"), None);
+    }
+
+    #[test]
+    fn users_are_counted_from_who_output() {
+        assert_eq!(
+            parse_who("alice    pts/0        2024-01-01 10:00 (10.0.0.2)\nbob      tty1         2024-01-01 09:00\n\n"),
+            2
+        );
+    }
+
+    #[test]
+    fn nice_is_parsed_from_the_stat() {
+        let stat =
+            "1234 (tokio (rt) w) S 1 1234 1234 0 -1 4194560 110 0 0 0 25 12 0 0 30 10 8 0 100";
+        assert_eq!(parse_stat_nice(stat), Some(10));
+        let stat = "1234 (bash) S 1 1234 1234 0 -1 4194560 110 0 0 0 25 12 0 0 0 -20 8 0 100";
+        assert_eq!(parse_stat_nice(stat), Some(-20));
+        assert_eq!(parse_stat_nice("1234 (bash) R 1"), None);
+    }
+
+    #[test]
+    fn mdstat_is_parsed_into_raid_arrays() {
+        let raid_arrays = parse_mdstat(
+            "Personalities : [raid1] [raid6]
+md1 : active raid1 sdd1[1](F) sdc1[0]
+      1046528 blocks super 1.2 [2/1] [U_]
+      [==>..................]  recovery = 12.6% (131072/1046528) finish=0.5min speed=26214K/sec
+
+md0 : active (auto-read-only) raid1 sdb1[1] sda1[0]
+      1046528 blocks super 1.2 [2/2] [UU]
+
+unused devices: <none>
+",
+        );
+        assert_eq!(raid_arrays.len(), 2);
+        assert_eq!(raid_arrays[0].name, "md1");
+        assert_eq!(raid_arrays[0].level, "raid1");
+        assert_eq!(raid_arrays[0].members, vec!["sdd1(F)", "sdc1"]);
+        assert_eq!(raid_arrays[0].device_count, (2, 1));
+        assert_eq!(
+            raid_arrays[0].sync_progress,
+            Some(("recovery".to_string(), 12.6))
+        );
+        assert!(raid_arrays[0].is_degraded());
+        assert_eq!(raid_arrays[1].state, "active (auto-read-only)");
+        assert!(!raid_arrays[1].is_degraded());
+    }
+
+    #[test]
+    fn zpool_status_is_parsed_into_raid_level_and_scrub() {
+        let (raid_level, scrub_status) = parse_zpool_status(
+            "tank",
+            "  pool: tank
+ state: ONLINE
+  scan: scrub repaired 0B in 00:01:02 with 0 errors on Sun Oct 11 00:25:03 2026
+config:
+
+        NAME        STATE     READ WRITE CKSUM
+        tank        ONLINE       0     0     0
+          raidz2-0  ONLINE       0     0     0
+            sda     ONLINE       0     0     0
+",
+        );
+        assert_eq!(raid_level, "raidz2");
+        assert_eq!(
+            scrub_status.as_deref(),
+            Some("scrub repaired 0B in 00:01:02 with 0 errors on Sun Oct 11 00:25:03 2026")
+        );
+    }
+
+    #[test]
+    fn ping_rtt_is_parsed_from_ping_output() {
+        assert_eq!(
+            parse_ping_rtt("64 bytes from 192.168.1.1: icmp_seq=1 ttl=64 time=12.3 ms"),
+            Some(12.3)
+        );
+        assert_eq!(
+            parse_ping_rtt("Reply from 192.168.1.1: bytes=32 time<1ms TTL=64"),
+            Some(1.0)
+        );
+        assert_eq!(parse_ping_rtt("Request timed out."), None);
+    }
+
+    #[test]
+    fn connection_counters_are_parsed_from_ss_output() {
+        let connection_counters = parse_ss_connections(
+            "0      0      192.168.1.2:22    192.168.1.5:51234\n\t cubic wscale:7,7 rto:204 bytes_sent:5120 bytes_acked:5121 bytes_received:2048 segs_out:10\n0      0      192.168.1.2:443    10.0.0.8:40000\n\t cubic bytes_acked:300 segs_out:3\n",
+        );
+        assert_eq!(
+            connection_counters[&(
+                "192.168.1.2:22".to_string(),
+                "192.168.1.5:51234".to_string()
+            )],
+            (5120, 2048)
+        );
+        assert_eq!(
+            connection_counters[&("192.168.1.2:443".to_string(), "10.0.0.8:40000".to_string())],
+            (300, 0)
+        );
+    }
+
+    #[test]
+    fn primary_dns_is_parsed_from_resolv_conf() {
+        assert_eq!(
+            parse_resolv_conf(
+                "# generated\nsearch lan\nnameserver 10.0.0.53\nnameserver 1.1.1.1\n"
+            ),
+            Some("10.0.0.53".to_string())
+        );
+        assert_eq!(parse_resolv_conf("search lan\n"), None);
+    }
 }
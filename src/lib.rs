@@ -0,0 +1,23 @@
+// ------------------------------------
+//
+// rtop as a library
+//
+// ------------------------------------
+// the `rtop` binary is a thin consumer of this crate, other programs can embed the same layers:
+//  - `get_sys_info` : the collectors, started through `MetricsSource` and sending `CSysInfo` / `CProcessesInfo`
+//  - `utils`        : the processing of the collected info into `SysInfo` / `ProcessesInfo` with their history
+//  - `types`        : the collected, processed and configuration data types
+//  - `components`   : the ratatui widgets drawing each container, and the theme and settings handling
+//  - `plugins`      : the interface of the custom panels, with the compiled and the executable plugins
+//  - `scripting`    : the rhai scripts of the custom process columns and the alerts
+//  - `app`          : the full TUI with its event loop
+pub mod app;
+pub mod capability;
+pub mod components;
+pub mod format;
+pub mod get_sys_info;
+pub mod logger;
+pub mod plugins;
+pub mod scripting;
+pub mod types;
+pub mod utils;
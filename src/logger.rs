@@ -0,0 +1,84 @@
+// ------------------------------------
+//
+// debug logging
+//
+// ------------------------------------
+// the TUI owns the terminal, so anything printed from the collector threads corrupts the screen and is lost
+// with `--debug`, the collector errors, channel failures and collection timings were written to a log file instead
+// without it, no logger was installed and the log macros do nothing
+use std::{
+    fs::{create_dir_all, File, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::utils::get_user_directory;
+
+const DEBUG_LOG_FILE: &str = ".rtop/debug.log";
+
+struct FileLogger {
+    file: Mutex<File>,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        return metadata.level() <= log::max_level();
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        // eg: 2025-01-01 12:00:00.000 ERROR [rtop::get_sys_info] Failed to send System Info: ...
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(
+                file,
+                "{} {:<5} [{}] {}",
+                Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+// None when the home directory is not set
+pub fn get_debug_log_path() -> Option<PathBuf> {
+    return get_user_directory().map(|user_directory| user_directory.join(DEBUG_LOG_FILE));
+}
+
+// start appending the logs to the debug log file, return the path of the log file
+pub fn init_debug_logging() -> io::Result<PathBuf> {
+    let Some(debug_log_path) = get_debug_log_path() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "the home directory is not set",
+        ));
+    };
+    if let Some(parent) = debug_log_path.parent() {
+        create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&debug_log_path)?;
+
+    log::set_boxed_logger(Box::new(FileLogger {
+        file: Mutex::new(file),
+    }))
+    .map_err(io::Error::other)?;
+    log::set_max_level(LevelFilter::Debug);
+
+    return Ok(debug_log_path);
+}
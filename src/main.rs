@@ -1,71 +1,97 @@
-pub mod app;
-pub mod components;
-pub mod get_sys_info;
-pub mod types;
-pub mod utils;
-
-use clap::Parser;
-use components::*;
-
-use app::*;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use inquire::Select;
 
-use crate::components::theme::set_theme;
+use rtop::app::app;
+use rtop::capability::{get_long_version, print_doctor_report};
+use rtop::components::theme::{
+    detect_terminal_background, get_all_theme_names, get_config_directory, get_max_history_config,
+    get_view_config, read_theme_config_or_default, set_theme,
+};
+use rtop::logger::init_debug_logging;
+use rtop::types::{set_maximum_data_collection, SelectedContainer};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Arg {
     #[arg(long)]
     theme: bool,
+
+    /// The number of points kept in the graph history ( 100 - 100000 ), overrides `max_history` in the settings
+    #[arg(long, value_name = "POINTS")]
+    max_history: Option<usize>,
+
+    /// Start with the container in full screen, overrides `view` in the settings
+    #[arg(long, value_name = "CONTAINER", value_parser = ["cpu", "memory", "disk", "network", "process"])]
+    view: Option<String>,
+
+    /// Write the collector errors, channel failures and collection timings to ~/.rtop/debug.log
+    #[arg(long)]
+    debug: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Report the compiled features and which optional subsystems are available on this machine
+    Doctor,
 }
 
 fn main() {
-    let args = Arg::parse();
-    if args.theme {
+    // the long version ( --version ) will include the compiled features
+    let long_version: &'static str = Box::leak(get_long_version().into_boxed_str());
+    let matches = Arg::command().long_version(long_version).get_matches();
+    let args = Arg::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+    if let Some(Command::Doctor) = args.command {
+        print_doctor_report();
+    } else if args.theme {
         prompt_for_theme();
     } else {
-        app();
+        if args.debug {
+            match init_debug_logging() {
+                Ok(debug_log_path) => {
+                    log::info!("rtop {} started", env!("CARGO_PKG_VERSION"));
+                    println!("Writing the debug log to {}", debug_log_path.display());
+                }
+                Err(error) => eprintln!("rtop: failed to open the debug log: {}", error),
+            }
+        }
+        detect_terminal_background();
+        let theme_config = read_theme_config_or_default(get_config_directory().as_deref());
+        set_maximum_data_collection(
+            args.max_history
+                .or_else(|| get_max_history_config(&theme_config)),
+        );
+        let view = args
+            .view
+            .and_then(|view| SelectedContainer::from_name(&view))
+            .or_else(|| get_view_config(&theme_config));
+        if let Err(error) = app(view) {
+            eprintln!("rtop: {}", error);
+            std::process::exit(1);
+        }
     }
 }
 
 fn prompt_for_theme() {
     println!("Starting in theme selection mode...");
 
-    let themes = vec![
-        "default",
-        "dracula",
-        "gruvbox_dark",
-        "gruvbox_light",
-        "gruvbox_mat_dark",
-        "ayu",
-        "everforest_dark",
-        "everforest_light",
-        "flatremix",
-        "flatremix_light",
-        "grayscale",
-        "horizon",
-        "kanagawa_wave",
-        "kanagawa_lotus",
-        "monokai",
-        "onedark",
-        "nightowl",
-        "rosepine",
-        "matcha_dark_sea",
-        "paper",
-        "solarized_dark",
-        "solarized_light",
-        "tokyo_night",
-        "tokyo_storm",
-        "catppuccin_mocha",
-        "github_dark",
-    ];
+    let config_directory = get_config_directory();
+    let themes = get_all_theme_names(config_directory.as_deref());
     let ans = Select::new("Please choose a color theme:", themes).prompt();
 
     match ans {
         Ok(theme) => {
             println!("You chose: {}. Setting theme...", theme);
-            set_theme(theme.to_string());
-            println!("Theme set")
+            let Some(config_directory) = config_directory else {
+                println!("Fail to set chosen theme: the home directory is not set");
+                return;
+            };
+            match set_theme(&config_directory, theme.to_string()) {
+                Ok(_) => println!("Theme set"),
+                Err(e) => println!("Fail to set chosen theme: {}", e),
+            }
         }
         Err(_) => {
             println!("Fail to set chosen theme");
@@ -0,0 +1,272 @@
+// ------------------------------------
+//
+// widget plugins
+//
+// ------------------------------------
+// a plugin adds its own panel to the "plugins" container without changing rtop itself
+// the data was requested from a collector task at the refresh interval of the plugin, the latest data will then
+// be passed to the draw callback of the plugin together with the area of its panel
+//
+// the plugins were either compiled in behind their own cargo feature ( see `get_compiled_plugins` )
+// or the executables in ~/.rtop/plugins, which were run at every refresh and their output was shown line by line
+#[cfg(feature = "redis-plugin")]
+pub mod redis;
+
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use log::error;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use tokio::{task, time};
+
+use crate::{types::AppColorInfo, utils::get_user_directory};
+
+const PLUGIN_DIRECTORY: &str = ".rtop/plugins";
+// how often should the data of the plugin be requested if the plugin doesn't have its own interval
+pub const DEFAULT_PLUGIN_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+// how long can an executable plugin run before it was killed, so a hung plugin won't stop its panel from updating
+const COMMAND_PLUGIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub trait WidgetPlugin: Send + Sync {
+    // the title of the panel
+    fn name(&self) -> String;
+
+    // how often should the data be requested
+    fn refresh_interval(&self) -> Duration {
+        return DEFAULT_PLUGIN_REFRESH_INTERVAL;
+    }
+
+    // the data request, it was called on the blocking thread pool so it can be slow ( eg: querying a server )
+    fn collect(&self) -> Vec<String>;
+
+    // the draw callback, draw the latest collected data into the area inside the border of the panel
+    // the lines were shown as they are by default
+    fn draw(&self, data: &[String], area: Rect, frame: &mut Frame, app_color_info: &AppColorInfo) {
+        let lines: Vec<Line> = data.iter().map(|line| Line::from(line.as_str())).collect();
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(app_color_info.base_app_text_color))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
+    }
+}
+
+// an executable in the plugin directory, named after the file
+pub struct CommandPlugin {
+    name: String,
+    path: PathBuf,
+}
+
+impl WidgetPlugin for CommandPlugin {
+    fn name(&self) -> String {
+        return self.name.clone();
+    }
+
+    // the error was shown in the panel, so a broken plugin can be spotted without the debug log
+    fn collect(&self) -> Vec<String> {
+        return run_command_plugin(&self.name, &self.path, COMMAND_PLUGIN_TIMEOUT);
+    }
+}
+
+// run the executable and get its output line by line, it was killed if it didn't exit within the timeout
+fn run_command_plugin(name: &str, path: &Path, timeout: Duration) -> Vec<String> {
+    let mut child = match Command::new(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            error!("Failed to run the {} plugin: {}", name, e);
+            return vec![format!("failed to run: {}", e)];
+        }
+    };
+
+    // the output was read in its own thread, so a plugin that fills the pipe won't block the timeout
+    let Some(mut stdout) = child.stdout.take() else {
+        return vec![];
+    };
+    let (output_tx, output_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = stdout.read_to_end(&mut output);
+        let _ = output_tx.send(output);
+    });
+
+    let Ok(output) = output_rx.recv_timeout(timeout) else {
+        error!("The {} plugin timed out after {:?}", name, timeout);
+        let _ = child.kill();
+        let _ = child.wait();
+        return vec!["timed out".to_string()];
+    };
+    return match child.wait() {
+        Ok(status) if status.success() => String::from_utf8_lossy(&output)
+            .lines()
+            .map(|line| line.to_string())
+            .collect(),
+        Ok(status) => vec![format!("exited with {}", status)],
+        Err(e) => {
+            error!("Failed to run the {} plugin: {}", name, e);
+            vec![format!("failed to run: {}", e)]
+        }
+    };
+}
+
+// only the executables in the plugin directory were run, so the notes or config files beside them were skipped
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    return fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0);
+}
+
+// windows has no executable bit, so the known executable extensions were used instead
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    const EXECUTABLE_EXTENSIONS: [&str; 4] = ["exe", "bat", "cmd", "com"];
+
+    return path.is_file()
+        && path.extension().is_some_and(|extension| {
+            EXECUTABLE_EXTENSIONS
+                .iter()
+                .any(|executable_extension| extension.eq_ignore_ascii_case(executable_extension))
+        });
+}
+
+// the plugins that were compiled in with their cargo feature
+fn get_compiled_plugins() -> Vec<Arc<dyn WidgetPlugin>> {
+    #[allow(unused_mut)]
+    let mut plugins: Vec<Arc<dyn WidgetPlugin>> = vec![];
+    #[cfg(feature = "redis-plugin")]
+    plugins.push(Arc::new(redis::RedisPlugin));
+    return plugins;
+}
+
+// the executables in the plugin directory, sorted by their name so the panels keep their order
+fn get_command_plugins() -> Vec<Arc<dyn WidgetPlugin>> {
+    let Some(user_directory) = get_user_directory() else {
+        return vec![];
+    };
+    let Ok(entries) = fs::read_dir(user_directory.join(PLUGIN_DIRECTORY)) else {
+        return vec![];
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| is_executable(path))
+        .collect();
+    paths.sort();
+
+    return paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            let plugin: Arc<dyn WidgetPlugin> = Arc::new(CommandPlugin { name, path });
+            Some(plugin)
+        })
+        .collect();
+}
+
+// all the plugins, the compiled ones first
+pub fn get_plugins() -> Vec<Arc<dyn WidgetPlugin>> {
+    let mut plugins = get_compiled_plugins();
+    plugins.extend(get_command_plugins());
+    return plugins;
+}
+
+// request the data of each plugin in its own task, so a slow plugin won't hold back the others
+// the data was sent back with the index of the plugin
+pub fn spawn_plugin_collectors(
+    plugins: &[Arc<dyn WidgetPlugin>],
+    tx: Sender<(usize, Vec<String>)>,
+) {
+    for (index, plugin) in plugins.iter().enumerate() {
+        let plugin = Arc::clone(plugin);
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            loop {
+                let collecting_plugin = Arc::clone(&plugin);
+                let Ok(data) = task::spawn_blocking(move || collecting_plugin.collect()).await
+                else {
+                    error!(
+                        "The data request of the {} plugin was aborted",
+                        plugin.name()
+                    );
+                    break;
+                };
+                // the app was closed
+                if tx.send((index, data)).is_err() {
+                    error!(
+                        "Failed to send the data of the {} plugin, the channel was disconnected",
+                        plugin.name()
+                    );
+                    break;
+                }
+                time::sleep(plugin.refresh_interval()).await;
+            }
+        });
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn write_plugin(path: &Path, script: &str, mode: u32) {
+        fs::write(path, script).unwrap();
+        fs::set_permissions(path, fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    #[test]
+    fn command_plugins_are_only_the_executables() {
+        let plugin_directory =
+            std::env::temp_dir().join(format!("rtop-test-plugins-{}", std::process::id()));
+        fs::create_dir_all(&plugin_directory).unwrap();
+        let executable_path = plugin_directory.join("uptime.sh");
+        let note_path = plugin_directory.join("README.md");
+        write_plugin(&executable_path, "#!/bin/sh\necho up\n", 0o755);
+        write_plugin(&note_path, "the notes of the plugins", 0o644);
+
+        assert!(is_executable(&executable_path));
+        assert!(!is_executable(&note_path));
+        assert!(!is_executable(&plugin_directory));
+        assert_eq!(
+            run_command_plugin("uptime", &executable_path, COMMAND_PLUGIN_TIMEOUT),
+            ["up"]
+        );
+        fs::remove_dir_all(&plugin_directory).unwrap();
+    }
+
+    #[test]
+    fn hung_command_plugin_is_shown_as_timed_out() {
+        let plugin_directory =
+            std::env::temp_dir().join(format!("rtop-test-hung-plugin-{}", std::process::id()));
+        fs::create_dir_all(&plugin_directory).unwrap();
+        let plugin_path = plugin_directory.join("hung.sh");
+        write_plugin(&plugin_path, "#!/bin/sh\nexec sleep 10\n", 0o755);
+
+        assert_eq!(
+            run_command_plugin("hung", &plugin_path, Duration::from_millis(200)),
+            ["timed out"]
+        );
+        fs::remove_dir_all(&plugin_directory).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, process::Command};
+
+use crate::plugins::WidgetPlugin;
+
+// the server to query, the local server on the default port will be queried if it wasn't set
+const REDIS_URL_ENV: &str = "RTOP_REDIS_URL";
+
+// the Redis stats panel, queried with the system `redis-cli` as there is no redis client in the dependencies
+pub struct RedisPlugin;
+
+impl WidgetPlugin for RedisPlugin {
+    fn name(&self) -> String {
+        return "Redis".to_string();
+    }
+
+    fn collect(&self) -> Vec<String> {
+        let mut command = Command::new("redis-cli");
+        if let Ok(redis_url) = std::env::var(REDIS_URL_ENV) {
+            command.args(["-u", &redis_url]);
+        }
+        return match command.arg("INFO").output() {
+            Ok(output) if output.status.success() => {
+                parse_redis_info(&String::from_utf8_lossy(&output.stdout))
+            }
+            Ok(_) => vec!["the server can't be reached".to_string()],
+            Err(_) => vec!["`redis-cli` not found in PATH".to_string()],
+        };
+    }
+}
+
+// the lines of the panel from the output of `redis-cli INFO`, which was "key:value" lines grouped in "# Section"
+pub fn parse_redis_info(output: &str) -> Vec<String> {
+    let info: HashMap<&str, &str> = output
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.trim().split_once(':'))
+        .collect();
+    let get_info = |key: &str| info.get(key).copied().unwrap_or("-");
+
+    let mut lines = vec![
+        format!("{:<10} {}", "version", get_info("redis_version")),
+        format!("{:<10} {}", "clients", get_info("connected_clients")),
+        format!("{:<10} {}", "memory", get_info("used_memory_human")),
+        format!(
+            "{:<10} {}",
+            "ops/sec",
+            get_info("instantaneous_ops_per_sec")
+        ),
+    ];
+
+    let keyspace_hits = get_info("keyspace_hits").parse::<f64>().unwrap_or(0.0);
+    let keyspace_misses = get_info("keyspace_misses").parse::<f64>().unwrap_or(0.0);
+    if keyspace_hits + keyspace_misses > 0.0 {
+        lines.push(format!(
+            "{:<10} {:.1}%",
+            "hit rate",
+            keyspace_hits / (keyspace_hits + keyspace_misses) * 100.0
+        ));
+    }
+
+    // the keyspace of each database, eg: "db0:keys=12,expires=0,avg_ttl=0"
+    let mut databases: Vec<(&str, &str)> = info
+        .iter()
+        .filter(|(key, _)| key.starts_with("db"))
+        .map(|(key, value)| (*key, *value))
+        .collect();
+    databases.sort();
+    for (database, keyspace) in databases {
+        let keys = keyspace
+            .split(',')
+            .find_map(|field| field.strip_prefix("keys="))
+            .unwrap_or("0");
+        lines.push(format!("{:<10} {} keys", database, keys));
+    }
+
+    return lines;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redis_info_is_parsed_into_panel_lines() {
+        let output = "# Server\r\nredis_version:7.2.4\r\n# Clients\r\nconnected_clients:3\r\n# Memory\r\nused_memory_human:1.50M\r\n# Stats\r\ninstantaneous_ops_per_sec:12\r\nkeyspace_hits:90\r\nkeyspace_misses:10\r\n# Keyspace\r\ndb0:keys=25,expires=1,avg_ttl=0\r\n";
+        let lines = parse_redis_info(output);
+
+        assert_eq!(lines[0], "version    7.2.4");
+        assert_eq!(lines[1], "clients    3");
+        assert_eq!(lines[2], "memory     1.50M");
+        assert_eq!(lines[3], "ops/sec    12");
+        assert_eq!(lines[4], "hit rate   90.0%");
+        assert_eq!(lines[5], "db0        25 keys");
+    }
+}
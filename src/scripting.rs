@@ -0,0 +1,191 @@
+// ------------------------------------
+//
+// user scripts
+//
+// ------------------------------------
+// the custom process columns and the alerts in the settings file were small rhai scripts
+//
+// the column scripts were evaluated for each process, with the variables:
+//   pid, name, cmd, user, status, cpu_usage ( % ), memory ( bytes ), virtual_memory ( bytes ), threads, elapsed ( s )
+//   eg: { "name": "MB/thr", "script": "memory / 1048576.0 / threads" }
+//
+// the alert scripts were evaluated on every system refresh and should return true when the alert should be raised
+// with the variables: cpu ( % ), memory ( % ), load_1, load_5, load_15, uptime ( s ), process_count
+// and processes, the array of the processes with the same fields as the column scripts
+//   eg: { "name": "runaway process", "script": "processes.some(|p| p.cpu_usage > 95.0)" }
+use std::collections::{HashMap, HashSet};
+
+use log::{debug, error};
+use rhai::{Array, Dynamic, Engine, Map, Scope, AST};
+
+use crate::types::{NamedScript, ProcessData, ScriptConfig, SysInfo};
+
+// the maximum number of operations of each evaluation, so a runaway script can't freeze the UI
+// the column scripts also share a time budget in each iteration of the main loop, see update_custom_column_values
+const MAX_SCRIPT_OPERATIONS: u64 = 100_000;
+
+pub struct ScriptEngine {
+    engine: Engine,
+    columns: Vec<(String, AST)>,
+    alerts: Vec<(String, AST)>,
+    raised_alerts: HashSet<String>, // the alerts that are currently raised, so they will only be reported once
+}
+
+impl ScriptEngine {
+    // compile the scripts in the settings, the scripts that failed to compile were left out
+    // and their errors were returned so they can be shown to the user
+    pub fn new(script_config: &ScriptConfig) -> (ScriptEngine, Vec<String>) {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+
+        let mut errors = vec![];
+        let mut compile = |scripts: &[NamedScript]| -> Vec<(String, AST)> {
+            return scripts
+                .iter()
+                .filter_map(|named_script| match engine.compile(&named_script.script) {
+                    Ok(ast) => Some((named_script.name.clone(), ast)),
+                    Err(e) => {
+                        error!("Failed to compile the {} script: {}", named_script.name, e);
+                        errors.push(format!("Script error in {}: {}", named_script.name, e));
+                        None
+                    }
+                })
+                .collect();
+        };
+        let columns = compile(&script_config.columns);
+        let alerts = compile(&script_config.alerts);
+
+        return (
+            ScriptEngine {
+                engine,
+                columns,
+                alerts,
+                raised_alerts: HashSet::new(),
+            },
+            errors,
+        );
+    }
+
+    pub fn get_column_names(&self) -> Vec<String> {
+        return self.columns.iter().map(|(name, _)| name.clone()).collect();
+    }
+
+    // the value of each custom column of the process, "err" if the script failed
+    pub fn evaluate_columns(&self, process: &ProcessData) -> Vec<String> {
+        let mut scope = Scope::new();
+        for (name, value) in get_process_map(process) {
+            scope.push_dynamic(name.as_str(), value);
+        }
+
+        return self
+            .columns
+            .iter()
+            .map(|(name, ast)| {
+                match self
+                    .engine
+                    .eval_ast_with_scope::<Dynamic>(&mut scope.clone(), ast)
+                {
+                    Ok(value) => format_script_value(&value),
+                    Err(e) => {
+                        debug!("The {} column failed for pid {}: {}", name, process.pid, e);
+                        "err".to_string()
+                    }
+                }
+            })
+            .collect();
+    }
+
+    // the names of the alerts that were raised by this refresh, the alert that stays raised won't be returned again
+    // until its script returns false
+    pub fn evaluate_alerts(
+        &mut self,
+        sys_info: &SysInfo,
+        processes: &HashMap<String, ProcessData>,
+    ) -> Vec<String> {
+        if self.alerts.is_empty() {
+            return vec![];
+        }
+
+        let memory_percentage = match sys_info.memory.used_memory_vec.last() {
+            Some(used_memory) if sys_info.memory.total_memory > 0.0 => {
+                used_memory / sys_info.memory.total_memory * 100.0
+            }
+            _ => 0.0,
+        };
+        let process_maps: Array = processes
+            .values()
+            .map(|process| Dynamic::from_map(get_process_map(process)))
+            .collect();
+
+        let mut scope = Scope::new();
+        scope.push(
+            "cpu",
+            sys_info
+                .cpus
+                .first()
+                .map(|cpu| cpu.usage as f64)
+                .unwrap_or(0.0),
+        );
+        scope.push("memory", memory_percentage);
+        scope.push("load_1", sys_info.system.load_average_one);
+        scope.push("load_5", sys_info.system.load_average_five);
+        scope.push("load_15", sys_info.system.load_average_fifteen);
+        scope.push("uptime", sys_info.system.uptime as i64);
+        scope.push("process_count", processes.len() as i64);
+        scope.push("processes", process_maps);
+
+        let mut newly_raised_alerts = vec![];
+        for (name, ast) in &self.alerts {
+            let is_raised = match self
+                .engine
+                .eval_ast_with_scope::<bool>(&mut scope.clone(), ast)
+            {
+                Ok(is_raised) => is_raised,
+                Err(e) => {
+                    debug!("The {} alert failed: {}", name, e);
+                    false
+                }
+            };
+
+            if !is_raised {
+                self.raised_alerts.remove(name);
+            } else if self.raised_alerts.insert(name.clone()) {
+                newly_raised_alerts.push(name.clone());
+            }
+        }
+        return newly_raised_alerts;
+    }
+}
+
+// the fields of the process that the scripts can use
+fn get_process_map(process: &ProcessData) -> Map {
+    let mut process_map = Map::new();
+    process_map.insert("pid".into(), Dynamic::from(process.pid as i64));
+    process_map.insert("name".into(), Dynamic::from(process.name.clone()));
+    process_map.insert("cmd".into(), Dynamic::from(process.cmd.join(" ")));
+    process_map.insert("user".into(), Dynamic::from(process.user.clone()));
+    process_map.insert("status".into(), Dynamic::from(process.status.clone()));
+    process_map.insert(
+        "cpu_usage".into(),
+        Dynamic::from(process.cpu_usage.last().copied().unwrap_or(0.0) as f64),
+    );
+    process_map.insert(
+        "memory".into(),
+        Dynamic::from(process.memory.last().copied().unwrap_or(0.0)),
+    );
+    process_map.insert(
+        "virtual_memory".into(),
+        Dynamic::from(process.virtual_memory),
+    );
+    process_map.insert("threads".into(), Dynamic::from(process.thread_count as i64));
+    process_map.insert("elapsed".into(), Dynamic::from(process.elapsed as i64));
+    return process_map;
+}
+
+// the floats were rounded to 2 decimal places to fit in the column
+fn format_script_value(value: &Dynamic) -> String {
+    if let Ok(float) = value.as_float() {
+        return format!("{:.2}", float);
+    }
+    return value.to_string();
+}
@@ -1,11 +1,204 @@
-use ratatui::style::Color;
+use log::error;
+use ratatui::{
+    style::Color,
+    symbols::Marker,
+    widgets::{GraphType, ListState},
+};
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use sysinfo::Signal;
 
+// all the color fields of AppColorInfo in the order they are declared, this generates APP_COLOR_INFO_FIELDS for the
+// theme wizard to walk through every color, and the get_color / set_color to access a field by its name without
+// going through serde, new field in AppColorInfo should also be added here
+macro_rules! app_color_info_fields {
+    ($($field:ident),* $(,)?) => {
+        pub const APP_COLOR_INFO_FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+        impl AppColorInfo {
+            // get the color of a field by its name, the name should be one of APP_COLOR_INFO_FIELDS
+            pub fn get_color(&self, field: &str) -> Option<Color> {
+                return match field {
+                    $(stringify!($field) => Some(self.$field),)*
+                    _ => None,
+                };
+            }
+
+            // set the color of a field by its name, the name should be one of APP_COLOR_INFO_FIELDS
+            pub fn set_color(&mut self, field: &str, color: Color) {
+                match field {
+                    $(stringify!($field) => self.$field = color,)*
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+app_color_info_fields!(
+    background_color,
+    base_app_text_color,
+    key_text_color,
+    app_title_color,
+    pop_up_color,
+    pop_up_selected_color_bg,
+    pop_up_blur_bg,
+    cpu_container_selected_color,
+    cpu_main_block_color,
+    cpu_selected_color,
+    cpu_base_graph_color,
+    cpu_info_block_color,
+    cpu_text_color,
+    memory_container_selected_color,
+    memory_main_block_color,
+    used_memory_base_graph_color,
+    available_memory_base_graph_color,
+    free_memory_base_graph_color,
+    cached_memory_base_graph_color,
+    swap_memory_base_graph_color,
+    memory_text_color,
+    disk_container_selected_color,
+    disk_main_block_color,
+    disk_bytes_written_base_graph_color,
+    disk_bytes_read_base_graph_color,
+    disk_text_color,
+    network_container_selected_color,
+    network_main_block_color,
+    network_received_base_graph_color,
+    network_transmitted_base_graph_color,
+    network_info_block_color,
+    network_text_color,
+    process_container_selected_color,
+    process_main_block_color,
+    process_base_graph_color,
+    process_info_block_color,
+    process_title_color,
+    process_text_color,
+    process_selected_color_bg,
+    process_selected_color_fg,
+);
+
 #[derive(Serialize, Deserialize)]
 pub struct ThemeConfig {
     pub theme: String,
+    // "light" or "dark" to skip the terminal background detection when theme is "auto"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    // the theme to use for light terminal background when theme is "auto"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light_theme: Option<String>,
+    // the theme to use for dark terminal background when theme is "auto"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dark_theme: Option<String>,
+    // "auto", "mib", "gib" or "percent" for the unit of the memory values on startup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_unit: Option<String>,
+    // "bytes" or "bits" for the unit of the network rates on startup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_unit: Option<String>,
+    // the host to ping continuously, "gateway" for the default gateway, the ping will be disabled if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ping_target: Option<String>,
+    // true to look up the public IP from an external service every 5 minutes, it was disabled by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_ip_lookup: Option<bool>,
+    // true to capture the mouse so the process list can be sorted by clicking on its headers, it was disabled by
+    // default as the terminal can't select and copy the text while the mouse was captured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mouse_capture: Option<bool>,
+    // glob patterns, only the disks that match one of them will be shown, all disks will be shown if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_include: Option<Vec<String>>,
+    // glob patterns, the disks that match one of them will be hidden ( eg: "/snap/*", "squashfs" )
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_exclude: Option<Vec<String>>,
+    // the minimum free space in percentage keyed by the mount point, "*" will apply to all the other mount points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_free_thresholds: Option<HashMap<String, f64>>,
+    // glob patterns, only the network interfaces that match one of them will be shown, all interfaces will be shown if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_include: Option<Vec<String>>,
+    // glob patterns, the network interfaces that match one of them will be hidden ( eg: "docker0", "veth*", "lo" )
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_exclude: Option<Vec<String>>,
+    // the refresh tick in ms for the system metrics on startup ( 100 - 10000 ), default is 1000ms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_tick: Option<u32>,
+    // the refresh tick in ms for the processes on startup ( 100 - 10000 ), the same as the refresh_tick if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process_refresh_tick: Option<u32>,
+    // the number of points kept in the graph history ( 100 - 100000 ), default is 500, `--max-history` will override it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_history: Option<usize>,
+    // the graph style keyed by the container ( "cpu", "memory", "disk", "network", "process" ), "*" will apply to all the other containers
+    // "braille-bar", "braille-line", "block-bar", "block-line", "dot-bar" or "dot-line"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graph_style: Option<HashMap<String, String>>,
+    // show the y axis labels and grid lines on the graphs, hidden by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graph_axis: Option<bool>,
+    // the arrangement of the containers, the containers that were left out will be hidden
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<ContainerLayout>,
+    // the named tabs with their own layout, switched with the number keys 1 - 9, `layout` will be the only tab if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tabs: Option<Vec<LayoutTab>>,
+    // "cpu", "memory", "disk", "network" or "process" to start with the container in full screen, `--view` will override it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<String>,
+    // show the key hints of the current context at the bottom, hidden by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_bar: Option<bool>,
+    // show the summary line with the clock, uptime, user count and load average below the header, hidden by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_line: Option<bool>,
+    // the rhai scripts of the custom process columns and the alerts, see scripting.rs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<ScriptConfig>,
+}
+
+impl ThemeConfig {
+    // the settings with only the theme, all the other settings will fallback to their default
+    pub fn new(theme: &str) -> ThemeConfig {
+        return ThemeConfig {
+            theme: theme.to_string(),
+            background: None,
+            light_theme: None,
+            dark_theme: None,
+            memory_unit: None,
+            network_unit: None,
+            ping_target: None,
+            public_ip_lookup: None,
+            mouse_capture: None,
+            disk_include: None,
+            disk_exclude: None,
+            disk_free_thresholds: None,
+            network_include: None,
+            network_exclude: None,
+            refresh_tick: None,
+            process_refresh_tick: None,
+            max_history: None,
+            graph_style: None,
+            graph_axis: None,
+            layout: None,
+            tabs: None,
+            view: None,
+            status_bar: None,
+            summary_line: None,
+            scripts: None,
+        };
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ThemeAppearance {
+    Light,
+    Dark,
 }
 
 // the main type structture for the application
@@ -14,12 +207,38 @@ pub struct SysInfo {
     pub memory: MemoryData,
     pub disks: HashMap<String, DiskData>,
     pub networks: HashMap<String, NetworkData>,
+    pub system: SystemData,
+    pub static_info: StaticSystemInfo,
+    pub pressure: PressureInfo,
+    pub raid_arrays: Vec<RaidArray>, // the software RAID arrays from /proc/mdstat, empty if there is none
+    pub connections: Vec<ConnectionThroughput>, // the established TCP connections, empty if `ss` isn't available
+    pub connectivity: NetworkConnectivity, // the public IP won't be collected here as it has its own collector
+}
+
+impl SysInfo {
+    // the empty info before the first collection, the collected info will be applied with `process_sys_info`
+    pub fn new(static_info: StaticSystemInfo) -> SysInfo {
+        return SysInfo {
+            cpus: vec![],
+            memory: MemoryData::default(),
+            disks: HashMap::new(),
+            networks: HashMap::new(),
+            system: SystemData::default(),
+            static_info,
+            pressure: PressureInfo::default(),
+            raid_arrays: vec![],
+            connections: vec![],
+            connectivity: NetworkConnectivity::default(),
+        };
+    }
 }
 
+#[derive(Default)]
 pub struct ProcessesInfo {
     pub processes: HashMap<String, ProcessData>, // as a hashmap to easily update existing data by retrieving it based on PID which is the key
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct AppColorInfo {
     pub background_color: Color,
     pub base_app_text_color: Color,
@@ -72,46 +291,431 @@ pub struct AppColorInfo {
     pub process_text_color: Color,
     pub process_selected_color_bg: Color,
     pub process_selected_color_fg: Color,
+
+    // the cpu, memory and process graphs will be colored by the value instead of the base graph color if it was set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graph_gradient: Option<GraphGradient>,
+
+    // the process rows will be colored by their state and the usage cells by their value, the default heat
+    // will be used if it wasn't set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process_heat: Option<ProcessHeat>,
+}
+
+// the graph colors by the value in percentage, defined in the theme file as { "low": .., "medium": .., "high": .. }
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+pub struct GraphGradient {
+    pub low: Color,    // below GRADIENT_MEDIUM_PERCENTAGE
+    pub medium: Color, // below GRADIENT_HIGH_PERCENTAGE
+    pub high: Color,
+}
+
+impl GraphGradient {
+    pub fn get_color(&self, percentage: f64) -> Color {
+        if percentage < GRADIENT_MEDIUM_PERCENTAGE {
+            return self.low;
+        }
+        if percentage < GRADIENT_HIGH_PERCENTAGE {
+            return self.medium;
+        }
+        return self.high;
+    }
+}
+
+// the colors of the process states and of the cpu / memory usage above the thresholds in percentage, defined in the
+// theme file as { "running": .., "stopped": .., "zombie": .., "medium_percentage": 50.0, "high_percentage": 80.0,
+// "medium": .., "high": .. }
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ProcessHeat {
+    pub running: Color,
+    pub stopped: Color,
+    pub zombie: Color,
+    pub medium_percentage: f64,
+    pub high_percentage: f64,
+    pub medium: Color, // from medium_percentage to high_percentage
+    pub high: Color,   // from high_percentage
+}
+
+pub const DEFAULT_PROCESS_HEAT: ProcessHeat = ProcessHeat {
+    running: Color::Green,
+    stopped: Color::Yellow,
+    zombie: Color::Red,
+    medium_percentage: 50.0,
+    high_percentage: 80.0,
+    medium: Color::Yellow,
+    high: Color::Red,
+};
+
+impl ProcessHeat {
+    // the color of the process state, None for the other states so they keep the base color
+    pub fn get_state_color(&self, status: &str) -> Option<Color> {
+        let status = status.to_lowercase();
+        if status.starts_with("run") {
+            return Some(self.running);
+        }
+        if status.starts_with("stop") {
+            return Some(self.stopped);
+        }
+        if status.starts_with("zombie") {
+            return Some(self.zombie);
+        }
+        return None;
+    }
+
+    // the color of the usage, None below the medium threshold so it keeps the base color
+    pub fn get_usage_color(&self, percentage: f64) -> Option<Color> {
+        if percentage >= self.high_percentage {
+            return Some(self.high);
+        }
+        if percentage >= self.medium_percentage {
+            return Some(self.medium);
+        }
+        return None;
+    }
+}
+
+// the number of points kept in each history, set on startup from `--max-history` or the settings file
+static MAXIMUM_DATA_COLLECTION: AtomicUsize = AtomicUsize::new(DEFAULT_MAXIMUM_DATA_COLLECTION);
+const DEFAULT_MAXIMUM_DATA_COLLECTION: usize = 500;
+// the graphs show at least 100 points, so the history shouldn't be shorter than that
+const MIN_MAXIMUM_DATA_COLLECTION: usize = 100;
+const MAX_MAXIMUM_DATA_COLLECTION: usize = 100_000;
+// set the number of points kept in each history, None for the default
+pub fn set_maximum_data_collection(maximum_data_collection: Option<usize>) {
+    MAXIMUM_DATA_COLLECTION.store(
+        maximum_data_collection
+            .unwrap_or(DEFAULT_MAXIMUM_DATA_COLLECTION)
+            .clamp(MIN_MAXIMUM_DATA_COLLECTION, MAX_MAXIMUM_DATA_COLLECTION),
+        Ordering::Relaxed,
+    );
+}
+
+pub fn get_maximum_data_collection() -> usize {
+    return MAXIMUM_DATA_COLLECTION.load(Ordering::Relaxed);
+}
+
+// the cpu and memory history of each process was capped separately, as there can be thousands of processes
+const MAX_PROCESS_DATA_COLLECTION: usize = 1_000;
+
+pub fn get_maximum_process_data_collection() -> usize {
+    return get_maximum_data_collection().min(MAX_PROCESS_DATA_COLLECTION);
+}
+
+// the history of a metric for the graphs, the oldest points were dropped once it was longer than the maximum length
+// the dropped points were only removed from the storage once they outnumber the kept points, so adding a point was
+// amortized O(1) instead of shifting the whole history, while the kept points can still be borrowed as a slice
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct History<T> {
+    points: Vec<T>,
+    start: usize, // the index of the oldest kept point
+}
+
+impl<T> History<T> {
+    pub fn new() -> History<T> {
+        return History {
+            points: Vec::new(),
+            start: 0,
+        };
+    }
+
+    // add the latest point, the oldest points beyond the maximum length were dropped
+    pub fn push(&mut self, point: T, maximum_length: usize) {
+        self.points.push(point);
+        let length = self.points.len() - self.start;
+        if length > maximum_length {
+            self.start += length - maximum_length;
+        }
+        if self.start > 0 && self.start >= self.points.len() - self.start {
+            self.points.drain(..self.start);
+            self.start = 0;
+        }
+    }
+
+    // borrow the history without the latest graph_offset points, to pan back through the history,
+    // the oldest point was always kept as the latest value was read from it
+    pub fn get_panned(&self, graph_offset: usize) -> &[T] {
+        let length = self
+            .len()
+            .saturating_sub(graph_offset)
+            .max(1)
+            .min(self.len());
+        return &self[..length];
+    }
 }
 
-const MAXIMUM_DATA_COLLECTION: usize = 500;
+impl<T> Deref for History<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        return &self.points[self.start..];
+    }
+}
 
+impl<T> From<Vec<T>> for History<T> {
+    fn from(points: Vec<T>) -> History<T> {
+        return History { points, start: 0 };
+    }
+}
+
+impl<T> FromIterator<T> for History<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> History<T> {
+        return History::from(iter.into_iter().collect::<Vec<T>>());
+    }
+}
+
+// the percentage where the graph gradient switch to the medium and high color
+const GRADIENT_MEDIUM_PERCENTAGE: f64 = 50.0;
+const GRADIENT_HIGH_PERCENTAGE: f64 = 80.0;
+// ratio of throttled enforcement periods for a process to be flagged as heavily throttled
+const HEAVILY_THROTTLED_RATIO: f64 = 0.25;
+
+#[derive(Clone)]
 pub struct CpuData {
     pub id: String,
     pub brand: String,
     pub usage: f32,
-    pub usage_history_vec: Vec<f32>,
+    pub usage_history_vec: History<f32>,
+    pub topology: Option<(u32, u32)>, // the ( socket id, physical core id ) of this logical cpu, None if it can't be detected
+    pub time_breakdown_vec: History<CpuTimeBreakdown>, // empty if the breakdown isn't available on this platform
+}
+
+// percentage of the cpu time spent in each state since the last refresh
+#[derive(Clone, Copy, Default)]
+pub struct CpuTimeBreakdown {
+    pub user: f32, // including the niced user time
+    pub system: f32,
+    pub iowait: f32,
+    pub irq: f32,   // including the soft irq time
+    pub steal: f32, // time stolen by the hypervisor for other virtual machines
+}
+
+// PSI ( pressure stall information ), only available on linux kernel 4.20 and above
+#[derive(Default)]
+pub struct PressureInfo {
+    pub cpu: Option<PressureData>,
+    pub memory: Option<PressureData>,
+    pub io: Option<PressureData>,
+}
+
+#[derive(Clone)]
+pub struct PressureData {
+    pub some_vec: History<f64>, // percentage of time in the last 10 seconds that at least one task was stalled
+    pub full: f64, // percentage of time in the last 10 seconds that all non-idle tasks were stalled at the same time
 }
 
+// info of the machine that doesn't change while the app is running
+#[derive(Default)]
+pub struct StaticSystemInfo {
+    pub host_name: String,
+    pub os_name: String, // OS name with its version ( Ubuntu 24.04 for example )
+    pub kernel_version: String,
+    pub architecture: String, // CPU architecture ( x86_64 for example )
+}
+
+#[derive(Default)]
+pub struct SystemData {
+    pub load_average_one: f64,          // load average over the last 1 minute
+    pub load_average_five: f64,         // load average over the last 5 minutes
+    pub load_average_fifteen: f64,      // load average over the last 15 minutes
+    pub uptime: u64,                    // how long the system has been running, in seconds
+    pub logged_in_users: Option<usize>, // the number of login sessions, None if `who` isn't available or the summary line isn't shown
+}
+
+#[derive(Clone)]
 pub struct MemoryData {
     pub total_memory: f64,
-    pub available_memory_vec: Vec<f64>, // available is the combination of free memory, cachedmemory and ready to be reused memory
-    pub used_memory_vec: Vec<f64>,
-    pub used_swap_vec: Vec<f64>,
-    pub free_memory_vec: Vec<f64>, // free means memory that is not used at all
-    pub cached_memory_vec: Vec<f64>,
+    pub available_memory_vec: History<f64>, // available is the combination of free memory, cachedmemory and ready to be reused memory
+    pub used_memory_vec: History<f64>,
+    pub used_swap_vec: History<f64>,
+    pub free_memory_vec: History<f64>, // free means memory that is not used at all
+    pub cached_memory_vec: History<f64>,
+    pub breakdown: Option<MemoryBreakdown>, // only the latest value was kept as it was shown as text, None if it isn't available on this platform
+    pub compressed_swap: Option<CompressedSwapInfo>, // None if neither zram nor zswap was active
+    pub swap_devices: Vec<SwapDevice>, // only the latest value was kept, empty if it isn't available on this platform
+    pub zfs_arc: Option<ZfsArcInfo>,   // only the latest value was kept, None if ZFS isn't present
+    pub zfs_arc_size_vec: History<f64>, // the history of the ARC size, 0 if ZFS isn't present
 }
 
+// the ZFS ARC stats from /proc/spl/kstat/zfs/arcstats, sizes are in bytes
+#[derive(Clone, Default)]
+pub struct ZfsArcInfo {
+    pub size: f64,
+    pub target_size: f64, // the size the ARC is currently trying to reach ( c )
+    pub hits: u64,        // cumulative hits since boot
+    pub misses: u64,      // cumulative misses since boot
+    pub hit_rate: f64,    // percentage of hits since the previous collection
+}
+
+// a swap device or file from /proc/swaps, sizes are in bytes
+#[derive(Clone, Default)]
+pub struct SwapDevice {
+    pub name: String,
+    pub kind: String, // "partition" or "file"
+    pub size: f64,
+    pub used: f64,
+    pub priority: i32,
+}
+
+// the compressed swap stats of zram and zswap, sizes are in bytes
+#[derive(Clone, Default)]
+pub struct CompressedSwapInfo {
+    pub kind: String, // "zram", "zswap" or "zram+zswap"
+    pub original_size: f64,
+    pub compressed_size: f64,
+}
+
+impl CompressedSwapInfo {
+    pub fn get_compression_ratio(&self) -> f64 {
+        if self.compressed_size <= 0.0 {
+            return 0.0;
+        }
+        return self.original_size / self.compressed_size;
+    }
+}
+
+// the detailed memory stats from /proc/meminfo, all in bytes
+#[derive(Clone, Default)]
+pub struct MemoryBreakdown {
+    pub buffers: f64,
+    pub slab: f64,
+    pub dirty: f64,     // waiting to be written back to the disk
+    pub writeback: f64, // currently being written back to the disk
+    pub huge_pages_total: f64,
+    pub huge_pages_free: f64,
+    pub huge_page_size: f64,
+}
+
+#[derive(Clone)]
 pub struct DiskData {
     pub name: String,
     pub total_space: f64,
     pub available_space: f64,
     pub used_space: f64,
-    pub bytes_written_vec: Vec<f64>, // Number of written bytes since the last refresh. in B
-    pub bytes_read_vec: Vec<f64>,    // Number of read bytes since the last refresh. in B
+    pub bytes_written_vec: History<f64>, // Number of written bytes since the last refresh. in B
+    pub bytes_read_vec: History<f64>,    // Number of read bytes since the last refresh. in B
     pub file_system: String, // file system used on this disk (so for example: EXT4, NTFS, etc…).
     pub mount_point: String, // mount point of the disk (/ for example). And mount point will also served as the unique identifier for the disk
     pub disk_kind: String,   // kind of disk.( SSD for example )
+    pub inodes: Option<(u64, u64)>, // ( total inodes, free inodes ), None if the file system doesn't report inodes
+    pub temperature: Option<f64>, // the latest drive temperature in celsius from hwmon, None if it isn't exposed
+    pub temperature_vec: History<f64>, // the history of the drive temperature, only the collected ones were kept
+    pub io_stats_vec: History<DiskIoStats>, // the history of the io latency and utilization, empty if it isn't available on this platform
+    pub physical_device: String, // the device this partition belongs to, used to group the partitions in the disk list
+    pub session_bytes_written: f64, // bytes written since the app started or the disk counters were reset. in B
+    pub session_bytes_read: f64, // bytes read since the app started or the disk counters were reset. in B
     pub is_updated: bool, // this was to keep tracked of exsiting disk data we collected was still connected to the system
 }
 
+// the io latency and utilization of a disk between two collections, calculated from /proc/diskstats
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct DiskIoStats {
+    pub await_ms: f64, // average time for an io request to be served, including the time in queue
+    pub queue_depth: f64, // average number of io requests in flight
+    pub utilization: f64, // percentage of time the disk was busy doing io
+}
+
+// the free space thresholds from the settings file, the disk will be highlighted and alerted when it drop below the threshold
+#[derive(Default)]
+pub struct DiskFreeThresholds {
+    thresholds: HashMap<String, f64>, // the minimum free space in percentage keyed by the mount point or "*"
+}
+
+impl DiskFreeThresholds {
+    pub fn new(thresholds: HashMap<String, f64>) -> DiskFreeThresholds {
+        return DiskFreeThresholds { thresholds };
+    }
+
+    // the threshold of the mount point, fallback to the "*" threshold
+    fn get_threshold(&self, mount_point: &str) -> Option<f64> {
+        return self
+            .thresholds
+            .get(mount_point)
+            .or(self.thresholds.get("*"))
+            .copied();
+    }
+
+    pub fn is_low_on_space(&self, disk: &DiskData) -> bool {
+        let Some(threshold) = self.get_threshold(&disk.mount_point) else {
+            return false;
+        };
+        if disk.total_space <= 0.0 {
+            return false;
+        }
+        return disk.available_space / disk.total_space * 100.0 < threshold;
+    }
+}
+
+// a software RAID array from /proc/mdstat
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RaidArray {
+    pub name: String,                         // md0
+    pub level: String,                        // raid1, raid5 etc...
+    pub state: String,                        // active, inactive or active (auto-read-only)
+    pub members: Vec<String>, // the member disks, the failed and spare ones are marked with (F) and (S)
+    pub device_count: (u32, u32), // ( total devices, working devices ), ( 0, 0 ) if it wasn't reported
+    pub sync_progress: Option<(String, f64)>, // ( recovery, resync, reshape or check, percentage ), None if nothing is running
+}
+
+impl RaidArray {
+    pub fn is_degraded(&self) -> bool {
+        return self.device_count.1 < self.device_count.0;
+    }
+}
+
+// a btrfs file system or ZFS pool, the space was counted at the pool level as the per mount numbers are misleading for them
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct StoragePool {
+    pub name: String, // the pool name for ZFS, the label ( or uuid if unlabeled ) for btrfs
+    pub kind: String, // zfs or btrfs
+    pub raid_level: String, // mirror, raidz1, raid1, single etc...
+    pub devices: Vec<String>, // the member block devices ( sda2 ), only reported for btrfs
+    pub size: f64,    // the raw size of all the devices in the pool. in B
+    pub allocated: f64, // the raw space allocated on the devices. in B
+    pub health: Option<String>, // ONLINE, DEGRADED etc..., only reported for ZFS
+    pub scrub_status: Option<String>, // the last or running scrub, only reported for ZFS
+}
+
+impl StoragePool {
+    pub fn get_free(&self) -> f64 {
+        return (self.size - self.allocated).max(0.0);
+    }
+
+    // check if the disk is a dataset or a member of this pool
+    pub fn contains_disk(&self, disk: &DiskData) -> bool {
+        if disk.file_system != self.kind {
+            return false;
+        }
+        if self.kind == "zfs" {
+            return disk.name == self.name || disk.name.starts_with(&format!("{}/", self.name));
+        }
+        let device = disk.name.strip_prefix("/dev/").unwrap_or(&disk.name);
+        return self.devices.iter().any(|pool_device| pool_device == device);
+    }
+}
+
+// the SMART health of the physical device that a disk belongs to, from `smartctl`
+#[derive(Clone, Default)]
+pub struct SmartInfo {
+    pub device: String,           // the physical device, eg: /dev/sda for /dev/sda1
+    pub is_healthy: Option<bool>, // the overall health self-assessment
+    pub reallocated_sectors: Option<u64>, // only reported by ATA devices
+    pub wear_level: Option<u64>,  // percentage of the rated endurance used, only reported by SSDs
+    pub temperature: Option<f64>, // in celsius
+}
+
+#[derive(Clone)]
 pub struct NetworkData {
     pub interface_name: String,
-    pub ip_network: Option<String>,
-    pub current_received_vec: Vec<f64>,
-    pub current_transmitted_vec: Vec<f64>,
+    pub ip_networks: Vec<String>, // all the IPv4 addresses followed by the IPv6 addresses
+    pub vpn_info: Option<VpnInfo>,
+    pub current_received_vec: History<f64>,
+    pub current_transmitted_vec: History<f64>,
     pub total_received: f64,
     pub total_transmitted: f64,
+    pub session_received: f64, // bytes received since the app started or the network counters were reset
+    pub session_transmitted: f64, // bytes transmitted since the app started or the network counters were reset
+    pub packet_stats: NetworkPacketStats, // the packet, error and drop counts of the latest refresh
+    pub interface_details: NetworkInterfaceDetails,
     pub is_updated: bool,
 }
 
@@ -122,17 +726,151 @@ pub struct ProcessData {
     pub exe_path: Option<String>,
     pub cmd: Vec<String>,
     pub user: String,
-    pub cpu_usage: Vec<f32>,
+    pub cpu_usage: History<f32>,
     pub thread_count: u32,
-    pub memory: Vec<f64>,
+    pub memory: History<f64>,
+    pub virtual_memory: f64,
+    pub shared_memory: Option<f64>, // resident memory that is backed by a file or shared with other processes (Linux only)
+    pub oom_score: Option<(i32, i32)>, // ( oom_score, oom_score_adj ) (Linux only)
+    pub nice: Option<i32>, // -20 ( highest priority ) to 19, mapped from the base priority on windows
     pub status: String,
     pub elapsed: u64,
+    pub cpu_time: u64, // the cpu time used since the process was started in ms, summed over all the cores
     pub parent: String,
     pub current_read_disk_usage: u64,
     pub total_read_disk_usage: u64,
     pub current_write_disk_usage: u64,
     pub total_write_disk_usage: u64,
-    pub is_updated: bool,
+    pub delay_info: Option<ProcessDelayInfo>, // accumulated delay accounting (Linux only)
+    pub current_delay_info: Option<ProcessDelayInfo>, // delay accumulated since the last refresh (Linux only)
+    pub container_info: Option<ContainerInfo>, // container that the process belongs to (Linux only)
+    pub cgroup: Option<String>, // the innermost cgroup of the process, eg: nginx.service (Linux only)
+    pub cpu_throttle_info: Option<CpuThrottleInfo>, // accumulated cpu throttling of the cgroup with cpu quota (Linux only)
+    pub current_cpu_throttle_info: Option<CpuThrottleInfo>, // cpu throttling since the last refresh (Linux only)
+}
+
+// delay accounting of a process, all values are in nanoseconds
+#[derive(Debug, Clone)]
+pub struct ProcessDelayInfo {
+    pub cpu_wait_time: u64, // time spent waiting on the run queue for the cpu
+    pub io_wait_time: Option<u64>, // time spent waiting for block io, None if delay accounting is disabled
+}
+
+// a thread of the process shown in the threads view of the process detail
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadData {
+    pub tid: u64, // the thread id on linux, the thread handle on macos
+    pub name: String,
+    pub state: String,
+    pub cpu_usage: f32, // in % of a single core, since the last collection
+}
+
+// the packet, error and drop counts of a network interface since the last refresh
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NetworkPacketStats {
+    pub packets_received: u64,
+    pub packets_transmitted: u64,
+    pub errors_received: u64,
+    pub errors_transmitted: u64,
+    pub drops: Option<(u64, u64)>, // ( received, transmitted ), None if it isn't available on this platform
+}
+
+// the addresses for a quick connectivity diagnostic, shown in the network container header
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkConnectivity {
+    pub gateway: Option<String>,   // the default IPv4 gateway
+    pub dns: Option<String>,       // the primary DNS server
+    pub public_ip: Option<String>, // None if the lookup wasn't enabled in the settings or it failed
+}
+
+// the parts of the system info that were only collected while they were shown, as they were too slow to collect every tick
+// it was watched by the system info collector
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ShownSystemInfo {
+    pub is_connection_list_shown: bool, // the connections listed in the full screen network container
+    pub is_summary_line_shown: bool,    // the logged in user count of the summary line
+}
+
+// the bytes sent and received of an established TCP connection since the last refresh, sampled from the socket counters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectionThroughput {
+    pub local: String,  // local address:port
+    pub remote: String, // remote address:port
+    pub sent: f64,
+    pub received: f64,
+}
+
+// the result of a single ping, None rtt means the ping was lost
+pub struct PingResult {
+    pub target: String, // the resolved target, eg: the gateway address
+    pub rtt_ms: Option<f64>,
+}
+
+// the round trip time history of the continuous ping
+pub struct PingData {
+    pub target: String,
+    pub rtt_vec: History<Option<f64>>, // in ms, None for the lost pings
+}
+
+impl PingData {
+    pub fn new(target: String) -> PingData {
+        return PingData {
+            target,
+            rtt_vec: History::new(),
+        };
+    }
+
+    pub fn update(&mut self, ping_result: PingResult) {
+        self.target = ping_result.target;
+        self.rtt_vec
+            .push(ping_result.rtt_ms, get_maximum_data_collection());
+    }
+
+    // the percentage of lost pings within the latest sample_count pings
+    pub fn get_loss(&self, sample_count: usize) -> f64 {
+        let start_idx = self.rtt_vec.len().saturating_sub(sample_count);
+        let samples = &self.rtt_vec[start_idx..];
+        if samples.is_empty() {
+            return 0.0;
+        }
+        let lost_count = samples.iter().filter(|rtt| rtt.is_none()).count();
+        return lost_count as f64 / samples.len() as f64 * 100.0;
+    }
+}
+
+// the attributes of a network interface that rarely change
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkInterfaceDetails {
+    pub mac_address: String,
+    pub mtu: u64,
+    pub speed: Option<u64>, // the negotiated link speed in Mb/s, None for virtual interfaces or when the link is down
+    pub duplex: Option<String>, // full or half, None if it isn't reported
+}
+
+// info of a network interface that was detected as a vpn tunnel
+#[derive(Debug, Clone)]
+pub struct VpnInfo {
+    pub kind: String,                  // type of the tunnel, eg: WireGuard, TUN, TAP
+    pub endpoint: Option<String>,      // remote endpoint of the tunnel (WireGuard only)
+    pub latest_handshake: Option<u64>, // unix timestamp of the latest handshake (WireGuard only)
+}
+
+// container metadata of a containerized process, resolved from the process cgroup
+#[derive(Debug, Clone)]
+pub struct ContainerInfo {
+    pub id: String,                 // full container id
+    pub runtime: String,            // container runtime, eg: docker, podman, containerd, cri-o
+    pub name: Option<String>,       // container name, queried from the runtime
+    pub image: Option<String>,      // container image, queried from the runtime
+    pub restart_count: Option<u64>, // container restart count, queried from the runtime
+}
+
+// cpu throttling statistics from the cgroup cpu.stat
+#[derive(Debug, Clone)]
+pub struct CpuThrottleInfo {
+    pub nr_periods: u64,     // number of enforcement periods that have elapsed
+    pub nr_throttled: u64,   // number of periods that the cgroup was throttled
+    pub throttled_time: u64, // total time the cgroup was throttled (in nanoseconds)
 }
 
 pub struct CurrentProcessSignalStateData {
@@ -142,10 +880,18 @@ pub struct CurrentProcessSignalStateData {
     pub name: String,
     pub yes_confirmation: bool,
     pub no_confirmation: bool,
+    pub tree_confirmation: bool, // the signal will also be sent to all the descendants of the process
+    pub signal_name_input: String, // the typed signal name in the signal menu, the signal id was completed from it
 }
 
 impl CpuData {
-    pub fn new(id: i8, brand: String, usage: f32) -> CpuData {
+    pub fn new(
+        id: i8,
+        brand: String,
+        usage: f32,
+        topology: Option<(u32, u32)>,
+        time_breakdown: Option<CpuTimeBreakdown>,
+    ) -> CpuData {
         let id = if id == -1 {
             "CPU-AVG".to_string()
         } else {
@@ -155,11 +901,13 @@ impl CpuData {
             id,
             brand,
             usage,
-            usage_history_vec: vec![],
+            usage_history_vec: History::new(),
+            topology,
+            time_breakdown_vec: time_breakdown.into_iter().collect(),
         }
     }
 
-    pub fn update(&mut self, id: i8, usage: f32) {
+    pub fn update(&mut self, id: i8, usage: f32, time_breakdown: Option<CpuTimeBreakdown>) {
         let id = if id == -1 {
             "CPU-AVG".to_string()
         } else {
@@ -167,10 +915,13 @@ impl CpuData {
         };
         if id == self.id {
             self.usage = usage;
-            if self.usage_history_vec.len() >= MAXIMUM_DATA_COLLECTION {
-                self.usage_history_vec.remove(0);
+            self.usage_history_vec
+                .push(usage, get_maximum_data_collection());
+
+            if let Some(time_breakdown) = time_breakdown {
+                self.time_breakdown_vec
+                    .push(time_breakdown, get_maximum_data_collection());
             }
-            self.usage_history_vec.push(usage);
         }
     }
 }
@@ -179,11 +930,16 @@ impl MemoryData {
     pub fn default() -> MemoryData {
         MemoryData {
             total_memory: -1.0,
-            available_memory_vec: vec![0.0],
-            used_memory_vec: vec![0.0],
-            used_swap_vec: vec![0.0],
-            free_memory_vec: vec![0.0],
-            cached_memory_vec: vec![0.0],
+            available_memory_vec: History::from(vec![0.0]),
+            used_memory_vec: History::from(vec![0.0]),
+            used_swap_vec: History::from(vec![0.0]),
+            free_memory_vec: History::from(vec![0.0]),
+            cached_memory_vec: History::from(vec![0.0]),
+            breakdown: None,
+            compressed_swap: None,
+            swap_devices: Vec::new(),
+            zfs_arc: None,
+            zfs_arc_size_vec: History::from(vec![0.0]),
         }
     }
 
@@ -194,14 +950,26 @@ impl MemoryData {
         used_swap: f64,
         free: f64,
         cached: f64,
+        breakdown: Option<MemoryBreakdown>,
+        compressed_swap: Option<CompressedSwapInfo>,
+        swap_devices: Vec<SwapDevice>,
+        zfs_arc: Option<ZfsArcInfo>,
     ) -> MemoryData {
         return MemoryData {
             total_memory: total,
-            available_memory_vec: vec![available],
-            used_memory_vec: vec![used],
-            used_swap_vec: vec![used_swap],
-            free_memory_vec: vec![free],
-            cached_memory_vec: vec![cached],
+            available_memory_vec: History::from(vec![available]),
+            used_memory_vec: History::from(vec![used]),
+            used_swap_vec: History::from(vec![used_swap]),
+            free_memory_vec: History::from(vec![free]),
+            cached_memory_vec: History::from(vec![cached]),
+            breakdown,
+            compressed_swap,
+            swap_devices,
+            zfs_arc_size_vec: History::from(vec![zfs_arc
+                .as_ref()
+                .map(|zfs_arc| zfs_arc.size)
+                .unwrap_or(0.0)]),
+            zfs_arc,
         };
     }
 
@@ -213,29 +981,27 @@ impl MemoryData {
         used_swap: f64,
         free: f64,
         cached: f64,
+        breakdown: Option<MemoryBreakdown>,
+        compressed_swap: Option<CompressedSwapInfo>,
+        swap_devices: Vec<SwapDevice>,
+        zfs_arc: Option<ZfsArcInfo>,
     ) {
         self.total_memory = total;
-        self.available_memory_vec.push(available);
-        self.used_memory_vec.push(used);
-        self.used_swap_vec.push(used_swap);
-        self.free_memory_vec.push(free);
-        self.cached_memory_vec.push(cached);
-
-        if self.available_memory_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.available_memory_vec.remove(0);
-        }
-        if self.used_memory_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.used_memory_vec.remove(0);
-        }
-        if self.used_swap_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.used_swap_vec.remove(0);
-        }
-        if self.free_memory_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.free_memory_vec.remove(0);
-        }
-        if self.cached_memory_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.cached_memory_vec.remove(0);
-        }
+        self.breakdown = breakdown;
+        self.compressed_swap = compressed_swap;
+        self.swap_devices = swap_devices;
+        let maximum_data_collection = get_maximum_data_collection();
+        self.zfs_arc_size_vec.push(
+            zfs_arc.as_ref().map(|zfs_arc| zfs_arc.size).unwrap_or(0.0),
+            maximum_data_collection,
+        );
+        self.zfs_arc = zfs_arc;
+        self.available_memory_vec
+            .push(available, maximum_data_collection);
+        self.used_memory_vec.push(used, maximum_data_collection);
+        self.used_swap_vec.push(used_swap, maximum_data_collection);
+        self.free_memory_vec.push(free, maximum_data_collection);
+        self.cached_memory_vec.push(cached, maximum_data_collection);
     }
 }
 
@@ -250,17 +1016,28 @@ impl DiskData {
         file_system: String,
         mount_point: String,
         kind: String,
+        inodes: Option<(u64, u64)>,
+        temperature: Option<f64>,
+        io_stats: Option<DiskIoStats>,
+        physical_device: String,
     ) -> DiskData {
         DiskData {
             name,
             total_space,
             available_space,
             used_space,
-            bytes_written_vec: vec![bytes_written],
-            bytes_read_vec: vec![bytes_read],
+            bytes_written_vec: History::from(vec![bytes_written]),
+            bytes_read_vec: History::from(vec![bytes_read]),
             file_system,
             mount_point,
             disk_kind: kind,
+            inodes,
+            temperature,
+            temperature_vec: temperature.into_iter().collect(),
+            io_stats_vec: io_stats.into_iter().collect(),
+            physical_device,
+            session_bytes_written: bytes_written,
+            session_bytes_read: bytes_read,
             is_updated: true,
         }
     }
@@ -276,6 +1053,10 @@ impl DiskData {
         file_system: String,
         mount_point: String,
         kind: String,
+        inodes: Option<(u64, u64)>,
+        temperature: Option<f64>,
+        io_stats: Option<DiskIoStats>,
+        physical_device: String,
     ) {
         if mount_point == self.mount_point {
             self.name = name;
@@ -284,35 +1065,72 @@ impl DiskData {
             self.used_space = used_space;
             self.file_system = file_system;
             self.disk_kind = kind;
-            self.bytes_written_vec.push(bytes_written);
-            self.bytes_read_vec.push(bytes_read);
-            if self.bytes_written_vec.len() > MAXIMUM_DATA_COLLECTION {
-                self.bytes_written_vec.remove(0);
+            self.inodes = inodes;
+            self.physical_device = physical_device;
+            self.temperature = temperature;
+            let maximum_data_collection = get_maximum_data_collection();
+            if let Some(temperature) = temperature {
+                self.temperature_vec
+                    .push(temperature, maximum_data_collection);
             }
-            if self.bytes_read_vec.len() > MAXIMUM_DATA_COLLECTION {
-                self.bytes_read_vec.remove(0);
+            if let Some(io_stats) = io_stats {
+                self.io_stats_vec.push(io_stats, maximum_data_collection);
             }
+            self.bytes_written_vec
+                .push(bytes_written, maximum_data_collection);
+            self.bytes_read_vec
+                .push(bytes_read, maximum_data_collection);
+            self.session_bytes_written += bytes_written;
+            self.session_bytes_read += bytes_read;
             self.is_updated = true;
         }
     }
+
+    // reset the bytes written and read of this session
+    pub fn reset_session(&mut self) {
+        self.session_bytes_written = 0.0;
+        self.session_bytes_read = 0.0;
+    }
+}
+
+impl PressureData {
+    pub fn new(some: f64, full: f64) -> PressureData {
+        return PressureData {
+            some_vec: History::from(vec![some]),
+            full,
+        };
+    }
+
+    pub fn update(&mut self, some: f64, full: f64) {
+        self.some_vec.push(some, get_maximum_data_collection());
+        self.full = full;
+    }
 }
 
 impl NetworkData {
     pub fn new(
         interface_name: String,
-        ip_network: Option<String>,
+        ip_networks: Vec<String>,
+        vpn_info: Option<VpnInfo>,
         current_received: f64,
         current_transmitted: f64,
         total_received: f64,
         total_transmitted: f64,
+        packet_stats: NetworkPacketStats,
+        interface_details: NetworkInterfaceDetails,
     ) -> NetworkData {
         return NetworkData {
             interface_name,
-            ip_network,
-            current_received_vec: vec![current_received],
-            current_transmitted_vec: vec![current_transmitted],
+            ip_networks,
+            vpn_info,
+            current_received_vec: History::from(vec![current_received]),
+            current_transmitted_vec: History::from(vec![current_transmitted]),
             total_received,
             total_transmitted,
+            session_received: current_received,
+            session_transmitted: current_transmitted,
+            packet_stats,
+            interface_details,
             is_updated: true,
         };
     }
@@ -320,29 +1138,68 @@ impl NetworkData {
     pub fn update(
         &mut self,
         interface_name: String,
-        ip_network: Option<String>,
+        ip_networks: Vec<String>,
+        vpn_info: Option<VpnInfo>,
         current_received: f64,
         current_transmitted: f64,
         total_received: f64,
         total_transmitted: f64,
+        packet_stats: NetworkPacketStats,
+        interface_details: NetworkInterfaceDetails,
     ) {
         self.interface_name = interface_name;
-        self.ip_network = ip_network;
-        self.current_received_vec.push(current_received);
-        self.current_transmitted_vec.push(current_transmitted);
-        if self.current_received_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.current_received_vec.remove(0);
-        }
-        if self.current_transmitted_vec.len() > MAXIMUM_DATA_COLLECTION {
-            self.current_transmitted_vec.remove(0);
-        }
+        self.ip_networks = ip_networks;
+        self.vpn_info = vpn_info;
+        self.current_received_vec
+            .push(current_received, get_maximum_data_collection());
+        self.current_transmitted_vec
+            .push(current_transmitted, get_maximum_data_collection());
         self.total_received = total_received;
         self.total_transmitted = total_transmitted;
+        self.session_received += current_received;
+        self.session_transmitted += current_transmitted;
+        self.packet_stats = packet_stats;
+        self.interface_details = interface_details;
         self.is_updated = true;
     }
+
+    // reset the bytes received and transmitted of this session
+    pub fn reset_session(&mut self) {
+        self.session_received = 0.0;
+        self.session_transmitted = 0.0;
+    }
 }
 
 impl ProcessData {
+    // the container of the process, its cgroup if it wasn't in a container, "-" if neither was known
+    pub fn get_container_name(&self) -> String {
+        if let Some(container_info) = self.container_info.as_ref() {
+            return match container_info.name.as_ref() {
+                Some(name) => name.clone(),
+                None => format!(
+                    "{}:{}",
+                    container_info.runtime,
+                    container_info.id.get(..12).unwrap_or(&container_info.id)
+                ),
+            };
+        }
+        return self.cgroup.clone().unwrap_or("-".to_string());
+    }
+
+    // a process is considered heavily throttled if its cgroup was throttled in more than
+    // HEAVILY_THROTTLED_RATIO of the enforcement periods since the last refresh
+    pub fn is_heavily_throttled(&self) -> bool {
+        match self.current_cpu_throttle_info.as_ref() {
+            Some(current_cpu_throttle_info) => {
+                current_cpu_throttle_info.nr_periods > 0
+                    && current_cpu_throttle_info.nr_throttled as f64
+                        / current_cpu_throttle_info.nr_periods as f64
+                        >= HEAVILY_THROTTLED_RATIO
+            }
+            None => false,
+        }
+    }
+
     pub fn new(
         pid: u32,
         name: String,
@@ -352,13 +1209,22 @@ impl ProcessData {
         cpu_usage: f32,
         thread_count: u32,
         memory: f64,
+        virtual_memory: f64,
+        shared_memory: Option<f64>,
+        oom_score: Option<(i32, i32)>,
+        nice: Option<i32>,
         status: String,
         elapsed: u64,
+        cpu_time: u64,
         parent: String,
         current_read_disk_usage: u64,
         total_read_disk_usage: u64,
         current_write_disk_usage: u64,
         total_write_disk_usage: u64,
+        delay_info: Option<ProcessDelayInfo>,
+        container_info: Option<ContainerInfo>,
+        cgroup: Option<String>,
+        cpu_throttle_info: Option<CpuThrottleInfo>,
     ) -> ProcessData {
         return ProcessData {
             pid,
@@ -366,17 +1232,27 @@ impl ProcessData {
             exe_path,
             cmd,
             user,
-            cpu_usage: vec![cpu_usage],
+            cpu_usage: History::from(vec![cpu_usage]),
             thread_count,
-            memory: vec![memory],
+            memory: History::from(vec![memory]),
+            virtual_memory,
+            shared_memory,
+            oom_score,
+            nice,
             status,
             elapsed,
+            cpu_time,
             parent,
-            is_updated: true,
             current_read_disk_usage,
             total_read_disk_usage,
             current_write_disk_usage,
             total_write_disk_usage,
+            delay_info,
+            current_delay_info: None,
+            container_info,
+            cgroup,
+            cpu_throttle_info,
+            current_cpu_throttle_info: None,
         };
     }
 
@@ -390,61 +1266,175 @@ impl ProcessData {
         cpu_usage: f32,
         thread_count: u32,
         memory: f64,
+        virtual_memory: f64,
+        shared_memory: Option<f64>,
+        oom_score: Option<(i32, i32)>,
+        nice: Option<i32>,
         status: String,
         elapsed: u64,
+        cpu_time: u64,
         parent: String,
         current_read_disk_usage: u64,
         total_read_disk_usage: u64,
         current_write_disk_usage: u64,
         total_write_disk_usage: u64,
+        delay_info: Option<ProcessDelayInfo>,
+        container_info: Option<ContainerInfo>,
+        cgroup: Option<String>,
+        cpu_throttle_info: Option<CpuThrottleInfo>,
     ) {
         if self.pid == pid {
             self.name = name;
             self.exe_path = exe_path;
             self.cmd = cmd;
             self.user = user;
-            self.cpu_usage.push(cpu_usage);
-            self.thread_count = thread_count;
-            self.memory.push(memory);
-            self.status = status;
-            self.elapsed = elapsed;
             self.parent = parent;
-            self.current_read_disk_usage = current_read_disk_usage;
-            self.total_read_disk_usage = total_read_disk_usage;
-            self.current_write_disk_usage = current_write_disk_usage;
-            self.total_write_disk_usage = total_write_disk_usage;
+            self.container_info = container_info;
+            self.cgroup = cgroup;
+            self.update_metrics(
+                cpu_usage,
+                thread_count,
+                memory,
+                virtual_memory,
+                shared_memory,
+                oom_score,
+                nice,
+                status,
+                elapsed,
+                cpu_time,
+                current_read_disk_usage,
+                total_read_disk_usage,
+                current_write_disk_usage,
+                total_write_disk_usage,
+                delay_info,
+                cpu_throttle_info,
+            );
+        }
+    }
 
-            if self.cpu_usage.len() > MAXIMUM_DATA_COLLECTION {
-                self.cpu_usage.remove(0);
-            }
+    // update the metrics that change on every refresh, the name, command and user of the process stay the same
+    pub fn update_metrics(
+        &mut self,
+        cpu_usage: f32,
+        thread_count: u32,
+        memory: f64,
+        virtual_memory: f64,
+        shared_memory: Option<f64>,
+        oom_score: Option<(i32, i32)>,
+        nice: Option<i32>,
+        status: String,
+        elapsed: u64,
+        cpu_time: u64,
+        current_read_disk_usage: u64,
+        total_read_disk_usage: u64,
+        current_write_disk_usage: u64,
+        total_write_disk_usage: u64,
+        delay_info: Option<ProcessDelayInfo>,
+        cpu_throttle_info: Option<CpuThrottleInfo>,
+    ) {
+        self.cpu_usage
+            .push(cpu_usage, get_maximum_process_data_collection());
+        self.thread_count = thread_count;
+        self.memory
+            .push(memory, get_maximum_process_data_collection());
+        self.virtual_memory = virtual_memory;
+        self.shared_memory = shared_memory;
+        self.oom_score = oom_score;
+        self.nice = nice;
+        self.status = status;
+        self.elapsed = elapsed;
+        self.cpu_time = cpu_time;
+        self.current_read_disk_usage = current_read_disk_usage;
+        self.total_read_disk_usage = total_read_disk_usage;
+        self.current_write_disk_usage = current_write_disk_usage;
+        self.total_write_disk_usage = total_write_disk_usage;
 
-            if self.memory.len() > MAXIMUM_DATA_COLLECTION {
-                self.memory.remove(0);
-            }
-            self.is_updated = true;
-        }
+        // the delay since last refresh will be the difference between the accumulated delay
+        self.current_delay_info = match (&self.delay_info, &delay_info) {
+            (Some(previous), Some(current)) => Some(ProcessDelayInfo {
+                cpu_wait_time: current.cpu_wait_time.saturating_sub(previous.cpu_wait_time),
+                io_wait_time: match (previous.io_wait_time, current.io_wait_time) {
+                    (Some(previous_io), Some(current_io)) => {
+                        Some(current_io.saturating_sub(previous_io))
+                    }
+                    _ => None,
+                },
+            }),
+            _ => None,
+        };
+        self.delay_info = delay_info;
+
+        self.current_cpu_throttle_info = match (&self.cpu_throttle_info, &cpu_throttle_info) {
+            (Some(previous), Some(current)) => Some(CpuThrottleInfo {
+                nr_periods: current.nr_periods.saturating_sub(previous.nr_periods),
+                nr_throttled: current.nr_throttled.saturating_sub(previous.nr_throttled),
+                throttled_time: current
+                    .throttled_time
+                    .saturating_sub(previous.throttled_time),
+            }),
+            _ => None,
+        };
+        self.cpu_throttle_info = cpu_throttle_info;
     }
 }
 
 // the structure of info collected from a seperated thread
 // a C infront mean Collected
+#[derive(Clone)]
 pub struct CSysInfo {
     pub cpus: Vec<CCpuData>,
     pub memory: CMemoryData,
     pub disks: Vec<CDiskData>,
     pub networks: Vec<CNetworkData>,
+    pub system: CSystemData,
+    pub pressure: CPressureInfo,
+    pub raid_arrays: Vec<RaidArray>,
+    pub connections: Vec<ConnectionThroughput>,
+    pub connectivity: NetworkConnectivity,
 }
 
+// the changes of the processes since the last collection, so the name, command and user of the thousands of
+// processes that stay the same won't be copied and sent on every refresh
+#[derive(Clone)]
 pub struct CProcessesInfo {
-    pub processes: Vec<CProcessData>,
+    pub is_snapshot: bool, // all the running processes were in `processes`, those that weren't in it should be removed
+    pub processes: Vec<CProcessData>, // the new processes, and those with a changed name, command, user or parent
+    pub updated_processes: Vec<CProcessUpdate>, // the metrics of the other running processes
+    pub removed_pids: Vec<u32>,       // the processes that exited since the last collection
 }
 
+#[derive(Clone)]
 pub struct CCpuData {
     pub id: i8,
     pub brand: String,
     pub usage: f32,
+    pub topology: Option<(u32, u32)>, // ( socket id, physical core id ), always None for the average cpu
+    pub time_breakdown: Option<CpuTimeBreakdown>, // None if it isn't available on this platform or on the first collection
+}
+
+#[derive(Clone)]
+pub struct CPressureInfo {
+    pub cpu: Option<CPressureData>,
+    pub memory: Option<CPressureData>,
+    pub io: Option<CPressureData>,
 }
 
+#[derive(Clone)]
+pub struct CPressureData {
+    pub some: f64, // the avg10 of the "some" line in percentage
+    pub full: f64, // the avg10 of the "full" line in percentage
+}
+
+#[derive(Clone)]
+pub struct CSystemData {
+    pub load_average_one: f64,
+    pub load_average_five: f64,
+    pub load_average_fifteen: f64,
+    pub uptime: u64, // in seconds
+    pub logged_in_users: Option<usize>,
+}
+
+#[derive(Clone)]
 pub struct CMemoryData {
     pub total_memory: f64,
     pub available_memory: f64, // available is the combination of free memory, cached memory and ready to be reused memory
@@ -452,8 +1442,13 @@ pub struct CMemoryData {
     pub used_swap: f64,
     pub free_memory: f64, // free means memory that is not used at all
     pub cached_memory: f64,
+    pub breakdown: Option<MemoryBreakdown>,
+    pub compressed_swap: Option<CompressedSwapInfo>,
+    pub swap_devices: Vec<SwapDevice>,
+    pub zfs_arc: Option<ZfsArcInfo>,
 }
 
+#[derive(Clone)]
 pub struct CDiskData {
     pub name: String,
     pub total_space: f64,
@@ -464,17 +1459,26 @@ pub struct CDiskData {
     pub file_system: String, // file system used on this disk (so for example: EXT4, NTFS, etc…).
     pub mount_point: String, // mount point of the disk (/ for example).
     pub kind: String,       // kind of disk.( SSD for example )
+    pub inodes: Option<(u64, u64)>, // ( total inodes, free inodes )
+    pub temperature: Option<f64>, // in celsius
+    pub io_stats: Option<DiskIoStats>,
+    pub physical_device: String, // the device this partition belongs to ( /dev/nvme0n1 for /dev/nvme0n1p2 ), same as the name if it isn't a partition
 }
 
+#[derive(Clone)]
 pub struct CNetworkData {
     pub interface_name: String,
-    pub ip_network: Option<String>,
+    pub ip_networks: Vec<String>, // all the IPv4 addresses followed by the IPv6 addresses
+    pub vpn_info: Option<VpnInfo>,
     pub current_received: f64,
     pub total_received: f64,
     pub current_transmitted: f64,
     pub total_transmitted: f64,
+    pub packet_stats: NetworkPacketStats,
+    pub interface_details: NetworkInterfaceDetails,
 }
 
+#[derive(Clone)]
 pub struct CProcessData {
     pub pid: u32,
     pub name: String,
@@ -484,22 +1488,54 @@ pub struct CProcessData {
     pub cpu_usage: f32,
     pub thread_count: u32,
     pub memory: f64,
+    pub virtual_memory: f64,
+    pub shared_memory: Option<f64>,
+    pub oom_score: Option<(i32, i32)>,
+    pub nice: Option<i32>,
     pub status: String,
     pub elapsed: u64,
+    pub cpu_time: u64,
     pub parent: String,
     pub current_read_disk_usage: u64,
     pub total_read_disk_usage: u64,
     pub current_write_disk_usage: u64,
     pub total_write_disk_usage: u64,
+    pub delay_info: Option<ProcessDelayInfo>,
+    pub container_info: Option<ContainerInfo>,
+    pub cgroup: Option<String>,
+    pub cpu_throttle_info: Option<CpuThrottleInfo>,
 }
 
-#[derive(PartialEq)]
+// the metrics of a process that was already sent
+#[derive(Clone)]
+pub struct CProcessUpdate {
+    pub pid: u32,
+    pub cpu_usage: f32,
+    pub thread_count: u32,
+    pub memory: f64,
+    pub virtual_memory: f64,
+    pub shared_memory: Option<f64>,
+    pub oom_score: Option<(i32, i32)>,
+    pub nice: Option<i32>,
+    pub status: String,
+    pub elapsed: u64,
+    pub cpu_time: u64,
+    pub current_read_disk_usage: u64,
+    pub total_read_disk_usage: u64,
+    pub current_write_disk_usage: u64,
+    pub total_write_disk_usage: u64,
+    pub delay_info: Option<ProcessDelayInfo>,
+    pub cpu_throttle_info: Option<CpuThrottleInfo>,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum SelectedContainer {
     Cpu,
     Memory,
     Disk,
     Network,
     Process,
+    Plugins, // the panels of the widget plugins, it can't be selected
     Menu,
     None,
 }
@@ -518,6 +1554,493 @@ pub enum AppPopUpType {
     KillConfirmation,
     TerminateConfirmation,
     SignalMenu,
+    ThemeMenu,
+    ThemeWizard,
+    UserMenu,
+    GoToPid,
+}
+
+impl SelectedContainer {
+    // the container name used in the settings file
+    pub fn from_name(name: &str) -> Option<SelectedContainer> {
+        match name.to_lowercase().as_str() {
+            "cpu" => Some(SelectedContainer::Cpu),
+            "memory" => Some(SelectedContainer::Memory),
+            "disk" => Some(SelectedContainer::Disk),
+            "network" => Some(SelectedContainer::Network),
+            "process" => Some(SelectedContainer::Process),
+            "plugins" => Some(SelectedContainer::Plugins),
+            _ => None,
+        }
+    }
+}
+
+// the result of resizing a node of the container arrangement
+#[derive(PartialEq)]
+enum ResizeResult {
+    NotFound,
+    Pending, // the container was found, but it was left for the parent to resize as it was the only child
+    Resized(bool), // false if it couldn't be resized any further
+}
+
+// the smallest share in percentage that a container can be shrunk to
+pub const MIN_CONTAINER_SHARE: u16 = 10;
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutDirection {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+// a node of the container arrangement, it is either a container or a split of the child nodes
+//
+// eg: process on the left at 70% and no network container
+// {
+//   "direction": "horizontal",
+//   "children": [
+//     { "container": "process", "size": 70 },
+//     { "children": [{ "container": "cpu" }, { "container": "memory" }, { "container": "disk" }] }
+//   ]
+// }
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct ContainerLayout {
+    // "cpu", "memory", "disk", "network" or "process", the child nodes will be ignored when it was set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    // the percentage of the parent split, the nodes without it will share the remaining space
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u16>,
+    // the direction to split the child nodes, default to "vertical"
+    #[serde(default)]
+    pub direction: LayoutDirection,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ContainerLayout>,
+}
+
+impl ContainerLayout {
+    pub fn container(name: &str, size: u16) -> ContainerLayout {
+        ContainerLayout {
+            container: Some(name.to_string()),
+            size: Some(size),
+            direction: LayoutDirection::Vertical,
+            children: vec![],
+        }
+    }
+
+    pub fn split(
+        direction: LayoutDirection,
+        size: u16,
+        children: Vec<ContainerLayout>,
+    ) -> ContainerLayout {
+        ContainerLayout {
+            container: None,
+            size: Some(size),
+            direction,
+            children,
+        }
+    }
+
+    // the default arrangement
+    //
+    //   ------------------------------------------------------------
+    //   |                  CPU INFO (top 30.0%)                    |
+    //   ------------------------------------------------------------
+    //   |   (MEMORY AND DIKS)     |                                |
+    //   |    Bottom left (45%)    |   (PROCESS bottom right 55%)   |
+    //   |      & top (65%)        |                                |
+    //   |--------------------(BOTTOM 70%)                          |
+    //   |      (NETWORK)          |                                |
+    //   |    Bottom left (45%)    |                                |
+    //   |     & bottom (35%)      |                                |
+    //   ------------------------------------------------------------
+    pub fn default_layout() -> ContainerLayout {
+        ContainerLayout::split(
+            LayoutDirection::Vertical,
+            100,
+            vec![
+                ContainerLayout::container("cpu", 30),
+                ContainerLayout::split(
+                    LayoutDirection::Horizontal,
+                    70,
+                    vec![
+                        ContainerLayout::split(
+                            LayoutDirection::Vertical,
+                            45,
+                            vec![
+                                ContainerLayout::split(
+                                    LayoutDirection::Horizontal,
+                                    65,
+                                    vec![
+                                        ContainerLayout::container("memory", 50),
+                                        ContainerLayout::container("disk", 50),
+                                    ],
+                                ),
+                                ContainerLayout::container("network", 35),
+                            ],
+                        ),
+                        ContainerLayout::container("process", 55),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    // the built-in arrangements that can be cycled with 0, on top of the layout of the tab
+    pub fn get_presets() -> Vec<LayoutTab> {
+        vec![
+            LayoutTab {
+                name: "full dashboard".to_string(),
+                layout: ContainerLayout::default_layout(),
+            },
+            LayoutTab {
+                name: "process focused".to_string(),
+                layout: ContainerLayout::split(
+                    LayoutDirection::Horizontal,
+                    100,
+                    vec![
+                        ContainerLayout::container("process", 70),
+                        ContainerLayout::split(
+                            LayoutDirection::Vertical,
+                            30,
+                            vec![
+                                ContainerLayout::container("cpu", 50),
+                                ContainerLayout::container("memory", 50),
+                            ],
+                        ),
+                    ],
+                ),
+            },
+            LayoutTab {
+                name: "graphs only".to_string(),
+                layout: ContainerLayout::split(
+                    LayoutDirection::Vertical,
+                    100,
+                    vec![
+                        ContainerLayout::container("cpu", 40),
+                        ContainerLayout::split(
+                            LayoutDirection::Horizontal,
+                            60,
+                            vec![
+                                ContainerLayout::container("memory", 1),
+                                ContainerLayout::container("disk", 1),
+                                ContainerLayout::container("network", 1),
+                            ],
+                        ),
+                    ],
+                ),
+            },
+        ]
+    }
+
+    // grow or shrink the share of the container in its parent split by the step in percentage, the other child nodes
+    // in the split will give up or take the space in proportion to their share
+    // the closest ancestor that has any sibling will be resized instead when the container is the only child
+    // return false if the container wasn't found or it can't be resized
+    pub fn resize_container(&mut self, container: SelectedContainer, step: i32) -> bool {
+        return self.resize_child_node(container, step) == ResizeResult::Resized(true);
+    }
+
+    fn resize_child_node(&mut self, container: SelectedContainer, step: i32) -> ResizeResult {
+        if let Some(name) = self.container.as_ref() {
+            if SelectedContainer::from_name(name) == Some(container) {
+                return ResizeResult::Pending;
+            }
+            return ResizeResult::NotFound;
+        }
+
+        for i in 0..self.children.len() {
+            match self.children[i].resize_child_node(container, step) {
+                ResizeResult::NotFound => {}
+                ResizeResult::Pending => {
+                    if self.children.len() < 2 {
+                        return ResizeResult::Pending;
+                    }
+                    return ResizeResult::Resized(self.resize_child(i, step));
+                }
+                resized => return resized,
+            }
+        }
+        return ResizeResult::NotFound;
+    }
+
+    fn resize_child(&mut self, child_index: usize, step: i32) -> bool {
+        // the share of each child node in percentage, the same as how the split was laid out
+        let is_all_sized = self.children.iter().all(|child| child.size.is_some());
+        let sized_total: f64 = self
+            .children
+            .iter()
+            .filter_map(|child| child.size)
+            .map(|size| size as f64)
+            .sum();
+        let unsized_count = self
+            .children
+            .iter()
+            .filter(|child| child.size.is_none())
+            .count();
+        let shares: Vec<f64> = self
+            .children
+            .iter()
+            .map(|child| match child.size {
+                Some(size) if is_all_sized => size as f64 / sized_total.max(1.0) * 100.0,
+                Some(size) => size as f64,
+                None => (100.0 - sized_total).max(0.0) / unsized_count as f64,
+            })
+            .collect();
+
+        let min_share = MIN_CONTAINER_SHARE as f64;
+        let max_share = 100.0 - min_share * (self.children.len() - 1) as f64;
+        let new_share = (shares[child_index] + step as f64).clamp(min_share, max_share);
+        if new_share == shares[child_index] {
+            return false;
+        }
+
+        // the other child nodes will be resized in proportion to their share, but not smaller than the minimum share
+        let others_total = 100.0 - shares[child_index];
+        let new_others_total = 100.0 - new_share;
+        for (i, child) in self.children.iter_mut().enumerate() {
+            let share = if i == child_index {
+                new_share
+            } else if others_total > 0.0 {
+                (shares[i] / others_total * new_others_total).max(min_share)
+            } else {
+                new_others_total / (shares.len() - 1) as f64
+            };
+            child.size = Some(share.round() as u16);
+        }
+        return true;
+    }
+
+    // all the containers in this node, in the order they were arranged
+    pub fn get_containers(&self) -> Vec<SelectedContainer> {
+        if let Some(name) = self.container.as_ref() {
+            return SelectedContainer::from_name(name).into_iter().collect();
+        }
+        return self
+            .children
+            .iter()
+            .flat_map(|child| child.get_containers())
+            .collect();
+    }
+}
+
+// the user scripts in the settings file
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct ScriptConfig {
+    #[serde(default)]
+    pub columns: Vec<NamedScript>, // the custom columns of the process list, evaluated for each process
+    #[serde(default)]
+    pub alerts: Vec<NamedScript>, // the alert conditions, evaluated on every system refresh
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct NamedScript {
+    pub name: String,
+    pub script: String,
+}
+
+// a named tab with its own arrangement of the containers
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LayoutTab {
+    pub name: String,
+    pub layout: ContainerLayout,
+}
+
+// the graph type and marker of the charts in a container, "braille-bar" was the default
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum GraphStyle {
+    BrailleBar,
+    BrailleLine,
+    BlockBar,
+    BlockLine,
+    DotBar,
+    DotLine,
+}
+
+impl GraphStyle {
+    pub fn from_config(graph_style: &str) -> GraphStyle {
+        match graph_style.to_lowercase().as_str() {
+            "braille-line" => GraphStyle::BrailleLine,
+            "block-bar" => GraphStyle::BlockBar,
+            "block-line" => GraphStyle::BlockLine,
+            "dot-bar" => GraphStyle::DotBar,
+            "dot-line" => GraphStyle::DotLine,
+            _ => GraphStyle::BrailleBar,
+        }
+    }
+
+    pub fn get_next(&self) -> GraphStyle {
+        match self {
+            GraphStyle::BrailleBar => GraphStyle::BrailleLine,
+            GraphStyle::BrailleLine => GraphStyle::BlockBar,
+            GraphStyle::BlockBar => GraphStyle::BlockLine,
+            GraphStyle::BlockLine => GraphStyle::DotBar,
+            GraphStyle::DotBar => GraphStyle::DotLine,
+            GraphStyle::DotLine => GraphStyle::BrailleBar,
+        }
+    }
+
+    pub fn get_graph_type(&self) -> GraphType {
+        match self {
+            GraphStyle::BrailleBar | GraphStyle::BlockBar | GraphStyle::DotBar => GraphType::Bar,
+            GraphStyle::BrailleLine | GraphStyle::BlockLine | GraphStyle::DotLine => {
+                GraphType::Line
+            }
+        }
+    }
+
+    pub fn get_marker(&self) -> Marker {
+        match self {
+            GraphStyle::BrailleBar | GraphStyle::BrailleLine => Marker::Braille,
+            GraphStyle::BlockBar | GraphStyle::BlockLine => Marker::Block,
+            GraphStyle::DotBar | GraphStyle::DotLine => Marker::Dot,
+        }
+    }
+
+    // the name shown when the graph style was switched
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            GraphStyle::BrailleBar => "braille bar",
+            GraphStyle::BrailleLine => "braille line",
+            GraphStyle::BlockBar => "block bar",
+            GraphStyle::BlockLine => "block line",
+            GraphStyle::DotBar => "dot bar",
+            GraphStyle::DotLine => "dot line",
+        }
+    }
+}
+
+// the unit used to display the network rates, the totals are always shown in bytes
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum NetworkUnit {
+    Bytes, // B/s, KiB/s, MiB/s ...
+    Bits,  // bps, Kbps, Mbps ... ( 1000 based )
+}
+
+impl NetworkUnit {
+    pub fn from_config(network_unit: &str) -> NetworkUnit {
+        match network_unit.to_lowercase().as_str() {
+            "bits" => NetworkUnit::Bits,
+            _ => NetworkUnit::Bytes,
+        }
+    }
+
+    pub fn get_next(&self) -> NetworkUnit {
+        match self {
+            NetworkUnit::Bytes => NetworkUnit::Bits,
+            NetworkUnit::Bits => NetworkUnit::Bytes,
+        }
+    }
+}
+
+// the unit used to display the memory values in the memory container and the process list
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MemoryUnit {
+    Auto, // pick the largest unit that keep the value above 1
+    MiB,
+    GiB,
+    Percent, // percentage of the total memory
+}
+
+impl MemoryUnit {
+    pub fn from_config(memory_unit: &str) -> MemoryUnit {
+        match memory_unit.to_lowercase().as_str() {
+            "mib" => MemoryUnit::MiB,
+            "gib" => MemoryUnit::GiB,
+            "percent" => MemoryUnit::Percent,
+            _ => MemoryUnit::Auto,
+        }
+    }
+
+    pub fn get_next(&self) -> MemoryUnit {
+        match self {
+            MemoryUnit::Auto => MemoryUnit::MiB,
+            MemoryUnit::MiB => MemoryUnit::GiB,
+            MemoryUnit::GiB => MemoryUnit::Percent,
+            MemoryUnit::Percent => MemoryUnit::Auto,
+        }
+    }
+
+    pub fn get_label(&self) -> String {
+        match self {
+            MemoryUnit::Auto => "Auto".to_string(),
+            MemoryUnit::MiB => "MiB".to_string(),
+            MemoryUnit::GiB => "GiB".to_string(),
+            MemoryUnit::Percent => "%".to_string(),
+        }
+    }
+}
+
+// convert the glob patterns of the disk and network filters to the anchored regex, `*` matches any characters and
+// `?` matches a single character, the patterns that can't be converted were logged and ignored
+fn build_glob_patterns(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| {
+            let regex_pattern = regex::escape(pattern)
+                .replace("\\*", ".*")
+                .replace("\\?", ".");
+            match Regex::new(&format!("^{}$", regex_pattern)) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    error!("Invalid filter pattern \"{}\" was ignored: {}", pattern, e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+// the include / exclude glob patterns from the settings file, used by the collector to skip the unwanted disks
+// a pattern is matched against the whole device name, mount point or file system of the disk, the same way as
+// the network filter
+pub struct DiskFilter {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl DiskFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> DiskFilter {
+        return DiskFilter {
+            include: build_glob_patterns(include),
+            exclude: build_glob_patterns(exclude),
+        };
+    }
+
+    pub fn is_disk_shown(&self, name: &str, mount_point: &str, file_system: &str) -> bool {
+        let is_match = |regex: &Regex| {
+            regex.is_match(name) || regex.is_match(mount_point) || regex.is_match(file_system)
+        };
+        if !self.include.is_empty() && !self.include.iter().any(is_match) {
+            return false;
+        }
+        return !self.exclude.iter().any(is_match);
+    }
+}
+
+// the include / exclude glob patterns from the settings file, used by the collector to skip the unwanted network interfaces
+// a pattern is matched against the whole interface name, `*` matches any characters and `?` matches a single character
+pub struct NetworkFilter {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl NetworkFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> NetworkFilter {
+        return NetworkFilter {
+            include: build_glob_patterns(include),
+            exclude: build_glob_patterns(exclude),
+        };
+    }
+
+    pub fn is_network_shown(&self, interface_name: &str) -> bool {
+        let is_match = |regex: &Regex| regex.is_match(interface_name);
+        if !self.include.is_empty() && !self.include.iter().any(is_match) {
+            return false;
+        }
+        return !self.exclude.iter().any(is_match);
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -529,6 +2052,15 @@ pub enum ProcessSortType {
     Name,
     Command,
     User,
+    DiskRead,
+    DiskWrite,
+    Elapsed,
+    VirtualMemory,
+    SharedMemory,
+    OomScore,
+    CpuTime,
+    StartTime,
+    Nice,
 }
 
 impl ProcessSortType {
@@ -541,6 +2073,15 @@ impl ProcessSortType {
             4 => ProcessSortType::Name,
             5 => ProcessSortType::Command,
             6 => ProcessSortType::User,
+            7 => ProcessSortType::DiskRead,
+            8 => ProcessSortType::DiskWrite,
+            9 => ProcessSortType::Elapsed,
+            10 => ProcessSortType::VirtualMemory,
+            11 => ProcessSortType::SharedMemory,
+            12 => ProcessSortType::OomScore,
+            13 => ProcessSortType::CpuTime,
+            14 => ProcessSortType::StartTime,
+            15 => ProcessSortType::Nice,
             _ => ProcessSortType::Thread,
         }
     }
@@ -554,12 +2095,145 @@ impl ProcessSortType {
             ProcessSortType::Name => "Name".to_string(),
             ProcessSortType::Command => "Command".to_string(),
             ProcessSortType::User => "User".to_string(),
+            ProcessSortType::DiskRead => "Disk Read".to_string(),
+            ProcessSortType::DiskWrite => "Disk Write".to_string(),
+            ProcessSortType::Elapsed => "Elapsed".to_string(),
+            ProcessSortType::VirtualMemory => "Virtual Memory".to_string(),
+            ProcessSortType::SharedMemory => "Shared Memory".to_string(),
+            ProcessSortType::OomScore => "OOM Score".to_string(),
+            ProcessSortType::CpuTime => "CPU Time".to_string(),
+            ProcessSortType::StartTime => "Start Time".to_string(),
+            ProcessSortType::Nice => "Nice".to_string(),
+        }
+    }
+
+    // the id of the sort type, the reverse of `get_process_sort_type_from_int`
+    pub fn get_sort_id(&self) -> u8 {
+        match self {
+            ProcessSortType::Thread => 0,
+            ProcessSortType::Memory => 1,
+            ProcessSortType::Cpu => 2,
+            ProcessSortType::Pid => 3,
+            ProcessSortType::Name => 4,
+            ProcessSortType::Command => 5,
+            ProcessSortType::User => 6,
+            ProcessSortType::DiskRead => 7,
+            ProcessSortType::DiskWrite => 8,
+            ProcessSortType::Elapsed => 9,
+            ProcessSortType::VirtualMemory => 10,
+            ProcessSortType::SharedMemory => 11,
+            ProcessSortType::OomScore => 12,
+            ProcessSortType::CpuTime => 13,
+            ProcessSortType::StartTime => 14,
+            ProcessSortType::Nice => 15,
         }
     }
 
     pub fn total_selection_count() -> u8 {
-        7
+        16
+    }
+}
+
+// prefix of the process filter input to use regex / fuzzy matching instead of the plain substring matching
+pub const PROCESS_FILTER_REGEX_PREFIX: &str = "re:";
+pub const PROCESS_FILTER_FUZZY_PREFIX: &str = "fz:";
+
+// the process filter parsed from the user input, all the matching are case insensitive
+#[derive(Debug, Clone)]
+pub enum ProcessFilter {
+    Substring(Vec<char>),
+    Regex(Regex),
+    Fuzzy(Vec<char>),
+    InvalidRegex, // the regex is still being typed or was invalid, nothing will be matched
+}
+
+impl ProcessFilter {
+    // parse the user input into the process filter, None will be returned if there is nothing to filter
+    pub fn new(filter: &str) -> Option<ProcessFilter> {
+        if let Some(pattern) = filter.strip_prefix(PROCESS_FILTER_REGEX_PREFIX) {
+            if pattern.is_empty() {
+                return None;
+            }
+            return match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(regex) => Some(ProcessFilter::Regex(regex)),
+                Err(_) => Some(ProcessFilter::InvalidRegex),
+            };
+        } else if let Some(pattern) = filter.strip_prefix(PROCESS_FILTER_FUZZY_PREFIX) {
+            // whitespace was ignored for fuzzy matching
+            let pattern: Vec<char> = pattern
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .map(lowercase_char)
+                .collect();
+            if pattern.is_empty() {
+                return None;
+            }
+            return Some(ProcessFilter::Fuzzy(pattern));
+        } else {
+            if filter.is_empty() {
+                return None;
+            }
+            return Some(ProcessFilter::Substring(
+                filter.chars().map(lowercase_char).collect(),
+            ));
+        }
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        return matches!(self, ProcessFilter::InvalidRegex);
     }
+
+    // find the matching characters in the text, the indices returned were character indices ( not byte indices )
+    // so that it can be used for highlighting, None will be returned if the text was not matched
+    pub fn find_match_indices(&self, text: &str) -> Option<Vec<usize>> {
+        match self {
+            ProcessFilter::Substring(pattern) => {
+                let text: Vec<char> = text.chars().map(lowercase_char).collect();
+                if pattern.len() > text.len() {
+                    return None;
+                }
+                for start in 0..=(text.len() - pattern.len()) {
+                    if text[start..start + pattern.len()] == pattern[..] {
+                        return Some((start..start + pattern.len()).collect());
+                    }
+                }
+                return None;
+            }
+            ProcessFilter::Regex(regex) => {
+                let regex_match = regex.find(text)?;
+                let start = text[..regex_match.start()].chars().count();
+                let length = regex_match.as_str().chars().count();
+                return Some((start..start + length).collect());
+            }
+            ProcessFilter::Fuzzy(pattern) => {
+                // every character of the pattern should appear in the text in the same order
+                let mut indices: Vec<usize> = Vec::new();
+                let mut pattern_chars = pattern.iter().peekable();
+                for (index, c) in text.chars().map(lowercase_char).enumerate() {
+                    if let Some(pattern_char) = pattern_chars.peek() {
+                        if **pattern_char == c {
+                            indices.push(index);
+                            pattern_chars.next();
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                if pattern_chars.peek().is_some() {
+                    return None;
+                }
+                return Some(indices);
+            }
+            ProcessFilter::InvalidRegex => {
+                return None;
+            }
+        }
+    }
+}
+
+// lowercase a character while keeping one to one mapping so the character indices stay the same
+fn lowercase_char(c: char) -> char {
+    return c.to_lowercase().next().unwrap_or(c);
 }
 
 impl AppPopUpType {
@@ -568,13 +2242,51 @@ impl AppPopUpType {
             AppPopUpType::KillConfirmation => " KILL ".to_string(),
             AppPopUpType::TerminateConfirmation => " TERMINATION ".to_string(),
             AppPopUpType::SignalMenu => " SIGNAL ".to_string(),
+            AppPopUpType::ThemeMenu => " THEME ".to_string(),
+            AppPopUpType::ThemeWizard => " THEME WIZARD ".to_string(),
+            AppPopUpType::UserMenu => " USER ".to_string(),
+            AppPopUpType::GoToPid => " GO TO PID ".to_string(),
             _ => "".to_string(),
         }
     }
 }
 
+impl AppColorInfo {
+    pub fn get_process_heat(&self) -> &ProcessHeat {
+        return self.process_heat.as_ref().unwrap_or(&DEFAULT_PROCESS_HEAT);
+    }
+}
+
+// state of the theme wizard pop up
+pub struct ThemeWizardState {
+    pub field_selected_state: ListState, // the AppColorInfo field that is currently being edited
+    pub palette_selected_index: u8,      // the cursor in the 256 colors palette
+    pub is_palette_focused: bool,        // user is choosing a color from the palette
+    pub original_color: Option<Color>, // the color of the field before user start choosing from the palette
+    pub is_naming: bool,               // user is typing the name of the new theme
+    pub theme_name: String,
+    pub message: Option<String>, // error message when the theme fail to be saved
+}
+
+impl Default for ThemeWizardState {
+    fn default() -> ThemeWizardState {
+        let mut field_selected_state = ListState::default();
+        field_selected_state.select(Some(0));
+        return ThemeWizardState {
+            field_selected_state,
+            palette_selected_index: 0,
+            is_palette_focused: false,
+            original_color: None,
+            is_naming: false,
+            theme_name: String::new(),
+            message: None,
+        };
+    }
+}
+
 pub trait SignalExt {
     fn get_display_name(&self) -> String;
+    fn get_description(&self) -> String;
 }
 
 impl SignalExt for Signal {
@@ -614,4 +2326,108 @@ impl SignalExt for Signal {
             _ => "".to_string(),
         }
     }
+
+    // the short description of the signal, shown next to its name in the signal menu
+    fn get_description(&self) -> String {
+        match self {
+            Signal::Hangup => "Hangup, often reloads the config".to_string(),
+            Signal::Interrupt => "Interrupt from the keyboard".to_string(),
+            Signal::Quit => "Quit with a core dump".to_string(),
+            Signal::Illegal => "Illegal instruction".to_string(),
+            Signal::Trap => "Trace or breakpoint trap".to_string(),
+            Signal::Abort => "Abort".to_string(),
+            Signal::IOT => "IOT trap, same as abort".to_string(),
+            Signal::Bus => "Bus error".to_string(),
+            Signal::FloatingPointException => "Floating point exception".to_string(),
+            Signal::Kill => "Kill, can't be caught".to_string(),
+            Signal::User1 => "User defined signal 1".to_string(),
+            Signal::Segv => "Invalid memory reference".to_string(),
+            Signal::User2 => "User defined signal 2".to_string(),
+            Signal::Pipe => "Broken pipe".to_string(),
+            Signal::Alarm => "Timer from alarm".to_string(),
+            Signal::Term => "Terminate gracefully".to_string(),
+            Signal::Child => "Child stopped or terminated".to_string(),
+            Signal::Continue => "Continue if stopped".to_string(),
+            Signal::Stop => "Stop, can't be caught".to_string(),
+            Signal::TSTP => "Stop from the terminal".to_string(),
+            Signal::TTIN => "Terminal input for background process".to_string(),
+            Signal::TTOU => "Terminal output for background process".to_string(),
+            Signal::Urgent => "Urgent condition on socket".to_string(),
+            Signal::XCPU => "CPU time limit exceeded".to_string(),
+            Signal::XFSZ => "File size limit exceeded".to_string(),
+            Signal::VirtualAlarm => "Virtual alarm clock".to_string(),
+            Signal::Profiling => "Profiling timer expired".to_string(),
+            Signal::Winch => "Window resize".to_string(),
+            Signal::IO => "I/O now possible".to_string(),
+            Signal::Poll => "Pollable event".to_string(),
+            Signal::Sys => "Bad system call".to_string(),
+            _ => "".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_keeps_the_latest_points_within_the_maximum_length() {
+        let mut history = History::new();
+        for point in 0..25 {
+            history.push(point, 10);
+            assert!(history.len() <= 10);
+            // the dropped points were removed once they outnumber the kept points
+            assert!(history.points.len() <= 20);
+        }
+        assert_eq!(history[..], (15..25).collect::<Vec<i32>>()[..]);
+        assert_eq!(history.last(), Some(&24));
+
+        // the maximum length can be shorter than the existing history
+        history.push(25, 3);
+        assert_eq!(history[..], [23, 24, 25]);
+    }
+
+    #[test]
+    fn disk_filter_applies_include_and_exclude_globs() {
+        let disk_filter = DiskFilter::new(&[], &["/snap/*".to_string(), "squashfs".to_string()]);
+        assert!(disk_filter.is_disk_shown("/dev/sda1", "/", "ext4"));
+        assert!(!disk_filter.is_disk_shown("/dev/loop3", "/snap/core/1", "squashfs"));
+        assert!(!disk_filter.is_disk_shown("/dev/loop4", "/mnt/image", "squashfs"));
+        // the whole value was matched, like the network filter
+        assert!(disk_filter.is_disk_shown("/dev/sdb1", "/mnt/snap/backup", "ext4"));
+
+        // only the included disks were shown, the exclude patterns still apply on top of them
+        let disk_filter = DiskFilter::new(&["/dev/nvme*".to_string()], &["/boot".to_string()]);
+        assert!(disk_filter.is_disk_shown("/dev/nvme0n1p2", "/", "ext4"));
+        assert!(!disk_filter.is_disk_shown("/dev/nvme0n1p1", "/boot", "vfat"));
+        assert!(!disk_filter.is_disk_shown("/dev/sda1", "/data", "ext4"));
+
+        // the regex syntax was matched as it is instead of being an invalid pattern
+        let disk_filter = DiskFilter::new(&["/dev/sd[a".to_string()], &[]);
+        assert!(disk_filter.is_disk_shown("/dev/sd[a", "/", "ext4"));
+        assert!(!disk_filter.is_disk_shown("/dev/sda1", "/", "ext4"));
+    }
+
+    #[test]
+    fn network_filter_applies_include_and_exclude_globs() {
+        let network_filter = NetworkFilter::new(
+            &[],
+            &["docker0".to_string(), "veth*".to_string(), "lo".to_string()],
+        );
+        assert!(network_filter.is_network_shown("eth0"));
+        assert!(network_filter.is_network_shown("lo1"));
+        assert!(!network_filter.is_network_shown("lo"));
+        assert!(!network_filter.is_network_shown("docker0"));
+        assert!(!network_filter.is_network_shown("veth1a2b3c"));
+
+        // only the included interfaces were shown, the exclude patterns still apply on top of them
+        let network_filter = NetworkFilter::new(
+            &["en?".to_string(), "wl*".to_string()],
+            &["wlan1".to_string()],
+        );
+        assert!(network_filter.is_network_shown("en0"));
+        assert!(network_filter.is_network_shown("wlan0"));
+        assert!(!network_filter.is_network_shown("wlan1"));
+        assert!(!network_filter.is_network_shown("eth0"));
+    }
 }
@@ -1,42 +1,56 @@
 use std::{
+    borrow::Cow,
     cmp::Ordering,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs::{create_dir_all, File},
-    path::PathBuf,
+    io::{self, Write},
+    path::{Path, PathBuf},
     thread,
 };
 
+use chrono::{DateTime, Datelike, Local, TimeDelta};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Style, Stylize},
+    style::{Color, Modifier, Style, Stylize},
     symbols::border,
     text::{Line, Span},
-    widgets::Block,
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, GraphType, List, ListItem, ListState,
+        Paragraph,
+    },
     Frame,
 };
 use sysinfo::{Pid, Signal, System};
 
+use crate::format::format_bytes;
 use crate::types::{
-    AppColorInfo, AppPopUpType, CProcessesInfo, CSysInfo, CpuData, CurrentProcessSignalStateData,
-    DiskData, MemoryData, NetworkData, ProcessData, ProcessSortType, ProcessesInfo, SignalExt,
-    SysInfo,
+    AppColorInfo, AppPopUpType, CPressureData, CProcessesInfo, CSysInfo, ContainerLayout, CpuData,
+    CurrentProcessSignalStateData, DiskData, GraphGradient, GraphStyle, History, LayoutDirection,
+    MemoryData, NetworkData, NetworkInterfaceDetails, NetworkPacketStats, PressureData,
+    ProcessData, ProcessFilter, ProcessSortType, ProcessesInfo, SelectedContainer, SignalExt,
+    SysInfo, ThemeWizardState, APP_COLOR_INFO_FIELDS,
 };
 
-pub fn get_user_directory() -> PathBuf {
+// None when the home directory is not set, eg: in a minimal container, nothing will be saved then
+pub fn get_user_directory() -> Option<PathBuf> {
     let home_dir = if cfg!(unix) {
-        std::env::var("HOME").unwrap()
+        std::env::var_os("HOME")
     } else {
-        std::env::var("USERPROFILE").unwrap()
+        std::env::var_os("USERPROFILE")
     };
 
-    return PathBuf::from(home_dir);
+    return home_dir
+        .filter(|home_dir| !home_dir.is_empty())
+        .map(PathBuf::from);
 }
 
-pub fn create_file_with_dirs(path: &str) {
-    // Create all missing directories in the path
-    let _ = create_dir_all(std::path::Path::new(path).parent().unwrap());
+// create ( or truncate ) the file for writing, with all the missing directories in the path
+pub fn create_file_with_dirs(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
 
-    File::create(path).unwrap();
+    return File::create(path);
 }
 
 pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSysInfo) {
@@ -47,12 +61,22 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
     // -------------------------------------------
     if current_sys_info.cpus.len() == 0 {
         for cpu in collected_sys_info.cpus.iter() {
-            let cpu = CpuData::new(cpu.id as i8, cpu.brand.clone(), cpu.usage);
+            let cpu = CpuData::new(
+                cpu.id as i8,
+                cpu.brand.clone(),
+                cpu.usage,
+                cpu.topology,
+                cpu.time_breakdown,
+            );
             current_sys_info.cpus.push(cpu);
         }
     } else {
         for cpu in collected_sys_info.cpus.iter() {
-            current_sys_info.cpus[cpu.id as usize + 1].update(cpu.id as i8, cpu.usage);
+            current_sys_info.cpus[(cpu.id + 1) as usize].update(
+                cpu.id as i8,
+                cpu.usage,
+                cpu.time_breakdown,
+            );
         }
     }
 
@@ -69,6 +93,10 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
             collected_sys_info.memory.used_swap,
             collected_sys_info.memory.free_memory,
             collected_sys_info.memory.cached_memory,
+            collected_sys_info.memory.breakdown.clone(),
+            collected_sys_info.memory.compressed_swap.clone(),
+            collected_sys_info.memory.swap_devices.clone(),
+            collected_sys_info.memory.zfs_arc.clone(),
         );
     } else {
         current_sys_info.memory.update(
@@ -78,6 +106,10 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
             collected_sys_info.memory.used_swap,
             collected_sys_info.memory.free_memory,
             collected_sys_info.memory.cached_memory,
+            collected_sys_info.memory.breakdown.clone(),
+            collected_sys_info.memory.compressed_swap.clone(),
+            collected_sys_info.memory.swap_devices.clone(),
+            collected_sys_info.memory.zfs_arc.clone(),
         );
     }
 
@@ -98,6 +130,10 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
                 disk.file_system.clone(),
                 disk.mount_point.clone(),
                 disk.kind.clone(),
+                disk.inodes,
+                disk.temperature,
+                disk.io_stats,
+                disk.physical_device.clone(),
             );
             current_sys_info
                 .disks
@@ -128,6 +164,10 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
                         disk.file_system.clone(),
                         disk.mount_point.clone(),
                         disk.kind.clone(),
+                        disk.inodes,
+                        disk.temperature,
+                        disk.io_stats,
+                        disk.physical_device.clone(),
                     );
                 }
                 None => {
@@ -141,6 +181,10 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
                         disk.file_system.clone(),
                         disk.mount_point.clone(),
                         disk.kind.clone(),
+                        disk.inodes,
+                        disk.temperature,
+                        disk.io_stats,
+                        disk.physical_device.clone(),
                     );
                     current_sys_info
                         .disks
@@ -171,11 +215,14 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
         for network in collected_sys_info.networks.iter() {
             let network = NetworkData::new(
                 network.interface_name.clone(),
-                network.ip_network.clone(),
+                network.ip_networks.clone(),
+                network.vpn_info.clone(),
                 network.current_received,
                 network.current_transmitted,
                 network.total_received,
                 network.total_transmitted,
+                network.packet_stats,
+                network.interface_details.clone(),
             );
             current_sys_info
                 .networks
@@ -198,21 +245,27 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
                 Some(e_n) => {
                     e_n.update(
                         network.interface_name.clone(),
-                        network.ip_network.clone(),
+                        network.ip_networks.clone(),
+                        network.vpn_info.clone(),
                         network.current_received,
                         network.current_transmitted,
                         network.total_received,
                         network.total_transmitted,
+                        network.packet_stats,
+                        network.interface_details.clone(),
                     );
                 }
                 None => {
                     let network = NetworkData::new(
                         network.interface_name.clone(),
-                        network.ip_network.clone(),
+                        network.ip_networks.clone(),
+                        network.vpn_info.clone(),
                         network.current_received,
                         network.current_transmitted,
                         network.total_received,
                         network.total_transmitted,
+                        network.packet_stats,
+                        network.interface_details.clone(),
                     );
                     current_sys_info
                         .networks
@@ -234,131 +287,302 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
         }
     }
 
+    // -------------------------------------------
+    //
+    //      LOAD AVERAGE AND UPTIME INFO UPDATE
+    //
+    // -------------------------------------------
+    current_sys_info.system.load_average_one = collected_sys_info.system.load_average_one;
+    current_sys_info.system.load_average_five = collected_sys_info.system.load_average_five;
+    current_sys_info.system.load_average_fifteen = collected_sys_info.system.load_average_fifteen;
+    current_sys_info.system.uptime = collected_sys_info.system.uptime;
+    current_sys_info.system.logged_in_users = collected_sys_info.system.logged_in_users;
+
+    // -------------------------------------------
+    //
+    //           PRESSURE INFO UPDATE
+    //
+    // -------------------------------------------
+    update_pressure_data(
+        &mut current_sys_info.pressure.cpu,
+        &collected_sys_info.pressure.cpu,
+    );
+    update_pressure_data(
+        &mut current_sys_info.pressure.memory,
+        &collected_sys_info.pressure.memory,
+    );
+    update_pressure_data(
+        &mut current_sys_info.pressure.io,
+        &collected_sys_info.pressure.io,
+    );
+
+    // -------------------------------------------
+    //
+    //             RAID INFO UPDATE
+    //
+    // -------------------------------------------
+    current_sys_info.raid_arrays = collected_sys_info.raid_arrays.clone();
+
+    // -------------------------------------------
+    //
+    //          CONNECTIONS INFO UPDATE
+    //
+    // -------------------------------------------
+    // the public IP was received separately by the app, so it will be kept
+    current_sys_info.connectivity.gateway = collected_sys_info.connectivity.gateway.clone();
+    current_sys_info.connectivity.dns = collected_sys_info.connectivity.dns.clone();
+
+    // the busiest connections first
+    current_sys_info.connections = collected_sys_info.connections.clone();
+    current_sys_info.connections.sort_by(|a, b| {
+        (b.sent + b.received)
+            .partial_cmp(&(a.sent + a.received))
+            .unwrap_or(Ordering::Equal)
+    });
+
     // drop the collected system info that we got from a seperated thread
     drop(collected_sys_info);
 }
 
-pub fn process_processes_info(
-    current_process_info: &mut ProcessesInfo,
-    collected_process_info: CProcessesInfo,
-    process_detail_info: &mut Option<HashMap<String, ProcessData>>,
-) {
-    if current_process_info.processes.len() == 0 {
-        for process in collected_process_info.processes.iter() {
-            let process_data = ProcessData::new(
-                process.pid,
-                process.name.clone(),
-                process.exe_path.clone(),
-                process.cmd.clone(),
-                process.user.clone(),
-                process.cpu_usage,
-                process.thread_count,
-                process.memory,
-                process.status.clone(),
-                process.elapsed,
-                process.parent.clone(),
-                process.current_read_disk_usage,
-                process.total_read_disk_usage,
-                process.current_write_disk_usage,
-                process.total_write_disk_usage,
-            );
-            let pid_string = format!("{}", process.pid);
-            current_process_info
-                .processes
-                .insert(pid_string, process_data.clone());
+// the disks sorted by their physical device and mount point, so the partitions of the same device stay together
+// this order was used for both the disk selection and the disk list
+pub fn get_ordered_disks(disks: &HashMap<String, DiskData>) -> Vec<&DiskData> {
+    let mut ordered_disks: Vec<&DiskData> = disks.values().collect();
+    ordered_disks.sort_by(|a, b| {
+        a.physical_device
+            .cmp(&b.physical_device)
+            .then_with(|| a.mount_point.cmp(&b.mount_point))
+    });
+    return ordered_disks;
+}
+
+// the interface name of the synthetic entry that sum up all the network interfaces
+pub const AGGREGATE_NETWORK_NAME: &str = "All interfaces";
+
+// sum up the throughput of all the network interfaces into a synthetic entry,
+// the loopback interface was skipped as its traffic never leave the machine
+// the histories were aligned from the latest collection as the interfaces could be added at different time
+pub fn get_aggregate_network(networks: &HashMap<String, NetworkData>) -> NetworkData {
+    let mut aggregate_network = NetworkData {
+        interface_name: AGGREGATE_NETWORK_NAME.to_string(),
+        ip_networks: vec![],
+        vpn_info: None,
+        current_received_vec: History::new(),
+        current_transmitted_vec: History::new(),
+        total_received: 0.0,
+        total_transmitted: 0.0,
+        session_received: 0.0,
+        session_transmitted: 0.0,
+        packet_stats: NetworkPacketStats::default(),
+        interface_details: NetworkInterfaceDetails::default(),
+        is_updated: true,
+    };
+
+    let mut received_history: Vec<f64> = vec![];
+    let mut transmitted_history: Vec<f64> = vec![];
+    let add_history = |aggregate_history: &mut Vec<f64>, history: &[f64]| {
+        if history.len() > aggregate_history.len() {
+            let missing_len = history.len() - aggregate_history.len();
+            aggregate_history.splice(0..0, vec![0.0; missing_len]);
         }
-    } else {
-        for process in current_process_info.processes.values_mut() {
-            process.is_updated = false;
+        let offset = aggregate_history.len() - history.len();
+        for (index, value) in history.iter().enumerate() {
+            aggregate_history[offset + index] += value;
         }
+    };
 
-        // also update the is_update file of process_detail_info to false if there is one
-        if let Some(process_detail_info_hashmap) = process_detail_info.as_mut() {
-            if let Some((_, value)) = process_detail_info_hashmap.iter_mut().next() {
-                value.is_updated = false;
-            }
+    for network in networks.values() {
+        if network.interface_name == "lo" || network.interface_name == "lo0" {
+            continue;
+        }
+        add_history(&mut received_history, &network.current_received_vec);
+        add_history(&mut transmitted_history, &network.current_transmitted_vec);
+        aggregate_network.total_received += network.total_received;
+        aggregate_network.total_transmitted += network.total_transmitted;
+        aggregate_network.session_received += network.session_received;
+        aggregate_network.session_transmitted += network.session_transmitted;
+
+        let packet_stats = &mut aggregate_network.packet_stats;
+        packet_stats.packets_received += network.packet_stats.packets_received;
+        packet_stats.packets_transmitted += network.packet_stats.packets_transmitted;
+        packet_stats.errors_received += network.packet_stats.errors_received;
+        packet_stats.errors_transmitted += network.packet_stats.errors_transmitted;
+        if let Some((dropped_received, dropped_transmitted)) = network.packet_stats.drops {
+            let (total_dropped_received, total_dropped_transmitted) =
+                packet_stats.drops.unwrap_or((0, 0));
+            packet_stats.drops = Some((
+                total_dropped_received + dropped_received,
+                total_dropped_transmitted + dropped_transmitted,
+            ));
         }
+    }
 
-        for process in collected_process_info.processes.iter() {
-            let current_process = current_process_info
-                .processes
-                .get_mut(&process.pid.to_string());
-            match current_process {
-                Some(p) => {
-                    p.update(
-                        process.pid,
-                        process.name.clone(),
-                        process.exe_path.clone(),
-                        process.cmd.clone(),
-                        process.user.clone(),
-                        process.cpu_usage,
-                        process.thread_count,
-                        process.memory,
-                        process.status.clone(),
-                        process.elapsed,
-                        process.parent.clone(),
-                        process.current_read_disk_usage,
-                        process.total_read_disk_usage,
-                        process.current_write_disk_usage,
-                        process.total_write_disk_usage,
-                    );
+    // the graph and the rates expect at least one collection
+    if received_history.is_empty() {
+        received_history.push(0.0);
+    }
+    if transmitted_history.is_empty() {
+        transmitted_history.push(0.0);
+    }
+    aggregate_network.current_received_vec = History::from(received_history);
+    aggregate_network.current_transmitted_vec = History::from(transmitted_history);
+    return aggregate_network;
+}
 
-                    // if there process detail info showing, update the process detail info
-                    if let Some(hashmap) = process_detail_info.as_mut() {
-                        let key = process.pid.to_string();
-                        if hashmap.contains_key(&key) {
-                            hashmap.entry(key).and_modify(|value| *value = p.to_owned());
-                        }
-                    }
-                }
-                None => {
-                    let p = ProcessData::new(
-                        process.pid,
-                        process.name.clone(),
-                        process.exe_path.clone(),
-                        process.cmd.clone(),
-                        process.user.clone(),
-                        process.cpu_usage,
-                        process.thread_count,
-                        process.memory,
-                        process.status.clone(),
-                        process.elapsed,
-                        process.parent.clone(),
-                        process.current_read_disk_usage,
-                        process.total_read_disk_usage,
-                        process.current_write_disk_usage,
-                        process.total_write_disk_usage,
-                    );
-                    let pid_string = format!("{}", process.pid);
-                    current_process_info.processes.insert(pid_string, p);
-                }
-            }
+fn update_pressure_data(
+    current_pressure: &mut Option<PressureData>,
+    collected_pressure: &Option<CPressureData>,
+) {
+    match (current_pressure.as_mut(), collected_pressure.as_ref()) {
+        (Some(current), Some(collected)) => current.update(collected.some, collected.full),
+        (None, Some(collected)) => {
+            *current_pressure = Some(PressureData::new(collected.some, collected.full));
         }
+        // the pressure file is no longer readable, so we stop showing it
+        (_, None) => *current_pressure = None,
+    }
+}
+
+// apply the changes of the processes since the last collection
+// a process that was killed while its detail was showing was still shown, but with the status marked as "killed"
+// so that any signal trigger action will be forbidden
+pub fn process_processes_info(
+    current_process_info: &mut ProcessesInfo,
+    collected_process_info: CProcessesInfo,
+    process_detail_info: &mut Option<HashMap<String, ProcessData>>,
+) {
+    let mut removed_pids = collected_process_info.removed_pids;
 
-        let keys_to_remove: Vec<String> = current_process_info
+    // the processes that weren't in the snapshot were exited while some of the changes were missed
+    if collected_process_info.is_snapshot {
+        let snapshot_pids: HashSet<u32> = collected_process_info
             .processes
             .iter()
-            .filter(|(_, process)| !process.is_updated)
-            .map(|(key, _)| key.clone())
+            .map(|process| process.pid)
             .collect();
+        removed_pids.extend(
+            current_process_info
+                .processes
+                .values()
+                .map(|process| process.pid)
+                .filter(|pid| !snapshot_pids.contains(pid)),
+        );
+    }
 
-        for key in keys_to_remove {
-            current_process_info.processes.remove(&key);
+    for process in collected_process_info.processes {
+        let pid_string = process.pid.to_string();
+        match current_process_info.processes.get_mut(&pid_string) {
+            Some(p) => {
+                p.update(
+                    process.pid,
+                    process.name,
+                    process.exe_path,
+                    process.cmd,
+                    process.user,
+                    process.cpu_usage,
+                    process.thread_count,
+                    process.memory,
+                    process.virtual_memory,
+                    process.shared_memory,
+                    process.oom_score,
+                    process.nice,
+                    process.status,
+                    process.elapsed,
+                    process.cpu_time,
+                    process.parent,
+                    process.current_read_disk_usage,
+                    process.total_read_disk_usage,
+                    process.current_write_disk_usage,
+                    process.total_write_disk_usage,
+                    process.delay_info,
+                    process.container_info,
+                    process.cgroup,
+                    process.cpu_throttle_info,
+                );
+                update_process_detail_info(process_detail_info, p);
+            }
+            None => {
+                let p = ProcessData::new(
+                    process.pid,
+                    process.name,
+                    process.exe_path,
+                    process.cmd,
+                    process.user,
+                    process.cpu_usage,
+                    process.thread_count,
+                    process.memory,
+                    process.virtual_memory,
+                    process.shared_memory,
+                    process.oom_score,
+                    process.nice,
+                    process.status,
+                    process.elapsed,
+                    process.cpu_time,
+                    process.parent,
+                    process.current_read_disk_usage,
+                    process.total_read_disk_usage,
+                    process.current_write_disk_usage,
+                    process.total_write_disk_usage,
+                    process.delay_info,
+                    process.container_info,
+                    process.cgroup,
+                    process.cpu_throttle_info,
+                );
+                current_process_info.processes.insert(pid_string, p);
+            }
         }
+    }
 
-        // if there is process detail info showing, and the field is_updated is not marked as true,
-        // it was possible due to process being killed/terminated
-        // we still show it but we forbid any signal trigger action and will marked the status as "killed"
+    for process_update in collected_process_info.updated_processes {
+        // the full info of the process was missed, it will be added by the next snapshot
+        let Some(p) = current_process_info
+            .processes
+            .get_mut(&process_update.pid.to_string())
+        else {
+            continue;
+        };
+        p.update_metrics(
+            process_update.cpu_usage,
+            process_update.thread_count,
+            process_update.memory,
+            process_update.virtual_memory,
+            process_update.shared_memory,
+            process_update.oom_score,
+            process_update.nice,
+            process_update.status,
+            process_update.elapsed,
+            process_update.cpu_time,
+            process_update.current_read_disk_usage,
+            process_update.total_read_disk_usage,
+            process_update.current_write_disk_usage,
+            process_update.total_write_disk_usage,
+            process_update.delay_info,
+            process_update.cpu_throttle_info,
+        );
+        update_process_detail_info(process_detail_info, p);
+    }
+
+    for pid in removed_pids {
+        let pid_string = pid.to_string();
+        current_process_info.processes.remove(&pid_string);
         if let Some(process_detail_info_hashmap) = process_detail_info.as_mut() {
-            if let Some((_, value)) = process_detail_info_hashmap.iter_mut().next() {
-                if !value.is_updated {
-                    value.status = "killed".to_string();
-                }
+            if let Some(value) = process_detail_info_hashmap.get_mut(&pid_string) {
+                value.status = "killed".to_string();
             }
         }
     }
+}
 
-    drop(collected_process_info);
+// if the process detail of the process is showing, update it with the latest process data
+fn update_process_detail_info(
+    process_detail_info: &mut Option<HashMap<String, ProcessData>>,
+    process: &ProcessData,
+) {
+    if let Some(hashmap) = process_detail_info.as_mut() {
+        if let Some(value) = hashmap.get_mut(&process.pid.to_string()) {
+            *value = process.clone();
+        }
+    }
 }
 
 // the line to show the current tick
@@ -377,60 +601,325 @@ pub fn get_tick_line_ui(tick: u64, app_color_info: &AppColorInfo) -> Line {
     return refresh_tick;
 }
 
-// break line into multiple line into a vector based on desire len of string (String -> Vec<String>)
-pub fn break_line_into_vectors_of_string(
-    line: String,
-    max_length_per_string: usize,
-    vector_size: usize,
-) -> Vec<String> {
-    if vector_size < 1 {
-        return vec![];
+// fill the points of a graph from the latest history into the reused buffer, the points were aligned to the right
+// so the latest point will always be at the right edge of the graph
+// the buffer was owned by the app, so the points won't be allocated again on every frame
+pub fn fill_graph_data_points<T: Copy>(
+    data_points: &mut Vec<(f64, f64)>,
+    history: &[T],
+    graph_show_range: usize,
+    get_y: impl Fn(T) -> f64,
+) {
+    let start_idx = history.len().saturating_sub(graph_show_range);
+    let shown_history = &history[start_idx..];
+    data_points.clear();
+    data_points.extend(shown_history.iter().enumerate().map(|(i, value)| {
+        (
+            graph_show_range as f64 - (shown_history.len() - i) as f64,
+            get_y(*value),
+        )
+    }));
+}
+
+// split the points of a percentage graph by the gradient color, so each bar was colored by its own value
+// a line can't be split, so the line graph will be colored by the latest value instead
+// the points of the graph in one color of the gradient
+pub type GradientPoints<'a> = (Color, Cow<'a, [(f64, f64)]>);
+
+// all the points will be in the base color if the theme doesn't have a gradient
+// the points were only copied when they were split
+pub fn get_gradient_points<'a>(
+    points: &'a [(f64, f64)],
+    base_color: Color,
+    graph_gradient: Option<&GraphGradient>,
+    graph_style: GraphStyle,
+) -> Vec<GradientPoints<'a>> {
+    let Some(graph_gradient) = graph_gradient else {
+        return vec![(base_color, Cow::Borrowed(points))];
+    };
+    if graph_style.get_graph_type() == GraphType::Line {
+        let latest_percentage = points.last().map(|(_, y)| *y).unwrap_or(0.0);
+        return vec![(
+            graph_gradient.get_color(latest_percentage),
+            Cow::Borrowed(points),
+        )];
     }
-    if line.len() <= max_length_per_string {
-        return vec![line];
-    } else {
-        let chars_vec: Vec<char> = line.chars().collect();
-        let mut line_vec = vec![];
-        for i in 0..vector_size {
-            let start = i * max_length_per_string;
-            let end = (i + 1) * max_length_per_string;
-
-            if end >= line.len() {
-                line_vec.push(chars_vec[start..line.len()].iter().collect());
-                break;
-            }
 
-            line_vec.push(chars_vec[start..end].iter().collect());
+    let mut gradient_points: Vec<(Color, Vec<(f64, f64)>)> = vec![
+        (graph_gradient.low, vec![]),
+        (graph_gradient.medium, vec![]),
+        (graph_gradient.high, vec![]),
+    ];
+    for point in points {
+        let color = graph_gradient.get_color(point.1);
+        if let Some((_, color_points)) = gradient_points
+            .iter_mut()
+            .find(|(gradient_color, _)| *gradient_color == color)
+        {
+            color_points.push(*point);
         }
+    }
+    return gradient_points
+        .into_iter()
+        .map(|(color, color_points)| (color, Cow::Owned(color_points)))
+        .collect();
+}
 
-        return line_vec;
+// a dataset for each color of the gradient points
+pub fn get_gradient_datasets<'a>(
+    gradient_points: &'a [GradientPoints<'a>],
+    graph_style: GraphStyle,
+) -> Vec<Dataset<'a>> {
+    return gradient_points
+        .iter()
+        .map(|(color, points)| {
+            Dataset::default()
+                .data(points)
+                .graph_type(graph_style.get_graph_type())
+                .marker(graph_style.get_marker())
+                .style(Style::default().fg(*color))
+        })
+        .collect();
+}
+
+// split the area according to the container arrangement, the containers that were left out or hidden will not get an area
+// and the remaining containers will fill up the space
+pub fn get_container_areas(
+    layout: &ContainerLayout,
+    hidden_containers: &[SelectedContainer],
+    area: Rect,
+) -> Vec<(SelectedContainer, Rect)> {
+    if let Some(name) = layout.container.as_ref() {
+        return match SelectedContainer::from_name(name) {
+            Some(container) if !hidden_containers.contains(&container) => vec![(container, area)],
+            _ => vec![],
+        };
+    }
+
+    // the child nodes without any shown container will not take up any space
+    let shown_children: Vec<&ContainerLayout> = layout
+        .children
+        .iter()
+        .filter(|child| {
+            child
+                .get_containers()
+                .iter()
+                .any(|container| !hidden_containers.contains(container))
+        })
+        .collect();
+
+    // the sizes will be used as ratio when all the child nodes have it, so it doesn't need to add up to 100
+    let is_all_sized = shown_children.iter().all(|child| child.size.is_some());
+    let constraints: Vec<Constraint> = shown_children
+        .iter()
+        .map(|child| match child.size {
+            Some(size) if is_all_sized => Constraint::Fill(size),
+            Some(size) => Constraint::Percentage(size),
+            None => Constraint::Fill(1),
+        })
+        .collect();
+    let child_areas = match layout.direction {
+        LayoutDirection::Horizontal => Layout::horizontal(constraints).split(area),
+        LayoutDirection::Vertical => Layout::vertical(constraints).split(area),
+    };
+
+    return shown_children
+        .iter()
+        .zip(child_areas.iter())
+        .flat_map(|(child, child_area)| get_container_areas(child, hidden_containers, *child_area))
+        .collect();
+}
+
+// the area of the container, None if it was left out of the arrangement
+pub fn get_container_area(
+    container_areas: &[(SelectedContainer, Rect)],
+    container: SelectedContainer,
+) -> Option<Rect> {
+    return container_areas
+        .iter()
+        .find(|(shown_container, _)| *shown_container == container)
+        .map(|(_, area)| *area);
+}
+
+// the y axis labels of the percentage graphs, empty when the graph axis was hidden
+pub fn get_percentage_axis_labels(is_graph_axis_shown: bool) -> Vec<String> {
+    if !is_graph_axis_shown {
+        return vec![];
     }
+    return ["0%", "25%", "50%", "75%", "100%"]
+        .iter()
+        .map(|label| label.to_string())
+        .collect();
 }
 
-pub fn round_to_2_decimal(value: f32) -> f32 {
-    (value * 100.0).round() / 100.0
+// the y axis labels of the graphs that were scaled to the max value shown, empty when the graph axis was hidden
+pub fn get_scaled_axis_labels(
+    is_graph_axis_shown: bool,
+    max: f64,
+    format_value: impl Fn(f64) -> String,
+) -> Vec<String> {
+    if !is_graph_axis_shown {
+        return vec![];
+    }
+    return vec![
+        format_value(0.0),
+        format_value(max / 2.0),
+        format_value(max),
+    ];
+}
+
+// the y axis of the graphs, the labels will be spread evenly from the bottom to the top of the graph
+pub fn get_graph_y_axis(
+    bound: f64,
+    labels: &[String],
+    app_color_info: &AppColorInfo,
+) -> Axis<'static> {
+    return Axis::default()
+        .bounds([0.0, bound])
+        .labels(labels.to_vec())
+        .style(
+            Style::default()
+                .fg(app_color_info.base_app_text_color)
+                .add_modifier(Modifier::DIM),
+        );
 }
 
-pub fn process_to_kib_mib_gib(value: f64) -> String {
-    let mut value = value;
-    let mut unit = "B";
+// draw the faint grid lines on the same rows as the y axis labels in between the top and bottom label
+// this should be rendered before the chart, so that the chart will be drawn on top of the grid lines
+pub fn draw_graph_grid_lines(
+    area: Rect,
+    labels: &[String],
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    if labels.len() < 3 || area.height < 2 {
+        return;
+    }
+
+    // the chart will shift the graph to the right by the widest label and the y axis line
+    let labels_width = labels
+        .iter()
+        .map(|label| Line::from(label.as_str()).width() as u16)
+        .max()
+        .unwrap_or(0)
+        .min(area.width / 3)
+        + 1;
+    if labels_width >= area.width {
+        return;
+    }
 
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "KiB";
+    let grid_line_style = Style::default()
+        .fg(app_color_info.base_app_text_color)
+        .add_modifier(Modifier::DIM);
+    let grid_width = area.width - labels_width;
+    let labels_len = labels.len() as u16;
+    for i in 1..labels_len - 1 {
+        let dy = i * (area.height - 1) / (labels_len - 1);
+        let grid_line_area =
+            Rect::new(area.x + labels_width, area.bottom() - 1 - dy, grid_width, 1);
+        frame.render_widget(
+            Line::from("┄".repeat(grid_width as usize)).style(grid_line_style),
+            grid_line_area,
+        );
     }
+}
 
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "MiB";
+// label the peak value of the shown window at the top left of the graph
+pub fn draw_graph_peak_label(
+    area: Rect,
+    peak: String,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    if area.height == 0 {
+        return;
     }
+    let peak_label = Line::from(format!("peak {}", peak)).style(
+        Style::default()
+            .fg(app_color_info.base_app_text_color)
+            .add_modifier(Modifier::DIM),
+    );
+    let peak_label_area = Rect::new(
+        area.x,
+        area.y,
+        (peak_label.width() as u16).min(area.width),
+        1,
+    );
+    frame.render_widget(peak_label, peak_label_area);
+}
+
+// how far the graphs were panned back, { and } will pan the graphs
+pub fn get_graph_offset_line_ui(
+    graph_offset: usize,
+    app_color_info: &AppColorInfo,
+) -> Line<'static> {
+    let graph_offset_line = Line::from(vec![
+        Span::styled("  ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("{", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            format!(" -{} ", graph_offset),
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+        Span::styled("}", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled("  ", Style::default().fg(app_color_info.app_title_color)),
+    ]);
+
+    return graph_offset_line;
+}
+
+// the session counters of a panel and the time it was started / reset, x will reset the counters
+pub fn get_session_line_ui(
+    session_since: &DateTime<Local>,
+    session_up: f64,
+    session_down: f64,
+    app_color_info: &AppColorInfo,
+) -> Line<'static> {
+    let session_line = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("X", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            format!(
+                " since {} ▲ {} ▼ {} ",
+                session_since.format("%H:%M"),
+                format_bytes(session_up),
+                format_bytes(session_down)
+            ),
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+    ]);
+
+    return session_line;
+}
 
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "GiB";
+// wrap the text into the lines of the given width ( in characters ), at least one line was returned
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() || width == 0 {
+        return vec![text.to_string()];
     }
+    return chars
+        .chunks(width)
+        .map(|chunk| chunk.iter().collect())
+        .collect();
+}
+
+pub fn round_to_2_decimal(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
 
-    return format!("{:.2} {}", ((value * 1000.0).round() / 1000.0), unit);
+// format nanoseconds into a readable duration, eg: 350ns, 12.50ms, 3.20s
+pub fn format_nanoseconds(value: u64) -> String {
+    if value < 1_000 {
+        return format!("{}ns", value);
+    } else if value < 1_000_000 {
+        return format!("{:.2}µs", value as f64 / 1_000.0);
+    } else if value < 1_000_000_000 {
+        return format!("{:.2}ms", value as f64 / 1_000_000.0);
+    } else {
+        return format!("{:.2}s", value as f64 / 1_000_000_000.0);
+    }
 }
 
 pub fn format_seconds(value: u64) -> String {
@@ -445,30 +934,225 @@ pub fn format_seconds(value: u64) -> String {
     }
 }
 
+// ------------------------------------
+//
+// redaction for sensitive values
+//
+// ------------------------------------
+// the placeholder that replaces a sensitive value when the redaction is on
+pub const REDACTED_PLACEHOLDER: &str = "***";
+
+// mask the user name of a process
+pub fn redact_user(user: &str, is_redacted: bool) -> String {
+    if is_redacted {
+        return REDACTED_PLACEHOLDER.to_string();
+    }
+    return user.to_string();
+}
+
+// mask the ip address, the prefix length of a cidr notation ( eg: /24 ) will be kept
+pub fn redact_ip(ip: &str, is_redacted: bool) -> String {
+    if is_redacted {
+        return match ip.split_once('/') {
+            Some((_, prefix)) => format!("{}/{}", REDACTED_PLACEHOLDER, prefix),
+            None => REDACTED_PLACEHOLDER.to_string(),
+        };
+    }
+    return ip.to_string();
+}
+
+// mask the command line, only the file name of the program ( first item ) will be kept
+// as the program path and arguments could contains user directory, tokens or other sensitive value
+pub fn redact_command(cmd: &[String], separator: &str, is_redacted: bool) -> String {
+    if is_redacted {
+        if cmd.is_empty() {
+            return "".to_string();
+        }
+        let program = Path::new(&cmd[0])
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or(cmd[0].clone());
+        if cmd.len() > 1 {
+            return format!("{} {}", program, REDACTED_PLACEHOLDER);
+        }
+        return program;
+    }
+    return cmd.join(separator);
+}
+
+// format seconds into a short readable duration, eg: 1d 2h, 2h 13m, 13m 5s, 5s
+pub fn format_duration(value: u64) -> String {
+    let days = value / (24 * 60 * 60);
+    let hours = value % (24 * 60 * 60) / (60 * 60);
+    let minutes = value % (60 * 60) / 60;
+    let seconds = value % 60;
+    if days > 0 {
+        return format!("{}d {}h", days, hours);
+    } else if hours > 0 {
+        return format!("{}h {}m", hours, minutes);
+    } else if minutes > 0 {
+        return format!("{}m {}s", minutes, seconds);
+    } else {
+        return format!("{}s", seconds);
+    }
+}
+
+// the time the process was started, from its elapsed time in seconds
+pub fn get_start_time(elapsed: u64, now: &DateTime<Local>) -> DateTime<Local> {
+    return *now - TimeDelta::seconds(elapsed as i64);
+}
+
+// the start time in the shortest form that still tells the day apart, like the STIME of `ps`
+// eg: 14:02 if it was started today, Oct 12 if it was this year, 2024 otherwise
+pub fn format_start_time(start_time: &DateTime<Local>, now: &DateTime<Local>) -> String {
+    if start_time.date_naive() == now.date_naive() {
+        return start_time.format("%H:%M").to_string();
+    } else if start_time.year() == now.year() {
+        return start_time.format("%b %d").to_string();
+    } else {
+        return start_time.format("%Y").to_string();
+    }
+}
+
+// get the PIDs of the direct child processes of the given process, ordered by PID
+pub fn get_child_pids(
+    parent_pid: &str,
+    process_data: &HashMap<String, ProcessData>,
+) -> Vec<String> {
+    let mut child_pids: Vec<String> = process_data
+        .iter()
+        .filter(|(_, value)| value.parent == parent_pid)
+        .map(|(key, _)| key.clone())
+        .collect();
+    child_pids.sort_by_key(|pid| pid.parse::<u32>().unwrap_or(u32::MAX));
+    return child_pids;
+}
+
+// all the descendants of the process, each child comes after its own descendants so the tree can be signaled
+// from the leaves up, the pids that were already visited were skipped in case the parent links form a loop
+pub fn get_descendant_pids(
+    parent_pid: &str,
+    process_data: &HashMap<String, ProcessData>,
+) -> Vec<String> {
+    let mut descendant_pids = vec![];
+    let mut visited_pids = HashSet::from([parent_pid.to_string()]);
+    collect_descendant_pids(
+        parent_pid,
+        process_data,
+        &mut visited_pids,
+        &mut descendant_pids,
+    );
+    return descendant_pids;
+}
+
+fn collect_descendant_pids(
+    parent_pid: &str,
+    process_data: &HashMap<String, ProcessData>,
+    visited_pids: &mut HashSet<String>,
+    descendant_pids: &mut Vec<String>,
+) {
+    for child_pid in get_child_pids(parent_pid, process_data) {
+        if visited_pids.insert(child_pid.clone()) {
+            collect_descendant_pids(&child_pid, process_data, visited_pids, descendant_pids);
+            descendant_pids.push(child_pid);
+        }
+    }
+}
+
+// the minimum upper bound of the pressure graph in percentage
+const PRESSURE_GRAPH_MIN_SCALE: f64 = 10.0;
+// width smaller than this will only show a short label for the pressure graph
+const PRESSURE_GRAPH_SMALL_WIDTH: u16 = 30;
+
+// render the PSI graph at the bottom of the cpu, memory and disk containers,
+// as pressure was usually low, the graph was scaled to the highest value shown with a minimum of PRESSURE_GRAPH_MIN_SCALE
+pub fn draw_pressure_graph(
+    label: &str,
+    pressure: &PressureData,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    graph_offset: usize, // the number of the latest points to skip, to pan back through the history
+    graph_style: GraphStyle,
+    graph_color: Color,
+    block_color: Color,
+    app_color_info: &AppColorInfo,
+    graph_data_points: &mut Vec<(f64, f64)>, // the reused buffer for the points of the graph
+) {
+    let some_vec = pressure.some_vec.get_panned(graph_offset);
+    let [_, pressure_graph] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+
+    let pressure_label = if area.width < PRESSURE_GRAPH_SMALL_WIDTH {
+        Line::from("P").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from(label.to_string()).style(app_color_info.base_app_text_color)
+    };
+    let pressure_usage = Line::from(format!(
+        "some {:.2}% full {:.2}%",
+        some_vec[some_vec.len() - 1],
+        pressure.full
+    ))
+    .style(app_color_info.app_title_color)
+    .bold();
+    let pressure_block = Block::new()
+        .title(pressure_label.left_aligned())
+        .title(pressure_usage.right_aligned())
+        .style(block_color)
+        .borders(if area.width < PRESSURE_GRAPH_SMALL_WIDTH {
+            Borders::NONE
+        } else {
+            Borders::TOP
+        });
+
+    fill_graph_data_points(graph_data_points, some_vec, graph_show_range, |value| value);
+    let max_pressure = graph_data_points
+        .iter()
+        .fold(PRESSURE_GRAPH_MIN_SCALE, |max, (_, value)| max.max(*value));
+
+    let dataset = Dataset::default()
+        .data(graph_data_points)
+        .graph_type(graph_style.get_graph_type())
+        .marker(graph_style.get_marker())
+        .style(Style::default().fg(graph_color));
+
+    let pressure_chart = Chart::new(vec![dataset])
+        .x_axis(Axis::default().bounds([0.0, graph_show_range as f64]))
+        .y_axis(Axis::default().bounds([0.0, max_pressure]))
+        .bg(app_color_info.background_color);
+
+    frame.render_widget(pressure_block, area);
+    frame.render_widget(pressure_chart, pressure_graph);
+}
+
 // function to sort and filter the process list based on user selected sort type, sorting order and filtering input
-pub fn sort_process(
+pub fn sort_process<'a>(
     sort_type: ProcessSortType,
     is_reversed: bool,
     filter: String,
-    process_data: &HashMap<String, ProcessData>,
-) -> Vec<ProcessData> {
-    // we first map the hashmap into a vec for easy processing
-    let mut processes: Vec<ProcessData> = process_data
-        .iter()
-        .map(|(_, value)| value)
-        .cloned()
-        .collect();
+    user_filter: &Option<String>,
+    process_data: &'a HashMap<String, ProcessData>,
+) -> Vec<&'a ProcessData> {
+    // we first map the hashmap into a vec for easy processing, the processes were borrowed as it was sorted on every frame
+    let mut processes: Vec<&ProcessData> = process_data.values().collect();
+
+    // if a user was picked from the user menu, only the processes owned by the user will be retrieved
+    if let Some(user) = user_filter.as_ref() {
+        processes.retain(|process| process.user == *user);
+    }
 
-    // if user input for filter is not empty, we will retrieve those that name/cmd/user is matching the user inpu
-    if !filter.is_empty() {
+    // if user input for filter is not empty, we will retrieve those that name/cmd/user/container is matching the user inpu
+    // the filter could be a plain substring, a regex ( prefix "re:" ) or a fuzzy pattern ( prefix "fz:" )
+    if let Some(process_filter) = ProcessFilter::new(&filter) {
         processes.retain(|process| {
-            process.name.to_lowercase().contains(&filter.to_lowercase())
-                || process
-                    .cmd
-                    .join(" ")
-                    .to_lowercase()
-                    .contains(&filter.to_lowercase())
-                || process.user.to_lowercase().contains(&filter.to_lowercase())
+            process_filter.find_match_indices(&process.name).is_some()
+                || process_filter
+                    .find_match_indices(&process.cmd.join(" "))
+                    .is_some()
+                || process_filter.find_match_indices(&process.user).is_some()
+                || process_filter
+                    .find_match_indices(&process.get_container_name())
+                    .is_some()
         });
     }
 
@@ -554,59 +1238,140 @@ pub fn sort_process(
                 ordering
             }
         })
-    }
-    return processes;
-}
-
-pub fn render_pop_up_menu(
-    area: Rect,
-    frame: &mut Frame,
-    pop_up_type: &mut AppPopUpType,
-    current_process_signal_state_data: &CurrentProcessSignalStateData,
-    app_color_info: &AppColorInfo,
-) {
-    let pop_up_dimension: (u16, u16) = if *pop_up_type == AppPopUpType::KillConfirmation
-        || *pop_up_type == AppPopUpType::TerminateConfirmation
-    {
-        (50, 10)
-    } else {
-        (80.min(area.width), 20.min(area.height))
-    };
-
-    let [_, pop_up_width, _] = Layout::horizontal(vec![
-        Constraint::Fill(1),
-        Constraint::Length(pop_up_dimension.0),
-        Constraint::Fill(1),
-    ])
-    .areas(area);
-
-    let [_, pop_up, _] = Layout::vertical(vec![
-        Constraint::Fill(1),
-        Constraint::Length(pop_up_dimension.1),
-        Constraint::Fill(1),
-    ])
-    .areas(pop_up_width);
+    } else if sort_type == ProcessSortType::DiskRead {
+        // sort by the bytes read since the last refresh, the total bytes read will be used when it was equal
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .current_read_disk_usage
+                .cmp(&b.current_read_disk_usage)
+                .then(a.total_read_disk_usage.cmp(&b.total_read_disk_usage));
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::DiskWrite {
+        // sort by the bytes written since the last refresh, the total bytes written will be used when it was equal
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .current_write_disk_usage
+                .cmp(&b.current_write_disk_usage)
+                .then(a.total_write_disk_usage.cmp(&b.total_write_disk_usage));
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::Elapsed {
+        processes.sort_by(|a, b| {
+            let ordering = a.elapsed.cmp(&b.elapsed);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::VirtualMemory {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .virtual_memory
+                .partial_cmp(&b.virtual_memory)
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::SharedMemory {
+        // process without the shared memory info will be treated as 0
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .shared_memory
+                .unwrap_or(0.0)
+                .partial_cmp(&b.shared_memory.unwrap_or(0.0))
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::OomScore {
+        // process without the oom score will be treated as the least likely to be killed
+        processes.sort_by(|a, b| {
+            let a_oom_score = a.oom_score.map(|(score, _)| score).unwrap_or(-1);
+            let b_oom_score = b.oom_score.map(|(score, _)| score).unwrap_or(-1);
+            let ordering = a_oom_score.cmp(&b_oom_score);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::StartTime {
+        // the process started earlier has been running for longer, so the elapsed time was compared the other way
+        processes.sort_by(|a, b| {
+            let ordering = b.elapsed.cmp(&a.elapsed);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::CpuTime {
+        // sort by the cpu time used since the process was started, so the long running hogs can be told apart
+        // from the short spikes of the cpu usage
+        processes.sort_by(|a, b| {
+            let ordering = a.cpu_time.cmp(&b.cpu_time);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::Nice {
+        // process without the nice value will be treated as the normal priority
+        // the higher nice value was the lower priority, so the deprioritized processes come first by default
+        processes.sort_by(|a, b| {
+            let ordering = a.nice.unwrap_or(0).cmp(&b.nice.unwrap_or(0));
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    }
+    return processes;
+}
 
-    let info = Line::from(vec![Span::styled(
-        pop_up_type.get_string_name(),
-        Style::default().fg(app_color_info.app_title_color).bold(),
-    )]);
+// the signals in the signal menu were from 0 to this id, see `get_signal_from_int`
+pub const MAX_SIGNAL_ID: u16 = 30;
 
-    let pop_up_blur_block = Block::new().style(Style::default().bg(app_color_info.pop_up_blur_bg));
+// how many children of the process will be listed in the kill or terminate confirmation
+const POP_UP_MAX_LISTED_CHILDREN: usize = 3;
 
-    let pop_up_block = Block::bordered()
-        .title(info.left_aligned())
-        .style(
-            Style::reset()
-                .bg(app_color_info.background_color)
-                .fg(app_color_info.background_color),
-        )
-        .border_style(app_color_info.pop_up_color)
-        .border_set(border::ROUNDED);
+pub fn render_pop_up_menu(
+    area: Rect,
+    frame: &mut Frame,
+    pop_up_type: &mut AppPopUpType,
+    current_process_signal_state_data: &CurrentProcessSignalStateData,
+    process_data: &HashMap<String, ProcessData>,
+    signal_menu_selected_state: &mut ListState,
+    app_color_info: &AppColorInfo,
+) {
+    let pop_up_dimension: (u16, u16) = if *pop_up_type == AppPopUpType::KillConfirmation
+        || *pop_up_type == AppPopUpType::TerminateConfirmation
+    {
+        (60, 15)
+    } else {
+        (80.min(area.width), 24.min(area.height))
+    };
 
-    // Render the pop-up block second (centered)
-    frame.render_widget(pop_up_blur_block, frame.area());
-    frame.render_widget(pop_up_block, pop_up);
+    let pop_up =
+        render_pop_up_container(area, frame, pop_up_type, pop_up_dimension, app_color_info);
 
     // for kill or termination signal pop up
     if *pop_up_type == AppPopUpType::KillConfirmation
@@ -618,10 +1383,12 @@ pub fn render_pop_up_menu(
             Constraint::Fill(1),
         ])
         .areas(pop_up);
-        let [_, info_layout, _, button_layout, _] = Layout::vertical(vec![
+        let [_, info_layout, _, children_layout, _, button_layout, _] = Layout::vertical(vec![
             Constraint::Fill(1),
             Constraint::Length(2),
             Constraint::Length(1),
+            Constraint::Length(POP_UP_MAX_LISTED_CHILDREN as u16 + 1),
+            Constraint::Length(1),
             Constraint::Length(3),
             Constraint::Fill(1),
         ])
@@ -629,8 +1396,12 @@ pub fn render_pop_up_menu(
 
         let [signal_info, pid_info] =
             Layout::vertical(vec![Constraint::Length(1), Constraint::Length(1)]).areas(info_layout);
-        let [yes_button_layout, no_button_layout] =
-            Layout::horizontal(vec![Constraint::Fill(1), Constraint::Fill(1)]).areas(button_layout);
+        let [yes_button_layout, tree_button_layout, no_button_layout] = Layout::horizontal(vec![
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(button_layout);
 
         let signal_type = if *pop_up_type == AppPopUpType::KillConfirmation {
             Span::styled("KILL", Style::default().fg(app_color_info.key_text_color))
@@ -667,6 +1438,59 @@ pub fn render_pop_up_menu(
         frame.render_widget(signal_info_line, signal_info);
         frame.render_widget(pid_info_line, pid_info);
 
+        // the children of the process, so it can be seen whether the kill will orphan them
+        let child_pids = get_child_pids(&current_process_signal_state_data.pid, process_data);
+        let descendant_count =
+            get_descendant_pids(&current_process_signal_state_data.pid, process_data).len();
+        let children_count_text = if child_pids.is_empty() {
+            "none".to_string()
+        } else if descendant_count > child_pids.len() {
+            format!("{} ( {} in the tree )", child_pids.len(), descendant_count)
+        } else {
+            format!("{}", child_pids.len())
+        };
+        let mut children_lines = vec![Line::from(vec![
+            Span::styled(
+                "CHILDREN: ",
+                Style::default().fg(app_color_info.base_app_text_color),
+            )
+            .bold(),
+            Span::styled(
+                children_count_text,
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+        ])];
+        for (index, child_pid) in child_pids.iter().enumerate() {
+            // the last line tells how many children were not listed
+            if index == POP_UP_MAX_LISTED_CHILDREN - 1
+                && child_pids.len() > POP_UP_MAX_LISTED_CHILDREN
+            {
+                children_lines.push(Line::from(Span::styled(
+                    format!(
+                        "  ... and {} more",
+                        child_pids.len() - POP_UP_MAX_LISTED_CHILDREN + 1
+                    ),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                )));
+                break;
+            }
+            let child_name = process_data
+                .get(child_pid)
+                .map(|child| child.name.clone())
+                .unwrap_or_default();
+            children_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", child_pid),
+                    Style::default().fg(app_color_info.key_text_color),
+                ),
+                Span::styled(
+                    format!("({})", child_name),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+            ]));
+        }
+        frame.render_widget(Paragraph::new(children_lines), children_layout);
+
         // yes button confimation
         let [_, padded_yes_button_layout, _] = Layout::horizontal(vec![
             Constraint::Fill(1),
@@ -705,6 +1529,44 @@ pub fn render_pop_up_menu(
             Style::default().fg(app_color_info.base_app_text_color),
         ));
 
+        // tree button confirmation, send the signal to the process and all its descendants
+        let [_, padded_tree_button_layout, _] = Layout::horizontal(vec![
+            Constraint::Fill(1),
+            Constraint::Length(15),
+            Constraint::Fill(1),
+        ])
+        .areas(tree_button_layout);
+        let [_, ppadded_tree_button_layout, _] = Layout::horizontal(vec![
+            Constraint::Length(1),
+            Constraint::Length(13),
+            Constraint::Length(1),
+        ])
+        .areas(padded_tree_button_layout);
+        let [_, tree_button_line_text_layout, _] = Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(ppadded_tree_button_layout);
+
+        let mut tree_button_block = Block::bordered()
+            .style(app_color_info.background_color)
+            .border_style(app_color_info.pop_up_color)
+            .border_set(border::ROUNDED);
+
+        if current_process_signal_state_data.tree_confirmation {
+            tree_button_block = tree_button_block.border_style(app_color_info.key_text_color);
+        }
+
+        let tree_button_line = Line::from(Span::styled(
+            format!(
+                "{:^width$}",
+                "Tree (T/t)".to_string(),
+                width = tree_button_line_text_layout.width as usize
+            ),
+            Style::default().fg(app_color_info.base_app_text_color),
+        ));
+
         // no button confirmation
         let [_, padded_no_button_layout, _] = Layout::horizontal(vec![
             Constraint::Fill(1),
@@ -746,6 +1608,9 @@ pub fn render_pop_up_menu(
         frame.render_widget(yes_button_block, padded_yes_button_layout);
         frame.render_widget(yes_button_line, yes_button_line_text_layout);
 
+        frame.render_widget(tree_button_block, padded_tree_button_layout);
+        frame.render_widget(tree_button_line, tree_button_line_text_layout);
+
         frame.render_widget(no_button_block, padded_no_button_layout);
         frame.render_widget(no_button_line, no_button_line_text_layout);
     } else if *pop_up_type == AppPopUpType::SignalMenu {
@@ -760,10 +1625,10 @@ pub fn render_pop_up_menu(
                 Constraint::Length(2),
                 Constraint::Length(4),
                 Constraint::Length(1),
-                Constraint::Length(6),
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Length(4),
                 Constraint::Length(1),
-                Constraint::Length(3),
-                Constraint::Length(3),
             ])
             .areas(padded_pop_up);
 
@@ -789,377 +1654,646 @@ pub fn render_pop_up_menu(
             .bold(),
         ]);
 
-        // which signal information
-        let signal_info_line = Line::from(vec![Span::styled(
-            format!(
-                "Enter Signal ID: {}_",
-                if current_process_signal_state_data.signal_id.is_none() {
-                    "".to_string()
-                } else {
-                    current_process_signal_state_data
-                        .signal_id
-                        .unwrap()
-                        .to_string()
+        // which signal information, the typed signal name was followed by the rest of the completed name
+        let signal_info_line = if !current_process_signal_state_data
+            .signal_name_input
+            .is_empty()
+        {
+            let signal_name_input = &current_process_signal_state_data.signal_name_input;
+            let completion = match current_process_signal_state_data.signal {
+                Some(signal) => {
+                    let signal_name = signal.get_display_name();
+                    let short_signal_name = signal_name[3..].to_string();
+                    if short_signal_name.starts_with(signal_name_input.as_str()) {
+                        short_signal_name[signal_name_input.len()..].to_string()
+                    } else if signal_name.starts_with(signal_name_input.as_str()) {
+                        signal_name[signal_name_input.len()..].to_string()
+                    } else {
+                        "".to_string()
+                    }
                 }
-            ),
-            Style::default().fg(app_color_info.base_app_text_color),
-        )]);
+                None => " ( no match )".to_string(),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("Enter Signal ID or Name: {}", signal_name_input),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(completion, Style::default().fg(app_color_info.pop_up_color)),
+                Span::styled("_", Style::default().fg(app_color_info.base_app_text_color)),
+            ])
+        } else {
+            Line::from(vec![Span::styled(
+                format!(
+                    "Enter Signal ID or Name: {}_",
+                    match current_process_signal_state_data.signal_id {
+                        Some(signal_id) => signal_id.to_string(),
+                        None => "".to_string(),
+                    }
+                ),
+                Style::default().fg(app_color_info.base_app_text_color),
+            )])
+        };
 
         frame.render_widget(pid_info_line, pid_layout);
         frame.render_widget(signal_info_line, signal_layout);
 
-        // seperate the signal menu into 6 part in vertical
-        let [signal_menu_1, signal_menu_2, signal_menu_3, signal_menu_4, signal_menu_5, signal_menu_6] =
-            Layout::vertical(vec![
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Length(2),
-            ])
-            .areas(signal_menu_layout);
-
-        // each signal vertical line will be split into 5 parts ( so will be a total of 30 (6*5) )
-        //
-        // Signal Menu FIRST PART
-        let [signal_menu_1_1_layout, signal_menu_1_2_layout, signal_menu_1_3_layout, signal_menu_1_4_layout, signal_menu_1_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-            ])
-            .areas(signal_menu_1);
-
-        render_signal_menu_choice_selection(
-            1,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_1_1_layout,
-            frame,
-        );
-        render_signal_menu_choice_selection(
-            2,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_1_2_layout,
-            frame,
-        );
-
-        render_signal_menu_choice_selection(
-            3,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_1_3_layout,
-            frame,
-        );
-
-        render_signal_menu_choice_selection(
-            4,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_1_4_layout,
-            frame,
+        // all the signals with their ids and descriptions, the selected signal was kept in view
+        let signal_items: Vec<ListItem> = (0..=MAX_SIGNAL_ID)
+            .map(|signal_id| {
+                let signal = get_signal_from_int(signal_id);
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<4}", signal_id),
+                        Style::default().fg(app_color_info.key_text_color),
+                    ),
+                    Span::styled(
+                        format!("{:<11}", signal.get_display_name()),
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    ),
+                    Span::styled(
+                        signal.get_description(),
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    ),
+                ]))
+            })
+            .collect();
+        let signal_list = List::new(signal_items).highlight_style(
+            Style::default()
+                .bg(app_color_info.pop_up_selected_color_bg)
+                .bold(),
         );
-
-        render_signal_menu_choice_selection(
-            5,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_1_5_layout,
-            frame,
+        signal_menu_selected_state.select(
+            current_process_signal_state_data
+                .signal_id
+                .map(|signal_id| signal_id as usize),
         );
+        frame.render_stateful_widget(signal_list, signal_menu_layout, signal_menu_selected_state);
 
-        // Signal Menu SECOND PART
-        let [signal_menu_2_1_layout, signal_menu_2_2_layout, signal_menu_2_3_layout, signal_menu_2_4_layout, signal_menu_2_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
+        let [instruction_line_1_layout, instruction_line_2_layout, instruction_line_3_layout, instruction_line_4_layout] =
+            Layout::vertical(vec![
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
             ])
-            .areas(signal_menu_2);
-
-        render_signal_menu_choice_selection(
-            6,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_2_1_layout,
-            frame,
-        );
+            .areas(instruction_layout);
 
-        render_signal_menu_choice_selection(
-            7,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_2_2_layout,
-            frame,
-        );
+        let instruction_line_1 = Line::from(vec![
+            Span::styled(
+                "0~9 A~Z ",
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+            Span::styled(
+                "| Enter Signal ID or Name",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+        ]);
+        let instruction_line_2 = Line::from(vec![
+            Span::styled(
+                "↑↓      ",
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+            Span::styled(
+                "| Select Signal",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+        ]);
+        let instruction_line_3 = Line::from(vec![
+            Span::styled(
+                "ENTER   ",
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+            Span::styled(
+                "| Send Signal",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+        ]);
+        let instruction_line_4 = Line::from(vec![
+            Span::styled(
+                "ESC     ",
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+            Span::styled(
+                "| Abort Current Action",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+        ]);
 
-        render_signal_menu_choice_selection(
-            8,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_2_3_layout,
-            frame,
-        );
+        frame.render_widget(instruction_line_1, instruction_line_1_layout);
+        frame.render_widget(instruction_line_2, instruction_line_2_layout);
+        frame.render_widget(instruction_line_3, instruction_line_3_layout);
+        frame.render_widget(instruction_line_4, instruction_line_4_layout);
+    }
+}
 
-        render_signal_menu_choice_selection(
-            9,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_2_4_layout,
-            frame,
-        );
+// render the blurred background and the bordered pop up block, returning the area of the pop up
+fn render_pop_up_container(
+    area: Rect,
+    frame: &mut Frame,
+    pop_up_type: &AppPopUpType,
+    pop_up_dimension: (u16, u16),
+    app_color_info: &AppColorInfo,
+) -> Rect {
+    let [_, pop_up_width, _] = Layout::horizontal(vec![
+        Constraint::Fill(1),
+        Constraint::Length(pop_up_dimension.0),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
 
-        render_signal_menu_choice_selection(
-            10,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_2_5_layout,
-            frame,
-        );
+    let [_, pop_up, _] = Layout::vertical(vec![
+        Constraint::Fill(1),
+        Constraint::Length(pop_up_dimension.1),
+        Constraint::Fill(1),
+    ])
+    .areas(pop_up_width);
 
-        // Signal Menu THIRD PART
-        let [signal_menu_3_1_layout, signal_menu_3_2_layout, signal_menu_3_3_layout, signal_menu_3_4_layout, signal_menu_3_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-            ])
-            .areas(signal_menu_3);
-
-        render_signal_menu_choice_selection(
-            11,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_3_1_layout,
-            frame,
-        );
+    let info = Line::from(vec![Span::styled(
+        pop_up_type.get_string_name(),
+        Style::default().fg(app_color_info.app_title_color).bold(),
+    )]);
 
-        render_signal_menu_choice_selection(
-            12,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_3_2_layout,
-            frame,
-        );
+    let pop_up_blur_block = Block::new().style(Style::default().bg(app_color_info.pop_up_blur_bg));
 
-        render_signal_menu_choice_selection(
-            13,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_3_3_layout,
-            frame,
-        );
+    let pop_up_block = Block::bordered()
+        .title(info.left_aligned())
+        .style(
+            Style::reset()
+                .bg(app_color_info.background_color)
+                .fg(app_color_info.background_color),
+        )
+        .border_style(app_color_info.pop_up_color)
+        .border_set(border::ROUNDED);
 
-        render_signal_menu_choice_selection(
-            14,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_3_4_layout,
-            frame,
-        );
+    // Render the pop-up block second (centered)
+    frame.render_widget(pop_up_blur_block, frame.area());
+    frame.render_widget(pop_up_block, pop_up);
 
-        render_signal_menu_choice_selection(
-            15,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_3_5_layout,
-            frame,
-        );
+    pop_up
+}
 
-        // Signal Menu FORTH PART
-        let [signal_menu_4_1_layout, signal_menu_4_2_layout, signal_menu_4_3_layout, signal_menu_4_4_layout, signal_menu_4_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-            ])
-            .areas(signal_menu_4);
-
-        render_signal_menu_choice_selection(
-            16,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_4_1_layout,
-            frame,
-        );
+// pop up for choosing the theme, the theme was previewed as user navigate through the list
+pub fn render_theme_menu_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    theme_names: &[String],
+    theme_selected_state: &mut ListState,
+    app_color_info: &AppColorInfo,
+) {
+    let pop_up = render_pop_up_container(
+        area,
+        frame,
+        &AppPopUpType::ThemeMenu,
+        (40.min(area.width), 21.min(area.height)),
+        app_color_info,
+    );
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .areas(pop_up);
+    let [_, theme_list_layout, _, instruction_layout, _] = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(4),
+        Constraint::Length(1),
+    ])
+    .areas(padded_pop_up);
 
-        render_signal_menu_choice_selection(
-            17,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_4_2_layout,
-            frame,
-        );
+    let theme_items: Vec<ListItem> = theme_names
+        .iter()
+        .map(|theme| {
+            ListItem::new(Line::from(Span::styled(
+                theme.to_string(),
+                Style::default().fg(app_color_info.base_app_text_color),
+            )))
+        })
+        .collect();
 
-        render_signal_menu_choice_selection(
-            18,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_4_3_layout,
-            frame,
-        );
+    let theme_list = List::new(theme_items)
+        .highlight_style(
+            Style::default()
+                .fg(app_color_info.key_text_color)
+                .bg(app_color_info.pop_up_selected_color_bg)
+                .bold(),
+        )
+        .highlight_symbol(">> ");
 
-        render_signal_menu_choice_selection(
-            19,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_4_4_layout,
-            frame,
-        );
+    frame.render_stateful_widget(theme_list, theme_list_layout, theme_selected_state);
 
-        render_signal_menu_choice_selection(
-            20,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_4_5_layout,
-            frame,
-        );
+    let [instruction_line_1_layout, instruction_line_2_layout, instruction_line_3_layout, instruction_line_4_layout] =
+        Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(instruction_layout);
 
-        // Signal Menu FIFTH PART
-        let [signal_menu_5_1_layout, signal_menu_5_2_layout, signal_menu_5_3_layout, signal_menu_5_4_layout, signal_menu_5_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-            ])
-            .areas(signal_menu_5);
-
-        render_signal_menu_choice_selection(
-            21,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_5_1_layout,
-            frame,
-        );
+    let instruction_line_1 = Line::from(vec![
+        Span::styled("↑/↓   ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Preview Theme",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_2 = Line::from(vec![
+        Span::styled("ENTER ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Apply Theme",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_3 = Line::from(vec![
+        Span::styled("E     ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Create Theme From Preview",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_4 = Line::from(vec![
+        Span::styled("ESC   ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Abort Current Action",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
 
-        render_signal_menu_choice_selection(
-            22,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_5_2_layout,
-            frame,
-        );
+    frame.render_widget(instruction_line_1, instruction_line_1_layout);
+    frame.render_widget(instruction_line_2, instruction_line_2_layout);
+    frame.render_widget(instruction_line_3, instruction_line_3_layout);
+    frame.render_widget(instruction_line_4, instruction_line_4_layout);
+}
 
-        render_signal_menu_choice_selection(
-            23,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_5_3_layout,
-            frame,
-        );
+// pop up for restricting the process list to a single user, the first entry was for showing all the users
+pub fn render_user_menu_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    user_names: &[String],
+    user_selected_state: &mut ListState,
+    is_redacted: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let pop_up = render_pop_up_container(
+        area,
+        frame,
+        &AppPopUpType::UserMenu,
+        (40.min(area.width), 21.min(area.height)),
+        app_color_info,
+    );
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .areas(pop_up);
+    let [_, user_list_layout, _, instruction_layout, _] = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(padded_pop_up);
+
+    let mut user_items: Vec<ListItem> = vec![ListItem::new(Line::from(Span::styled(
+        "All Users",
+        Style::default().fg(app_color_info.base_app_text_color),
+    )))];
+    user_items.extend(user_names.iter().map(|user| {
+        ListItem::new(Line::from(Span::styled(
+            redact_user(user, is_redacted),
+            Style::default().fg(app_color_info.base_app_text_color),
+        )))
+    }));
+
+    let user_list = List::new(user_items)
+        .highlight_style(
+            Style::default()
+                .fg(app_color_info.key_text_color)
+                .bg(app_color_info.pop_up_selected_color_bg)
+                .bold(),
+        )
+        .highlight_symbol(">> ");
 
-        render_signal_menu_choice_selection(
-            24,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_5_4_layout,
-            frame,
-        );
+    frame.render_stateful_widget(user_list, user_list_layout, user_selected_state);
 
-        render_signal_menu_choice_selection(
-            25,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_5_5_layout,
-            frame,
-        );
+    let [instruction_line_1_layout, instruction_line_2_layout, instruction_line_3_layout] =
+        Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(instruction_layout);
 
-        // Signal Menu SIXTH PART
-        let [signal_menu_6_1_layout, signal_menu_6_2_layout, signal_menu_6_3_layout, signal_menu_6_4_layout, signal_menu_6_5_layout] =
-            Layout::horizontal(vec![
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-            ])
-            .areas(signal_menu_6);
-
-        render_signal_menu_choice_selection(
-            26,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_6_1_layout,
-            frame,
-        );
+    let instruction_line_1 = Line::from(vec![
+        Span::styled("↑/↓   ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Select User",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_2 = Line::from(vec![
+        Span::styled("ENTER ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Filter By User",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_3 = Line::from(vec![
+        Span::styled("ESC   ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Abort Current Action",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
 
-        render_signal_menu_choice_selection(
-            27,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_6_2_layout,
-            frame,
-        );
+    frame.render_widget(instruction_line_1, instruction_line_1_layout);
+    frame.render_widget(instruction_line_2, instruction_line_2_layout);
+    frame.render_widget(instruction_line_3, instruction_line_3_layout);
+}
 
-        render_signal_menu_choice_selection(
-            28,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_6_3_layout,
-            frame,
-        );
+// pop up for typing the PID to jump to
+pub fn render_go_to_pid_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    go_to_pid_input: &str,
+    app_color_info: &AppColorInfo,
+) {
+    let pop_up = render_pop_up_container(
+        area,
+        frame,
+        &AppPopUpType::GoToPid,
+        (40.min(area.width), 7.min(area.height)),
+        app_color_info,
+    );
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .areas(pop_up);
+    let [_, input_layout, _, instruction_line_1_layout, instruction_line_2_layout] =
+        Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(padded_pop_up);
 
-        render_signal_menu_choice_selection(
-            29,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_6_4_layout,
-            frame,
-        );
+    let input_line = Line::from(vec![
+        Span::styled("PID: ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            format!("{}_", go_to_pid_input),
+            Style::default()
+                .fg(app_color_info.base_app_text_color)
+                .bold(),
+        ),
+    ]);
+    let instruction_line_1 = Line::from(vec![
+        Span::styled("ENTER ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Show Process Detail",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
+    let instruction_line_2 = Line::from(vec![
+        Span::styled("ESC   ", Style::default().fg(app_color_info.key_text_color)),
+        Span::styled(
+            "| Abort Current Action",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ]);
 
-        render_signal_menu_choice_selection(
-            30,
-            current_process_signal_state_data.signal_id,
-            app_color_info,
-            signal_menu_6_5_layout,
-            frame,
-        );
+    frame.render_widget(input_line, input_layout);
+    frame.render_widget(instruction_line_1, instruction_line_1_layout);
+    frame.render_widget(instruction_line_2, instruction_line_2_layout);
+}
 
-        let [instruction_line_1_layout, instruction_line_2_layout, instruction_line_3_layout] =
-            Layout::vertical(vec![
-                Constraint::Length(1),
-                Constraint::Length(1),
-                Constraint::Length(1),
-            ])
-            .areas(instruction_layout);
+// a short message shown at the bottom of the screen, eg: to notify the user that an action failed
+pub fn render_toast(area: Rect, frame: &mut Frame, message: &str, app_color_info: &AppColorInfo) {
+    let toast_width = (message.chars().count() as u16 + 4).min(area.width);
+    let [_, toast_horizontal, _] = Layout::horizontal(vec![
+        Constraint::Fill(1),
+        Constraint::Length(toast_width),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    let [_, toast, _] = Layout::vertical(vec![
+        Constraint::Fill(1),
+        Constraint::Length(3),
+        Constraint::Length(2),
+    ])
+    .areas(toast_horizontal);
 
-        let instruction_line_1 = Line::from(vec![
-            Span::styled("0~9   ", Style::default().fg(app_color_info.key_text_color)),
+    let toast_block = Block::bordered()
+        .style(
+            Style::reset()
+                .bg(app_color_info.background_color)
+                .fg(app_color_info.key_text_color),
+        )
+        .border_style(app_color_info.key_text_color)
+        .border_set(border::ROUNDED);
+    let toast_message =
+        Paragraph::new(Line::from(message.to_string()).centered()).block(toast_block);
+
+    frame.render_widget(Clear, toast);
+    frame.render_widget(toast_message, toast);
+}
+
+// pop up for creating a new theme, user walk through every AppColorInfo field and pick a color
+// from the 256 colors palette, the app_color_info passed in is the theme that is being created
+pub fn render_theme_wizard_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    theme_wizard_state: &mut ThemeWizardState,
+    app_color_info: &AppColorInfo,
+) {
+    let pop_up = render_pop_up_container(
+        area,
+        frame,
+        &AppPopUpType::ThemeWizard,
+        (80.min(area.width), 24.min(area.height)),
+        app_color_info,
+    );
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(pop_up);
+    let [_, wizard_layout, _, instruction_layout, _] = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(padded_pop_up);
+
+    let [field_list_layout, _, palette_layout] = Layout::horizontal(vec![
+        Constraint::Fill(1),
+        Constraint::Length(2),
+        Constraint::Length(32),
+    ])
+    .areas(wizard_layout);
+
+    // ------------------------------------------------------------
+    // list of AppColorInfo fields with the current color
+    // ------------------------------------------------------------
+    let field_items: Vec<ListItem> = APP_COLOR_INFO_FIELDS
+        .iter()
+        .map(|field| {
+            let color = app_color_info.get_color(field).unwrap_or(Color::Reset);
+            ListItem::new(Line::from(vec![
+                Span::styled("██ ", Style::default().fg(color)),
+                Span::styled(
+                    field.to_string(),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut field_list_highlight_style = Style::default()
+        .fg(app_color_info.key_text_color)
+        .bg(app_color_info.pop_up_selected_color_bg)
+        .bold();
+    if theme_wizard_state.is_palette_focused {
+        field_list_highlight_style = field_list_highlight_style.add_modifier(Modifier::DIM);
+    }
+    let field_list = List::new(field_items)
+        .highlight_style(field_list_highlight_style)
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(
+        field_list,
+        field_list_layout,
+        &mut theme_wizard_state.field_selected_state,
+    );
+
+    // ------------------------------------------------------------
+    // 256 colors palette, 16 colors per row
+    // ------------------------------------------------------------
+    let [palette_title_layout, palette_grid_layout, _] = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Length(16),
+        Constraint::Fill(1),
+    ])
+    .areas(palette_layout);
+
+    let palette_title = if theme_wizard_state.is_palette_focused {
+        format!("Color: {}", theme_wizard_state.palette_selected_index)
+    } else {
+        "Palette ( TAB to choose )".to_string()
+    };
+    frame.render_widget(
+        Line::from(Span::styled(
+            palette_title,
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        )),
+        palette_title_layout,
+    );
+
+    let palette_row_layouts =
+        Layout::vertical(vec![Constraint::Length(1); 16]).split(palette_grid_layout);
+    for row in 0..16u16 {
+        let cells: Vec<Span> = (0..16u16)
+            .map(|column| {
+                let index = (row * 16 + column) as u8;
+                if theme_wizard_state.is_palette_focused
+                    && index == theme_wizard_state.palette_selected_index
+                {
+                    Span::styled(
+                        "[]",
+                        Style::default()
+                            .fg(app_color_info.key_text_color)
+                            .bg(Color::Indexed(index))
+                            .bold(),
+                    )
+                } else {
+                    Span::styled("  ", Style::default().bg(Color::Indexed(index)))
+                }
+            })
+            .collect();
+        frame.render_widget(Line::from(cells), palette_row_layouts[row as usize]);
+    }
+
+    // ------------------------------------------------------------
+    // instruction or the theme name input
+    // ------------------------------------------------------------
+    let [instruction_line_1_layout, instruction_line_2_layout, instruction_line_3_layout] =
+        Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(instruction_layout);
+
+    let instructions: Vec<(&str, &str)> = if theme_wizard_state.is_naming {
+        vec![
+            ("ENTER    ", "| Save And Apply Theme"),
+            ("ESC      ", "| Back To Editing"),
+        ]
+    } else if theme_wizard_state.is_palette_focused {
+        vec![
+            ("←/→/↑/↓  ", "| Preview Color"),
+            ("ENTER    ", "| Confirm Color"),
+            ("ESC      ", "| Discard Color"),
+        ]
+    } else {
+        vec![
+            ("↑/↓ TAB  ", "| Select Field | Choose Color"),
+            ("S        ", "| Save Theme"),
+            ("ESC      ", "| Abort Current Action"),
+        ]
+    };
+
+    let mut instruction_lines: Vec<Line> = Vec::new();
+    if theme_wizard_state.is_naming {
+        instruction_lines.push(Line::from(vec![
             Span::styled(
-                "| Enter Signal ID",
-                Style::default().fg(app_color_info.base_app_text_color),
+                "Name: ",
+                Style::default().fg(app_color_info.app_title_color).bold(),
             ),
-        ]);
-        let instruction_line_2 = Line::from(vec![
-            Span::styled("ENTER ", Style::default().fg(app_color_info.key_text_color)),
             Span::styled(
-                "| Send Signal",
+                format!("{}_", theme_wizard_state.theme_name),
                 Style::default().fg(app_color_info.base_app_text_color),
             ),
-        ]);
-        let instruction_line_3 = Line::from(vec![
-            Span::styled("ESC   ", Style::default().fg(app_color_info.key_text_color)),
             Span::styled(
-                "| Abort Current Action",
+                match theme_wizard_state.message.as_ref() {
+                    Some(message) => format!("  {}", message),
+                    None => "".to_string(),
+                },
+                Style::default().fg(app_color_info.key_text_color),
+            ),
+        ]));
+    }
+    for (key, instruction) in instructions {
+        instruction_lines.push(Line::from(vec![
+            Span::styled(key, Style::default().fg(app_color_info.key_text_color)),
+            Span::styled(
+                instruction,
                 Style::default().fg(app_color_info.base_app_text_color),
             ),
-        ]);
+        ]));
+    }
 
-        frame.render_widget(instruction_line_1, instruction_line_1_layout);
-        frame.render_widget(instruction_line_2, instruction_line_2_layout);
-        frame.render_widget(instruction_line_3, instruction_line_3_layout);
+    for (line, line_layout) in instruction_lines.into_iter().zip([
+        instruction_line_1_layout,
+        instruction_line_2_layout,
+        instruction_line_3_layout,
+    ]) {
+        frame.render_widget(line, line_layout);
     }
 }
 
@@ -1172,6 +2306,51 @@ pub fn send_signal(pid: usize, signal: Signal) {
     });
 }
 
+// send the signal to the processes one by one in the given order
+pub fn send_signal_to_pids(pids: Vec<usize>, signal: Signal) {
+    thread::spawn(move || {
+        let s = System::new_all();
+        for pid in pids {
+            if let Some(process) = s.process(Pid::from(pid)) {
+                process.kill_with(signal);
+            }
+        }
+    });
+}
+
+// copy the text to the clipboard of the terminal with the OSC 52 escape sequence, which also works over ssh
+// printed between the frames, so the terminal receives it on its own
+pub fn copy_to_clipboard(text: &str) {
+    print!("{}", get_clipboard_sequence(text));
+    let _ = io::stdout().flush();
+}
+
+// the OSC 52 escape sequence with the base64 encoded text, eg: "\x1b]52;c;aGVsbG8=\x07"
+pub fn get_clipboard_sequence(text: &str) -> String {
+    const BASE64_CHARS: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::new();
+    for chunk in text.as_bytes().chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let triple = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        // a chunk of n bytes was encoded into n + 1 characters, the rest was padded with "="
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(BASE64_CHARS[(triple >> (18 - index * 6) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    return format!("\x1b]52;c;{}\x07", encoded);
+}
+
 pub fn get_signal_from_int(int: u16) -> Signal {
     match int {
         0 => Signal::Hangup,
@@ -1209,68 +2388,20 @@ pub fn get_signal_from_int(int: u16) -> Signal {
     }
 }
 
-fn render_signal_menu_choice_selection(
-    signal_id: u16,
-    current_selected_signal_id: Option<u16>,
-    app_color_info: &AppColorInfo,
-    area: Rect,
-    frame: &mut Frame,
-) {
-    let area_width = area.width as usize;
-    let signal_id_span = if current_selected_signal_id.is_some() {
-        if current_selected_signal_id.unwrap() == signal_id {
-            Span::styled(
-                format!("{:<width$}", signal_id, width = 3),
-                Style::default()
-                    .fg(app_color_info.key_text_color)
-                    .bg(app_color_info.pop_up_selected_color_bg),
-            )
-        } else {
-            Span::styled(
-                format!("{:<width$}", signal_id, width = 3),
-                Style::default().fg(app_color_info.key_text_color),
-            )
-        }
-    } else {
-        Span::styled(
-            format!("{:<width$}", signal_id, width = 3),
-            Style::default().fg(app_color_info.key_text_color),
-        )
-    };
-    let signal_name_span = if current_selected_signal_id.is_some() {
-        if current_selected_signal_id.unwrap() == signal_id {
-            Span::styled(
-                format!(
-                    "{:width$}",
-                    get_signal_from_int(signal_id).get_display_name(),
-                    width = area_width - 3
-                ),
-                Style::default()
-                    .fg(app_color_info.base_app_text_color)
-                    .bg(app_color_info.pop_up_selected_color_bg),
-            )
-        } else {
-            Span::styled(
-                format!(
-                    "{:width$}",
-                    get_signal_from_int(signal_id).get_display_name(),
-                    width = area_width - 3
-                ),
-                Style::default().fg(app_color_info.base_app_text_color),
-            )
-        }
-    } else {
-        Span::styled(
-            format!(
-                "{:width$}",
-                get_signal_from_int(signal_id).get_display_name(),
-                width = area_width - 3
-            ),
-            Style::default().fg(app_color_info.base_app_text_color),
-        )
-    };
-
-    let signal_menu_choice = Line::from(vec![signal_id_span, signal_name_span]);
-
-    frame.render_widget(signal_menu_choice, area);
+// the id of the first signal whose name starts with the typed name, eg: "HU" or "SIGHU" for SIGHUP
+// the name without the "SIG" prefix was matched first, so "S" will be SIGSEGV instead of SIGINT
+pub fn get_signal_id_from_name(name: &str) -> Option<u16> {
+    let name = name.to_uppercase();
+    let signal_names: Vec<(u16, String)> = (0..=MAX_SIGNAL_ID)
+        .map(|signal_id| (signal_id, get_signal_from_int(signal_id).get_display_name()))
+        .collect();
+    return signal_names
+        .iter()
+        .find(|(_, signal_name)| signal_name[3..].starts_with(&name))
+        .or_else(|| {
+            signal_names
+                .iter()
+                .find(|(_, signal_name)| signal_name.starts_with(&name))
+        })
+        .map(|(signal_id, _)| *signal_id);
 }